@@ -0,0 +1,342 @@
+use garden_maths::{
+    matrices::Mat4,
+    vectors::{Vec2, Vec3},
+};
+
+use crate::{
+    viewport::{GetScaleMode, ScaleMode, Viewport},
+    GetBackgroundColour, GetHeight, GetWidth,
+};
+
+pub trait GetViewProjection {
+    fn get_view_projection(&self, viewport_width: f32, viewport_height: f32) -> Mat4;
+}
+
+pub struct Camera {
+    position: Vec2,
+    zoom: f32,
+    rotation: f32,
+    width: f32,
+    height: f32,
+    scale_mode: ScaleMode,
+    background_colour: Option<(f32, f32, f32)>,
+}
+
+impl Camera {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            position: Vec2::ZERO,
+            zoom: 1.0,
+            rotation: 0.0,
+            width,
+            height,
+            scale_mode: ScaleMode::default(),
+            background_colour: None,
+        }
+    }
+
+    pub fn from_scene<TScene: GetWidth + GetHeight + GetScaleMode + GetBackgroundColour>(
+        scene: &TScene,
+    ) -> Self {
+        let mut camera = Self::default();
+        camera.set_scene(scene);
+
+        camera
+    }
+
+    pub fn set_scene<TScene: GetWidth + GetHeight + GetScaleMode + GetBackgroundColour>(
+        &mut self,
+        scene: &TScene,
+    ) {
+        self.set_size(scene.get_width(), scene.get_height());
+        self.set_scale_mode(scene.get_scale_mode());
+        self.background_colour = scene.get_background_colour();
+    }
+
+    pub fn get_position(&self) -> Vec2 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: Vec2) {
+        self.position = position;
+    }
+
+    pub fn get_zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+    }
+
+    pub fn get_rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+    }
+
+    pub fn set_size(&mut self, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn get_scale_mode(&self) -> ScaleMode {
+        self.scale_mode
+    }
+
+    pub fn set_scale_mode(&mut self, scale_mode: ScaleMode) {
+        self.scale_mode = scale_mode;
+    }
+
+    pub fn get_background_colour(&self) -> Option<(f32, f32, f32)> {
+        self.background_colour
+    }
+
+    pub fn set_background_colour(&mut self, background_colour: Option<(f32, f32, f32)>) {
+        self.background_colour = background_colour;
+    }
+
+    pub fn get_viewport(&self, window_width: i32, window_height: i32) -> Viewport {
+        self.scale_mode
+            .calculate_viewport(self.width, self.height, window_width, window_height)
+    }
+
+    pub fn get_view(&self) -> Mat4 {
+        Mat4::from_rotation_z(-self.rotation.to_radians())
+            * Mat4::from_translation(-self.position.extend(0.0))
+    }
+
+    pub fn get_projection(&self, viewport_width: f32, viewport_height: f32) -> Mat4 {
+        let (width, height) = self.get_visible_size(viewport_width, viewport_height);
+
+        Mat4::orthographic(-width, width, -height, height, -1.0, 1.0)
+    }
+
+    pub fn get_visible_size(&self, viewport_width: f32, viewport_height: f32) -> (f32, f32) {
+        let width = self.width / self.zoom;
+        let height = self.height / self.zoom;
+
+        if self.scale_mode != ScaleMode::Expand || viewport_width <= 0.0 || viewport_height <= 0.0 {
+            return (width, height);
+        }
+
+        let viewport_aspect = viewport_width / viewport_height;
+
+        if viewport_aspect > width / height {
+            (height * viewport_aspect, height)
+        } else {
+            (width, width / viewport_aspect)
+        }
+    }
+
+    pub fn convert_window_position_to_world(
+        &self,
+        window_position: (f32, f32),
+        window_size: (f32, f32),
+    ) -> Vec2 {
+        let viewport = self.get_viewport(window_size.0 as i32, window_size.1 as i32);
+
+        if viewport.width <= 0 || viewport.height <= 0 {
+            return self.position;
+        }
+
+        let viewport_width = viewport.width as f32;
+        let viewport_height = viewport.height as f32;
+        let viewport_top = window_size.1 - (viewport.y + viewport.height) as f32;
+
+        let normalised_position = Vec3::new(
+            (window_position.0 - viewport.x as f32) / viewport_width * 2.0 - 1.0,
+            1.0 - (window_position.1 - viewport_top) / viewport_height * 2.0,
+            0.0,
+        );
+
+        match self
+            .get_view_projection(viewport_width, viewport_height)
+            .inverse()
+        {
+            Some(inverse) => inverse.transform_point(normalised_position).truncate(),
+            None => self.position,
+        }
+    }
+}
+
+impl GetViewProjection for Camera {
+    fn get_view_projection(&self, viewport_width: f32, viewport_height: f32) -> Mat4 {
+        self.get_projection(viewport_width, viewport_height) * self.get_view()
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use garden_maths::vectors::{Vec2, Vec3};
+    use rstest::rstest;
+
+    use crate::{
+        camera::{Camera, GetViewProjection},
+        viewport::{GetScaleMode, ScaleMode, Viewport},
+        GetBackgroundColour, GetHeight, GetWidth,
+    };
+
+    struct Scene {
+        width: f32,
+        height: f32,
+        scale_mode: ScaleMode,
+        background_colour: Option<(f32, f32, f32)>,
+    }
+
+    impl GetWidth for Scene {
+        fn get_width(&self) -> f32 {
+            self.width
+        }
+    }
+
+    impl GetHeight for Scene {
+        fn get_height(&self) -> f32 {
+            self.height
+        }
+    }
+
+    impl GetScaleMode for Scene {
+        fn get_scale_mode(&self) -> ScaleMode {
+            self.scale_mode
+        }
+    }
+
+    impl GetBackgroundColour for Scene {
+        fn get_background_colour(&self) -> Option<(f32, f32, f32)> {
+            self.background_colour
+        }
+    }
+
+    fn assert_approximately_equal(expected: Vec2, result: Vec2) {
+        assert!(
+            (expected - result).length() < 0.0001,
+            "expected {expected:?}, got {result:?}"
+        );
+    }
+
+    #[rstest]
+    #[case(800.0, 400.0, (800.0, 400.0))]
+    #[case(1600.0, 400.0, (1600.0, 400.0))]
+    #[case(800.0, 800.0, (800.0, 800.0))]
+    #[case(0.0, 0.0, (800.0, 400.0))]
+    fn when_a_camera_gets_its_visible_size_then_the_scene_is_kept_in_view_without_stretching(
+        #[case] viewport_width: f32,
+        #[case] viewport_height: f32,
+        #[case] expected_visible_size: (f32, f32),
+    ) {
+        let camera = Camera::new(800.0, 400.0);
+
+        let result = camera.get_visible_size(viewport_width, viewport_height);
+
+        assert_eq!(expected_visible_size, result);
+    }
+
+    #[rstest]
+    #[case(ScaleMode::Stretch, (1600.0, 400.0))]
+    #[case(ScaleMode::Letterbox, (800.0, 400.0))]
+    #[case(ScaleMode::Integer, (800.0, 400.0))]
+    fn when_a_camera_that_does_not_expand_gets_its_visible_size_then_only_the_scene_is_visible(
+        #[case] scale_mode: ScaleMode,
+        #[case] viewport_size: (f32, f32),
+    ) {
+        let mut camera = Camera::new(800.0, 400.0);
+        camera.set_scale_mode(scale_mode);
+
+        let result = camera.get_visible_size(viewport_size.0, viewport_size.1);
+
+        assert_eq!((800.0, 400.0), result);
+    }
+
+    #[test]
+    fn when_a_camera_with_a_wide_viewport_projects_a_square_then_it_stays_square_on_screen() {
+        let camera = Camera::new(10.0, 10.0);
+
+        let view_projection = camera.get_view_projection(1600.0, 800.0);
+
+        let corner = view_projection.transform_point(Vec3::new(5.0, 5.0, 0.0));
+
+        assert_approximately_equal(Vec2::new(0.25, 0.5), corner.truncate());
+        assert_eq!(1600.0 * corner.x, 800.0 * corner.y);
+    }
+
+    #[test]
+    fn when_a_camera_is_moved_zoomed_and_rotated_then_its_position_is_the_centre_of_the_screen() {
+        let mut camera = Camera::new(10.0, 5.0);
+        camera.set_position(Vec2::new(20.0, -4.0));
+        camera.set_zoom(2.0);
+        camera.set_rotation(90.0);
+
+        let view_projection = camera.get_view_projection(800.0, 400.0);
+
+        let centre = view_projection.transform_point(Vec3::new(20.0, -4.0, 0.0));
+        let above = view_projection.transform_point(Vec3::new(20.0, -1.5, 0.0));
+
+        assert_approximately_equal(Vec2::ZERO, centre.truncate());
+        assert_approximately_equal(Vec2::new(0.5, 0.0), above.truncate());
+    }
+
+    #[rstest]
+    #[case((400.0, 200.0), Vec2::new(3.0, 1.0))]
+    #[case((800.0, 0.0), Vec2::new(13.0, 6.0))]
+    #[case((0.0, 400.0), Vec2::new(-7.0, -4.0))]
+    fn when_a_camera_converts_a_window_position_to_the_world_then_the_view_is_undone(
+        #[case] window_position: (f32, f32),
+        #[case] expected_result: Vec2,
+    ) {
+        let mut camera = Camera::new(10.0, 5.0);
+        camera.set_position(Vec2::new(3.0, 1.0));
+
+        let result = camera.convert_window_position_to_world(window_position, (800.0, 400.0));
+
+        assert_approximately_equal(expected_result, result);
+    }
+
+    #[test]
+    fn when_a_letterboxed_camera_converts_a_window_position_to_the_world_then_the_bars_are_skipped()
+    {
+        let mut camera = Camera::new(10.0, 5.0);
+        camera.set_scale_mode(ScaleMode::Letterbox);
+
+        let viewport = camera.get_viewport(800, 800);
+
+        let top_left = camera.convert_window_position_to_world((0.0, 200.0), (800.0, 800.0));
+        let centre = camera.convert_window_position_to_world((400.0, 400.0), (800.0, 800.0));
+
+        assert_eq!(Viewport::new(0, 200, 800, 400), viewport);
+        assert_approximately_equal(Vec2::new(-10.0, 5.0), top_left);
+        assert_approximately_equal(Vec2::ZERO, centre);
+    }
+
+    #[test]
+    fn when_a_camera_switches_scene_then_it_takes_the_size_scale_mode_and_background_of_the_new_scene(
+    ) {
+        let mut camera = Camera::from_scene(&Scene {
+            width: 400.0,
+            height: 300.0,
+            scale_mode: ScaleMode::Letterbox,
+            background_colour: Some((0.2, 0.4, 0.6)),
+        });
+
+        assert_eq!(Some((0.2, 0.4, 0.6)), camera.get_background_colour());
+
+        camera.set_scene(&Scene {
+            width: 10.0,
+            height: 5.0,
+            scale_mode: ScaleMode::Stretch,
+            background_colour: None,
+        });
+
+        assert_eq!(ScaleMode::Stretch, camera.get_scale_mode());
+        assert_eq!(None, camera.get_background_colour());
+        assert_eq!((10.0, 5.0), camera.get_visible_size(800.0, 800.0));
+    }
+}
@@ -0,0 +1,850 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io,
+};
+
+use crate::{camera::Camera, GetHeight, GetName, GetWidth};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Key0,
+    Key1,
+    Key2,
+    Key3,
+    Key4,
+    Key5,
+    Key6,
+    Key7,
+    Key8,
+    Key9,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    Up,
+    Down,
+    Left,
+    Right,
+    Space,
+    Enter,
+    Escape,
+    Tab,
+    Backspace,
+    LeftShift,
+    RightShift,
+    LeftControl,
+    RightControl,
+    LeftAlt,
+    RightAlt,
+}
+
+pub const KEY_NAMES: [(Key, &str); 63] = [
+    (Key::A, "A"),
+    (Key::B, "B"),
+    (Key::C, "C"),
+    (Key::D, "D"),
+    (Key::E, "E"),
+    (Key::F, "F"),
+    (Key::G, "G"),
+    (Key::H, "H"),
+    (Key::I, "I"),
+    (Key::J, "J"),
+    (Key::K, "K"),
+    (Key::L, "L"),
+    (Key::M, "M"),
+    (Key::N, "N"),
+    (Key::O, "O"),
+    (Key::P, "P"),
+    (Key::Q, "Q"),
+    (Key::R, "R"),
+    (Key::S, "S"),
+    (Key::T, "T"),
+    (Key::U, "U"),
+    (Key::V, "V"),
+    (Key::W, "W"),
+    (Key::X, "X"),
+    (Key::Y, "Y"),
+    (Key::Z, "Z"),
+    (Key::Key0, "Key0"),
+    (Key::Key1, "Key1"),
+    (Key::Key2, "Key2"),
+    (Key::Key3, "Key3"),
+    (Key::Key4, "Key4"),
+    (Key::Key5, "Key5"),
+    (Key::Key6, "Key6"),
+    (Key::Key7, "Key7"),
+    (Key::Key8, "Key8"),
+    (Key::Key9, "Key9"),
+    (Key::F1, "F1"),
+    (Key::F2, "F2"),
+    (Key::F3, "F3"),
+    (Key::F4, "F4"),
+    (Key::F5, "F5"),
+    (Key::F6, "F6"),
+    (Key::F7, "F7"),
+    (Key::F8, "F8"),
+    (Key::F9, "F9"),
+    (Key::F10, "F10"),
+    (Key::F11, "F11"),
+    (Key::F12, "F12"),
+    (Key::Up, "Up"),
+    (Key::Down, "Down"),
+    (Key::Left, "Left"),
+    (Key::Right, "Right"),
+    (Key::Space, "Space"),
+    (Key::Enter, "Enter"),
+    (Key::Escape, "Escape"),
+    (Key::Tab, "Tab"),
+    (Key::Backspace, "Backspace"),
+    (Key::LeftShift, "LeftShift"),
+    (Key::RightShift, "RightShift"),
+    (Key::LeftControl, "LeftControl"),
+    (Key::RightControl, "RightControl"),
+    (Key::LeftAlt, "LeftAlt"),
+    (Key::RightAlt, "RightAlt"),
+];
+
+impl GetName for Key {
+    fn get_name(&self) -> &str {
+        KEY_NAMES
+            .iter()
+            .find(|(key, _)| key == self)
+            .map(|(_, name)| *name)
+            .unwrap()
+    }
+}
+
+pub fn find_key_by_name(name: &str) -> Option<Key> {
+    KEY_NAMES
+        .iter()
+        .find(|(_, key_name)| *key_name == name)
+        .map(|(key, _)| *key)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Other(u16),
+}
+
+pub const MOUSE_BUTTON_NAMES: [(MouseButton, &str); 3] = [
+    (MouseButton::Left, "MouseLeft"),
+    (MouseButton::Right, "MouseRight"),
+    (MouseButton::Middle, "MouseMiddle"),
+];
+
+pub fn convert_mouse_button_to_name(mouse_button: MouseButton) -> String {
+    match mouse_button {
+        MouseButton::Other(number) => format!("Mouse{number}"),
+        mouse_button => MOUSE_BUTTON_NAMES
+            .iter()
+            .find(|(named_mouse_button, _)| *named_mouse_button == mouse_button)
+            .map(|(_, name)| name.to_string())
+            .unwrap(),
+    }
+}
+
+pub fn find_mouse_button_by_name(name: &str) -> Option<MouseButton> {
+    if let Some((mouse_button, _)) = MOUSE_BUTTON_NAMES
+        .iter()
+        .find(|(_, mouse_button_name)| *mouse_button_name == name)
+    {
+        return Some(*mouse_button);
+    }
+
+    name.strip_prefix("Mouse")
+        .and_then(|number| number.parse::<u16>().ok())
+        .map(MouseButton::Other)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputEvent {
+    KeyPressed(Key),
+    KeyReleased(Key),
+    MouseButtonPressed(MouseButton),
+    MouseButtonReleased(MouseButton),
+    MouseMoved { x: f32, y: f32 },
+    MouseWheelScrolled { x: f32, y: f32 },
+    WindowResized { width: f32, height: f32 },
+}
+
+pub trait HandleInputEvent {
+    fn handle_input_event(&mut self, input_event: &InputEvent);
+}
+
+pub trait EndInputTick {
+    fn end_input_tick(&mut self);
+}
+
+pub trait QueryInput {
+    fn is_key_down(&self, key: Key) -> bool;
+
+    fn was_key_pressed(&self, key: Key) -> bool;
+
+    fn was_key_released(&self, key: Key) -> bool;
+
+    fn was_key_down_last_tick(&self, key: Key) -> bool;
+
+    fn is_mouse_button_down(&self, mouse_button: MouseButton) -> bool;
+
+    fn was_mouse_button_pressed(&self, mouse_button: MouseButton) -> bool;
+
+    fn was_mouse_button_released(&self, mouse_button: MouseButton) -> bool;
+
+    fn was_mouse_button_down_last_tick(&self, mouse_button: MouseButton) -> bool;
+
+    fn get_mouse_window_position(&self) -> (f32, f32);
+
+    fn get_mouse_wheel_delta(&self) -> (f32, f32);
+}
+
+pub trait GetMouseScenePosition {
+    fn get_mouse_scene_position<TScene: GetWidth + GetHeight>(&self, scene: &TScene) -> (f32, f32);
+}
+
+pub trait GetMouseWorldPosition {
+    fn get_mouse_world_position(&self, camera: &Camera) -> (f32, f32);
+}
+
+pub struct Input {
+    keys_down: HashSet<Key>,
+    keys_pressed: HashSet<Key>,
+    keys_released: HashSet<Key>,
+    keys_down_last_tick: HashSet<Key>,
+    mouse_buttons_down: HashSet<MouseButton>,
+    mouse_buttons_pressed: HashSet<MouseButton>,
+    mouse_buttons_released: HashSet<MouseButton>,
+    mouse_buttons_down_last_tick: HashSet<MouseButton>,
+    mouse_window_position: (f32, f32),
+    mouse_wheel_delta: (f32, f32),
+    window_size: (f32, f32),
+}
+
+impl Input {
+    pub fn new(window_width: f32, window_height: f32) -> Self {
+        Self {
+            keys_down: HashSet::new(),
+            keys_pressed: HashSet::new(),
+            keys_released: HashSet::new(),
+            keys_down_last_tick: HashSet::new(),
+            mouse_buttons_down: HashSet::new(),
+            mouse_buttons_pressed: HashSet::new(),
+            mouse_buttons_released: HashSet::new(),
+            mouse_buttons_down_last_tick: HashSet::new(),
+            mouse_window_position: (0.0, 0.0),
+            mouse_wheel_delta: (0.0, 0.0),
+            window_size: (window_width, window_height),
+        }
+    }
+}
+
+impl HandleInputEvent for Input {
+    fn handle_input_event(&mut self, input_event: &InputEvent) {
+        match *input_event {
+            InputEvent::KeyPressed(key) => {
+                if self.keys_down.insert(key) {
+                    self.keys_pressed.insert(key);
+                }
+            }
+            InputEvent::KeyReleased(key) => {
+                if self.keys_down.remove(&key) {
+                    self.keys_released.insert(key);
+                }
+            }
+            InputEvent::MouseButtonPressed(mouse_button) => {
+                if self.mouse_buttons_down.insert(mouse_button) {
+                    self.mouse_buttons_pressed.insert(mouse_button);
+                }
+            }
+            InputEvent::MouseButtonReleased(mouse_button) => {
+                if self.mouse_buttons_down.remove(&mouse_button) {
+                    self.mouse_buttons_released.insert(mouse_button);
+                }
+            }
+            InputEvent::MouseMoved { x, y } => self.mouse_window_position = (x, y),
+            InputEvent::MouseWheelScrolled { x, y } => {
+                self.mouse_wheel_delta.0 += x;
+                self.mouse_wheel_delta.1 += y;
+            }
+            InputEvent::WindowResized { width, height } => self.window_size = (width, height),
+        }
+    }
+}
+
+impl EndInputTick for Input {
+    fn end_input_tick(&mut self) {
+        self.keys_pressed.clear();
+        self.keys_released.clear();
+        self.keys_down_last_tick.clone_from(&self.keys_down);
+        self.mouse_buttons_pressed.clear();
+        self.mouse_buttons_released.clear();
+        self.mouse_buttons_down_last_tick
+            .clone_from(&self.mouse_buttons_down);
+        self.mouse_wheel_delta = (0.0, 0.0);
+    }
+}
+
+impl QueryInput for Input {
+    fn is_key_down(&self, key: Key) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    fn was_key_pressed(&self, key: Key) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    fn was_key_released(&self, key: Key) -> bool {
+        self.keys_released.contains(&key)
+    }
+
+    fn was_key_down_last_tick(&self, key: Key) -> bool {
+        self.keys_down_last_tick.contains(&key)
+    }
+
+    fn is_mouse_button_down(&self, mouse_button: MouseButton) -> bool {
+        self.mouse_buttons_down.contains(&mouse_button)
+    }
+
+    fn was_mouse_button_pressed(&self, mouse_button: MouseButton) -> bool {
+        self.mouse_buttons_pressed.contains(&mouse_button)
+    }
+
+    fn was_mouse_button_released(&self, mouse_button: MouseButton) -> bool {
+        self.mouse_buttons_released.contains(&mouse_button)
+    }
+
+    fn was_mouse_button_down_last_tick(&self, mouse_button: MouseButton) -> bool {
+        self.mouse_buttons_down_last_tick.contains(&mouse_button)
+    }
+
+    fn get_mouse_window_position(&self) -> (f32, f32) {
+        self.mouse_window_position
+    }
+
+    fn get_mouse_wheel_delta(&self) -> (f32, f32) {
+        self.mouse_wheel_delta
+    }
+}
+
+impl GetMouseScenePosition for Input {
+    fn get_mouse_scene_position<TScene: GetWidth + GetHeight>(&self, scene: &TScene) -> (f32, f32) {
+        let (window_width, window_height) = self.window_size;
+
+        if window_width <= 0.0 || window_height <= 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let normalised_x = self.mouse_window_position.0 / window_width * 2.0 - 1.0;
+        let normalised_y = 1.0 - self.mouse_window_position.1 / window_height * 2.0;
+
+        (
+            normalised_x * scene.get_width(),
+            normalised_y * scene.get_height(),
+        )
+    }
+}
+
+impl GetMouseWorldPosition for Input {
+    fn get_mouse_world_position(&self, camera: &Camera) -> (f32, f32) {
+        camera
+            .convert_window_position_to_world(self.mouse_window_position, self.window_size)
+            .into()
+    }
+}
+
+pub trait ReadInputEvents {
+    fn read_input_events(&mut self, tick: u64) -> Vec<InputEvent>;
+}
+
+pub trait EndInputEventStream {
+    fn end_input_event_stream(&mut self, _number_of_ticks: u64) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub trait RunInputEventStream: HandleInputEvent + ReadInputEvents + EndInputEventStream {}
+
+impl<TInputEventStream: HandleInputEvent + ReadInputEvents + EndInputEventStream>
+    RunInputEventStream for TInputEventStream
+{
+}
+
+pub struct LiveInputEventStream {
+    input_events: Vec<InputEvent>,
+}
+
+impl LiveInputEventStream {
+    pub fn new() -> Self {
+        Self {
+            input_events: Vec::new(),
+        }
+    }
+}
+
+impl Default for LiveInputEventStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandleInputEvent for LiveInputEventStream {
+    fn handle_input_event(&mut self, input_event: &InputEvent) {
+        self.input_events.push(*input_event);
+    }
+}
+
+impl ReadInputEvents for LiveInputEventStream {
+    fn read_input_events(&mut self, _tick: u64) -> Vec<InputEvent> {
+        self.input_events.drain(..).collect()
+    }
+}
+
+impl EndInputEventStream for LiveInputEventStream {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordedInputEvent {
+    tick: u64,
+    input_event: InputEvent,
+}
+
+impl RecordedInputEvent {
+    pub fn new(tick: u64, input_event: InputEvent) -> Self {
+        Self { tick, input_event }
+    }
+
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn get_input_event(&self) -> &InputEvent {
+        &self.input_event
+    }
+}
+
+pub struct InputRecording {
+    recorded_input_events: Vec<RecordedInputEvent>,
+    number_of_ticks: u64,
+}
+
+impl InputRecording {
+    pub fn new(recorded_input_events: Vec<RecordedInputEvent>, number_of_ticks: u64) -> Self {
+        Self {
+            recorded_input_events,
+            number_of_ticks,
+        }
+    }
+
+    pub fn get_recorded_input_events(&self) -> &[RecordedInputEvent] {
+        &self.recorded_input_events
+    }
+
+    pub fn get_number_of_ticks(&self) -> u64 {
+        self.number_of_ticks
+    }
+}
+
+pub trait SaveInputRecording {
+    fn save_input_recording(&self, input_recording: &InputRecording) -> io::Result<()>;
+}
+
+impl<TFunction: Fn(&InputRecording) -> io::Result<()>> SaveInputRecording for TFunction {
+    fn save_input_recording(&self, input_recording: &InputRecording) -> io::Result<()> {
+        self(input_recording)
+    }
+}
+
+pub struct RecordingInputEventStream<TInputEventStream, TInputRecordingSaver> {
+    input_event_stream: TInputEventStream,
+    input_recording_saver: TInputRecordingSaver,
+    recorded_input_events: Vec<RecordedInputEvent>,
+}
+
+impl<TInputEventStream, TInputRecordingSaver>
+    RecordingInputEventStream<TInputEventStream, TInputRecordingSaver>
+{
+    pub fn new(
+        input_event_stream: TInputEventStream,
+        input_recording_saver: TInputRecordingSaver,
+    ) -> Self {
+        Self {
+            input_event_stream,
+            input_recording_saver,
+            recorded_input_events: Vec::new(),
+        }
+    }
+}
+
+impl<TInputEventStream: HandleInputEvent, TInputRecordingSaver> HandleInputEvent
+    for RecordingInputEventStream<TInputEventStream, TInputRecordingSaver>
+{
+    fn handle_input_event(&mut self, input_event: &InputEvent) {
+        self.input_event_stream.handle_input_event(input_event);
+    }
+}
+
+impl<TInputEventStream: ReadInputEvents, TInputRecordingSaver> ReadInputEvents
+    for RecordingInputEventStream<TInputEventStream, TInputRecordingSaver>
+{
+    fn read_input_events(&mut self, tick: u64) -> Vec<InputEvent> {
+        let input_events = self.input_event_stream.read_input_events(tick);
+
+        self.recorded_input_events.extend(
+            input_events
+                .iter()
+                .map(|input_event| RecordedInputEvent::new(tick, *input_event)),
+        );
+
+        input_events
+    }
+}
+
+impl<TInputEventStream: EndInputEventStream, TInputRecordingSaver: SaveInputRecording>
+    EndInputEventStream for RecordingInputEventStream<TInputEventStream, TInputRecordingSaver>
+{
+    fn end_input_event_stream(&mut self, number_of_ticks: u64) -> io::Result<()> {
+        self.input_event_stream
+            .end_input_event_stream(number_of_ticks)?;
+
+        self.input_recording_saver
+            .save_input_recording(&InputRecording::new(
+                std::mem::take(&mut self.recorded_input_events),
+                number_of_ticks,
+            ))
+    }
+}
+
+pub struct ReplayInputEventStream {
+    recorded_input_events: VecDeque<RecordedInputEvent>,
+}
+
+impl ReplayInputEventStream {
+    pub fn new(input_recording: InputRecording) -> Self {
+        let mut recorded_input_events = input_recording.recorded_input_events;
+
+        recorded_input_events.sort_by_key(|recorded_input_event| recorded_input_event.tick);
+
+        Self {
+            recorded_input_events: recorded_input_events.into(),
+        }
+    }
+}
+
+impl HandleInputEvent for ReplayInputEventStream {
+    fn handle_input_event(&mut self, _input_event: &InputEvent) {}
+}
+
+impl ReadInputEvents for ReplayInputEventStream {
+    fn read_input_events(&mut self, tick: u64) -> Vec<InputEvent> {
+        let mut input_events = Vec::new();
+
+        while let Some(recorded_input_event) = self.recorded_input_events.front() {
+            if recorded_input_event.tick > tick {
+                break;
+            }
+
+            input_events.push(recorded_input_event.input_event);
+
+            self.recorded_input_events.pop_front();
+        }
+
+        input_events
+    }
+}
+
+impl EndInputEventStream for ReplayInputEventStream {}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        camera::Camera,
+        input::{
+            convert_mouse_button_to_name, find_key_by_name, find_mouse_button_by_name,
+            EndInputEventStream, EndInputTick, GetMouseScenePosition, GetMouseWorldPosition,
+            HandleInputEvent, Input, InputEvent, InputRecording, Key, LiveInputEventStream,
+            MouseButton, QueryInput, ReadInputEvents, RecordingInputEventStream,
+            ReplayInputEventStream, KEY_NAMES,
+        },
+        GetHeight, GetName, GetWidth,
+    };
+
+    struct Scene {}
+
+    impl GetWidth for Scene {
+        fn get_width(&self) -> f32 {
+            400.0
+        }
+    }
+
+    impl GetHeight for Scene {
+        fn get_height(&self) -> f32 {
+            300.0
+        }
+    }
+
+    #[test]
+    fn when_a_key_is_pressed_then_it_is_down_and_pressed_until_the_tick_ends() {
+        let mut input = Input::new(800.0, 600.0);
+
+        input.handle_input_event(&InputEvent::KeyPressed(Key::Space));
+
+        assert!(input.is_key_down(Key::Space));
+        assert!(input.was_key_pressed(Key::Space));
+        assert!(!input.was_key_released(Key::Space));
+
+        input.end_input_tick();
+
+        assert!(input.is_key_down(Key::Space));
+        assert!(!input.was_key_pressed(Key::Space));
+    }
+
+    #[test]
+    fn when_a_held_key_repeats_then_it_is_not_pressed_again() {
+        let mut input = Input::new(800.0, 600.0);
+        input.handle_input_event(&InputEvent::KeyPressed(Key::W));
+        input.end_input_tick();
+
+        input.handle_input_event(&InputEvent::KeyPressed(Key::W));
+
+        assert!(input.is_key_down(Key::W));
+        assert!(!input.was_key_pressed(Key::W));
+    }
+
+    #[test]
+    fn when_a_key_is_pressed_and_released_within_a_tick_then_both_edges_are_seen() {
+        let mut input = Input::new(800.0, 600.0);
+
+        input.handle_input_event(&InputEvent::KeyPressed(Key::Enter));
+        input.handle_input_event(&InputEvent::KeyReleased(Key::Enter));
+
+        assert!(!input.is_key_down(Key::Enter));
+        assert!(input.was_key_pressed(Key::Enter));
+        assert!(input.was_key_released(Key::Enter));
+        assert!(!input.was_key_down_last_tick(Key::Enter));
+    }
+
+    #[test]
+    fn when_a_tick_ends_then_the_held_keys_and_mouse_buttons_were_down_last_tick() {
+        let mut input = Input::new(800.0, 600.0);
+        input.handle_input_event(&InputEvent::KeyPressed(Key::Space));
+        input.handle_input_event(&InputEvent::MouseButtonPressed(MouseButton::Left));
+
+        assert!(!input.was_key_down_last_tick(Key::Space));
+        assert!(!input.was_mouse_button_down_last_tick(MouseButton::Left));
+
+        input.end_input_tick();
+        input.handle_input_event(&InputEvent::KeyReleased(Key::Space));
+
+        assert!(input.was_key_down_last_tick(Key::Space));
+        assert!(input.was_mouse_button_down_last_tick(MouseButton::Left));
+
+        input.end_input_tick();
+
+        assert!(!input.was_key_down_last_tick(Key::Space));
+        assert!(input.was_mouse_button_down_last_tick(MouseButton::Left));
+    }
+
+    #[test]
+    fn when_a_mouse_button_is_pressed_and_released_then_its_state_is_tracked() {
+        let mut input = Input::new(800.0, 600.0);
+
+        input.handle_input_event(&InputEvent::MouseButtonPressed(MouseButton::Left));
+
+        assert!(input.is_mouse_button_down(MouseButton::Left));
+        assert!(input.was_mouse_button_pressed(MouseButton::Left));
+        assert!(!input.is_mouse_button_down(MouseButton::Right));
+
+        input.end_input_tick();
+        input.handle_input_event(&InputEvent::MouseButtonReleased(MouseButton::Left));
+
+        assert!(!input.is_mouse_button_down(MouseButton::Left));
+        assert!(input.was_mouse_button_released(MouseButton::Left));
+    }
+
+    #[test]
+    fn when_the_mouse_wheel_scrolls_then_the_delta_accumulates_until_the_tick_ends() {
+        let mut input = Input::new(800.0, 600.0);
+
+        input.handle_input_event(&InputEvent::MouseWheelScrolled { x: 0.0, y: 1.0 });
+        input.handle_input_event(&InputEvent::MouseWheelScrolled { x: 0.5, y: 2.0 });
+
+        assert_eq!((0.5, 3.0), input.get_mouse_wheel_delta());
+
+        input.end_input_tick();
+
+        assert_eq!((0.0, 0.0), input.get_mouse_wheel_delta());
+    }
+
+    #[test]
+    fn when_the_mouse_moves_then_its_scene_position_is_relative_to_the_scene_centre() {
+        let mut input = Input::new(800.0, 600.0);
+        let scene = Scene {};
+
+        input.handle_input_event(&InputEvent::MouseMoved { x: 400.0, y: 300.0 });
+        assert_eq!((0.0, 0.0), input.get_mouse_scene_position(&scene));
+
+        input.handle_input_event(&InputEvent::MouseMoved { x: 800.0, y: 0.0 });
+        assert_eq!((400.0, 300.0), input.get_mouse_scene_position(&scene));
+
+        input.handle_input_event(&InputEvent::MouseMoved { x: 0.0, y: 600.0 });
+        assert_eq!((-400.0, -300.0), input.get_mouse_scene_position(&scene));
+        assert_eq!((0.0, 600.0), input.get_mouse_window_position());
+    }
+
+    #[test]
+    fn when_the_window_is_resized_then_the_mouse_scene_position_uses_the_new_size() {
+        let mut input = Input::new(800.0, 600.0);
+        let scene = Scene {};
+
+        input.handle_input_event(&InputEvent::WindowResized {
+            width: 400.0,
+            height: 300.0,
+        });
+        input.handle_input_event(&InputEvent::MouseMoved { x: 400.0, y: 0.0 });
+
+        assert_eq!((400.0, 300.0), input.get_mouse_scene_position(&scene));
+    }
+
+    #[test]
+    fn when_the_camera_has_moved_then_the_mouse_world_position_follows_the_camera() {
+        let mut input = Input::new(800.0, 600.0);
+        let mut camera = Camera::new(400.0, 300.0);
+        camera.set_position(garden_maths::vectors::Vec2::new(100.0, -50.0));
+
+        input.handle_input_event(&InputEvent::MouseMoved { x: 400.0, y: 300.0 });
+        assert_eq!((100.0, -50.0), input.get_mouse_world_position(&camera));
+
+        input.handle_input_event(&InputEvent::MouseMoved { x: 800.0, y: 0.0 });
+        assert_eq!((500.0, 250.0), input.get_mouse_world_position(&camera));
+    }
+
+    #[test]
+    fn when_every_key_is_named_then_it_can_be_found_by_its_name() {
+        for (key, _) in KEY_NAMES.iter() {
+            assert_eq!(Some(*key), find_key_by_name(key.get_name()));
+        }
+
+        assert_eq!("LeftShift", Key::LeftShift.get_name());
+        assert_eq!(None, find_key_by_name("Spce"));
+    }
+
+    #[test]
+    fn when_live_input_events_are_read_then_they_are_only_read_once() {
+        let mut live_input_event_stream = LiveInputEventStream::new();
+
+        live_input_event_stream.handle_input_event(&InputEvent::KeyPressed(Key::A));
+        live_input_event_stream.handle_input_event(&InputEvent::MouseMoved { x: 1.0, y: 2.0 });
+
+        assert_eq!(
+            vec![
+                InputEvent::KeyPressed(Key::A),
+                InputEvent::MouseMoved { x: 1.0, y: 2.0 }
+            ],
+            live_input_event_stream.read_input_events(1)
+        );
+        assert!(live_input_event_stream.read_input_events(2).is_empty());
+    }
+
+    #[test]
+    fn when_a_recorded_session_is_replayed_then_each_event_is_read_on_its_recorded_tick() {
+        let input_recording = Rc::new(RefCell::new(None));
+        let saved_input_recording = input_recording.clone();
+        let mut recording_input_event_stream = RecordingInputEventStream::new(
+            LiveInputEventStream::new(),
+            move |input_recording: &InputRecording| {
+                saved_input_recording.replace(Some(InputRecording::new(
+                    input_recording.get_recorded_input_events().to_vec(),
+                    input_recording.get_number_of_ticks(),
+                )));
+
+                Ok(())
+            },
+        );
+        let live_input_events = vec![
+            vec![InputEvent::KeyPressed(Key::Space)],
+            vec![],
+            vec![
+                InputEvent::KeyReleased(Key::Space),
+                InputEvent::MouseButtonPressed(MouseButton::Other(4)),
+            ],
+        ];
+
+        let mut recorded_ticks = Vec::new();
+        for (index, input_events) in live_input_events.iter().enumerate() {
+            for input_event in input_events {
+                recording_input_event_stream.handle_input_event(input_event);
+            }
+            recorded_ticks.push(recording_input_event_stream.read_input_events(index as u64 + 1));
+        }
+        recording_input_event_stream
+            .end_input_event_stream(3)
+            .unwrap();
+
+        let input_recording = input_recording.take().unwrap();
+        assert_eq!(3, input_recording.get_number_of_ticks());
+
+        let mut replay_input_event_stream = ReplayInputEventStream::new(input_recording);
+        replay_input_event_stream.handle_input_event(&InputEvent::KeyPressed(Key::Q));
+
+        let replayed_ticks = (1..=3)
+            .map(|tick| replay_input_event_stream.read_input_events(tick))
+            .collect::<Vec<Vec<InputEvent>>>();
+
+        assert_eq!(live_input_events, recorded_ticks);
+        assert_eq!(live_input_events, replayed_ticks);
+    }
+
+    #[test]
+    fn when_mouse_buttons_are_named_then_they_can_be_found_by_their_names() {
+        for mouse_button in [
+            MouseButton::Left,
+            MouseButton::Right,
+            MouseButton::Middle,
+            MouseButton::Other(7),
+        ] {
+            assert_eq!(
+                Some(mouse_button),
+                find_mouse_button_by_name(&convert_mouse_button_to_name(mouse_button))
+            );
+        }
+
+        assert_eq!(None, find_mouse_button_by_name("MouseSide"));
+    }
+}
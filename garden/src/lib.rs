@@ -1,48 +1,98 @@
+pub mod camera;
+pub mod input;
+pub mod viewport;
+pub mod window;
+
+use std::io;
+
+use input::{Input, RunInputEventStream};
+
 pub trait GetName {
     fn get_name(&self) -> &str;
 }
 
-pub trait Initialise {
-    fn initialise(&self);
-}
+pub trait RunComponentLifecycle {
+    fn initialise(&mut self) {}
 
-pub trait RunEndComponent {
-    fn run_end_component(self);
+    fn run_end_component(self: Box<Self>) {}
 }
 
-pub trait OnDraw {
-    unsafe fn on_draw(&self, gl: &gl::Gl);
+pub trait UpdateComponent {
+    fn run_loop(&mut self, _frame_time: &FrameTime, _input: &Input) {}
 }
 
-pub trait OnCreateGlutinVbo {
-    unsafe fn on_create_glutin_vbo(&self, gl: &gl::Gl);
+pub trait DrawComponent {
+    unsafe fn on_create_glutin_vbo(&mut self, _gl: &gl::Gl) {}
+
+    unsafe fn on_draw(&mut self, _gl: &gl::Gl, _interpolation: f32) {}
 }
 
-pub trait GetInitialiser<TInitialise> {
-    fn get_initialiser(self) -> TInitialise;
+pub struct FrameTime {
+    delta_seconds: f32,
+    elapsed_seconds: f64,
+    tick: u64,
 }
 
-pub trait RunLoop {
-    fn run_loop(&self);
+impl FrameTime {
+    pub fn new(delta_seconds: f32, elapsed_seconds: f64, tick: u64) -> Self {
+        Self {
+            delta_seconds,
+            elapsed_seconds,
+            tick,
+        }
+    }
+
+    pub fn get_delta_seconds(&self) -> f32 {
+        self.delta_seconds
+    }
+
+    pub fn get_elapsed_seconds(&self) -> f64 {
+        self.elapsed_seconds
+    }
+
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
 }
 
 pub trait Run {
     fn run(&self);
 }
 
-pub trait GetLoopRunner<TRunLoop> {
-    fn get_loop_runner(&self) -> &TRunLoop;
-}
-
 pub trait GetEnder<TEnder> {
     fn get_ender(&self) -> &TEnder;
 }
 
-pub trait RunFullComponent:
-    Initialise + RunLoop + RunEndComponent + OnDraw + OnCreateGlutinVbo
+pub trait RunFullComponent: RunComponentLifecycle + UpdateComponent + DrawComponent {}
+
+impl<TComponent: RunComponentLifecycle + UpdateComponent + DrawComponent> RunFullComponent
+    for TComponent
 {
 }
 
+pub trait AddComponent {
+    fn add<T: RunFullComponent + 'static>(&mut self, t: T);
+}
+
+pub trait SetTickRate {
+    fn set_tick_rate(&mut self, ticks_per_second: f64);
+}
+
+pub trait SetInputEventStream {
+    fn set_input_event_stream<TInputEventStream: RunInputEventStream + 'static>(
+        &mut self,
+        input_event_stream: TInputEventStream,
+    );
+}
+
+pub trait RunLoopSystem {
+    fn run_loop_system(
+        self,
+        components: &mut [Box<dyn RunFullComponent>],
+        input_event_stream: &mut dyn RunInputEventStream,
+    ) -> io::Result<u64>;
+}
+
 pub trait Create<T> {
     fn create(&self) -> T;
 }
@@ -55,6 +105,10 @@ pub trait GetHeight {
     fn get_height(&self) -> f32;
 }
 
+pub trait GetBackgroundColour {
+    fn get_background_colour(&self) -> Option<(f32, f32, f32)>;
+}
+
 pub mod gl {
     #![allow(clippy::all)]
     include!(concat!(env!("OUT_DIR"), "/gl_bindings.rs"));
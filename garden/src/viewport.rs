@@ -0,0 +1,155 @@
+use crate::GetName;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScaleMode {
+    Stretch,
+    Letterbox,
+    #[default]
+    Expand,
+    Integer,
+}
+
+pub const SCALE_MODE_NAMES: [(ScaleMode, &str); 4] = [
+    (ScaleMode::Stretch, "stretch"),
+    (ScaleMode::Letterbox, "letterbox"),
+    (ScaleMode::Expand, "expand"),
+    (ScaleMode::Integer, "integer"),
+];
+
+impl GetName for ScaleMode {
+    fn get_name(&self) -> &str {
+        SCALE_MODE_NAMES
+            .iter()
+            .find(|(scale_mode, _)| scale_mode == self)
+            .map(|(_, name)| *name)
+            .unwrap()
+    }
+}
+
+pub fn find_scale_mode_by_name(name: &str) -> Option<ScaleMode> {
+    SCALE_MODE_NAMES
+        .iter()
+        .find(|(_, scale_mode_name)| *scale_mode_name == name)
+        .map(|(scale_mode, _)| *scale_mode)
+}
+
+pub trait GetScaleMode {
+    fn get_scale_mode(&self) -> ScaleMode;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Viewport {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Viewport {
+    pub const fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    fn centre(window_width: i32, window_height: i32, width: i32, height: i32) -> Self {
+        Self::new(
+            (window_width - width) / 2,
+            (window_height - height) / 2,
+            width,
+            height,
+        )
+    }
+}
+
+impl ScaleMode {
+    pub fn calculate_viewport(
+        &self,
+        scene_width: f32,
+        scene_height: f32,
+        window_width: i32,
+        window_height: i32,
+    ) -> Viewport {
+        let full_viewport = Viewport::new(0, 0, window_width, window_height);
+
+        let scene_pixel_width = scene_width * 2.0;
+        let scene_pixel_height = scene_height * 2.0;
+
+        if scene_pixel_width <= 0.0
+            || scene_pixel_height <= 0.0
+            || window_width <= 0
+            || window_height <= 0
+        {
+            return full_viewport;
+        }
+
+        let scale = (window_width as f32 / scene_pixel_width)
+            .min(window_height as f32 / scene_pixel_height);
+
+        match self {
+            ScaleMode::Stretch | ScaleMode::Expand => full_viewport,
+            ScaleMode::Letterbox => Viewport::centre(
+                window_width,
+                window_height,
+                (scene_pixel_width * scale).round() as i32,
+                (scene_pixel_height * scale).round() as i32,
+            ),
+            ScaleMode::Integer => {
+                let scale = scale.floor().max(1.0);
+
+                Viewport::centre(
+                    window_width,
+                    window_height,
+                    (scene_pixel_width * scale).round() as i32,
+                    (scene_pixel_height * scale).round() as i32,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        viewport::{find_scale_mode_by_name, ScaleMode, Viewport, SCALE_MODE_NAMES},
+        GetName,
+    };
+
+    #[rstest]
+    #[case(ScaleMode::Stretch, 1000, 500, Viewport::new(0, 0, 1000, 500))]
+    #[case(ScaleMode::Expand, 1000, 500, Viewport::new(0, 0, 1000, 500))]
+    #[case(ScaleMode::Letterbox, 1000, 500, Viewport::new(166, 0, 667, 500))]
+    #[case(ScaleMode::Letterbox, 800, 1000, Viewport::new(0, 200, 800, 600))]
+    #[case(ScaleMode::Letterbox, 800, 600, Viewport::new(0, 0, 800, 600))]
+    #[case(ScaleMode::Integer, 1000, 700, Viewport::new(100, 50, 800, 600))]
+    #[case(ScaleMode::Integer, 1700, 1300, Viewport::new(50, 50, 1600, 1200))]
+    #[case(ScaleMode::Integer, 600, 400, Viewport::new(-100, -100, 800, 600))]
+    #[case(ScaleMode::Letterbox, 0, 0, Viewport::new(0, 0, 0, 0))]
+    fn when_a_scale_mode_calculates_a_viewport_for_an_800_by_600_scene_then_the_viewport_follows_the_scale_mode(
+        #[case] scale_mode: ScaleMode,
+        #[case] window_width: i32,
+        #[case] window_height: i32,
+        #[case] expected_viewport: Viewport,
+    ) {
+        let result = scale_mode.calculate_viewport(400.0, 300.0, window_width, window_height);
+
+        assert_eq!(expected_viewport, result);
+    }
+
+    #[test]
+    fn when_a_scale_mode_is_found_by_its_name_then_the_same_scale_mode_is_returned() {
+        for (scale_mode, _) in SCALE_MODE_NAMES {
+            assert_eq!(
+                Some(scale_mode),
+                find_scale_mode_by_name(scale_mode.get_name())
+            );
+        }
+
+        assert_eq!(None, find_scale_mode_by_name("fit"));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{GetHeight, GetName, GetWidth};
+
+pub const DEFAULT_CLEAR_COLOUR: (f32, f32, f32) = (0.1, 0.1, 0.1);
+pub const DEFAULT_PIXELS_PER_UNIT: f32 = 1.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FullscreenMode {
+    #[default]
+    Windowed,
+    Borderless,
+    Exclusive,
+}
+
+pub const FULLSCREEN_MODE_NAMES: [(FullscreenMode, &str); 3] = [
+    (FullscreenMode::Windowed, "windowed"),
+    (FullscreenMode::Borderless, "borderless"),
+    (FullscreenMode::Exclusive, "exclusive"),
+];
+
+impl GetName for FullscreenMode {
+    fn get_name(&self) -> &str {
+        FULLSCREEN_MODE_NAMES
+            .iter()
+            .find(|(fullscreen_mode, _)| fullscreen_mode == self)
+            .map(|(_, name)| *name)
+            .unwrap()
+    }
+}
+
+pub fn find_fullscreen_mode_by_name(name: &str) -> Option<FullscreenMode> {
+    FULLSCREEN_MODE_NAMES
+        .iter()
+        .find(|(_, fullscreen_mode_name)| *fullscreen_mode_name == name)
+        .map(|(fullscreen_mode, _)| *fullscreen_mode)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowSettings {
+    title: Option<String>,
+    size: Option<(u32, u32)>,
+    min_size: Option<(u32, u32)>,
+    pixels_per_unit: f32,
+    fullscreen_mode: FullscreenMode,
+    resizable: bool,
+    vsync: bool,
+    clear_colour: (f32, f32, f32),
+}
+
+impl WindowSettings {
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            size: None,
+            min_size: None,
+            pixels_per_unit: DEFAULT_PIXELS_PER_UNIT,
+            fullscreen_mode: FullscreenMode::default(),
+            resizable: true,
+            vsync: true,
+            clear_colour: DEFAULT_CLEAR_COLOUR,
+        }
+    }
+
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.to_string());
+    }
+
+    pub fn get_size(&self) -> Option<(u32, u32)> {
+        self.size
+    }
+
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.size = Some((width, height));
+    }
+
+    pub fn set_default_size_from_scene<TScene: GetWidth + GetHeight>(&mut self, scene: &TScene) {
+        if self.size.is_none() {
+            self.set_size(
+                (scene.get_width() * 2.0 * self.pixels_per_unit).round() as u32,
+                (scene.get_height() * 2.0 * self.pixels_per_unit).round() as u32,
+            );
+        }
+    }
+
+    pub fn get_min_size(&self) -> Option<(u32, u32)> {
+        self.min_size
+    }
+
+    pub fn set_min_size(&mut self, width: u32, height: u32) {
+        self.min_size = Some((width, height));
+    }
+
+    pub fn get_pixels_per_unit(&self) -> f32 {
+        self.pixels_per_unit
+    }
+
+    pub fn set_pixels_per_unit(&mut self, pixels_per_unit: f32) {
+        self.pixels_per_unit = pixels_per_unit;
+    }
+
+    pub fn get_fullscreen_mode(&self) -> FullscreenMode {
+        self.fullscreen_mode
+    }
+
+    pub fn set_fullscreen_mode(&mut self, fullscreen_mode: FullscreenMode) {
+        self.fullscreen_mode = fullscreen_mode;
+    }
+
+    pub fn is_resizable(&self) -> bool {
+        self.resizable
+    }
+
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.resizable = resizable;
+    }
+
+    pub fn is_vsync_enabled(&self) -> bool {
+        self.vsync
+    }
+
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.vsync = vsync;
+    }
+
+    pub fn get_clear_colour(&self) -> (f32, f32, f32) {
+        self.clear_colour
+    }
+
+    pub fn set_clear_colour(&mut self, red: f32, green: f32, blue: f32) {
+        self.clear_colour = (red, green, blue);
+    }
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        window::{
+            find_fullscreen_mode_by_name, FullscreenMode, WindowSettings, FULLSCREEN_MODE_NAMES,
+        },
+        GetHeight, GetName, GetWidth,
+    };
+
+    struct Scene {}
+
+    impl GetWidth for Scene {
+        fn get_width(&self) -> f32 {
+            40.0
+        }
+    }
+
+    impl GetHeight for Scene {
+        fn get_height(&self) -> f32 {
+            30.0
+        }
+    }
+
+    #[test]
+    fn when_window_settings_are_created_then_they_default_to_a_resizable_window_with_vsync() {
+        let window_settings = WindowSettings::new();
+
+        assert_eq!(None, window_settings.get_title());
+        assert_eq!(None, window_settings.get_size());
+        assert_eq!(1.0, window_settings.get_pixels_per_unit());
+        assert_eq!(
+            FullscreenMode::Windowed,
+            window_settings.get_fullscreen_mode()
+        );
+        assert!(window_settings.is_resizable());
+        assert!(window_settings.is_vsync_enabled());
+        assert_eq!((0.1, 0.1, 0.1), window_settings.get_clear_colour());
+    }
+
+    #[test]
+    fn when_window_settings_without_a_size_default_to_the_scene_size_then_the_window_fits_the_whole_scene(
+    ) {
+        for (pixels_per_unit, expected_size) in
+            [(1.0, (80, 60)), (2.0, (160, 120)), (12.5, (1000, 750))]
+        {
+            let mut window_settings = WindowSettings::new();
+            window_settings.set_pixels_per_unit(pixels_per_unit);
+
+            window_settings.set_default_size_from_scene(&Scene {});
+
+            assert_eq!(Some(expected_size), window_settings.get_size());
+        }
+    }
+
+    #[test]
+    fn when_window_settings_without_a_size_or_pixels_per_unit_default_to_the_scene_size_then_the_scene_is_shown_at_one_pixel_per_unit(
+    ) {
+        let mut window_settings = WindowSettings::new();
+
+        window_settings.set_default_size_from_scene(&Scene {});
+
+        assert_eq!(Some((80, 60)), window_settings.get_size());
+    }
+
+    #[test]
+    fn when_window_settings_with_a_size_default_to_the_scene_size_then_the_size_is_kept() {
+        let mut window_settings = WindowSettings::new();
+        window_settings.set_size(1280, 720);
+
+        window_settings.set_default_size_from_scene(&Scene {});
+
+        assert_eq!(Some((1280, 720)), window_settings.get_size());
+    }
+
+    #[test]
+    fn when_a_fullscreen_mode_is_found_by_its_name_then_the_same_fullscreen_mode_is_returned() {
+        for (fullscreen_mode, _) in FULLSCREEN_MODE_NAMES {
+            assert_eq!(
+                Some(fullscreen_mode),
+                find_fullscreen_mode_by_name(fullscreen_mode.get_name())
+            );
+        }
+
+        assert_eq!(None, find_fullscreen_mode_by_name("full"));
+    }
+}
@@ -0,0 +1,625 @@
+use std::collections::BTreeMap;
+
+use garden::{
+    input::{
+        convert_mouse_button_to_name, find_key_by_name, find_mouse_button_by_name, Key,
+        MouseButton, QueryInput, KEY_NAMES, MOUSE_BUTTON_NAMES,
+    },
+    GetName,
+};
+use garden_json::ConvertToJson;
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Button {
+    Key(Key),
+    MouseButton(MouseButton),
+}
+
+pub fn convert_button_to_name(button: Button) -> String {
+    match button {
+        Button::Key(key) => key.get_name().to_string(),
+        Button::MouseButton(mouse_button) => convert_mouse_button_to_name(mouse_button),
+    }
+}
+
+pub fn find_button_by_name(name: &str) -> Option<Button> {
+    find_key_by_name(name)
+        .map(Button::Key)
+        .or_else(|| find_mouse_button_by_name(name).map(Button::MouseButton))
+}
+
+pub trait QueryButton {
+    fn is_button_down<TInput: QueryInput>(&self, input: &TInput) -> bool;
+
+    fn was_button_pressed<TInput: QueryInput>(&self, input: &TInput) -> bool;
+
+    fn was_button_released<TInput: QueryInput>(&self, input: &TInput) -> bool;
+
+    fn was_button_down_last_tick<TInput: QueryInput>(&self, input: &TInput) -> bool;
+}
+
+impl QueryButton for Button {
+    fn is_button_down<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        match *self {
+            Button::Key(key) => input.is_key_down(key),
+            Button::MouseButton(mouse_button) => input.is_mouse_button_down(mouse_button),
+        }
+    }
+
+    fn was_button_pressed<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        match *self {
+            Button::Key(key) => input.was_key_pressed(key),
+            Button::MouseButton(mouse_button) => input.was_mouse_button_pressed(mouse_button),
+        }
+    }
+
+    fn was_button_released<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        match *self {
+            Button::Key(key) => input.was_key_released(key),
+            Button::MouseButton(mouse_button) => input.was_mouse_button_released(mouse_button),
+        }
+    }
+
+    fn was_button_down_last_tick<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        match *self {
+            Button::Key(key) => input.was_key_down_last_tick(key),
+            Button::MouseButton(mouse_button) => {
+                input.was_mouse_button_down_last_tick(mouse_button)
+            }
+        }
+    }
+}
+
+impl ConvertToJson for Button {
+    fn convert_to_json(&self) -> Value {
+        Value::String(convert_button_to_name(*self))
+    }
+}
+
+pub struct Binding {
+    buttons: Vec<Button>,
+}
+
+impl Binding {
+    pub fn new(buttons: Vec<Button>) -> Self {
+        Self { buttons }
+    }
+
+    pub fn get_buttons(&self) -> &[Button] {
+        &self.buttons
+    }
+}
+
+pub trait QueryBinding {
+    fn is_binding_down<TInput: QueryInput>(&self, input: &TInput) -> bool;
+
+    fn was_binding_pressed<TInput: QueryInput>(&self, input: &TInput) -> bool;
+
+    fn was_binding_released<TInput: QueryInput>(&self, input: &TInput) -> bool;
+
+    fn was_binding_down_last_tick<TInput: QueryInput>(&self, input: &TInput) -> bool;
+}
+
+impl QueryBinding for Binding {
+    fn is_binding_down<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        !self.buttons.is_empty()
+            && self
+                .buttons
+                .iter()
+                .all(|button| button.is_button_down(input))
+    }
+
+    fn was_binding_pressed<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        self.is_binding_down(input)
+            && self
+                .buttons
+                .iter()
+                .any(|button| button.was_button_pressed(input))
+    }
+
+    fn was_binding_released<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        self.was_binding_down_last_tick(input) && !self.is_binding_down(input)
+    }
+
+    fn was_binding_down_last_tick<TInput: QueryInput>(&self, input: &TInput) -> bool {
+        !self.buttons.is_empty()
+            && self
+                .buttons
+                .iter()
+                .all(|button| button.was_button_down_last_tick(input))
+    }
+}
+
+impl ConvertToJson for Binding {
+    fn convert_to_json(&self) -> Value {
+        Value::Array(
+            self.buttons
+                .iter()
+                .map(|button| button.convert_to_json())
+                .collect(),
+        )
+    }
+}
+
+pub struct AxisBinding {
+    negative: Binding,
+    positive: Binding,
+}
+
+impl AxisBinding {
+    pub fn new(negative: Binding, positive: Binding) -> Self {
+        Self { negative, positive }
+    }
+
+    pub fn get_axis_binding_value<TInput: QueryInput>(&self, input: &TInput) -> f32 {
+        let negative = if self.negative.is_binding_down(input) {
+            1.0
+        } else {
+            0.0
+        };
+        let positive = if self.positive.is_binding_down(input) {
+            1.0
+        } else {
+            0.0
+        };
+
+        positive - negative
+    }
+}
+
+impl ConvertToJson for AxisBinding {
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "negative": self.negative.convert_to_json(),
+            "positive": self.positive.convert_to_json()
+        })
+    }
+}
+
+pub trait QueryActions {
+    fn is_action_down<TInput: QueryInput>(&self, action: &str, input: &TInput) -> bool;
+
+    fn was_action_pressed<TInput: QueryInput>(&self, action: &str, input: &TInput) -> bool;
+
+    fn was_action_released<TInput: QueryInput>(&self, action: &str, input: &TInput) -> bool;
+
+    fn get_axis_value<TInput: QueryInput>(&self, axis: &str, input: &TInput) -> f32;
+}
+
+pub trait BindActions {
+    fn bind_action(&mut self, action: &str, binding: Binding);
+
+    fn rebind_action(&mut self, action: &str, bindings: Vec<Binding>);
+
+    fn unbind_action(&mut self, action: &str);
+
+    fn bind_axis(&mut self, axis: &str, axis_binding: AxisBinding);
+
+    fn rebind_axis(&mut self, axis: &str, axis_bindings: Vec<AxisBinding>);
+
+    fn unbind_axis(&mut self, axis: &str);
+}
+
+pub trait GetActionBindings {
+    fn get_action_bindings(&self, action: &str) -> Option<&Vec<Binding>>;
+
+    fn get_axis_bindings(&self, axis: &str) -> Option<&Vec<AxisBinding>>;
+}
+
+pub trait MergeActionMap {
+    fn merge_action_map(&mut self, action_map: ActionMap);
+}
+
+pub struct ActionMap {
+    actions: BTreeMap<String, Vec<Binding>>,
+    axes: BTreeMap<String, Vec<AxisBinding>>,
+}
+
+impl ActionMap {
+    pub fn new(
+        actions: BTreeMap<String, Vec<Binding>>,
+        axes: BTreeMap<String, Vec<AxisBinding>>,
+    ) -> Self {
+        Self { actions, axes }
+    }
+}
+
+impl QueryActions for ActionMap {
+    fn is_action_down<TInput: QueryInput>(&self, action: &str, input: &TInput) -> bool {
+        self.actions.get(action).is_some_and(|bindings| {
+            bindings
+                .iter()
+                .any(|binding| binding.is_binding_down(input))
+        })
+    }
+
+    fn was_action_pressed<TInput: QueryInput>(&self, action: &str, input: &TInput) -> bool {
+        self.actions.get(action).is_some_and(|bindings| {
+            bindings
+                .iter()
+                .any(|binding| binding.was_binding_pressed(input))
+        })
+    }
+
+    fn was_action_released<TInput: QueryInput>(&self, action: &str, input: &TInput) -> bool {
+        self.actions.get(action).is_some_and(|bindings| {
+            bindings
+                .iter()
+                .any(|binding| binding.was_binding_released(input))
+                && !self.is_action_down(action, input)
+        })
+    }
+
+    fn get_axis_value<TInput: QueryInput>(&self, axis: &str, input: &TInput) -> f32 {
+        self.axes.get(axis).map_or(0.0, |axis_bindings| {
+            axis_bindings
+                .iter()
+                .map(|axis_binding| axis_binding.get_axis_binding_value(input))
+                .sum::<f32>()
+                .clamp(-1.0, 1.0)
+        })
+    }
+}
+
+impl BindActions for ActionMap {
+    fn bind_action(&mut self, action: &str, binding: Binding) {
+        self.actions
+            .entry(action.to_string())
+            .or_default()
+            .push(binding);
+    }
+
+    fn rebind_action(&mut self, action: &str, bindings: Vec<Binding>) {
+        self.actions.insert(action.to_string(), bindings);
+    }
+
+    fn unbind_action(&mut self, action: &str) {
+        self.actions.remove(action);
+    }
+
+    fn bind_axis(&mut self, axis: &str, axis_binding: AxisBinding) {
+        self.axes
+            .entry(axis.to_string())
+            .or_default()
+            .push(axis_binding);
+    }
+
+    fn rebind_axis(&mut self, axis: &str, axis_bindings: Vec<AxisBinding>) {
+        self.axes.insert(axis.to_string(), axis_bindings);
+    }
+
+    fn unbind_axis(&mut self, axis: &str) {
+        self.axes.remove(axis);
+    }
+}
+
+impl GetActionBindings for ActionMap {
+    fn get_action_bindings(&self, action: &str) -> Option<&Vec<Binding>> {
+        self.actions.get(action)
+    }
+
+    fn get_axis_bindings(&self, axis: &str) -> Option<&Vec<AxisBinding>> {
+        self.axes.get(axis)
+    }
+}
+
+impl MergeActionMap for ActionMap {
+    fn merge_action_map(&mut self, action_map: ActionMap) {
+        self.actions.extend(action_map.actions);
+        self.axes.extend(action_map.axes);
+    }
+}
+
+impl ConvertToJson for ActionMap {
+    fn convert_to_json(&self) -> Value {
+        let actions = self
+            .actions
+            .iter()
+            .map(|(action, bindings)| {
+                (
+                    action.to_string(),
+                    Value::Array(
+                        bindings
+                            .iter()
+                            .map(|binding| binding.convert_to_json())
+                            .collect(),
+                    ),
+                )
+            })
+            .collect::<Map<String, Value>>();
+
+        let axes = self
+            .axes
+            .iter()
+            .map(|(axis, axis_bindings)| {
+                (
+                    axis.to_string(),
+                    Value::Array(
+                        axis_bindings
+                            .iter()
+                            .map(|axis_binding| axis_binding.convert_to_json())
+                            .collect(),
+                    ),
+                )
+            })
+            .collect::<Map<String, Value>>();
+
+        json!({
+            "actions": actions,
+            "axes": axes
+        })
+    }
+}
+
+pub fn capture_binding<TInput: QueryInput>(input: &TInput) -> Option<Binding> {
+    let buttons = KEY_NAMES
+        .iter()
+        .map(|(key, _)| Button::Key(*key))
+        .chain(
+            MOUSE_BUTTON_NAMES
+                .iter()
+                .map(|(mouse_button, _)| Button::MouseButton(*mouse_button)),
+        )
+        .collect::<Vec<Button>>();
+
+    if !buttons
+        .iter()
+        .any(|button| button.was_button_pressed(input))
+    {
+        return None;
+    }
+
+    Some(Binding::new(
+        buttons
+            .into_iter()
+            .filter(|button| button.is_button_down(input) || button.was_button_pressed(input))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use garden::input::{EndInputTick, HandleInputEvent, Input, InputEvent};
+
+    use super::*;
+
+    fn create_action_map() -> ActionMap {
+        let mut action_map = ActionMap::new(BTreeMap::new(), BTreeMap::new());
+
+        action_map.bind_action("jump", Binding::new(vec![Button::Key(Key::Space)]));
+        action_map.bind_action("jump", Binding::new(vec![Button::Key(Key::W)]));
+        action_map.bind_action(
+            "save",
+            Binding::new(vec![Button::Key(Key::LeftControl), Button::Key(Key::S)]),
+        );
+        action_map.bind_action(
+            "fire",
+            Binding::new(vec![Button::MouseButton(MouseButton::Left)]),
+        );
+        action_map.bind_axis(
+            "move_horizontal",
+            AxisBinding::new(
+                Binding::new(vec![Button::Key(Key::A)]),
+                Binding::new(vec![Button::Key(Key::D)]),
+            ),
+        );
+        action_map.bind_axis(
+            "move_horizontal",
+            AxisBinding::new(
+                Binding::new(vec![Button::Key(Key::Left)]),
+                Binding::new(vec![Button::Key(Key::Right)]),
+            ),
+        );
+
+        action_map
+    }
+
+    fn create_input(input_events: Vec<InputEvent>) -> Input {
+        let mut input = Input::new(800.0, 600.0);
+
+        for input_event in input_events.iter() {
+            input.handle_input_event(input_event);
+        }
+
+        input
+    }
+
+    #[test]
+    fn when_any_binding_of_an_action_is_pressed_then_the_action_is_pressed() {
+        let action_map = create_action_map();
+
+        let input = create_input(vec![InputEvent::KeyPressed(Key::W)]);
+
+        assert!(action_map.is_action_down("jump", &input));
+        assert!(action_map.was_action_pressed("jump", &input));
+        assert!(!action_map.is_action_down("fire", &input));
+        assert!(!action_map.is_action_down("unbound", &input));
+    }
+
+    #[test]
+    fn when_only_part_of_a_chord_is_down_then_the_action_is_not_down() {
+        let action_map = create_action_map();
+
+        let input = create_input(vec![InputEvent::KeyPressed(Key::S)]);
+
+        assert!(!action_map.is_action_down("save", &input));
+        assert!(!action_map.was_action_pressed("save", &input));
+    }
+
+    #[test]
+    fn when_a_chord_is_completed_then_the_action_is_pressed_once() {
+        let action_map = create_action_map();
+        let mut input = create_input(vec![InputEvent::KeyPressed(Key::LeftControl)]);
+        input.end_input_tick();
+
+        input.handle_input_event(&InputEvent::KeyPressed(Key::S));
+
+        assert!(action_map.was_action_pressed("save", &input));
+
+        input.end_input_tick();
+
+        assert!(action_map.is_action_down("save", &input));
+        assert!(!action_map.was_action_pressed("save", &input));
+
+        input.handle_input_event(&InputEvent::KeyReleased(Key::LeftControl));
+
+        assert!(!action_map.is_action_down("save", &input));
+        assert!(action_map.was_action_released("save", &input));
+    }
+
+    #[test]
+    fn when_a_chord_is_released_before_it_was_fully_held_then_the_action_is_not_released() {
+        let action_map = create_action_map();
+        let mut input = create_input(vec![InputEvent::KeyPressed(Key::LeftControl)]);
+        input.end_input_tick();
+
+        input.handle_input_event(&InputEvent::KeyPressed(Key::S));
+        input.handle_input_event(&InputEvent::KeyReleased(Key::S));
+        input.handle_input_event(&InputEvent::KeyReleased(Key::LeftControl));
+
+        assert!(!action_map.is_action_down("save", &input));
+        assert!(!action_map.was_action_released("save", &input));
+    }
+
+    #[test]
+    fn when_one_of_two_held_bindings_is_released_then_the_action_is_not_released() {
+        let action_map = create_action_map();
+        let mut input = create_input(vec![
+            InputEvent::KeyPressed(Key::Space),
+            InputEvent::KeyPressed(Key::W),
+        ]);
+        input.end_input_tick();
+
+        input.handle_input_event(&InputEvent::KeyReleased(Key::Space));
+
+        assert!(action_map.is_action_down("jump", &input));
+        assert!(!action_map.was_action_released("jump", &input));
+    }
+
+    #[test]
+    fn when_an_action_has_an_empty_binding_then_the_action_is_never_down() {
+        let mut action_map = ActionMap::new(BTreeMap::new(), BTreeMap::new());
+
+        action_map.bind_action("idle", Binding::new(vec![]));
+
+        let input = create_input(vec![]);
+
+        assert!(!action_map.is_action_down("idle", &input));
+        assert!(!action_map.was_action_pressed("idle", &input));
+        assert!(!action_map.was_action_released("idle", &input));
+    }
+
+    #[test]
+    fn when_axis_bindings_are_down_then_the_axis_value_is_clamped() {
+        let action_map = create_action_map();
+
+        assert_eq!(
+            -1.0,
+            action_map.get_axis_value(
+                "move_horizontal",
+                &create_input(vec![InputEvent::KeyPressed(Key::A)])
+            )
+        );
+        assert_eq!(
+            0.0,
+            action_map.get_axis_value(
+                "move_horizontal",
+                &create_input(vec![
+                    InputEvent::KeyPressed(Key::A),
+                    InputEvent::KeyPressed(Key::D)
+                ])
+            )
+        );
+        assert_eq!(
+            1.0,
+            action_map.get_axis_value(
+                "move_horizontal",
+                &create_input(vec![
+                    InputEvent::KeyPressed(Key::D),
+                    InputEvent::KeyPressed(Key::Right)
+                ])
+            )
+        );
+    }
+
+    #[test]
+    fn when_an_action_is_rebound_then_only_the_new_bindings_trigger_it() {
+        let mut action_map = create_action_map();
+
+        action_map.rebind_action("jump", vec![Binding::new(vec![Button::Key(Key::Up)])]);
+
+        assert!(!action_map.is_action_down(
+            "jump",
+            &create_input(vec![InputEvent::KeyPressed(Key::Space)])
+        ));
+        assert!(
+            action_map.is_action_down("jump", &create_input(vec![InputEvent::KeyPressed(Key::Up)]))
+        );
+        assert_eq!(1, action_map.get_action_bindings("jump").unwrap().len());
+    }
+
+    #[test]
+    fn when_a_binding_is_captured_then_it_contains_every_held_button() {
+        let mut input = create_input(vec![InputEvent::KeyPressed(Key::LeftControl)]);
+
+        assert!(capture_binding(&input).is_some());
+
+        input.end_input_tick();
+
+        assert!(capture_binding(&input).is_none());
+
+        input.handle_input_event(&InputEvent::MouseButtonPressed(MouseButton::Right));
+
+        let binding = capture_binding(&input).unwrap();
+
+        assert_eq!(
+            &[
+                Button::Key(Key::LeftControl),
+                Button::MouseButton(MouseButton::Right)
+            ],
+            binding.get_buttons()
+        );
+    }
+
+    #[test]
+    fn when_buttons_are_named_then_they_can_be_found_by_their_names() {
+        for button in [
+            Button::Key(Key::Key7),
+            Button::MouseButton(MouseButton::Middle),
+            Button::MouseButton(MouseButton::Other(4)),
+        ] {
+            assert_eq!(
+                Some(button),
+                find_button_by_name(&convert_button_to_name(button))
+            );
+        }
+
+        assert_eq!(None, find_button_by_name("Mouse"));
+        assert_eq!(None, find_button_by_name("Spce"));
+    }
+
+    #[test]
+    fn when_an_action_map_is_converted_to_json_then_bindings_are_named() {
+        let action_map = create_action_map();
+
+        let result = action_map.convert_to_json();
+
+        assert_eq!(
+            json!({
+                "actions": {
+                    "fire": [["MouseLeft"]],
+                    "jump": [["Space"], ["W"]],
+                    "save": [["LeftControl", "S"]]
+                },
+                "axes": {
+                    "move_horizontal": [
+                        { "negative": ["A"], "positive": ["D"] },
+                        { "negative": ["Left"], "positive": ["Right"] }
+                    ]
+                }
+            }),
+            result
+        );
+    }
+}
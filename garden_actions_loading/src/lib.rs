@@ -0,0 +1,545 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use garden_actions::{
+    find_button_by_name, ActionMap, AxisBinding, Binding, Button, MergeActionMap,
+};
+use garden_json::{
+    create_object_json_schema, ConvertJsonToValue, GetJsonSchema, JsonToStringConverter,
+    ReadJsonDocuments,
+};
+use garden_loading::{Load, LoadError};
+use serde_json::{json, Value};
+
+pub struct ActionMapLoader<TJsonToActionMapConverter, TJsonDocumentsReader> {
+    json_to_action_map_converter: TJsonToActionMapConverter,
+    json_documents_reader: TJsonDocumentsReader,
+}
+
+impl<TJsonToActionMapConverter, TJsonDocumentsReader>
+    ActionMapLoader<TJsonToActionMapConverter, TJsonDocumentsReader>
+{
+    fn new(
+        json_to_action_map_converter: TJsonToActionMapConverter,
+        json_documents_reader: TJsonDocumentsReader,
+    ) -> Self {
+        Self {
+            json_to_action_map_converter,
+            json_documents_reader,
+        }
+    }
+}
+
+impl<
+        TJsonToActionMapConverter: ConvertJsonToValue<ActionMap>,
+        TJsonDocumentsReader: ReadJsonDocuments,
+    > Load<ActionMap> for ActionMapLoader<TJsonToActionMapConverter, TJsonDocumentsReader>
+{
+    fn load(self) -> Result<ActionMap, LoadError> {
+        let json_documents = self.json_documents_reader.read_json_documents()?;
+
+        let mut action_map = ActionMap::new(BTreeMap::new(), BTreeMap::new());
+
+        for json_document in json_documents.iter() {
+            let document_action_map = self
+                .json_to_action_map_converter
+                .convert_json_to_value(json_document.get_json())
+                .map_err(|error| error.in_file(json_document.get_file_path()))?;
+
+            action_map.merge_action_map(document_action_map);
+        }
+
+        Ok(action_map)
+    }
+}
+
+impl<TJsonToActionMapConverter: GetJsonSchema, TJsonDocumentsReader> GetJsonSchema
+    for ActionMapLoader<TJsonToActionMapConverter, TJsonDocumentsReader>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_action_map_converter.get_json_schema()
+    }
+}
+
+pub struct JsonToButtonConverter<TJsonToStringConverter> {
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+}
+
+impl<TJsonToStringConverter> JsonToButtonConverter<TJsonToStringConverter> {
+    fn new(json_to_string_converter: Rc<TJsonToStringConverter>) -> Self {
+        Self {
+            json_to_string_converter,
+        }
+    }
+}
+
+impl<TJsonToStringConverter: ConvertJsonToValue<String>> ConvertJsonToValue<Button>
+    for JsonToButtonConverter<TJsonToStringConverter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<Button, LoadError> {
+        let name = self.json_to_string_converter.convert_json_to_value(json)?;
+
+        find_button_by_name(&name).ok_or_else(|| LoadError::unknown_value(name))
+    }
+}
+
+impl<TJsonToStringConverter: GetJsonSchema> GetJsonSchema
+    for JsonToButtonConverter<TJsonToStringConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_string_converter.get_json_schema()
+    }
+}
+
+pub struct JsonToBindingConverter<TJsonToButtonConverter> {
+    json_to_button_converter: Rc<TJsonToButtonConverter>,
+}
+
+impl<TJsonToButtonConverter> JsonToBindingConverter<TJsonToButtonConverter> {
+    fn new(json_to_button_converter: Rc<TJsonToButtonConverter>) -> Self {
+        Self {
+            json_to_button_converter,
+        }
+    }
+}
+
+impl<TJsonToButtonConverter: ConvertJsonToValue<Button>> ConvertJsonToValue<Binding>
+    for JsonToBindingConverter<TJsonToButtonConverter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<Binding, LoadError> {
+        let buttons_json = match json {
+            Value::Null => return Err(LoadError::missing_field()),
+            Value::Array(buttons_json) => buttons_json,
+            _ => return Err(LoadError::wrong_field_type("array")),
+        };
+
+        if buttons_json.is_empty() {
+            return Err(LoadError::missing_field().within("0"));
+        }
+
+        let mut buttons = vec![];
+
+        for (index, button_json) in buttons_json.iter().enumerate() {
+            let button = self
+                .json_to_button_converter
+                .convert_json_to_value(button_json)
+                .map_err(|error| error.within(&index.to_string()))?;
+
+            buttons.push(button);
+        }
+
+        Ok(Binding::new(buttons))
+    }
+}
+
+impl<TJsonToButtonConverter: GetJsonSchema> GetJsonSchema
+    for JsonToBindingConverter<TJsonToButtonConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        json!({
+            "type": "array",
+            "items": self.json_to_button_converter.get_json_schema()
+        })
+    }
+}
+
+pub struct JsonToAxisBindingConverter<TJsonToBindingConverter> {
+    json_to_binding_converter: Rc<TJsonToBindingConverter>,
+}
+
+impl<TJsonToBindingConverter> JsonToAxisBindingConverter<TJsonToBindingConverter> {
+    fn new(json_to_binding_converter: Rc<TJsonToBindingConverter>) -> Self {
+        Self {
+            json_to_binding_converter,
+        }
+    }
+}
+
+impl<TJsonToBindingConverter: ConvertJsonToValue<Binding>> ConvertJsonToValue<AxisBinding>
+    for JsonToAxisBindingConverter<TJsonToBindingConverter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<AxisBinding, LoadError> {
+        let negative = self
+            .json_to_binding_converter
+            .convert_json_to_value(&json["negative"])
+            .map_err(|error| error.within("negative"))?;
+
+        let positive = self
+            .json_to_binding_converter
+            .convert_json_to_value(&json["positive"])
+            .map_err(|error| error.within("positive"))?;
+
+        Ok(AxisBinding::new(negative, positive))
+    }
+}
+
+impl<TJsonToBindingConverter: GetJsonSchema> GetJsonSchema
+    for JsonToAxisBindingConverter<TJsonToBindingConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("negative", self.json_to_binding_converter.get_json_schema()),
+            ("positive", self.json_to_binding_converter.get_json_schema()),
+        ])
+    }
+}
+
+pub struct JsonToActionMapConverter<TJsonToBindingConverter, TJsonToAxisBindingConverter> {
+    json_to_binding_converter: Rc<TJsonToBindingConverter>,
+    json_to_axis_binding_converter: Rc<TJsonToAxisBindingConverter>,
+}
+
+impl<TJsonToBindingConverter, TJsonToAxisBindingConverter>
+    JsonToActionMapConverter<TJsonToBindingConverter, TJsonToAxisBindingConverter>
+{
+    fn new(
+        json_to_binding_converter: Rc<TJsonToBindingConverter>,
+        json_to_axis_binding_converter: Rc<TJsonToAxisBindingConverter>,
+    ) -> Self {
+        Self {
+            json_to_binding_converter,
+            json_to_axis_binding_converter,
+        }
+    }
+}
+
+impl<
+        TJsonToBindingConverter: ConvertJsonToValue<Binding>,
+        TJsonToAxisBindingConverter: ConvertJsonToValue<AxisBinding>,
+    > ConvertJsonToValue<ActionMap>
+    for JsonToActionMapConverter<TJsonToBindingConverter, TJsonToAxisBindingConverter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<ActionMap, LoadError> {
+        let actions = convert_json_to_named_bindings(
+            &json["actions"],
+            self.json_to_binding_converter.as_ref(),
+        )
+        .map_err(|error| error.within("actions"))?;
+
+        let axes = convert_json_to_named_bindings(
+            &json["axes"],
+            self.json_to_axis_binding_converter.as_ref(),
+        )
+        .map_err(|error| error.within("axes"))?;
+
+        Ok(ActionMap::new(actions, axes))
+    }
+}
+
+impl<TJsonToBindingConverter: GetJsonSchema, TJsonToAxisBindingConverter: GetJsonSchema>
+    GetJsonSchema
+    for JsonToActionMapConverter<TJsonToBindingConverter, TJsonToAxisBindingConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "actions": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "array",
+                        "items": self.json_to_binding_converter.get_json_schema()
+                    }
+                },
+                "axes": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "array",
+                        "items": self.json_to_axis_binding_converter.get_json_schema()
+                    }
+                }
+            },
+            "additionalProperties": false
+        })
+    }
+}
+
+fn convert_json_to_named_bindings<
+    TBinding,
+    TJsonToBindingConverter: ConvertJsonToValue<TBinding>,
+>(
+    json: &Value,
+    json_to_binding_converter: &TJsonToBindingConverter,
+) -> Result<BTreeMap<String, Vec<TBinding>>, LoadError> {
+    let named_bindings_json = match json {
+        Value::Null => return Ok(BTreeMap::new()),
+        Value::Object(named_bindings_json) => named_bindings_json,
+        _ => return Err(LoadError::wrong_field_type("object")),
+    };
+
+    let mut named_bindings = BTreeMap::new();
+
+    for (name, bindings_json) in named_bindings_json.iter() {
+        let bindings = convert_json_to_bindings(bindings_json, json_to_binding_converter)
+            .map_err(|error| error.within(name))?;
+
+        named_bindings.insert(name.to_string(), bindings);
+    }
+
+    Ok(named_bindings)
+}
+
+fn convert_json_to_bindings<TBinding, TJsonToBindingConverter: ConvertJsonToValue<TBinding>>(
+    json: &Value,
+    json_to_binding_converter: &TJsonToBindingConverter,
+) -> Result<Vec<TBinding>, LoadError> {
+    let bindings_json = match json {
+        Value::Array(bindings_json) => bindings_json,
+        _ => return Err(LoadError::wrong_field_type("array")),
+    };
+
+    let mut bindings = vec![];
+
+    for (index, binding_json) in bindings_json.iter().enumerate() {
+        let binding = json_to_binding_converter
+            .convert_json_to_value(binding_json)
+            .map_err(|error| error.within(&index.to_string()))?;
+
+        bindings.push(binding);
+    }
+
+    Ok(bindings)
+}
+
+pub type ComposedJsonToActionMapConverter = JsonToActionMapConverter<
+    JsonToBindingConverter<JsonToButtonConverter<JsonToStringConverter>>,
+    JsonToAxisBindingConverter<
+        JsonToBindingConverter<JsonToButtonConverter<JsonToStringConverter>>,
+    >,
+>;
+
+pub fn compose_json_to_action_map_converter(
+    json_to_string_converter: Rc<JsonToStringConverter>,
+) -> ComposedJsonToActionMapConverter {
+    let json_to_button_converter = Rc::new(JsonToButtonConverter::new(json_to_string_converter));
+    let json_to_binding_converter = Rc::new(JsonToBindingConverter::new(json_to_button_converter));
+    let json_to_axis_binding_converter = Rc::new(JsonToAxisBindingConverter::new(
+        json_to_binding_converter.clone(),
+    ));
+
+    JsonToActionMapConverter::new(json_to_binding_converter, json_to_axis_binding_converter)
+}
+
+pub fn compose_action_map_loader<TJsonDocumentsReader: ReadJsonDocuments>(
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    json_documents_reader: TJsonDocumentsReader,
+) -> ActionMapLoader<ComposedJsonToActionMapConverter, TJsonDocumentsReader> {
+    ActionMapLoader::new(
+        compose_json_to_action_map_converter(json_to_string_converter),
+        json_documents_reader,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process, rc::Rc};
+
+    use garden::input::Key;
+    use garden_actions::{Button, GetActionBindings};
+    use garden_json::{
+        write_json_file, ConvertJsonToValue, ConvertToJson, GetJsonSchema, JsonDocument,
+        JsonFileReader, JsonReader, JsonSchemaValidator, JsonToStringConverter, ReadJsonDocuments,
+        ValidateJson,
+    };
+    use garden_loading::{Load, LoadError};
+    use serde_json::json;
+
+    use crate::{compose_action_map_loader, compose_json_to_action_map_converter};
+
+    #[test]
+    fn when_an_action_map_is_converted_from_json_then_every_binding_is_converted() {
+        let json_to_action_map_converter =
+            compose_json_to_action_map_converter(Rc::new(JsonToStringConverter::new()));
+
+        let json = json!({
+            "actions": {
+                "jump": [["Space"], ["MouseLeft"]],
+                "quit": [["LeftControl", "Q"]]
+            },
+            "axes": {
+                "move_horizontal": [{ "negative": ["A"], "positive": ["D"] }]
+            }
+        });
+
+        let action_map = json_to_action_map_converter
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        let quit_bindings = action_map.get_action_bindings("quit").unwrap();
+
+        assert_eq!(2, action_map.get_action_bindings("jump").unwrap().len());
+        assert_eq!(
+            &vec![Button::Key(Key::LeftControl), Button::Key(Key::Q)],
+            quit_bindings[0].get_buttons()
+        );
+        assert_eq!(
+            1,
+            action_map
+                .get_axis_bindings("move_horizontal")
+                .unwrap()
+                .len()
+        );
+        assert_eq!(json, action_map.convert_to_json());
+    }
+
+    #[test]
+    fn when_a_binding_names_an_unknown_button_then_an_unknown_value_error_is_returned() {
+        let json_to_action_map_converter =
+            compose_json_to_action_map_converter(Rc::new(JsonToStringConverter::new()));
+
+        let json = json!({
+            "actions": {
+                "jump": [["Space"], ["Spacebar"]]
+            }
+        });
+
+        let error = json_to_action_map_converter
+            .convert_json_to_value(&json)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            LoadError::unknown_value("Spacebar".to_string())
+                .within("0")
+                .within("1")
+                .within("jump")
+                .within("actions"),
+            error
+        );
+    }
+
+    #[test]
+    fn when_a_binding_is_empty_then_a_missing_field_error_is_returned() {
+        let json_to_action_map_converter =
+            compose_json_to_action_map_converter(Rc::new(JsonToStringConverter::new()));
+
+        let json = json!({
+            "axes": {
+                "move_vertical": [{ "negative": [], "positive": ["W"] }]
+            }
+        });
+
+        let error = json_to_action_map_converter
+            .convert_json_to_value(&json)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            LoadError::missing_field()
+                .within("0")
+                .within("negative")
+                .within("0")
+                .within("move_vertical")
+                .within("axes"),
+            error
+        );
+    }
+
+    #[test]
+    fn when_action_maps_are_loaded_then_later_documents_override_earlier_ones() {
+        let defaults_json = json!({
+            "actions": {
+                "jump": [["Space"]],
+                "fire": [["MouseLeft"]]
+            }
+        });
+        let user_json = json!({
+            "actions": {
+                "jump": [["W"]]
+            }
+        });
+
+        let action_map = compose_action_map_loader(
+            Rc::new(JsonToStringConverter::new()),
+            MultipleJsonReaders(vec![
+                JsonReader::new("defaults", defaults_json.to_string().as_bytes()),
+                JsonReader::new("user", user_json.to_string().as_bytes()),
+            ]),
+        )
+        .load()
+        .unwrap();
+
+        assert_eq!(
+            json!({
+                "actions": {
+                    "fire": [["MouseLeft"]],
+                    "jump": [["W"]]
+                },
+                "axes": {}
+            }),
+            action_map.convert_to_json()
+        );
+    }
+
+    #[test]
+    fn when_an_action_map_is_saved_and_loaded_then_the_action_map_is_unchanged() {
+        let file_path = env::temp_dir().join(format!(
+            "garden_actions_loading_when_an_action_map_is_saved_and_loaded_{}.json",
+            process::id()
+        ));
+        let file_path = file_path.to_str().unwrap();
+
+        let json = json!({
+            "actions": {
+                "pause": [["Escape"], ["F1"]]
+            },
+            "axes": {
+                "zoom": [{ "negative": ["Mouse4"], "positive": ["Mouse5"] }]
+            }
+        });
+
+        let action_map =
+            compose_json_to_action_map_converter(Rc::new(JsonToStringConverter::new()))
+                .convert_json_to_value(&json)
+                .unwrap();
+
+        write_json_file(file_path, &action_map.convert_to_json()).unwrap();
+
+        let loaded_action_map = compose_action_map_loader(
+            Rc::new(JsonToStringConverter::new()),
+            JsonFileReader::new(file_path),
+        )
+        .load();
+
+        fs::remove_file(file_path).unwrap();
+
+        assert_eq!(json, loaded_action_map.unwrap().convert_to_json());
+    }
+
+    #[test]
+    fn when_action_map_json_is_validated_then_unknown_fields_and_missing_directions_are_rejected() {
+        let json_to_action_map_converter =
+            compose_json_to_action_map_converter(Rc::new(JsonToStringConverter::new()));
+
+        let json_schema_validator =
+            JsonSchemaValidator::new(json_to_action_map_converter.get_json_schema());
+
+        assert!(json_schema_validator
+            .validate_json(&json!({
+                "actions": { "jump": [["Space"]] },
+                "axes": { "move_horizontal": [{ "negative": ["A"], "positive": ["D"] }] }
+            }))
+            .is_ok());
+        assert!(json_schema_validator
+            .validate_json(&json!({ "bindings": {} }))
+            .is_err());
+        assert!(json_schema_validator
+            .validate_json(&json!({
+                "axes": { "move_horizontal": [{ "negative": ["A"] }] }
+            }))
+            .is_err());
+    }
+
+    struct MultipleJsonReaders<'a>(Vec<JsonReader<&'a [u8]>>);
+
+    impl<'a> ReadJsonDocuments for MultipleJsonReaders<'a> {
+        fn read_json_documents(self) -> Result<Vec<JsonDocument>, LoadError> {
+            let mut json_documents = vec![];
+
+            for json_reader in self.0 {
+                json_documents.extend(json_reader.read_json_documents()?);
+            }
+
+            Ok(json_documents)
+        }
+    }
+}
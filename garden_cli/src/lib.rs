@@ -0,0 +1,515 @@
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    rc::Rc,
+};
+
+use garden::{GetHeight, GetWidth};
+use garden_content::{Content, GetNumberOfObjects, GetNumberOfVertices};
+use garden_content_loading::compose_content_loader;
+use garden_json::{
+    create_json_schema_document, ConvertToJson, GetJsonSchema, JsonDocument, JsonFileReader,
+    JsonSchemaValidator, JsonToBoolConverter, JsonToU64Converter, ReadJsonDocuments, ValidateJson,
+};
+use garden_json_component::JsonComponent;
+use garden_loading::{Load, LoadError};
+use garden_maths_component::MathsComponent;
+use garden_scenes::TwoDScene;
+use garden_scenes_loading::compose_scene_loader;
+use garden_window_loading::compose_window_settings_loader;
+
+pub const USAGE: &str = "usage: garden_cli <validate|stats|dump> <file>";
+
+pub fn run_command<TWrite: Write>(arguments: &[String], output: &mut TWrite) -> io::Result<i32> {
+    let json_component = garden_json_component::compose_component();
+
+    let maths_component = garden_maths_component::compose_component();
+
+    let succeeded = match arguments {
+        [command, file_path] if command == "validate" => {
+            validate(&json_component, &maths_component, file_path, output)?
+        }
+        [command, file_path] if command == "stats" => {
+            stats(&json_component, &maths_component, file_path, output)?
+        }
+        [command, file_path] if command == "dump" => {
+            dump(&json_component, &maths_component, file_path, output)?
+        }
+        _ => {
+            writeln!(output, "{USAGE}")?;
+
+            return Ok(2);
+        }
+    };
+
+    Ok(if succeeded { 0 } else { 1 })
+}
+
+pub fn validate<TWrite: Write>(
+    json_component: &JsonComponent,
+    maths_component: &MathsComponent,
+    file_path: &str,
+    output: &mut TWrite,
+) -> io::Result<bool> {
+    let json_documents = match JsonFileReader::new(file_path).read_json_documents() {
+        Ok(json_documents) => json_documents,
+        Err(error) => {
+            writeln!(output, "error: {error}")?;
+
+            return Ok(false);
+        }
+    };
+
+    let content_loader = compose_content_loader(
+        json_component.get_json_to_f32_converter(),
+        json_component.get_json_to_string_converter(),
+        maths_component.get_trigonometry_calculator(),
+        JsonFileReader::new(file_path),
+    );
+
+    let scene_loader = compose_scene_loader(
+        json_component.get_json_to_f32_converter(),
+        json_component.get_json_to_string_converter(),
+        JsonFileReader::new(file_path),
+    );
+
+    let window_settings_loader = compose_window_settings_loader(
+        json_component.get_json_to_f32_converter(),
+        Rc::new(JsonToU64Converter::new()),
+        json_component.get_json_to_string_converter(),
+        Rc::new(JsonToBoolConverter::new()),
+        JsonFileReader::new(file_path),
+    );
+
+    let json_schema_validator = JsonSchemaValidator::new(create_json_schema_document(vec![
+        content_loader.get_json_schema(),
+        scene_loader.get_json_schema(),
+        window_settings_loader.get_json_schema(),
+    ]));
+
+    let mut errors = json_documents
+        .iter()
+        .filter_map(|json_document| {
+            json_schema_validator
+                .validate_json(json_document.get_json())
+                .err()
+                .map(|error| error.in_file(json_document.get_file_path()))
+        })
+        .collect::<Vec<LoadError>>();
+
+    errors.append(&mut find_unknown_content_names(&json_documents));
+
+    let mut warnings = vec![];
+
+    if errors.is_empty() {
+        if let Err(error) = content_loader.load() {
+            errors.push(error);
+        }
+
+        match scene_loader.load() {
+            Ok(scene) => warnings = find_object_instances_outside_scene(&json_documents, &scene),
+            Err(error) => errors.push(error),
+        }
+
+        if let Err(error) = window_settings_loader.load() {
+            errors.push(error);
+        }
+    }
+
+    for error in errors.iter() {
+        writeln!(output, "error: {error}")?;
+    }
+
+    for warning in warnings.iter() {
+        writeln!(output, "warning: {warning}")?;
+    }
+
+    if errors.is_empty() {
+        writeln!(output, "{file_path}: ok")?;
+    } else {
+        writeln!(output, "{file_path}: {} error(s)", errors.len())?;
+    }
+
+    Ok(errors.is_empty())
+}
+
+pub fn stats<TWrite: Write>(
+    json_component: &JsonComponent,
+    maths_component: &MathsComponent,
+    file_path: &str,
+    output: &mut TWrite,
+) -> io::Result<bool> {
+    let (content, scene) = match load(json_component, maths_component, file_path) {
+        Ok(content_and_scene) => content_and_scene,
+        Err(error) => {
+            writeln!(output, "error: {error}")?;
+
+            return Ok(false);
+        }
+    };
+
+    writeln!(
+        output,
+        "scene: {} x {}",
+        scene.get_width(),
+        scene.get_height()
+    )?;
+
+    writeln!(
+        output,
+        "objects: {}",
+        content
+            .get_objects()
+            .as_ref()
+            .map_or(0, |objects| objects.len())
+    )?;
+
+    let object_instance_runners = content.get_object_instance_runners().as_ref();
+
+    writeln!(
+        output,
+        "instances: {}",
+        object_instance_runners.map_or(0, |object_instance_runners| object_instance_runners.len())
+    )?;
+
+    for object_instance_runner in object_instance_runners.into_iter().flatten() {
+        let json = object_instance_runner.convert_to_json();
+
+        writeln!(
+            output,
+            "  {} ({} {}): number_of_vertices {}, number_of_objects {}",
+            json["name"].as_str().unwrap_or_default(),
+            json["type"].as_str().unwrap_or_default(),
+            json["contentName"].as_str().unwrap_or_default(),
+            object_instance_runner.get_number_of_vertices(),
+            object_instance_runner.get_number_of_objects()
+        )?;
+    }
+
+    writeln!(
+        output,
+        "number_of_vertices: {}",
+        content.get_number_of_vertices()
+    )?;
+
+    writeln!(
+        output,
+        "number_of_objects: {}",
+        content.get_number_of_objects()
+    )?;
+
+    Ok(true)
+}
+
+pub fn dump<TWrite: Write>(
+    json_component: &JsonComponent,
+    maths_component: &MathsComponent,
+    file_path: &str,
+    output: &mut TWrite,
+) -> io::Result<bool> {
+    let (content, scene) = match load(json_component, maths_component, file_path) {
+        Ok(content_and_scene) => content_and_scene,
+        Err(error) => {
+            writeln!(output, "error: {error}")?;
+
+            return Ok(false);
+        }
+    };
+
+    let mut json = content.convert_to_json();
+
+    json["scene"] = scene.convert_to_json();
+
+    writeln!(output, "{json:#}")?;
+
+    Ok(true)
+}
+
+fn load(
+    json_component: &JsonComponent,
+    maths_component: &MathsComponent,
+    file_path: &str,
+) -> Result<(Content, TwoDScene), LoadError> {
+    let content_loader = compose_content_loader(
+        json_component.get_json_to_f32_converter(),
+        json_component.get_json_to_string_converter(),
+        maths_component.get_trigonometry_calculator(),
+        JsonFileReader::new(file_path),
+    );
+
+    let scene_loader = compose_scene_loader(
+        json_component.get_json_to_f32_converter(),
+        json_component.get_json_to_string_converter(),
+        JsonFileReader::new(file_path),
+    );
+
+    Ok((content_loader.load()?, scene_loader.load()?))
+}
+
+fn find_unknown_content_names(json_documents: &[JsonDocument]) -> Vec<LoadError> {
+    let content_names = json_documents
+        .iter()
+        .filter_map(|json_document| json_document.get_json()["content"]["objects"].as_array())
+        .flatten()
+        .filter_map(|json| json["name"].as_str())
+        .collect::<HashSet<&str>>();
+
+    let mut errors = vec![];
+
+    for json_document in json_documents {
+        let object_instance_json_array = json_document.get_json()["objects"].as_array();
+
+        for (index, json) in object_instance_json_array.into_iter().flatten().enumerate() {
+            if let Some(content_name) = json["contentName"].as_str() {
+                if !content_names.contains(content_name) {
+                    errors.push(
+                        LoadError::unknown_content(content_name.to_string())
+                            .within("contentName")
+                            .within(&index.to_string())
+                            .within("objects")
+                            .in_file(json_document.get_file_path()),
+                    );
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+fn find_object_instances_outside_scene<TScene: GetWidth + GetHeight>(
+    json_documents: &[JsonDocument],
+    scene: &TScene,
+) -> Vec<String> {
+    let mut warnings = vec![];
+
+    for json_document in json_documents {
+        let object_instance_json_array = json_document.get_json()["objects"].as_array();
+
+        for (index, json) in object_instance_json_array.into_iter().flatten().enumerate() {
+            let x = json["position"]["x"].as_f64().unwrap_or_default() as f32;
+            let y = json["position"]["y"].as_f64().unwrap_or_default() as f32;
+
+            if x.abs() > scene.get_width() || y.abs() > scene.get_height() {
+                warnings.push(format!(
+                    "{}: instance \"{}\" at /objects/{index}/position ({x}, {y}) is outside the scene bounds (±{}, ±{})",
+                    json_document.get_file_path(),
+                    json["name"].as_str().unwrap_or_default(),
+                    scene.get_width(),
+                    scene.get_height()
+                ));
+            }
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use garden_loading::LoadError;
+    use serde_json::{json, Value};
+
+    use crate::{run_command, USAGE};
+
+    fn write_test_file(file_name: &str, json: &Value) -> String {
+        let file_path = env::temp_dir()
+            .join(format!("garden_cli_{}_{file_name}", process::id()))
+            .to_string_lossy()
+            .to_string();
+
+        fs::write(&file_path, json.to_string()).unwrap();
+
+        file_path
+    }
+
+    fn run_test_command(command: &str, file_path: &str) -> (i32, String) {
+        let mut output = Vec::<u8>::new();
+
+        let exit_code =
+            run_command(&[command.to_string(), file_path.to_string()], &mut output).unwrap();
+
+        (exit_code, String::from_utf8(output).unwrap())
+    }
+
+    fn create_test_json() -> Value {
+        json!({
+            "scene": { "width": 10.0, "height": 10.0, "scaleMode": "letterbox" },
+            "content": {
+                "objects": [
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 4.0,
+                        "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                    },
+                    {
+                        "name": "Circle1",
+                        "type": "circle",
+                        "diameter": 3.0,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 }
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Rectangle1-a",
+                    "contentName": "Rectangle1",
+                    "type": "rectangle",
+                    "scale": 1.0,
+                    "position": { "x": -5.0, "y": 5.0 }
+                },
+                {
+                    "name": "Circle1-a",
+                    "contentName": "Circle1",
+                    "type": "circle",
+                    "scale": 1.0,
+                    "position": { "x": 5.0, "y": -5.0 }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn when_valid_content_is_validated_then_it_passes() {
+        let file_path = write_test_file("valid.json", &create_test_json());
+
+        let (exit_code, output) = run_test_command("validate", &file_path);
+
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(0, exit_code);
+        assert_eq!(format!("{file_path}: ok\n"), output);
+    }
+
+    #[test]
+    fn when_invalid_content_is_validated_then_every_error_is_reported() {
+        let mut json = create_test_json();
+
+        json["content"]["objects"][1]["diamter"] = json!(3.0);
+        json["objects"][0]["contentName"] = json!("Rectangle2");
+        json["objects"][1]["contentName"] = json!("Circle2");
+
+        let file_path = write_test_file("invalid.json", &json);
+
+        let (exit_code, output) = run_test_command("validate", &file_path);
+
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(1, exit_code);
+        assert_eq!(
+            format!(
+                "error: {file_path}: unknown field at /content/objects/1/diamter\n\
+                 error: {file_path}: unknown content \"Rectangle2\" at /objects/0/contentName\n\
+                 error: {file_path}: unknown content \"Circle2\" at /objects/1/contentName\n\
+                 {file_path}: 3 error(s)\n"
+            ),
+            output
+        );
+    }
+
+    #[test]
+    fn when_content_with_an_instance_outside_the_scene_is_validated_then_a_warning_is_reported() {
+        let mut json = create_test_json();
+
+        json["objects"][1]["position"]["x"] = json!(12.5);
+
+        let file_path = write_test_file("outside.json", &json);
+
+        let (exit_code, output) = run_test_command("validate", &file_path);
+
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(0, exit_code);
+        assert_eq!(
+            format!(
+                "warning: {file_path}: instance \"Circle1-a\" at /objects/1/position (12.5, -5) is outside the scene bounds (±10, ±10)\n\
+                 {file_path}: ok\n"
+            ),
+            output
+        );
+    }
+
+    #[test]
+    fn when_content_with_an_unknown_fullscreen_mode_is_validated_then_an_error_is_reported() {
+        let mut json = create_test_json();
+
+        json["window"] = json!({ "fullscreen": "full" });
+
+        let file_path = write_test_file("window.json", &json);
+
+        let (exit_code, output) = run_test_command("validate", &file_path);
+
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(1, exit_code);
+        assert_eq!(
+            format!(
+                "error: {}\n{file_path}: 1 error(s)\n",
+                LoadError::unknown_value("full".to_string())
+                    .within("fullscreen")
+                    .within("window")
+                    .in_file(&file_path)
+            ),
+            output
+        );
+    }
+
+    #[test]
+    fn when_content_stats_are_requested_then_the_vertex_and_object_counts_are_reported() {
+        let file_path = write_test_file("stats.json", &create_test_json());
+
+        let (exit_code, output) = run_test_command("stats", &file_path);
+
+        fs::remove_file(&file_path).unwrap();
+
+        let lines = output.lines().collect::<Vec<&str>>();
+
+        assert_eq!(0, exit_code);
+        assert_eq!(
+            vec![
+                "scene: 10 x 10",
+                "objects: 2",
+                "instances: 2",
+                "  Rectangle1-a (rectangle Rectangle1): number_of_vertices 20, number_of_objects 2"
+            ],
+            lines[0..4]
+        );
+        assert!(lines[4].starts_with("  Circle1-a (circle Circle1): number_of_vertices "));
+        assert!(lines[5].starts_with("number_of_vertices: "));
+        assert!(lines[6].starts_with("number_of_objects: "));
+    }
+
+    #[test]
+    fn when_content_is_dumped_then_the_merged_json_is_written() {
+        let file_path = write_test_file("dump.json", &create_test_json());
+
+        let (exit_code, output) = run_test_command("dump", &file_path);
+
+        fs::remove_file(&file_path).unwrap();
+
+        let result = serde_json::from_str::<Value>(&output).unwrap();
+
+        assert_eq!(0, exit_code);
+        assert_eq!(create_test_json(), result);
+    }
+
+    #[test]
+    fn when_missing_content_is_dumped_then_the_error_is_reported() {
+        let (exit_code, output) = run_test_command("dump", "missing-garden-cli-content.json");
+
+        assert_eq!(1, exit_code);
+        assert!(output.starts_with("error: could not read missing-garden-cli-content.json"));
+    }
+
+    #[test]
+    fn when_an_unknown_command_is_run_then_the_usage_is_written() {
+        let (exit_code, output) = run_test_command("draw", "content.json");
+
+        assert_eq!(2, exit_code);
+        assert_eq!(format!("{USAGE}\n"), output);
+    }
+}
@@ -0,0 +1,15 @@
+use std::{env, io, process};
+
+use garden_cli::run_command;
+
+fn main() {
+    let arguments = env::args().skip(1).collect::<Vec<String>>();
+
+    match run_command(&arguments, &mut io::stdout()) {
+        Ok(exit_code) => process::exit(exit_code),
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+}
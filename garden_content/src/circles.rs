@@ -1,13 +1,19 @@
 use std::{cell::RefCell, marker::PhantomData, rc::Rc};
 
 use garden::GetName;
-use garden_maths::trigonometry::CalculateTrigonometry;
+use garden_json::{convert_f32_to_json, ConvertToJson};
+use garden_maths::trigonometry::{
+    CalculateTrigonometry, MAXIMUM_CIRCLE_SEGMENTS, MINIMUM_CIRCLE_SEGMENTS,
+};
+use serde_json::{json, Value};
 
 use crate::{
+    meshes::IndexedMesh,
+    transforms::{convert_object_instance_to_json, GetTransform, SetTransform, Transform},
     triangles::{ConstructGeometryTriangle, CreateGeometryTriangles},
-    ConstructObject, CreateObject, CreateTrianglePoint, CreateTwoDPoint, Get2DCoordiantes, GetB,
-    GetContentInstanceData, GetG, GetNumberOfObjects, GetNumberOfVertices, GetPosition, GetR,
-    GetRgb, GetRgbValues, GetScale, GetVertexData, Rgb, ScaleObjectInstance,
+    ConstructObject, CreateTrianglePoint, GetB, GetContentInstanceData, GetG, GetIndexData,
+    GetNumberOfObjects, GetNumberOfVertices, GetR, GetRgb, GetRgbValues, GetVertexData,
+    GetVertexDataVersion, Rgb,
 };
 
 pub trait GetDiameter {
@@ -22,11 +28,28 @@ pub trait GetCircle<TCircle> {
     fn get_circle(&self) -> Rc<RefCell<TCircle>>;
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CircleTessellation {
+    Segments(u32),
+    Tolerance(f32),
+}
+
+impl Default for CircleTessellation {
+    fn default() -> Self {
+        CircleTessellation::Segments(MAXIMUM_CIRCLE_SEGMENTS)
+    }
+}
+
+pub trait GetCircleTessellation {
+    fn get_circle_tessellation(&self) -> CircleTessellation;
+}
+
 pub struct Circle<TRgb> {
     name: String,
     diameter: f32,
     radius: f32,
     rgb: TRgb,
+    tessellation: Option<CircleTessellation>,
 }
 
 impl<TRgb> Circle<TRgb> {
@@ -36,8 +59,13 @@ impl<TRgb> Circle<TRgb> {
             diameter: diameter,
             radius: diameter / 2.0,
             rgb: rgb,
+            tessellation: None,
         }
     }
+
+    pub fn set_circle_tessellation(&mut self, tessellation: CircleTessellation) {
+        self.tessellation = Some(tessellation);
+    }
 }
 
 impl<TRgb> GetName for Circle<TRgb> {
@@ -54,7 +82,13 @@ impl<TRgb> GetDiameter for Circle<TRgb> {
 
 impl<TRgb> GetRadius for Circle<TRgb> {
     fn get_radius(&self) -> f32 {
-        self.radius / 10.0
+        self.radius
+    }
+}
+
+impl<TRgb> GetCircleTessellation for Circle<TRgb> {
+    fn get_circle_tessellation(&self) -> CircleTessellation {
+        self.tessellation.unwrap_or_default()
     }
 }
 
@@ -84,10 +118,34 @@ impl<TRgb: GetB> GetB for Circle<TRgb> {
 
 impl<TRgb: GetRgbValues> GetRgbValues for Circle<TRgb> {}
 
+impl<TRgb: ConvertToJson> ConvertToJson for Circle<TRgb> {
+    fn convert_to_json(&self) -> Value {
+        let mut json = json!({
+            "name": self.name,
+            "type": "circle",
+            "diameter": convert_f32_to_json(self.diameter),
+            "rgb": self.rgb.convert_to_json()
+        });
+
+        match self.tessellation {
+            Some(CircleTessellation::Segments(segments)) => {
+                json["segments"] = json!(segments);
+            }
+            Some(CircleTessellation::Tolerance(tolerance)) => {
+                json["tolerance"] = convert_f32_to_json(tolerance);
+            }
+            None => {}
+        }
+
+        json
+    }
+}
+
 pub struct CircleParameters<TRgb> {
     name: String,
     diameter: f32,
     rgb: TRgb,
+    tessellation: Option<CircleTessellation>,
 }
 
 impl<TRgb> CircleParameters<TRgb> {
@@ -96,8 +154,13 @@ impl<TRgb> CircleParameters<TRgb> {
             name,
             diameter,
             rgb,
+            tessellation: None,
         }
     }
+
+    pub fn set_circle_tessellation(&mut self, tessellation: CircleTessellation) {
+        self.tessellation = Some(tessellation);
+    }
 }
 
 pub struct CircleConstructor {}
@@ -110,68 +173,129 @@ impl CircleConstructor {
 
 impl<TRgb> ConstructObject<Circle<TRgb>, CircleParameters<TRgb>> for CircleConstructor {
     fn construct_object(&self, parameters: CircleParameters<TRgb>) -> Circle<TRgb> {
-        Circle::new(parameters.name, parameters.diameter, parameters.rgb)
+        let mut circle = Circle::new(parameters.name, parameters.diameter, parameters.rgb);
+
+        if let Some(tessellation) = parameters.tessellation {
+            circle.set_circle_tessellation(tessellation);
+        }
+
+        circle
     }
 }
 
-pub struct CircleInstanceParameters<TCircle, TTwoDPoint> {
+pub struct CircleInstanceParameters<TCircle> {
     name: String,
     circle: Rc<RefCell<TCircle>>,
-    scale: f32,
-    position: TTwoDPoint,
+    transform: Transform,
     diameter: f32,
 }
 
-impl<TCircle, TTwoDPoint> CircleInstanceParameters<TCircle, TTwoDPoint> {
+impl<TCircle> CircleInstanceParameters<TCircle> {
     pub fn new(
         name: String,
         circle: Rc<RefCell<TCircle>>,
-        scale: f32,
-        position: TTwoDPoint,
+        transform: Transform,
         diameter: f32,
     ) -> Self {
         Self {
             name,
             circle,
-            scale,
-            position,
+            transform,
             diameter,
         }
     }
 }
 
-pub struct CircleInstance<TPosition, TCircle, TGeometryTriangle> {
+pub struct CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> {
     name: String,
     circle: Rc<RefCell<TCircle>>,
-    scale: f32,
-    position: TPosition,
+    transform: Transform,
+    diameter: f32,
+    tessellation_diameter: f32,
+    geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
     number_of_vertices: i32,
     vertex_data: Vec<f32>,
+    index_data: Vec<u32>,
     geometry_triangles: Vec<TGeometryTriangle>,
+    vertex_data_version: u64,
     number_of_objects: i32,
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> CircleInstance<TPosition, TCircle, TGeometryTriangle> {
+impl<
+        TCircle: GetRgbValues,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCircle>,
+        TGeometryTriangle: GetNumberOfVertices + GetVertexData,
+    > CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
+{
     pub fn new(
         name: String,
         circle: Rc<RefCell<TCircle>>,
-        scale: f32,
-        position: TPosition,
-        number_of_vertices: i32,
-        vertex_data: Vec<f32>,
-        geometry_triangles: Vec<TGeometryTriangle>,
-        number_of_objects: i32,
+        transform: Transform,
+        diameter: f32,
+        geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
     ) -> Self {
-        Self {
+        let mut circle_instance = Self {
             name,
             circle,
-            scale,
-            position,
-            vertex_data,
-            number_of_vertices,
-            geometry_triangles,
-            number_of_objects,
+            transform,
+            diameter,
+            tessellation_diameter: calculate_tessellation_diameter(diameter, &transform),
+            geometry_triangles_creator,
+            number_of_vertices: 0,
+            vertex_data: vec![],
+            index_data: vec![],
+            geometry_triangles: vec![],
+            vertex_data_version: 0,
+            number_of_objects: 0,
+        };
+
+        circle_instance.tessellate();
+
+        circle_instance
+    }
+
+    fn tessellate(&mut self) {
+        let mut triangle_vertex_data = vec![];
+
+        let geometry_triangles = self.geometry_triangles_creator.create_geometry_triangles(
+            &self.circle.borrow(),
+            self.tessellation_diameter,
+            self.tessellation_diameter,
+        );
+
+        for geometry_triangle in geometry_triangles.iter() {
+            triangle_vertex_data.append(&mut geometry_triangle.get_vertex_data());
         }
+
+        let indexed_mesh = IndexedMesh::from_triangle_vertex_data(&triangle_vertex_data);
+
+        self.number_of_vertices = indexed_mesh.get_number_of_vertices();
+        self.number_of_objects = geometry_triangles.len() as i32;
+
+        (self.vertex_data, self.index_data) = indexed_mesh.into_data();
+        self.geometry_triangles = geometry_triangles;
+        self.vertex_data_version += 1;
+    }
+}
+
+fn calculate_tessellation_diameter(diameter: f32, transform: &Transform) -> f32 {
+    diameter
+        * transform
+            .get_scale_x()
+            .abs()
+            .max(transform.get_scale_y().abs())
+}
+
+impl<TCircle: GetName, TGeometryTrianglesCreator, TGeometryTriangle> ConvertToJson
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn convert_to_json(&self) -> Value {
+        convert_object_instance_to_json(
+            &self.name,
+            self.circle.borrow().get_name(),
+            "circle",
+            &self.transform,
+        )
     }
 }
 
@@ -193,203 +317,153 @@ impl<TGeometryTrianglesCreator, TGeometryTriangle>
 
 impl<
         TCircle: GetRgbValues,
-        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCircle, TTwoDPoint>,
-        TTwoDPoint: Get2DCoordiantes,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCircle>,
         TGeometryTriangle: GetNumberOfVertices + GetVertexData,
     >
     ConstructObject<
-        CircleInstance<TTwoDPoint, TCircle, TGeometryTriangle>,
-        CircleInstanceParameters<TCircle, TTwoDPoint>,
+        CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>,
+        CircleInstanceParameters<TCircle>,
     > for CircleInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn construct_object(
         &self,
-        parameters: CircleInstanceParameters<TCircle, TTwoDPoint>,
-    ) -> CircleInstance<TTwoDPoint, TCircle, TGeometryTriangle> {
-        let mut vertex_data = vec![];
-
-        let mut number_of_vertices = 0;
-
-        let geometry_triangles = self.geometry_triangles_creator.create_geometry_triangles(
-            &parameters.circle.borrow(),
-            &parameters.position,
-            parameters.diameter,
-            parameters.diameter,
-        );
-
-        for geometry_triangle in geometry_triangles.iter() {
-            number_of_vertices += geometry_triangle.get_number_of_vertices();
-            vertex_data.append(&mut geometry_triangle.get_vertex_data());
-        }
-
+        parameters: CircleInstanceParameters<TCircle>,
+    ) -> CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> {
         CircleInstance::new(
             parameters.name,
             parameters.circle,
-            parameters.scale,
-            parameters.position,
-            number_of_vertices,
-            vertex_data,
-            geometry_triangles,
-            360,
+            parameters.transform,
+            parameters.diameter,
+            Rc::clone(&self.geometry_triangles_creator),
         )
     }
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetName
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetName
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetVertexData
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetVertexData
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_vertex_data(&self) -> Vec<f32> {
         self.vertex_data.clone()
     }
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetNumberOfVertices
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetIndexData
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_index_data(&self) -> Vec<u32> {
+        self.index_data.clone()
+    }
+}
+
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetNumberOfVertices
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_number_of_vertices(&self) -> i32 {
         self.number_of_vertices
     }
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetNumberOfObjects
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetNumberOfObjects
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_number_of_objects(&self) -> i32 {
         self.number_of_objects
     }
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetContentInstanceData
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetContentInstanceData
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetScale
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetVertexDataVersion
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
-    fn get_scale(&self) -> f32 {
-        self.scale
+    fn get_vertex_data_version(&self) -> u64 {
+        self.vertex_data_version
     }
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetPosition<TPosition>
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetTransform
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
-    fn get_position(&self) -> &TPosition {
-        &self.position
+    fn get_transform(&self) -> Transform {
+        self.transform
     }
 }
 
-impl<TPosition, TCircle: GetDiameter, TGeometryTriangle> GetDiameter
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<
+        TCircle: GetRgbValues,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCircle>,
+        TGeometryTriangle: GetNumberOfVertices + GetVertexData,
+    > SetTransform for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+
+        let tessellation_diameter = calculate_tessellation_diameter(self.diameter, &transform);
+
+        if tessellation_diameter != self.tessellation_diameter {
+            self.tessellation_diameter = tessellation_diameter;
+
+            self.tessellate();
+        }
+    }
+}
+
+impl<TCircle: GetDiameter, TGeometryTrianglesCreator, TGeometryTriangle> GetDiameter
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_diameter(&self) -> f32 {
         self.circle.borrow().get_diameter()
     }
 }
 
-impl<TPosition, TCircle: GetRgb<Rgb>, TGeometryTriangle> GetR
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetR
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_r(&self) -> f32 {
         self.circle.borrow().get_rgb().get_r()
     }
 }
 
-impl<TPosition, TCircle: GetRgb<Rgb>, TGeometryTriangle> GetG
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetG
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_g(&self) -> f32 {
         self.circle.borrow().get_rgb().get_g()
     }
 }
 
-impl<TPosition, TCircle: GetRgb<Rgb>, TGeometryTriangle> GetB
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetB
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_b(&self) -> f32 {
         self.circle.borrow().get_rgb().get_b()
     }
 }
 
-impl<TPosition, TCircle: GetRgb<Rgb>, TGeometryTriangle> GetRgbValues
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetRgbValues
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
 }
 
-impl<TPosition, TCircle, TGeometryTriangle> GetCircle<TCircle>
-    for CircleInstance<TPosition, TCircle, TGeometryTriangle>
+impl<TCircle, TGeometryTrianglesCreator, TGeometryTriangle> GetCircle<TCircle>
+    for CircleInstance<TCircle, TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn get_circle(&self) -> Rc<RefCell<TCircle>> {
         Rc::clone(&self.circle)
     }
 }
 
-pub struct CircleInstanceScaler<TCircleInstanceCreator, TTwoDPointCreator, TTwoDPoint, TCircle> {
-    circle_instance_creator: Rc<TCircleInstanceCreator>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
-    two_d_point_type: PhantomData<TTwoDPoint>,
-    circle_type: PhantomData<TCircle>,
-}
-
-impl<TCircleInstanceCreator, TTwoDPointCreator, TTwoDPoint, TCircle>
-    CircleInstanceScaler<TCircleInstanceCreator, TTwoDPointCreator, TTwoDPoint, TCircle>
-{
-    pub fn new(
-        circle_instance_creator: Rc<TCircleInstanceCreator>,
-        two_d_point_creator: Rc<TTwoDPointCreator>,
-    ) -> Self {
-        Self {
-            circle_instance_creator: circle_instance_creator,
-            two_d_point_creator: two_d_point_creator,
-            two_d_point_type: PhantomData,
-            circle_type: PhantomData,
-        }
-    }
-}
-
-impl<
-        TCircleInstance: GetName
-            + GetScale
-            + GetPosition<TTwoDPoint>
-            + GetDiameter
-            + GetRgbValues
-            + GetCircle<TCircle>,
-        TCircleInstanceCreator: CreateObject<TCircleInstance, CircleInstanceParameters<TCircle, TTwoDPoint>>,
-        TTwoDPointCreator: CreateTwoDPoint<TTwoDPoint>,
-        TTwoDPoint: Get2DCoordiantes,
-        TCircle,
-    > ScaleObjectInstance<TCircleInstance>
-    for CircleInstanceScaler<TCircleInstanceCreator, TTwoDPointCreator, TTwoDPoint, TCircle>
-{
-    fn scale_object_instance(
-        &self,
-        circle_instance: Rc<RefCell<TCircleInstance>>,
-        x: f32,
-        y: f32,
-    ) -> Rc<RefCell<TCircleInstance>> {
-        self.circle_instance_creator
-            .create_object(CircleInstanceParameters::new(
-                circle_instance.borrow().get_name().to_string(),
-                circle_instance.borrow().get_circle(),
-                circle_instance.borrow().get_scale(),
-                self.two_d_point_creator.create_two_d_point(
-                    circle_instance.borrow().get_position().get_x() / x,
-                    circle_instance.borrow().get_position().get_y() / y,
-                ),
-                circle_instance.borrow().get_diameter() / x,
-            ))
-    }
-}
-
 pub struct CircleGeometryTrianglesCreator<
     TGeometryTriangleConstructor,
     TTrianglePointCreator,
@@ -430,14 +504,13 @@ impl<
 }
 
 impl<
-        TObject: GetRgbValues + GetRadius,
-        TPosition: Get2DCoordiantes,
+        TObject: GetRgbValues + GetRadius + GetCircleTessellation,
         TGeometryTriangle,
         TGeometryTriangleConstructor: ConstructGeometryTriangle<TGeometryTriangle, TTrianglePoint>,
         TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
         TTrigonometryCalculator: CalculateTrigonometry,
         TTrianglePoint,
-    > CreateGeometryTriangles<TGeometryTriangle, TObject, TPosition>
+    > CreateGeometryTriangles<TGeometryTriangle, TObject>
     for CircleGeometryTrianglesCreator<
         TGeometryTriangleConstructor,
         TTrianglePointCreator,
@@ -448,7 +521,6 @@ impl<
     fn create_geometry_triangles(
         &self,
         object: &TObject,
-        position: &TPosition,
         width: f32,
         height: f32,
     ) -> Vec<TGeometryTriangle> {
@@ -457,7 +529,7 @@ impl<
             Rc::clone(&self.triangle_point_creator),
             Rc::clone(&self.trigonometry_calculator),
             object,
-            position,
+            width.max(height) / 2.0,
         );
 
         builder.build()
@@ -471,25 +543,15 @@ pub struct CircleGeometryBuilder<
     TTrigonometryCalculator,
     TGeometryTriangle,
     TObject,
-    TPosition,
     TTrianglePoint,
 > {
     geometry_triangle_constructor: Rc<TGeometryTriangleConstructor>,
     triangle_point_creator: Rc<TTrianglePointCreator>,
     trigonometry_calculator: Rc<TTrigonometryCalculator>,
     object: &'a TObject,
-    position: &'a TPosition,
+    tessellation_radius: f32,
     geometry_triangle_type: PhantomData<TGeometryTriangle>,
     triangle_point_type: PhantomData<TTrianglePoint>,
-    point_1_x: f32,
-    point_1_y: f32,
-    point_2_x: f32,
-    point_2_y: f32,
-    point_3_x: f64,
-    point_3_y: f64,
-    triangle: i32,
-    next: i32,
-    geometry_triangles: Vec<TGeometryTriangle>,
 }
 
 impl<
@@ -498,8 +560,7 @@ impl<
         TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
         TTrigonometryCalculator: CalculateTrigonometry,
         TGeometryTriangle,
-        TObject: GetRgbValues + GetRadius,
-        TPosition: Get2DCoordiantes,
+        TObject: GetRgbValues + GetRadius + GetCircleTessellation,
         TTrianglePoint,
     >
     CircleGeometryBuilder<
@@ -509,7 +570,6 @@ impl<
         TTrigonometryCalculator,
         TGeometryTriangle,
         TObject,
-        TPosition,
         TTrianglePoint,
     >
 {
@@ -518,127 +578,254 @@ impl<
         triangle_point_creator: Rc<TTrianglePointCreator>,
         trigonometry_calculator: Rc<TTrigonometryCalculator>,
         object: &'a TObject,
-        position: &'a TPosition,
+        tessellation_radius: f32,
     ) -> Self {
         Self {
             geometry_triangle_constructor: geometry_triangle_constructor,
             triangle_point_creator: triangle_point_creator,
             trigonometry_calculator: trigonometry_calculator,
             object: object,
-            position: position,
+            tessellation_radius: tessellation_radius,
             geometry_triangle_type: PhantomData,
             triangle_point_type: PhantomData,
-            point_1_x: 0.0,
-            point_1_y: 0.0,
-            point_2_x: 0.0,
-            point_2_y: 0.0,
-            point_3_x: position.get_x() as f64 + object.get_radius() as f64,
-            point_3_y: position.get_y() as f64,
-            triangle: 0,
-            next: 1,
-            geometry_triangles: vec![],
         }
     }
 
-    fn build(mut self) -> Vec<TGeometryTriangle> {
-        self.point_1_x = self.position.get_x();
-        self.point_1_y = self.position.get_y();
-
-        while self.triangle < 360 {
-            let point_1 = self.triangle_point_creator.create_triangle_point(
-                self.point_1_x,
-                self.point_1_y,
-                self.object.get_r(),
-                self.object.get_g(),
-                self.object.get_b(),
-            );
-
-            self.point_2_x = self.point_3_x as f32;
-            self.point_2_y = self.point_3_y as f32;
-
-            let point_2 = self.triangle_point_creator.create_triangle_point(
-                self.point_2_x,
-                self.point_2_y,
-                self.object.get_r(),
-                self.object.get_g(),
-                self.object.get_b(),
-            );
-
-            if self.triangle < 90 {
-                let radians = self
-                    .trigonometry_calculator
-                    .convert_degrees_to_radians(self.next as f64);
-
-                self.point_3_x = self
-                    .trigonometry_calculator
-                    .calculate_adjacent(self.object.get_radius() as f64, radians)
-                    + self.position.get_x() as f64;
-                self.point_3_y = self
-                    .trigonometry_calculator
-                    .calculate_opposite(self.object.get_radius() as f64, radians)
-                    + self.position.get_y() as f64;
-            } else if self.triangle < 180 {
-                let radians = self
-                    .trigonometry_calculator
-                    .convert_degrees_to_radians((self.next - 90) as f64);
-
-                self.point_3_x = self.position.get_x() as f64
-                    - self
-                        .trigonometry_calculator
-                        .calculate_opposite(self.object.get_radius() as f64, radians);
-                self.point_3_y = self
-                    .trigonometry_calculator
-                    .calculate_adjacent(self.object.get_radius() as f64, radians)
-                    + self.position.get_y() as f64;
-            } else if self.triangle < 270 {
-                let radians = self
-                    .trigonometry_calculator
-                    .convert_degrees_to_radians((self.next - 180) as f64);
-
-                self.point_3_x = self.position.get_x() as f64
-                    - self
-                        .trigonometry_calculator
-                        .calculate_adjacent(self.object.get_radius() as f64, radians);
-                self.point_3_y = self.position.get_y() as f64
-                    - self
-                        .trigonometry_calculator
-                        .calculate_opposite(self.object.get_radius() as f64, radians);
-            } else if self.triangle < 359 {
-                let radians = self
-                    .trigonometry_calculator
-                    .convert_degrees_to_radians((self.next - 270) as f64);
-
-                self.point_3_x = self.position.get_x() as f64
-                    + self
-                        .trigonometry_calculator
-                        .calculate_adjacent(self.object.get_radius() as f64, radians);
-                self.point_3_y = self.position.get_y() as f64
-                    - self
-                        .trigonometry_calculator
-                        .calculate_opposite(self.object.get_radius() as f64, radians);
-            } else if self.triangle == 359 {
-                self.point_3_x = self.position.get_x() as f64 + self.object.get_radius() as f64;
-                self.point_3_y = self.position.get_y() as f64;
-            }
+    fn calculate_segments(&self) -> u32 {
+        match self.object.get_circle_tessellation() {
+            CircleTessellation::Segments(segments) => segments.max(MINIMUM_CIRCLE_SEGMENTS),
+            CircleTessellation::Tolerance(tolerance) => self
+                .trigonometry_calculator
+                .calculate_circle_segments(self.tessellation_radius as f64, tolerance as f64),
+        }
+    }
 
-            let point_3 = self.triangle_point_creator.create_triangle_point(
-                self.point_3_x as f32,
-                self.point_3_y as f32,
-                self.object.get_r(),
-                self.object.get_g(),
-                self.object.get_b(),
-            );
+    fn calculate_point(&self, segment: u32, segments: u32) -> (f32, f32) {
+        let radius = self.object.get_radius() as f64;
+
+        if segment % segments == 0 {
+            return (radius as f32, 0.0);
+        }
+
+        let radians = self
+            .trigonometry_calculator
+            .convert_degrees_to_radians(360.0 * segment as f64 / segments as f64);
+
+        (
+            self.trigonometry_calculator
+                .calculate_adjacent(radius, radians) as f32,
+            self.trigonometry_calculator
+                .calculate_opposite(radius, radians) as f32,
+        )
+    }
+
+    fn create_triangle_point(&self, (x, y): (f32, f32)) -> TTrianglePoint {
+        self.triangle_point_creator.create_triangle_point(
+            x,
+            y,
+            self.object.get_r(),
+            self.object.get_g(),
+            self.object.get_b(),
+        )
+    }
+
+    fn build(self) -> Vec<TGeometryTriangle> {
+        let segments = self.calculate_segments();
+
+        let mut geometry_triangles = vec![];
+
+        let mut point_2 = self.calculate_point(0, segments);
+
+        for segment in 1..=segments {
+            let point_3 = self.calculate_point(segment, segments);
 
             let geometry_triangle = self
                 .geometry_triangle_constructor
-                .construct_geometry_triangle(point_1, point_2, point_3);
+                .construct_geometry_triangle(
+                    self.create_triangle_point((0.0, 0.0)),
+                    self.create_triangle_point(point_2),
+                    self.create_triangle_point(point_3),
+                );
+
+            geometry_triangles.push(geometry_triangle);
 
-            self.geometry_triangles.push(geometry_triangle);
+            point_2 = point_3;
+        }
+
+        geometry_triangles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use garden_json::ConvertToJson;
+    use garden_maths::trigonometry::{
+        CalculateAdjacent, CalculateCircleSegments, CalculateOpposite, CalculateTrigonometry,
+        ConvertDegreesToRadians,
+    };
+    use mockall::{mock, predicate::eq};
+    use serde_json::json;
+
+    use crate::{
+        circles::{Circle, CircleGeometryTrianglesCreator, CircleTessellation},
+        triangles::{ConstructGeometryTriangle, CreateGeometryTriangles},
+        CreateTrianglePoint, Rgb,
+    };
+
+    #[test]
+    fn when_a_circle_with_a_segment_count_is_converted_to_triangles_then_there_is_one_triangle_per_segment(
+    ) {
+        let mut circle = Circle::new("Circle".to_string(), 2.0, Rgb::new(0.5, 0.5, 0.5));
+
+        circle.set_circle_tessellation(CircleTessellation::Segments(4));
+
+        let mut trigonometry_calculator = MockTrigonometryCalculator::new();
+
+        trigonometry_calculator
+            .expect_calculate_circle_segments()
+            .never();
+        trigonometry_calculator
+            .expect_convert_degrees_to_radians()
+            .times(3)
+            .returning(|degrees| degrees.to_radians());
+        trigonometry_calculator
+            .expect_calculate_adjacent()
+            .times(3)
+            .returning(|hypotenuse, angle_radians| (angle_radians.cos() * hypotenuse).round());
+        trigonometry_calculator
+            .expect_calculate_opposite()
+            .times(3)
+            .returning(|hypotenuse, angle_radians| (angle_radians.sin() * hypotenuse).round());
+
+        let result = create_geometry_triangles(&circle, trigonometry_calculator);
+
+        assert_eq!(
+            vec![
+                [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+                [(0.0, 0.0), (0.0, 1.0), (-1.0, 0.0)],
+                [(0.0, 0.0), (-1.0, 0.0), (0.0, -1.0)],
+                [(0.0, 0.0), (0.0, -1.0), (1.0, 0.0)],
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn when_a_circle_with_a_tolerance_is_converted_to_triangles_then_the_trigonometry_calculator_chooses_the_segment_count(
+    ) {
+        let mut circle = Circle::new("Circle".to_string(), 2.0, Rgb::new(0.5, 0.5, 0.5));
+
+        circle.set_circle_tessellation(CircleTessellation::Tolerance(0.25));
+
+        let mut trigonometry_calculator = MockTrigonometryCalculator::new();
+
+        trigonometry_calculator
+            .expect_calculate_circle_segments()
+            .with(eq(1.0), eq(0.25))
+            .times(1)
+            .returning(|_, _| 6);
+        trigonometry_calculator
+            .expect_convert_degrees_to_radians()
+            .returning(|degrees| degrees.to_radians());
+        trigonometry_calculator
+            .expect_calculate_adjacent()
+            .returning(|hypotenuse, angle_radians| angle_radians.cos() * hypotenuse);
+        trigonometry_calculator
+            .expect_calculate_opposite()
+            .returning(|hypotenuse, angle_radians| angle_radians.sin() * hypotenuse);
+
+        let result = create_geometry_triangles(&circle, trigonometry_calculator);
+
+        assert_eq!(6, result.len());
+        assert_eq!((1.0, 0.0), result[5][2]);
+    }
+
+    #[test]
+    fn when_a_circle_without_a_tessellation_is_converted_to_triangles_then_there_are_360_triangles()
+    {
+        let circle = Circle::new("Circle".to_string(), 2.0, Rgb::new(0.5, 0.5, 0.5));
+
+        let mut trigonometry_calculator = MockTrigonometryCalculator::new();
+
+        trigonometry_calculator
+            .expect_convert_degrees_to_radians()
+            .returning(|degrees| degrees.to_radians());
+        trigonometry_calculator
+            .expect_calculate_adjacent()
+            .returning(|hypotenuse, angle_radians| angle_radians.cos() * hypotenuse);
+        trigonometry_calculator
+            .expect_calculate_opposite()
+            .returning(|hypotenuse, angle_radians| angle_radians.sin() * hypotenuse);
+
+        let result = create_geometry_triangles(&circle, trigonometry_calculator);
+
+        assert_eq!(360, result.len());
+    }
+
+    #[test]
+    fn when_a_circle_with_a_tessellation_is_converted_to_json_then_the_tessellation_is_included() {
+        let mut circle = Circle::new("Circle".to_string(), 2.0, Rgb::new(0.5, 0.5, 0.5));
+
+        circle.set_circle_tessellation(CircleTessellation::Segments(32));
 
-            self.triangle = self.triangle + 1;
-            self.next = self.next + 1;
+        let result = circle.convert_to_json();
+
+        assert_eq!(json!(32), result["segments"]);
+        assert!(result["tolerance"].is_null());
+    }
+
+    type Point = (f32, f32);
+
+    struct PointCreator {}
+
+    impl CreateTrianglePoint<Point> for PointCreator {
+        fn create_triangle_point(&self, x: f32, y: f32, _r: f32, _g: f32, _b: f32) -> Point {
+            (x, y)
         }
+    }
+
+    struct TriangleConstructor {}
+
+    impl ConstructGeometryTriangle<[Point; 3], Point> for TriangleConstructor {
+        fn construct_geometry_triangle(
+            &self,
+            triangle_point_1: Point,
+            triangle_point_2: Point,
+            triangle_point_3: Point,
+        ) -> [Point; 3] {
+            [triangle_point_1, triangle_point_2, triangle_point_3]
+        }
+    }
 
-        return self.geometry_triangles;
+    fn create_geometry_triangles(
+        circle: &Circle<Rgb>,
+        trigonometry_calculator: MockTrigonometryCalculator,
+    ) -> Vec<[Point; 3]> {
+        let geometry_triangles_creator = CircleGeometryTrianglesCreator::new(
+            Rc::new(TriangleConstructor {}),
+            Rc::new(PointCreator {}),
+            Rc::new(trigonometry_calculator),
+        );
+
+        geometry_triangles_creator.create_geometry_triangles(circle, 2.0, 2.0)
+    }
+
+    mock! {
+        TrigonometryCalculator {}
+        impl ConvertDegreesToRadians for TrigonometryCalculator {
+            fn convert_degrees_to_radians(&self, degrees: f64) -> f64;
+        }
+        impl CalculateAdjacent for TrigonometryCalculator {
+            fn calculate_adjacent(&self, hypotenuse: f64, angle_radians: f64) -> f64;
+        }
+        impl CalculateOpposite for TrigonometryCalculator {
+            fn calculate_opposite(&self, hypotenuse: f64, angle_radians: f64) -> f64;
+        }
+        impl CalculateCircleSegments for TrigonometryCalculator {
+            fn calculate_circle_segments(&self, radius: f64, tolerance: f64) -> u32;
+        }
+        impl CalculateTrigonometry for TrigonometryCalculator {}
     }
 }
@@ -0,0 +1,919 @@
+use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+
+use garden::GetName;
+use garden_json::{convert_f32_to_json, ConvertToJson};
+use garden_maths::trigonometry::{CalculateTrigonometry, MINIMUM_CIRCLE_SEGMENTS};
+use serde_json::{json, Value};
+
+use crate::{
+    circles::{CircleTessellation, GetCircleTessellation},
+    meshes::IndexedMesh,
+    transforms::{convert_object_instance_to_json, GetTransform, SetTransform, Transform},
+    triangles::{ConstructGeometryTriangle, CreateGeometryTriangles},
+    ConstructObject, CreateTrianglePoint, GetB, GetContentInstanceData, GetG, GetIndexData,
+    GetNumberOfObjects, GetNumberOfVertices, GetR, GetRgb, GetRgbValues, GetVertexData,
+    GetVertexDataVersion, Rgb,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveShape {
+    Ellipse {
+        radius_x: f32,
+        radius_y: f32,
+    },
+    Arc {
+        inner_radius: f32,
+        outer_radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+    },
+    Sector {
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+    },
+    Ring {
+        inner_radius: f32,
+        outer_radius: f32,
+    },
+}
+
+impl CurveShape {
+    pub fn get_type(&self) -> &'static str {
+        match self {
+            CurveShape::Ellipse { .. } => "ellipse",
+            CurveShape::Arc { .. } => "arc",
+            CurveShape::Sector { .. } => "sector",
+            CurveShape::Ring { .. } => "ring",
+        }
+    }
+
+    fn get_outline(&self) -> CurveOutline {
+        match *self {
+            CurveShape::Ellipse { radius_x, radius_y } => {
+                CurveOutline::new((radius_x, radius_y), (0.0, 0.0), 0.0, 360.0)
+            }
+            CurveShape::Arc {
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+            } => CurveOutline::new(
+                (outer_radius, outer_radius),
+                (inner_radius, inner_radius),
+                start_angle,
+                end_angle,
+            ),
+            CurveShape::Sector {
+                radius,
+                start_angle,
+                end_angle,
+            } => CurveOutline::new((radius, radius), (0.0, 0.0), start_angle, end_angle),
+            CurveShape::Ring {
+                inner_radius,
+                outer_radius,
+            } => CurveOutline::new(
+                (outer_radius, outer_radius),
+                (inner_radius, inner_radius),
+                0.0,
+                360.0,
+            ),
+        }
+    }
+}
+
+impl ConvertToJson for CurveShape {
+    fn convert_to_json(&self) -> Value {
+        match *self {
+            CurveShape::Ellipse { radius_x, radius_y } => json!({
+                "radiusX": convert_f32_to_json(radius_x),
+                "radiusY": convert_f32_to_json(radius_y)
+            }),
+            CurveShape::Arc {
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+            } => json!({
+                "innerRadius": convert_f32_to_json(inner_radius),
+                "outerRadius": convert_f32_to_json(outer_radius),
+                "startAngle": convert_f32_to_json(start_angle),
+                "endAngle": convert_f32_to_json(end_angle)
+            }),
+            CurveShape::Sector {
+                radius,
+                start_angle,
+                end_angle,
+            } => json!({
+                "radius": convert_f32_to_json(radius),
+                "startAngle": convert_f32_to_json(start_angle),
+                "endAngle": convert_f32_to_json(end_angle)
+            }),
+            CurveShape::Ring {
+                inner_radius,
+                outer_radius,
+            } => json!({
+                "innerRadius": convert_f32_to_json(inner_radius),
+                "outerRadius": convert_f32_to_json(outer_radius)
+            }),
+        }
+    }
+}
+
+struct CurveOutline {
+    outer_radii: (f32, f32),
+    inner_radii: (f32, f32),
+    start_angle: f32,
+    end_angle: f32,
+}
+
+impl CurveOutline {
+    fn new(
+        outer_radii: (f32, f32),
+        inner_radii: (f32, f32),
+        start_angle: f32,
+        end_angle: f32,
+    ) -> Self {
+        Self {
+            outer_radii,
+            inner_radii,
+            start_angle,
+            end_angle,
+        }
+    }
+
+    fn is_filled(&self) -> bool {
+        self.inner_radii == (0.0, 0.0)
+    }
+
+    fn is_closed(&self) -> bool {
+        self.end_angle - self.start_angle >= 360.0
+    }
+}
+
+pub trait GetCurveShape {
+    fn get_curve_shape(&self) -> CurveShape;
+}
+
+pub trait GetCurve<TCurve> {
+    fn get_curve(&self) -> Rc<RefCell<TCurve>>;
+}
+
+pub struct Curve<TRgb> {
+    name: String,
+    shape: CurveShape,
+    rgb: TRgb,
+    tessellation: Option<CircleTessellation>,
+}
+
+impl<TRgb> Curve<TRgb> {
+    fn new(name: String, shape: CurveShape, rgb: TRgb) -> Self {
+        Self {
+            name,
+            shape,
+            rgb,
+            tessellation: None,
+        }
+    }
+
+    pub fn set_circle_tessellation(&mut self, tessellation: CircleTessellation) {
+        self.tessellation = Some(tessellation);
+    }
+}
+
+impl<TRgb> GetName for Curve<TRgb> {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<TRgb> GetCurveShape for Curve<TRgb> {
+    fn get_curve_shape(&self) -> CurveShape {
+        self.shape
+    }
+}
+
+impl<TRgb> GetCircleTessellation for Curve<TRgb> {
+    fn get_circle_tessellation(&self) -> CircleTessellation {
+        self.tessellation.unwrap_or_default()
+    }
+}
+
+impl<TRgb> GetRgb<TRgb> for Curve<TRgb> {
+    fn get_rgb(&self) -> &TRgb {
+        &self.rgb
+    }
+}
+
+impl<TRgb: GetR> GetR for Curve<TRgb> {
+    fn get_r(&self) -> f32 {
+        self.get_rgb().get_r()
+    }
+}
+
+impl<TRgb: GetG> GetG for Curve<TRgb> {
+    fn get_g(&self) -> f32 {
+        self.get_rgb().get_g()
+    }
+}
+
+impl<TRgb: GetB> GetB for Curve<TRgb> {
+    fn get_b(&self) -> f32 {
+        self.get_rgb().get_b()
+    }
+}
+
+impl<TRgb: GetRgbValues> GetRgbValues for Curve<TRgb> {}
+
+impl<TRgb: ConvertToJson> ConvertToJson for Curve<TRgb> {
+    fn convert_to_json(&self) -> Value {
+        let mut json = json!({
+            "name": self.name,
+            "type": self.shape.get_type()
+        });
+
+        if let Value::Object(shape_json) = self.shape.convert_to_json() {
+            for (key, value) in shape_json {
+                json[key] = value;
+            }
+        }
+
+        json["rgb"] = self.rgb.convert_to_json();
+
+        match self.tessellation {
+            Some(CircleTessellation::Segments(segments)) => {
+                json["segments"] = json!(segments);
+            }
+            Some(CircleTessellation::Tolerance(tolerance)) => {
+                json["tolerance"] = convert_f32_to_json(tolerance);
+            }
+            None => {}
+        }
+
+        json
+    }
+}
+
+pub struct CurveParameters<TRgb> {
+    name: String,
+    shape: CurveShape,
+    rgb: TRgb,
+    tessellation: Option<CircleTessellation>,
+}
+
+impl<TRgb> CurveParameters<TRgb> {
+    pub fn new(name: String, shape: CurveShape, rgb: TRgb) -> Self {
+        Self {
+            name,
+            shape,
+            rgb,
+            tessellation: None,
+        }
+    }
+
+    pub fn set_circle_tessellation(&mut self, tessellation: CircleTessellation) {
+        self.tessellation = Some(tessellation);
+    }
+}
+
+pub struct CurveConstructor {}
+
+impl CurveConstructor {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<TRgb> ConstructObject<Curve<TRgb>, CurveParameters<TRgb>> for CurveConstructor {
+    fn construct_object(&self, parameters: CurveParameters<TRgb>) -> Curve<TRgb> {
+        let mut curve = Curve::new(parameters.name, parameters.shape, parameters.rgb);
+
+        if let Some(tessellation) = parameters.tessellation {
+            curve.set_circle_tessellation(tessellation);
+        }
+
+        curve
+    }
+}
+
+pub struct CurveInstanceParameters<TCurve> {
+    name: String,
+    curve: Rc<RefCell<TCurve>>,
+    transform: Transform,
+}
+
+impl<TCurve> CurveInstanceParameters<TCurve> {
+    pub fn new(name: String, curve: Rc<RefCell<TCurve>>, transform: Transform) -> Self {
+        Self {
+            name,
+            curve,
+            transform,
+        }
+    }
+}
+
+pub struct CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> {
+    name: String,
+    curve: Rc<RefCell<TCurve>>,
+    transform: Transform,
+    tessellation_size: (f32, f32),
+    geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
+    number_of_vertices: i32,
+    vertex_data: Vec<f32>,
+    index_data: Vec<u32>,
+    geometry_triangles: Vec<TGeometryTriangle>,
+    vertex_data_version: u64,
+}
+
+impl<
+        TCurve: GetCurveShape,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCurve>,
+        TGeometryTriangle: GetVertexData,
+    > CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    pub fn new(
+        name: String,
+        curve: Rc<RefCell<TCurve>>,
+        transform: Transform,
+        geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
+    ) -> Self {
+        let tessellation_size =
+            calculate_tessellation_size(curve.borrow().get_curve_shape(), &transform);
+
+        let mut curve_instance = Self {
+            name,
+            curve,
+            transform,
+            tessellation_size,
+            geometry_triangles_creator,
+            number_of_vertices: 0,
+            vertex_data: vec![],
+            index_data: vec![],
+            geometry_triangles: vec![],
+            vertex_data_version: 0,
+        };
+
+        curve_instance.tessellate();
+
+        curve_instance
+    }
+
+    fn tessellate(&mut self) {
+        let mut triangle_vertex_data = vec![];
+
+        let geometry_triangles = self.geometry_triangles_creator.create_geometry_triangles(
+            &self.curve.borrow(),
+            self.tessellation_size.0,
+            self.tessellation_size.1,
+        );
+
+        for geometry_triangle in geometry_triangles.iter() {
+            triangle_vertex_data.append(&mut geometry_triangle.get_vertex_data());
+        }
+
+        let indexed_mesh = IndexedMesh::from_triangle_vertex_data(&triangle_vertex_data);
+
+        self.number_of_vertices = indexed_mesh.get_number_of_vertices();
+
+        (self.vertex_data, self.index_data) = indexed_mesh.into_data();
+        self.geometry_triangles = geometry_triangles;
+        self.vertex_data_version += 1;
+    }
+}
+
+fn calculate_tessellation_size(curve_shape: CurveShape, transform: &Transform) -> (f32, f32) {
+    let outline = curve_shape.get_outline();
+
+    (
+        outline.outer_radii.0 * 2.0 * transform.get_scale_x().abs(),
+        outline.outer_radii.1 * 2.0 * transform.get_scale_y().abs(),
+    )
+}
+
+impl<TCurve: GetName + GetCurveShape, TGeometryTrianglesCreator, TGeometryTriangle> ConvertToJson
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn convert_to_json(&self) -> Value {
+        let curve = self.curve.borrow();
+
+        convert_object_instance_to_json(
+            &self.name,
+            curve.get_name(),
+            curve.get_curve_shape().get_type(),
+            &self.transform,
+        )
+    }
+}
+
+pub struct CurveInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle> {
+    geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
+    geometry_triangle_type: PhantomData<TGeometryTriangle>,
+}
+
+impl<TGeometryTrianglesCreator, TGeometryTriangle>
+    CurveInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    pub fn new(geometry_triangles_creator: Rc<TGeometryTrianglesCreator>) -> Self {
+        Self {
+            geometry_triangles_creator,
+            geometry_triangle_type: PhantomData,
+        }
+    }
+}
+
+impl<
+        TCurve: GetCurveShape,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCurve>,
+        TGeometryTriangle: GetVertexData,
+    >
+    ConstructObject<
+        CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>,
+        CurveInstanceParameters<TCurve>,
+    > for CurveInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn construct_object(
+        &self,
+        parameters: CurveInstanceParameters<TCurve>,
+    ) -> CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> {
+        CurveInstance::new(
+            parameters.name,
+            parameters.curve,
+            parameters.transform,
+            Rc::clone(&self.geometry_triangles_creator),
+        )
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetName
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetVertexData
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_vertex_data(&self) -> Vec<f32> {
+        self.vertex_data.clone()
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetIndexData
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_index_data(&self) -> Vec<u32> {
+        self.index_data.clone()
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetNumberOfVertices
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_number_of_vertices(&self) -> i32 {
+        self.number_of_vertices
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetNumberOfObjects
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_number_of_objects(&self) -> i32 {
+        self.geometry_triangles.len() as i32
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetContentInstanceData
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetVertexDataVersion
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_vertex_data_version(&self) -> u64 {
+        self.vertex_data_version
+    }
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetTransform
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+}
+
+impl<
+        TCurve: GetCurveShape,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TCurve>,
+        TGeometryTriangle: GetVertexData,
+    > SetTransform for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+
+        let tessellation_size =
+            calculate_tessellation_size(self.curve.borrow().get_curve_shape(), &transform);
+
+        if tessellation_size != self.tessellation_size {
+            self.tessellation_size = tessellation_size;
+
+            self.tessellate();
+        }
+    }
+}
+
+impl<TCurve: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetR
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_r(&self) -> f32 {
+        self.curve.borrow().get_rgb().get_r()
+    }
+}
+
+impl<TCurve: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetG
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_g(&self) -> f32 {
+        self.curve.borrow().get_rgb().get_g()
+    }
+}
+
+impl<TCurve: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetB
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_b(&self) -> f32 {
+        self.curve.borrow().get_rgb().get_b()
+    }
+}
+
+impl<TCurve: GetRgb<Rgb>, TGeometryTrianglesCreator, TGeometryTriangle> GetRgbValues
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+}
+
+impl<TCurve, TGeometryTrianglesCreator, TGeometryTriangle> GetCurve<TCurve>
+    for CurveInstance<TCurve, TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn get_curve(&self) -> Rc<RefCell<TCurve>> {
+        Rc::clone(&self.curve)
+    }
+}
+
+pub struct CurveGeometryTrianglesCreator<
+    TGeometryTriangleConstructor,
+    TTrianglePointCreator,
+    TTrigonometryCalculator,
+    TTrianglePoint,
+> {
+    geometry_triangle_constructor: Rc<TGeometryTriangleConstructor>,
+    triangle_point_creator: Rc<TTrianglePointCreator>,
+    trigonometry_calculator: Rc<TTrigonometryCalculator>,
+    triangle_point_type: PhantomData<TTrianglePoint>,
+}
+
+impl<
+        TGeometryTriangleConstructor,
+        TTrianglePointCreator,
+        TTrigonometryCalculator,
+        TTrianglePoint,
+    >
+    CurveGeometryTrianglesCreator<
+        TGeometryTriangleConstructor,
+        TTrianglePointCreator,
+        TTrigonometryCalculator,
+        TTrianglePoint,
+    >
+{
+    pub fn new(
+        geometry_triangle_constructor: Rc<TGeometryTriangleConstructor>,
+        triangle_point_creator: Rc<TTrianglePointCreator>,
+        trigonometry_calculator: Rc<TTrigonometryCalculator>,
+    ) -> Self {
+        Self {
+            geometry_triangle_constructor,
+            triangle_point_creator,
+            trigonometry_calculator,
+            triangle_point_type: PhantomData,
+        }
+    }
+
+    fn calculate_segments<TObject: GetCircleTessellation>(
+        &self,
+        object: &TObject,
+        outline: &CurveOutline,
+        tessellation_radius: f32,
+    ) -> u32
+    where
+        TTrigonometryCalculator: CalculateTrigonometry,
+    {
+        let segments_per_turn = match object.get_circle_tessellation() {
+            CircleTessellation::Segments(segments) => segments.max(MINIMUM_CIRCLE_SEGMENTS),
+            CircleTessellation::Tolerance(tolerance) => self
+                .trigonometry_calculator
+                .calculate_circle_segments(tessellation_radius as f64, tolerance as f64),
+        };
+
+        if outline.is_closed() {
+            return segments_per_turn;
+        }
+
+        let turns = (outline.end_angle - outline.start_angle) / 360.0;
+
+        ((segments_per_turn as f32 * turns).ceil() as u32).max(1)
+    }
+
+    fn calculate_points(
+        &self,
+        radii: (f32, f32),
+        outline: &CurveOutline,
+        segments: u32,
+    ) -> Vec<(f32, f32)>
+    where
+        TTrigonometryCalculator: CalculateTrigonometry,
+    {
+        let mut points = vec![];
+
+        for segment in 0..=segments {
+            if segment == segments && outline.is_closed() {
+                points.push(points[0]);
+
+                break;
+            }
+
+            let degrees = outline.start_angle as f64
+                + (outline.end_angle - outline.start_angle) as f64 * segment as f64
+                    / segments as f64;
+
+            let radians = self
+                .trigonometry_calculator
+                .convert_degrees_to_radians(degrees);
+
+            points.push((
+                self.trigonometry_calculator
+                    .calculate_adjacent(radii.0 as f64, radians) as f32,
+                self.trigonometry_calculator
+                    .calculate_opposite(radii.1 as f64, radians) as f32,
+            ));
+        }
+
+        points
+    }
+}
+
+impl<
+        TObject: GetRgbValues + GetCurveShape + GetCircleTessellation,
+        TGeometryTriangle,
+        TGeometryTriangleConstructor: ConstructGeometryTriangle<TGeometryTriangle, TTrianglePoint>,
+        TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
+        TTrigonometryCalculator: CalculateTrigonometry,
+        TTrianglePoint,
+    > CreateGeometryTriangles<TGeometryTriangle, TObject>
+    for CurveGeometryTrianglesCreator<
+        TGeometryTriangleConstructor,
+        TTrianglePointCreator,
+        TTrigonometryCalculator,
+        TTrianglePoint,
+    >
+{
+    fn create_geometry_triangles(
+        &self,
+        object: &TObject,
+        width: f32,
+        height: f32,
+    ) -> Vec<TGeometryTriangle> {
+        let outline = object.get_curve_shape().get_outline();
+
+        let segments = self.calculate_segments(object, &outline, width.max(height) / 2.0);
+
+        let outer_points = self.calculate_points(outline.outer_radii, &outline, segments);
+
+        let inner_points = if outline.is_filled() {
+            vec![(0.0, 0.0); outer_points.len()]
+        } else {
+            self.calculate_points(outline.inner_radii, &outline, segments)
+        };
+
+        let create_triangle_point = |(x, y): (f32, f32)| {
+            self.triangle_point_creator.create_triangle_point(
+                x,
+                y,
+                object.get_r(),
+                object.get_g(),
+                object.get_b(),
+            )
+        };
+
+        let mut geometry_triangles = vec![];
+
+        for segment in 0..segments as usize {
+            geometry_triangles.push(
+                self.geometry_triangle_constructor
+                    .construct_geometry_triangle(
+                        create_triangle_point(inner_points[segment]),
+                        create_triangle_point(outer_points[segment]),
+                        create_triangle_point(outer_points[segment + 1]),
+                    ),
+            );
+
+            if !outline.is_filled() {
+                geometry_triangles.push(
+                    self.geometry_triangle_constructor
+                        .construct_geometry_triangle(
+                            create_triangle_point(inner_points[segment]),
+                            create_triangle_point(outer_points[segment + 1]),
+                            create_triangle_point(inner_points[segment + 1]),
+                        ),
+                );
+            }
+        }
+
+        geometry_triangles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use garden_json::ConvertToJson;
+    use garden_maths::trigonometry::{
+        CalculateAdjacent, CalculateCircleSegments, CalculateOpposite, CalculateTrigonometry,
+        ConvertDegreesToRadians,
+    };
+    use mockall::mock;
+    use serde_json::json;
+
+    use crate::{
+        circles::CircleTessellation,
+        curves::{Curve, CurveGeometryTrianglesCreator, CurveShape},
+        triangles::{ConstructGeometryTriangle, CreateGeometryTriangles},
+        CreateTrianglePoint, Rgb,
+    };
+
+    #[test]
+    fn when_an_ellipse_is_converted_to_triangles_then_the_points_use_both_radii() {
+        let shape = CurveShape::Ellipse {
+            radius_x: 2.0,
+            radius_y: 1.0,
+        };
+
+        let result = create_geometry_triangles(shape, 4);
+
+        assert_eq!(
+            vec![
+                [(0.0, 0.0), (2.0, 0.0), (0.0, 1.0)],
+                [(0.0, 0.0), (0.0, 1.0), (-2.0, 0.0)],
+                [(0.0, 0.0), (-2.0, 0.0), (0.0, -1.0)],
+                [(0.0, 0.0), (0.0, -1.0), (2.0, 0.0)],
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn when_a_sector_is_converted_to_triangles_then_only_the_angles_between_start_and_end_are_filled(
+    ) {
+        let shape = CurveShape::Sector {
+            radius: 1.0,
+            start_angle: 90.0,
+            end_angle: 180.0,
+        };
+
+        let result = create_geometry_triangles(shape, 4);
+
+        assert_eq!(vec![[(0.0, 0.0), (0.0, 1.0), (-1.0, 0.0)]], result);
+    }
+
+    #[test]
+    fn when_a_ring_is_converted_to_triangles_then_each_segment_is_a_band_between_the_radii() {
+        let shape = CurveShape::Ring {
+            inner_radius: 1.0,
+            outer_radius: 2.0,
+        };
+
+        let result = create_geometry_triangles(shape, 4);
+
+        assert_eq!(8, result.len());
+        assert_eq!(
+            vec![
+                [(1.0, 0.0), (2.0, 0.0), (0.0, 2.0)],
+                [(1.0, 0.0), (0.0, 2.0), (0.0, 1.0)],
+            ],
+            result[..2].to_vec()
+        );
+        assert_eq!([(0.0, -1.0), (2.0, 0.0), (1.0, 0.0)], result[7]);
+    }
+
+    #[test]
+    fn when_an_arc_is_converted_to_triangles_then_its_segments_are_a_share_of_a_full_turn() {
+        let shape = CurveShape::Arc {
+            inner_radius: 1.0,
+            outer_radius: 2.0,
+            start_angle: 0.0,
+            end_angle: 180.0,
+        };
+
+        let result = create_geometry_triangles(shape, 4);
+
+        assert_eq!(4, result.len());
+        assert_eq!([(0.0, 1.0), (0.0, 2.0), (-2.0, 0.0)], result[2]);
+        assert_eq!([(0.0, 1.0), (-2.0, 0.0), (-1.0, 0.0)], result[3]);
+    }
+
+    #[test]
+    fn when_a_curve_is_converted_to_json_then_the_type_and_shape_fields_are_included() {
+        let curve = Curve::new(
+            "Ring1".to_string(),
+            CurveShape::Ring {
+                inner_radius: 1.0,
+                outer_radius: 2.0,
+            },
+            Rgb::new(0.5, 0.5, 0.5),
+        );
+
+        let result = curve.convert_to_json();
+
+        assert_eq!(
+            json!({
+                "name": "Ring1",
+                "type": "ring",
+                "innerRadius": 1.0,
+                "outerRadius": 2.0,
+                "rgb": { "r": 0.5, "g": 0.5, "b": 0.5 }
+            }),
+            result
+        );
+    }
+
+    type Point = (f32, f32);
+
+    struct PointCreator {}
+
+    impl CreateTrianglePoint<Point> for PointCreator {
+        fn create_triangle_point(&self, x: f32, y: f32, _r: f32, _g: f32, _b: f32) -> Point {
+            (x, y)
+        }
+    }
+
+    struct TriangleConstructor {}
+
+    impl ConstructGeometryTriangle<[Point; 3], Point> for TriangleConstructor {
+        fn construct_geometry_triangle(
+            &self,
+            triangle_point_1: Point,
+            triangle_point_2: Point,
+            triangle_point_3: Point,
+        ) -> [Point; 3] {
+            [triangle_point_1, triangle_point_2, triangle_point_3]
+        }
+    }
+
+    fn create_geometry_triangles(shape: CurveShape, segments: u32) -> Vec<[Point; 3]> {
+        let mut curve = Curve::new("Curve".to_string(), shape, Rgb::new(0.5, 0.5, 0.5));
+
+        curve.set_circle_tessellation(CircleTessellation::Segments(segments));
+
+        let mut trigonometry_calculator = MockTrigonometryCalculator::new();
+
+        trigonometry_calculator
+            .expect_calculate_circle_segments()
+            .never();
+        trigonometry_calculator
+            .expect_convert_degrees_to_radians()
+            .returning(|degrees| degrees.to_radians());
+        trigonometry_calculator
+            .expect_calculate_adjacent()
+            .returning(|hypotenuse, angle_radians| (angle_radians.cos() * hypotenuse).round());
+        trigonometry_calculator
+            .expect_calculate_opposite()
+            .returning(|hypotenuse, angle_radians| (angle_radians.sin() * hypotenuse).round());
+
+        let geometry_triangles_creator = CurveGeometryTrianglesCreator::new(
+            Rc::new(TriangleConstructor {}),
+            Rc::new(PointCreator {}),
+            Rc::new(trigonometry_calculator),
+        );
+
+        geometry_triangles_creator.create_geometry_triangles(&curve, 0.0, 0.0)
+    }
+
+    mock! {
+        TrigonometryCalculator {}
+        impl ConvertDegreesToRadians for TrigonometryCalculator {
+            fn convert_degrees_to_radians(&self, degrees: f64) -> f64;
+        }
+        impl CalculateAdjacent for TrigonometryCalculator {
+            fn calculate_adjacent(&self, hypotenuse: f64, angle_radians: f64) -> f64;
+        }
+        impl CalculateOpposite for TrigonometryCalculator {
+            fn calculate_opposite(&self, hypotenuse: f64, angle_radians: f64) -> f64;
+        }
+        impl CalculateCircleSegments for TrigonometryCalculator {
+            fn calculate_circle_segments(&self, radius: f64, tolerance: f64) -> u32;
+        }
+        impl CalculateTrigonometry for TrigonometryCalculator {}
+    }
+}
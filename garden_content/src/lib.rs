@@ -1,11 +1,21 @@
 pub mod circles;
+pub mod curves;
 pub mod equilateral_triangles;
+pub mod meshes;
+pub mod polygons;
 pub mod rectangles;
+pub mod transforms;
 pub mod triangles;
 
 use std::{cell::RefCell, marker::PhantomData, rc::Rc};
 
 use garden::GetName;
+use garden_json::{convert_f32_to_json, ConvertToJson};
+use garden_maths::vectors::Vec2;
+use serde_json::{json, Value};
+use transforms::{GetTransform, SetTransform, Transform};
+
+const VERTEX_STRIDE: usize = (TwoDPoint::NUMBER_OF_VERTICES + Rgb::NUMBER_OF_VERTICES) as usize;
 
 pub trait GetVertexData {
     fn get_vertex_data(&self) -> Vec<f32>;
@@ -15,25 +25,19 @@ pub trait GetNumberOfVertices {
     fn get_number_of_vertices(&self) -> i32;
 }
 
-pub trait GetScale {
-    fn get_scale(&self) -> f32;
+pub trait GetIndexData {
+    fn get_index_data(&self) -> Vec<u32>;
 }
 
-pub trait Scale {
-    fn scale(&mut self, x: f32, y: f32);
+pub trait GetNumberOfIndices {
+    fn get_number_of_indices(&self) -> i32;
 }
 
-pub trait ScaleObjectInstance<TObjectInstance> {
-    fn scale_object_instance(
-        &self,
-        object_instance: Rc<RefCell<TObjectInstance>>,
-        x: f32,
-        y: f32,
-    ) -> Rc<RefCell<TObjectInstance>>;
+pub trait GetContentInstanceData:
+    GetVertexData + GetIndexData + GetNumberOfVertices + GetNumberOfObjects
+{
 }
 
-pub trait GetContentInstanceData: GetVertexData + GetNumberOfVertices + GetNumberOfObjects {}
-
 pub trait GetNumberOfObjects {
     fn get_number_of_objects(&self) -> i32;
 }
@@ -42,6 +46,42 @@ pub trait GetVertexDataPtr {
     fn get_vertex_data_ptr(&self) -> *const f32;
 }
 
+pub trait GetIndexDataPtr {
+    fn get_index_data_ptr(&self) -> *const u32;
+}
+
+pub trait GetVertexDataVersion {
+    fn get_vertex_data_version(&self) -> u64;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexRange {
+    first: i32,
+    count: i32,
+}
+
+impl IndexRange {
+    pub fn new(first: i32, count: i32) -> Self {
+        Self { first, count }
+    }
+
+    pub fn get_first(&self) -> i32 {
+        self.first
+    }
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+}
+
+pub trait GetIndexRanges {
+    fn get_index_ranges(&self) -> &[IndexRange];
+}
+
+pub trait GetObjectInstanceTransforms {
+    fn get_object_instance_transforms(&self) -> &[Transform];
+}
+
 pub trait GetX {
     fn get_x(&self) -> f32;
 }
@@ -81,6 +121,27 @@ impl GetY for TwoDPoint {
 
 impl Get2DCoordiantes for TwoDPoint {}
 
+impl From<Vec2> for TwoDPoint {
+    fn from(vec2: Vec2) -> Self {
+        Self::new(vec2.x, vec2.y)
+    }
+}
+
+impl From<&TwoDPoint> for Vec2 {
+    fn from(two_d_point: &TwoDPoint) -> Self {
+        Self::new(two_d_point.x, two_d_point.y)
+    }
+}
+
+impl ConvertToJson for TwoDPoint {
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "x": convert_f32_to_json(self.x),
+            "y": convert_f32_to_json(self.y)
+        })
+    }
+}
+
 impl GetVertexData for TwoDPoint {
     fn get_vertex_data(&self) -> Vec<f32> {
         self.vertex_data.clone()
@@ -115,24 +176,6 @@ impl CreateTwoDPoint<TwoDPoint> for TwoDPointCreator {
     }
 }
 
-pub trait TranslateTwoDPoint<TTwoDPoint> {
-    fn translate_two_d_point(&self, position: &TTwoDPoint, x: f32, y: f32) -> TTwoDPoint;
-}
-
-pub struct TwoDPointTranslator {}
-
-impl TwoDPointTranslator {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
-
-impl TranslateTwoDPoint<TwoDPoint> for TwoDPointTranslator {
-    fn translate_two_d_point(&self, position: &TwoDPoint, x: f32, y: f32) -> TwoDPoint {
-        TwoDPoint::new(position.get_x() / x, position.get_y() / y)
-    }
-}
-
 pub trait GetR {
     fn get_r(&self) -> f32;
 }
@@ -197,6 +240,16 @@ impl GetRgbValues for Rgb {}
 
 impl GetRgbProperties for Rgb {}
 
+impl ConvertToJson for Rgb {
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "r": convert_f32_to_json(self.r),
+            "g": convert_f32_to_json(self.g),
+            "b": convert_f32_to_json(self.b)
+        })
+    }
+}
+
 pub trait CreateRgb<TRgb> {
     fn create_rgb(&self, r: f32, g: f32, b: f32) -> TRgb;
 }
@@ -305,6 +358,17 @@ impl<TTwoDPoint: Get2DCoordiantes, TRgb: GetRgbValues> GetTrianglePointPropertie
 {
 }
 
+impl<TTwoDPoint: ConvertToJson, TRgb: ConvertToJson> ConvertToJson
+    for TrianglePoint<TTwoDPoint, TRgb>
+{
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "twoDPoint": self.point.convert_to_json(),
+            "rgb": self.rgb.convert_to_json()
+        })
+    }
+}
+
 pub trait ConstructTrianglePoint<TTwoDPoint, TRgb, TTrianglePoint> {
     fn construct_triangle_point(
         &self,
@@ -432,68 +496,150 @@ impl<
     }
 }
 
-pub trait GetPosition<TPosition> {
-    fn get_position(&self) -> &TPosition;
-}
+pub trait ContentObject: GetName + ConvertToJson {}
+
+impl<TContentObject: GetName + ConvertToJson> ContentObject for TContentObject {}
 
 pub struct Content {
-    objects: Option<Vec<Box<Rc<RefCell<dyn GetName>>>>>,
+    objects: Option<Vec<Box<Rc<RefCell<dyn ContentObject>>>>>,
     object_instance_runners: Option<Vec<Box<dyn RunObjectInstance>>>,
     vertex_data: Vec<f32>,
+    index_data: Vec<u32>,
+    index_ranges: Vec<IndexRange>,
+    object_instance_transforms: Vec<Transform>,
     number_of_vertices: i32,
     number_of_objects: i32,
+    vertex_data_version: u64,
 }
 
 impl Content {
     pub fn new(
-        objects: Vec<Box<Rc<RefCell<dyn GetName>>>>,
+        objects: Vec<Box<Rc<RefCell<dyn ContentObject>>>>,
         object_instance_runners: Vec<Box<dyn RunObjectInstance>>,
     ) -> Self {
-        let mut number_of_vertices = 0;
+        let mut content = Self {
+            objects: Some(objects),
+            object_instance_runners: Some(object_instance_runners),
+            vertex_data: vec![],
+            index_data: vec![],
+            index_ranges: vec![],
+            object_instance_transforms: vec![],
+            number_of_vertices: 0,
+            number_of_objects: 0,
+            vertex_data_version: 0,
+        };
 
-        let mut vertex_data = vec![];
+        content.update_vertex_data();
 
-        let mut number_of_objects = 0;
+        content
+    }
 
-        for object_instance in object_instance_runners.iter() {
-            number_of_vertices += object_instance.get_number_of_vertices();
-            vertex_data.append(&mut object_instance.get_vertex_data());
-            number_of_objects += object_instance.get_number_of_objects();
-        }
+    pub fn get_objects(&self) -> &Option<Vec<Box<Rc<RefCell<dyn ContentObject>>>>> {
+        &self.objects
+    }
 
-        Self {
-            objects: Some(objects),
-            object_instance_runners: Some(object_instance_runners),
-            vertex_data,
-            number_of_vertices,
-            number_of_objects,
-        }
+    pub fn get_object_instance_runners(&self) -> &Option<Vec<Box<dyn RunObjectInstance>>> {
+        &self.object_instance_runners
     }
 
-    pub fn get_objects(&self) -> &Option<Vec<Box<Rc<RefCell<dyn GetName>>>>> {
-        &self.objects
+    pub fn get_object_instance_transform(&self, name: &str) -> Option<Transform> {
+        self.object_instance_runners
+            .iter()
+            .flatten()
+            .find(|object_instance_runner| object_instance_runner.get_name() == name)
+            .map(|object_instance_runner| object_instance_runner.get_transform())
+    }
+
+    pub fn set_object_instance_transform(&mut self, name: &str, transform: Transform) -> bool {
+        let object_instance_runner = self
+            .object_instance_runners
+            .iter_mut()
+            .flatten()
+            .enumerate()
+            .find(|(_, object_instance_runner)| object_instance_runner.get_name() == name);
+
+        let (index, object_instance_runner) = match object_instance_runner {
+            Some(object_instance_runner) => object_instance_runner,
+            None => return false,
+        };
+
+        let vertex_data_version = object_instance_runner.get_vertex_data_version();
+
+        object_instance_runner.set_transform(transform);
+
+        if object_instance_runner.get_vertex_data_version() != vertex_data_version {
+            self.update_vertex_data();
+        } else {
+            self.object_instance_transforms[index] = transform;
+        }
+
+        true
     }
 
-    pub fn scale_object_instances(&mut self, x: f32, y: f32) {
-        let mut new_object_instance_runners = Vec::<Box<dyn RunObjectInstance>>::new();
+    fn update_vertex_data(&mut self) {
+        let mut vertex_data = vec![];
+        let mut index_data = vec![];
+        let mut index_ranges = vec![];
+        let mut object_instance_transforms = vec![];
+        let mut number_of_vertices = 0;
+        let mut number_of_objects = 0;
+
+        for object_instance in self.object_instance_runners.iter().flatten() {
+            number_of_vertices += object_instance.get_number_of_vertices();
+            number_of_objects += object_instance.get_number_of_objects();
+
+            let mut object_instance_vertex_data = object_instance.get_vertex_data();
+            let first_vertex = vertex_data.len() / VERTEX_STRIDE;
 
-        let mut new_vertex_data = vec![];
+            let object_instance_index_data = object_instance.get_index_data();
 
-        let mut i = self.object_instance_runners.as_ref().unwrap().len();
+            index_ranges.push(IndexRange::new(
+                index_data.len() as i32,
+                object_instance_index_data.len() as i32,
+            ));
 
-        let mut object_instance_runners = self.object_instance_runners.take().unwrap();
+            object_instance_transforms.push(object_instance.get_transform());
 
-        while i > 0 {
-            let mut t = object_instance_runners.remove(i - 1);
-            t.scale(x, y);
-            new_vertex_data.append(&mut t.get_vertex_data());
-            new_object_instance_runners.push(t);
-            i = i - 1;
+            index_data.extend(
+                object_instance_index_data
+                    .iter()
+                    .map(|index| index + first_vertex as u32),
+            );
+            vertex_data.append(&mut object_instance_vertex_data);
         }
 
-        self.object_instance_runners = Some(new_object_instance_runners);
+        self.vertex_data = vertex_data;
+        self.index_data = index_data;
+        self.index_ranges = index_ranges;
+        self.object_instance_transforms = object_instance_transforms;
+        self.number_of_vertices = number_of_vertices;
+        self.number_of_objects = number_of_objects;
+        self.vertex_data_version += 1;
+    }
+}
+
+impl ConvertToJson for Content {
+    fn convert_to_json(&self) -> Value {
+        let objects = self
+            .objects
+            .iter()
+            .flatten()
+            .map(|object| object.borrow().convert_to_json())
+            .collect::<Vec<Value>>();
+
+        let object_instances = self
+            .object_instance_runners
+            .iter()
+            .flatten()
+            .map(|object_instance_runner| object_instance_runner.convert_to_json())
+            .collect::<Vec<Value>>();
 
-        self.vertex_data = new_vertex_data;
+        json!({
+            "content": {
+                "objects": objects
+            },
+            "objects": object_instances
+        })
     }
 }
 
@@ -521,77 +667,141 @@ impl GetVertexDataPtr for Content {
     }
 }
 
-pub trait RunObjectInstance: GetContentInstanceData + Scale {}
+impl GetVertexDataVersion for Content {
+    fn get_vertex_data_version(&self) -> u64 {
+        self.vertex_data_version
+    }
+}
 
-pub struct ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler> {
-    object_instance: Rc<RefCell<TObjectInstance>>,
-    object_instance_scaler: Rc<TObjectInstanceScaler>,
+impl GetIndexData for Content {
+    fn get_index_data(&self) -> Vec<u32> {
+        self.index_data.clone()
+    }
+}
+
+impl GetNumberOfIndices for Content {
+    fn get_number_of_indices(&self) -> i32 {
+        self.index_data.len() as i32
+    }
+}
+
+impl GetIndexDataPtr for Content {
+    fn get_index_data_ptr(&self) -> *const u32 {
+        self.index_data.as_ptr()
+    }
 }
 
-impl<TObjectInstance, TObjectInstanceScaler>
-    ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
+impl GetIndexRanges for Content {
+    fn get_index_ranges(&self) -> &[IndexRange] {
+        &self.index_ranges
+    }
+}
+
+impl GetObjectInstanceTransforms for Content {
+    fn get_object_instance_transforms(&self) -> &[Transform] {
+        &self.object_instance_transforms
+    }
+}
+
+pub trait RunObjectInstance:
+    GetName
+    + GetContentInstanceData
+    + GetVertexDataVersion
+    + GetTransform
+    + SetTransform
+    + ConvertToJson
 {
-    pub fn new(
-        object_instance: Rc<RefCell<TObjectInstance>>,
-        object_instance_scaler: Rc<TObjectInstanceScaler>,
-    ) -> Self {
+}
+
+pub struct ObjectInstanceRunner<TObjectInstance> {
+    name: String,
+    object_instance: Rc<RefCell<TObjectInstance>>,
+}
+
+impl<TObjectInstance: GetName> ObjectInstanceRunner<TObjectInstance> {
+    pub fn new(object_instance: Rc<RefCell<TObjectInstance>>) -> Self {
+        let name = object_instance.borrow().get_name().to_string();
+
         Self {
+            name,
             object_instance,
-            object_instance_scaler,
         }
     }
 }
 
-impl<TObjectInstance, TObjectInstanceScaler: ScaleObjectInstance<TObjectInstance>> Scale
-    for ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
-{
-    fn scale(&mut self, x: f32, y: f32) {
-        self.object_instance = self.object_instance_scaler.scale_object_instance(
-            Rc::clone(&self.object_instance),
-            x,
-            y,
-        );
+impl<TObjectInstance> GetName for ObjectInstanceRunner<TObjectInstance> {
+    fn get_name(&self) -> &str {
+        &self.name
     }
 }
 
-impl<TObjectInstance: GetNumberOfObjects, TObjectInstanceScaler> GetNumberOfObjects
-    for ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
+impl<TObjectInstance: GetNumberOfObjects> GetNumberOfObjects
+    for ObjectInstanceRunner<TObjectInstance>
 {
     fn get_number_of_objects(&self) -> i32 {
         self.object_instance.borrow().get_number_of_objects()
     }
 }
 
-impl<TObjectInstance: GetNumberOfVertices, TObjectInstanceScaler> GetNumberOfVertices
-    for ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
+impl<TObjectInstance: GetNumberOfVertices> GetNumberOfVertices
+    for ObjectInstanceRunner<TObjectInstance>
 {
     fn get_number_of_vertices(&self) -> i32 {
         self.object_instance.borrow().get_number_of_vertices()
     }
 }
 
-impl<TObjectInstance: GetVertexData, TObjectInstanceScaler> GetVertexData
-    for ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
-{
+impl<TObjectInstance: GetVertexData> GetVertexData for ObjectInstanceRunner<TObjectInstance> {
     fn get_vertex_data(&self) -> Vec<f32> {
         self.object_instance.borrow().get_vertex_data()
     }
 }
 
-impl<TObjectInstance: GetContentInstanceData, TObjectInstanceScaler> GetContentInstanceData
-    for ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
+impl<TObjectInstance: GetIndexData> GetIndexData for ObjectInstanceRunner<TObjectInstance> {
+    fn get_index_data(&self) -> Vec<u32> {
+        self.object_instance.borrow().get_index_data()
+    }
+}
+
+impl<TObjectInstance: GetContentInstanceData> GetContentInstanceData
+    for ObjectInstanceRunner<TObjectInstance>
+{
+}
+
+impl<TObjectInstance: GetVertexDataVersion> GetVertexDataVersion
+    for ObjectInstanceRunner<TObjectInstance>
 {
+    fn get_vertex_data_version(&self) -> u64 {
+        self.object_instance.borrow().get_vertex_data_version()
+    }
+}
+
+impl<TObjectInstance: GetTransform> GetTransform for ObjectInstanceRunner<TObjectInstance> {
+    fn get_transform(&self) -> Transform {
+        self.object_instance.borrow().get_transform()
+    }
+}
+
+impl<TObjectInstance: SetTransform> SetTransform for ObjectInstanceRunner<TObjectInstance> {
+    fn set_transform(&mut self, transform: Transform) {
+        self.object_instance.borrow_mut().set_transform(transform)
+    }
+}
+
+impl<TObjectInstance: ConvertToJson> ConvertToJson for ObjectInstanceRunner<TObjectInstance> {
+    fn convert_to_json(&self) -> Value {
+        self.object_instance.borrow().convert_to_json()
+    }
 }
 
 impl<
-        TObjectInstance: GetContentInstanceData,
-        TObjectInstanceScaler: ScaleObjectInstance<TObjectInstance>,
-    > RunObjectInstance for ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>
+        TObjectInstance: GetContentInstanceData + GetVertexDataVersion + GetTransform + SetTransform + ConvertToJson,
+    > RunObjectInstance for ObjectInstanceRunner<TObjectInstance>
 {
 }
 
 pub trait GetContent<TContent> {
-    fn get_content(&self, content_name: String) -> Rc<RefCell<TContent>>;
+    fn get_content(&self, content_name: String) -> Option<Rc<RefCell<TContent>>>;
 }
 
 pub trait ConstructObject<TObject, TParameters> {
@@ -670,12 +880,19 @@ impl<TObject> StoreObject<TObject> for Store<TObject> {
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
+    use garden_json::ConvertToJson;
+    use garden_maths::vectors::Vec2;
     use mockall::mock;
+    use serde_json::Value;
+
+    use garden::GetName;
 
     use crate::{
-        ConstructObject, Content, CreateObject, GetContentInstanceData, GetNumberOfObjects,
-        GetNumberOfVertices, GetVertexData, GetX, GetY, ObjectCreator, Rgb, RunObjectInstance,
-        Scale, StoreObject, TrianglePoint, TwoDPoint,
+        transforms::{GetTransform, SetTransform, Transform},
+        ConstructObject, Content, CreateObject, GetContentInstanceData, GetIndexData,
+        GetIndexRanges, GetNumberOfIndices, GetNumberOfObjects, GetNumberOfVertices,
+        GetObjectInstanceTransforms, GetVertexData, GetVertexDataVersion, GetX, GetY, IndexRange,
+        ObjectCreator, Rgb, RunObjectInstance, StoreObject, TrianglePoint, TwoDPoint,
     };
 
     #[test]
@@ -706,6 +923,16 @@ mod tests {
         assert_eq!(expected_number_of_vertices, result);
     }
 
+    #[test]
+    fn when_a_two_d_point_is_converted_to_and_from_a_vec2_then_the_coordinates_are_kept() {
+        let two_d_point = TwoDPoint::from(Vec2::new(1.5, -2.0));
+
+        let result = Vec2::from(&two_d_point);
+
+        assert_eq!(vec![1.5, -2.0], two_d_point.get_vertex_data());
+        assert_eq!(Vec2::new(1.5, -2.0), result);
+    }
+
     #[test]
     fn when_a_rgb_gets_its_vertex_data_then_the_vertex_data_is_returned() {
         let r = 1.0;
@@ -1085,6 +1312,142 @@ mod tests {
         assert_eq!(result, expected_number_of_objects);
     }
 
+    #[test]
+    fn when_content_sets_an_object_instance_transform_then_only_that_instance_transform_is_changed_and_the_vertex_data_is_kept(
+    ) {
+        let transform = Transform::new(1.0, 2.0, 0.0, 1.0, 1.0);
+
+        let new_transform = Transform::new(3.0, 4.0, 90.0, 2.0, 2.0);
+
+        let object_instance_1 = create_mock_named_object_instance_runner("Instance1", transform);
+
+        let mut object_instance_2 =
+            create_mock_named_object_instance_runner("Instance2", transform);
+        object_instance_2
+            .expect_get_vertex_data_version()
+            .times(2)
+            .returning(move || 1);
+        object_instance_2
+            .expect_set_transform()
+            .withf(move |transform| *transform == new_transform)
+            .times(1)
+            .returning(|_| ());
+
+        let mut object_instances = Vec::<Box<dyn RunObjectInstance>>::new();
+        object_instances.push(Box::new(object_instance_1));
+        object_instances.push(Box::new(object_instance_2));
+
+        let mut content = Content::new(vec![], object_instances);
+
+        let vertex_data_version = content.get_vertex_data_version();
+
+        let found = content.set_object_instance_transform("Instance2", new_transform);
+        let missing = content.set_object_instance_transform("Instance3", new_transform);
+
+        assert!(found);
+        assert!(!missing);
+        assert_eq!(vertex_data_version, content.get_vertex_data_version());
+        assert_eq!(
+            [transform, new_transform],
+            content.get_object_instance_transforms()
+        );
+        assert_eq!(
+            Some(transform),
+            content.get_object_instance_transform("Instance1")
+        );
+        assert_eq!(None, content.get_object_instance_transform("Instance3"));
+    }
+
+    #[test]
+    fn when_content_sets_an_object_instance_transform_that_changes_its_vertex_data_then_the_vertex_data_is_updated(
+    ) {
+        let transform = Transform::new(1.0, 2.0, 0.0, 1.0, 1.0);
+
+        let new_transform = Transform::new(3.0, 4.0, 0.0, 2.0, 2.0);
+
+        let mut object_instance = create_mock_named_object_instance_runner("Instance1", transform);
+        let mut vertex_data_versions = vec![2, 1];
+        object_instance
+            .expect_get_vertex_data_version()
+            .times(2)
+            .returning(move || vertex_data_versions.pop().unwrap());
+        object_instance
+            .expect_set_transform()
+            .times(1)
+            .returning(|_| ());
+
+        let object_instances: Vec<Box<dyn RunObjectInstance>> = vec![Box::new(object_instance)];
+
+        let mut content = Content::new(vec![], object_instances);
+
+        let vertex_data_version = content.get_vertex_data_version();
+
+        let found = content.set_object_instance_transform("Instance1", new_transform);
+
+        assert!(found);
+        assert_eq!(vertex_data_version + 1, content.get_vertex_data_version());
+    }
+
+    #[test]
+    fn when_content_gets_its_index_data_then_the_indices_are_offset_to_each_object_instance_vertices(
+    ) {
+        let mut object_instance_1 = MockObjectInstanceRunner::new();
+        object_instance_1
+            .expect_get_number_of_vertices()
+            .returning(move || 15);
+        object_instance_1
+            .expect_get_number_of_objects()
+            .returning(move || 1);
+        object_instance_1
+            .expect_get_vertex_data()
+            .returning(move || vec![0.0; 15]);
+        object_instance_1
+            .expect_get_index_data()
+            .returning(move || vec![0, 1, 2]);
+        object_instance_1
+            .expect_get_transform()
+            .returning(move || Transform::new(0.0, 0.0, 0.0, 1.0, 1.0));
+
+        let mut object_instance_2 = MockObjectInstanceRunner::new();
+        object_instance_2
+            .expect_get_number_of_vertices()
+            .returning(move || 20);
+        object_instance_2
+            .expect_get_number_of_objects()
+            .returning(move || 2);
+        object_instance_2
+            .expect_get_vertex_data()
+            .returning(move || vec![0.0; 20]);
+        object_instance_2
+            .expect_get_index_data()
+            .returning(move || vec![0, 1, 2, 0, 2, 3]);
+        object_instance_2
+            .expect_get_transform()
+            .returning(move || Transform::new(1.0, 2.0, 0.0, 1.0, 1.0));
+
+        let mut object_instances = Vec::<Box<dyn RunObjectInstance>>::new();
+        object_instances.push(Box::new(object_instance_1));
+        object_instances.push(Box::new(object_instance_2));
+
+        let content = Content::new(vec![], object_instances);
+
+        let result = content.get_index_data();
+
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 3, 5, 6], result);
+        assert_eq!(9, content.get_number_of_indices());
+        assert_eq!(
+            [IndexRange::new(0, 3), IndexRange::new(3, 6)],
+            content.get_index_ranges()
+        );
+        assert_eq!(
+            [
+                Transform::new(0.0, 0.0, 0.0, 1.0, 1.0),
+                Transform::new(1.0, 2.0, 0.0, 1.0, 1.0)
+            ],
+            content.get_object_instance_transforms()
+        );
+    }
+
     #[test]
     fn when_two_d_point_gets_x_then_x_is_returned() {
         let x = 1.23;
@@ -1173,8 +1536,8 @@ mod tests {
         impl GetNumberOfVertices for VertexObject {
             fn get_number_of_vertices(&self) -> i32;
         }
-        impl Scale for VertexObject {
-            fn scale(&mut self, x: f32, y: f32);
+        impl GetIndexData for VertexObject {
+            fn get_index_data(&self) -> Vec<u32>;
         }
         impl GetNumberOfObjects for VertexObject {
             fn get_number_of_objects(&self) -> i32;
@@ -1193,8 +1556,20 @@ mod tests {
         ObjectInstanceRunner {}
         impl RunObjectInstance for ObjectInstanceRunner {}
         impl GetContentInstanceData for ObjectInstanceRunner {}
-        impl Scale for ObjectInstanceRunner {
-            fn scale(&mut self, x: f32, y: f32) {}
+        impl GetVertexDataVersion for ObjectInstanceRunner {
+            fn get_vertex_data_version(&self) -> u64;
+        }
+        impl ConvertToJson for ObjectInstanceRunner {
+            fn convert_to_json(&self) -> Value;
+        }
+        impl GetName for ObjectInstanceRunner {
+            fn get_name(&self) -> &str;
+        }
+        impl GetTransform for ObjectInstanceRunner {
+            fn get_transform(&self) -> Transform;
+        }
+        impl SetTransform for ObjectInstanceRunner {
+            fn set_transform(&mut self, transform: Transform);
         }
         impl GetNumberOfObjects for ObjectInstanceRunner {
             fn get_number_of_objects(&self) -> i32;
@@ -1205,6 +1580,9 @@ mod tests {
         impl GetVertexData for ObjectInstanceRunner {
             fn get_vertex_data(&self) -> Vec<f32>;
         }
+        impl GetIndexData for ObjectInstanceRunner {
+            fn get_index_data(&self) -> Vec<u32>;
+        }
     }
 
     struct Object {
@@ -1239,6 +1617,34 @@ mod tests {
         }
     }
 
+    fn create_mock_named_object_instance_runner(
+        name: &str,
+        transform: Transform,
+    ) -> MockObjectInstanceRunner {
+        let mut mock_object_instance_runner = MockObjectInstanceRunner::new();
+
+        mock_object_instance_runner
+            .expect_get_name()
+            .return_const(name.to_string());
+        mock_object_instance_runner
+            .expect_get_number_of_objects()
+            .returning(move || 1);
+        mock_object_instance_runner
+            .expect_get_number_of_vertices()
+            .returning(move || 0);
+        mock_object_instance_runner
+            .expect_get_vertex_data()
+            .returning(move || vec![]);
+        mock_object_instance_runner
+            .expect_get_index_data()
+            .returning(move || vec![]);
+        mock_object_instance_runner
+            .expect_get_transform()
+            .returning(move || transform);
+
+        mock_object_instance_runner
+    }
+
     fn create_mock_object_instance_runner(
         vertex_data: Vec<f32>,
         number_of_vertices: i32,
@@ -1253,6 +1659,12 @@ mod tests {
             .expect_get_number_of_vertices()
             .times(1)
             .returning(move || number_of_vertices);
+        mock_object_instance_runner
+            .expect_get_index_data()
+            .returning(move || vec![]);
+        mock_object_instance_runner
+            .expect_get_transform()
+            .returning(move || Transform::new(0.0, 0.0, 0.0, 1.0, 1.0));
 
         mock_object_instance_runner
     }
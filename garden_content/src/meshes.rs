@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::{GetIndexData, GetNumberOfVertices, GetVertexData, VERTEX_STRIDE};
+
+pub struct IndexedMesh {
+    vertex_data: Vec<f32>,
+    index_data: Vec<u32>,
+}
+
+impl IndexedMesh {
+    pub fn new(vertex_data: Vec<f32>, index_data: Vec<u32>) -> Self {
+        Self {
+            vertex_data,
+            index_data,
+        }
+    }
+
+    pub fn from_triangle_vertex_data(triangle_vertex_data: &[f32]) -> Self {
+        let mut vertex_data = vec![];
+        let mut index_data = vec![];
+        let mut indices_by_vertex = HashMap::<Vec<u32>, u32>::new();
+
+        for vertex in triangle_vertex_data.chunks(VERTEX_STRIDE) {
+            let key = vertex.iter().map(|value| value.to_bits()).collect();
+
+            let index = *indices_by_vertex.entry(key).or_insert_with(|| {
+                vertex_data.extend_from_slice(vertex);
+
+                (vertex_data.len() / VERTEX_STRIDE - 1) as u32
+            });
+
+            index_data.push(index);
+        }
+
+        Self::new(vertex_data, index_data)
+    }
+
+    pub fn into_data(self) -> (Vec<f32>, Vec<u32>) {
+        (self.vertex_data, self.index_data)
+    }
+}
+
+impl GetVertexData for IndexedMesh {
+    fn get_vertex_data(&self) -> Vec<f32> {
+        self.vertex_data.clone()
+    }
+}
+
+impl GetIndexData for IndexedMesh {
+    fn get_index_data(&self) -> Vec<u32> {
+        self.index_data.clone()
+    }
+}
+
+impl GetNumberOfVertices for IndexedMesh {
+    fn get_number_of_vertices(&self) -> i32 {
+        self.vertex_data.len() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{meshes::IndexedMesh, GetIndexData, GetNumberOfVertices, GetVertexData};
+
+    #[test]
+    fn when_an_indexed_mesh_is_created_from_two_triangles_sharing_an_edge_then_the_shared_vertices_are_stored_once(
+    ) {
+        let triangle_vertex_data = vec![
+            1.0, 1.0, 0.5, 0.5, 0.5, //
+            -1.0, 1.0, 0.5, 0.5, 0.5, //
+            -1.0, -1.0, 0.5, 0.5, 0.5, //
+            1.0, 1.0, 0.5, 0.5, 0.5, //
+            -1.0, -1.0, 0.5, 0.5, 0.5, //
+            1.0, -1.0, 0.5, 0.5, 0.5,
+        ];
+
+        let indexed_mesh = IndexedMesh::from_triangle_vertex_data(&triangle_vertex_data);
+
+        assert_eq!(
+            vec![
+                1.0, 1.0, 0.5, 0.5, 0.5, //
+                -1.0, 1.0, 0.5, 0.5, 0.5, //
+                -1.0, -1.0, 0.5, 0.5, 0.5, //
+                1.0, -1.0, 0.5, 0.5, 0.5,
+            ],
+            indexed_mesh.get_vertex_data()
+        );
+        assert_eq!(vec![0, 1, 2, 0, 2, 3], indexed_mesh.get_index_data());
+        assert_eq!(20, indexed_mesh.get_number_of_vertices());
+    }
+
+    #[test]
+    fn when_an_indexed_mesh_is_created_from_vertices_with_different_colours_then_they_are_not_merged(
+    ) {
+        let triangle_vertex_data = vec![
+            0.0, 0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, 0.0,
+        ];
+
+        let indexed_mesh = IndexedMesh::from_triangle_vertex_data(&triangle_vertex_data);
+
+        assert_eq!(vec![0, 1, 0], indexed_mesh.get_index_data());
+        assert_eq!(10, indexed_mesh.get_number_of_vertices());
+    }
+}
@@ -0,0 +1,539 @@
+use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+
+use garden::GetName;
+use garden_json::ConvertToJson;
+use garden_maths::vectors::Vec2;
+use serde_json::{json, Value};
+
+use crate::{
+    meshes::IndexedMesh,
+    transforms::{convert_object_instance_to_json, GetTransform, SetTransform, Transform},
+    triangles::{ConstructGeometryTriangle, CreateGeometryTriangles},
+    ConstructObject, CreateTrianglePoint, Get2DCoordiantes, GetB, GetContentInstanceData, GetG,
+    GetIndexData, GetNumberOfObjects, GetNumberOfVertices, GetR, GetRgb, GetRgbValues,
+    GetVertexData, GetVertexDataVersion, Rgb,
+};
+
+pub trait GetPolygonTriangles {
+    fn get_polygon_triangles(&self) -> Vec<[Vec2; 3]>;
+}
+
+pub trait GetPolygon<TPolygon> {
+    fn get_polygon(&self) -> Rc<RefCell<TPolygon>>;
+}
+
+pub struct Polygon<TTwoDPoint, TRgb> {
+    name: String,
+    points: Vec<TTwoDPoint>,
+    rgb: TRgb,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<TTwoDPoint, TRgb> Polygon<TTwoDPoint, TRgb> {
+    fn new(name: String, points: Vec<TTwoDPoint>, rgb: TRgb, triangles: Vec<[usize; 3]>) -> Self {
+        Self {
+            name,
+            points,
+            rgb,
+            triangles,
+        }
+    }
+
+    pub fn get_points(&self) -> &[TTwoDPoint] {
+        &self.points
+    }
+}
+
+impl<TTwoDPoint, TRgb> GetName for Polygon<TTwoDPoint, TRgb> {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<TTwoDPoint: Get2DCoordiantes, TRgb> GetPolygonTriangles for Polygon<TTwoDPoint, TRgb> {
+    fn get_polygon_triangles(&self) -> Vec<[Vec2; 3]> {
+        self.triangles
+            .iter()
+            .map(|triangle| {
+                triangle
+                    .map(|index| Vec2::new(self.points[index].get_x(), self.points[index].get_y()))
+            })
+            .collect()
+    }
+}
+
+impl<TTwoDPoint, TRgb> GetRgb<TRgb> for Polygon<TTwoDPoint, TRgb> {
+    fn get_rgb(&self) -> &TRgb {
+        &self.rgb
+    }
+}
+
+impl<TTwoDPoint, TRgb: GetR> GetR for Polygon<TTwoDPoint, TRgb> {
+    fn get_r(&self) -> f32 {
+        self.get_rgb().get_r()
+    }
+}
+
+impl<TTwoDPoint, TRgb: GetG> GetG for Polygon<TTwoDPoint, TRgb> {
+    fn get_g(&self) -> f32 {
+        self.get_rgb().get_g()
+    }
+}
+
+impl<TTwoDPoint, TRgb: GetB> GetB for Polygon<TTwoDPoint, TRgb> {
+    fn get_b(&self) -> f32 {
+        self.get_rgb().get_b()
+    }
+}
+
+impl<TTwoDPoint, TRgb: GetRgbValues> GetRgbValues for Polygon<TTwoDPoint, TRgb> {}
+
+impl<TTwoDPoint: ConvertToJson, TRgb: ConvertToJson> ConvertToJson for Polygon<TTwoDPoint, TRgb> {
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "type": "polygon",
+            "points": self
+                .points
+                .iter()
+                .map(|point| point.convert_to_json())
+                .collect::<Vec<Value>>(),
+            "rgb": self.rgb.convert_to_json()
+        })
+    }
+}
+
+pub struct PolygonParameters<TTwoDPoint, TRgb> {
+    name: String,
+    points: Vec<TTwoDPoint>,
+    rgb: TRgb,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<TTwoDPoint, TRgb> PolygonParameters<TTwoDPoint, TRgb> {
+    pub fn new(
+        name: String,
+        points: Vec<TTwoDPoint>,
+        rgb: TRgb,
+        triangles: Vec<[usize; 3]>,
+    ) -> Self {
+        Self {
+            name,
+            points,
+            rgb,
+            triangles,
+        }
+    }
+}
+
+pub struct PolygonConstructor {}
+
+impl PolygonConstructor {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<TTwoDPoint, TRgb>
+    ConstructObject<Polygon<TTwoDPoint, TRgb>, PolygonParameters<TTwoDPoint, TRgb>>
+    for PolygonConstructor
+{
+    fn construct_object(
+        &self,
+        parameters: PolygonParameters<TTwoDPoint, TRgb>,
+    ) -> Polygon<TTwoDPoint, TRgb> {
+        Polygon::new(
+            parameters.name,
+            parameters.points,
+            parameters.rgb,
+            parameters.triangles,
+        )
+    }
+}
+
+pub struct PolygonInstanceParameters<TPolygon> {
+    name: String,
+    polygon: Rc<RefCell<TPolygon>>,
+    transform: Transform,
+}
+
+impl<TPolygon> PolygonInstanceParameters<TPolygon> {
+    pub fn new(name: String, polygon: Rc<RefCell<TPolygon>>, transform: Transform) -> Self {
+        Self {
+            name,
+            polygon,
+            transform,
+        }
+    }
+}
+
+pub struct PolygonInstance<TPolygon, TGeometryTriangle> {
+    name: String,
+    polygon: Rc<RefCell<TPolygon>>,
+    transform: Transform,
+    number_of_vertices: i32,
+    vertex_data: Vec<f32>,
+    index_data: Vec<u32>,
+    geometry_triangles: Vec<TGeometryTriangle>,
+}
+
+impl<TPolygon, TGeometryTriangle> PolygonInstance<TPolygon, TGeometryTriangle> {
+    pub fn new(
+        name: String,
+        polygon: Rc<RefCell<TPolygon>>,
+        transform: Transform,
+        number_of_vertices: i32,
+        vertex_data: Vec<f32>,
+        index_data: Vec<u32>,
+        geometry_triangles: Vec<TGeometryTriangle>,
+    ) -> Self {
+        Self {
+            name,
+            polygon,
+            transform,
+            number_of_vertices,
+            vertex_data,
+            index_data,
+            geometry_triangles,
+        }
+    }
+}
+
+impl<TPolygon: GetName, TGeometryTriangle> ConvertToJson
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn convert_to_json(&self) -> Value {
+        convert_object_instance_to_json(
+            &self.name,
+            self.polygon.borrow().get_name(),
+            "polygon",
+            &self.transform,
+        )
+    }
+}
+
+pub struct PolygonInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle> {
+    geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
+    geometry_triangle_type: PhantomData<TGeometryTriangle>,
+}
+
+impl<TGeometryTrianglesCreator, TGeometryTriangle>
+    PolygonInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    pub fn new(geometry_triangles_creator: Rc<TGeometryTrianglesCreator>) -> Self {
+        Self {
+            geometry_triangles_creator,
+            geometry_triangle_type: PhantomData,
+        }
+    }
+}
+
+impl<
+        TPolygon,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TPolygon>,
+        TGeometryTriangle: GetVertexData,
+    >
+    ConstructObject<
+        PolygonInstance<TPolygon, TGeometryTriangle>,
+        PolygonInstanceParameters<TPolygon>,
+    > for PolygonInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle>
+{
+    fn construct_object(
+        &self,
+        parameters: PolygonInstanceParameters<TPolygon>,
+    ) -> PolygonInstance<TPolygon, TGeometryTriangle> {
+        let mut triangle_vertex_data = vec![];
+
+        let geometry_triangles = self.geometry_triangles_creator.create_geometry_triangles(
+            &parameters.polygon.borrow(),
+            0.0,
+            0.0,
+        );
+
+        for geometry_triangle in geometry_triangles.iter() {
+            triangle_vertex_data.append(&mut geometry_triangle.get_vertex_data());
+        }
+
+        let indexed_mesh = IndexedMesh::from_triangle_vertex_data(&triangle_vertex_data);
+
+        let number_of_vertices = indexed_mesh.get_number_of_vertices();
+
+        let (vertex_data, index_data) = indexed_mesh.into_data();
+
+        PolygonInstance::new(
+            parameters.name,
+            parameters.polygon,
+            parameters.transform,
+            number_of_vertices,
+            vertex_data,
+            index_data,
+            geometry_triangles,
+        )
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetName for PolygonInstance<TPolygon, TGeometryTriangle> {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetVertexData for PolygonInstance<TPolygon, TGeometryTriangle> {
+    fn get_vertex_data(&self) -> Vec<f32> {
+        self.vertex_data.clone()
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetIndexData for PolygonInstance<TPolygon, TGeometryTriangle> {
+    fn get_index_data(&self) -> Vec<u32> {
+        self.index_data.clone()
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetNumberOfVertices
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_number_of_vertices(&self) -> i32 {
+        self.number_of_vertices
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetNumberOfObjects
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_number_of_objects(&self) -> i32 {
+        self.geometry_triangles.len() as i32
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetContentInstanceData
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+}
+
+impl<TPolygon, TGeometryTriangle> GetVertexDataVersion
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_vertex_data_version(&self) -> u64 {
+        0
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> GetTransform for PolygonInstance<TPolygon, TGeometryTriangle> {
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+}
+
+impl<TPolygon, TGeometryTriangle> SetTransform for PolygonInstance<TPolygon, TGeometryTriangle> {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+impl<TPolygon: GetRgb<Rgb>, TGeometryTriangle> GetR
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_r(&self) -> f32 {
+        self.polygon.borrow().get_rgb().get_r()
+    }
+}
+
+impl<TPolygon: GetRgb<Rgb>, TGeometryTriangle> GetG
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_g(&self) -> f32 {
+        self.polygon.borrow().get_rgb().get_g()
+    }
+}
+
+impl<TPolygon: GetRgb<Rgb>, TGeometryTriangle> GetB
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_b(&self) -> f32 {
+        self.polygon.borrow().get_rgb().get_b()
+    }
+}
+
+impl<TPolygon: GetRgb<Rgb>, TGeometryTriangle> GetRgbValues
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+}
+
+impl<TPolygon, TGeometryTriangle> GetPolygon<TPolygon>
+    for PolygonInstance<TPolygon, TGeometryTriangle>
+{
+    fn get_polygon(&self) -> Rc<RefCell<TPolygon>> {
+        Rc::clone(&self.polygon)
+    }
+}
+
+pub struct PolygonGeometryTrianglesCreator<
+    TGeometryTriangleConstructor,
+    TTrianglePointCreator,
+    TTrianglePoint,
+> {
+    geometry_triangle_constructor: Rc<TGeometryTriangleConstructor>,
+    triangle_point_creator: Rc<TTrianglePointCreator>,
+    triangle_point_type: PhantomData<TTrianglePoint>,
+}
+
+impl<TGeometryTriangleConstructor, TTrianglePointCreator, TTrianglePoint>
+    PolygonGeometryTrianglesCreator<
+        TGeometryTriangleConstructor,
+        TTrianglePointCreator,
+        TTrianglePoint,
+    >
+{
+    pub fn new(
+        geometry_triangle_constructor: Rc<TGeometryTriangleConstructor>,
+        triangle_point_creator: Rc<TTrianglePointCreator>,
+    ) -> Self {
+        Self {
+            geometry_triangle_constructor,
+            triangle_point_creator,
+            triangle_point_type: PhantomData,
+        }
+    }
+}
+
+impl<
+        TObject: GetRgbValues + GetPolygonTriangles,
+        TGeometryTriangle,
+        TGeometryTriangleConstructor: ConstructGeometryTriangle<TGeometryTriangle, TTrianglePoint>,
+        TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
+        TTrianglePoint,
+    > CreateGeometryTriangles<TGeometryTriangle, TObject>
+    for PolygonGeometryTrianglesCreator<
+        TGeometryTriangleConstructor,
+        TTrianglePointCreator,
+        TTrianglePoint,
+    >
+{
+    fn create_geometry_triangles(
+        &self,
+        object: &TObject,
+        _width: f32,
+        _height: f32,
+    ) -> Vec<TGeometryTriangle> {
+        let create_triangle_point = |point: Vec2| {
+            self.triangle_point_creator.create_triangle_point(
+                point.x,
+                point.y,
+                object.get_r(),
+                object.get_g(),
+                object.get_b(),
+            )
+        };
+
+        object
+            .get_polygon_triangles()
+            .into_iter()
+            .map(|[point_1, point_2, point_3]| {
+                self.geometry_triangle_constructor
+                    .construct_geometry_triangle(
+                        create_triangle_point(point_1),
+                        create_triangle_point(point_2),
+                        create_triangle_point(point_3),
+                    )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use garden_json::ConvertToJson;
+    use serde_json::json;
+
+    use crate::{
+        polygons::{Polygon, PolygonGeometryTrianglesCreator},
+        triangles::{ConstructGeometryTriangle, CreateGeometryTriangles},
+        CreateTrianglePoint, Rgb, TwoDPoint,
+    };
+
+    #[test]
+    fn when_a_polygon_is_converted_to_triangles_then_each_triangle_uses_its_points() {
+        let polygon = Polygon::new(
+            "Polygon1".to_string(),
+            vec![
+                TwoDPoint::new(0.0, 0.0),
+                TwoDPoint::new(2.0, 0.0),
+                TwoDPoint::new(2.0, 2.0),
+                TwoDPoint::new(0.0, 2.0),
+            ],
+            Rgb::new(0.5, 0.5, 0.5),
+            vec![[3, 0, 1], [1, 2, 3]],
+        );
+
+        let geometry_triangles_creator = PolygonGeometryTrianglesCreator::new(
+            Rc::new(TriangleConstructor {}),
+            Rc::new(PointCreator {}),
+        );
+
+        let result = geometry_triangles_creator.create_geometry_triangles(&polygon, 0.0, 0.0);
+
+        assert_eq!(
+            vec![
+                [(0.0, 2.0), (0.0, 0.0), (2.0, 0.0)],
+                [(2.0, 0.0), (2.0, 2.0), (0.0, 2.0)],
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn when_a_polygon_is_converted_to_json_then_its_points_are_included() {
+        let polygon = Polygon::new(
+            "Polygon1".to_string(),
+            vec![
+                TwoDPoint::new(0.0, 0.0),
+                TwoDPoint::new(1.0, 0.0),
+                TwoDPoint::new(0.0, 1.0),
+            ],
+            Rgb::new(0.5, 0.5, 0.5),
+            vec![[0, 1, 2]],
+        );
+
+        let result = polygon.convert_to_json();
+
+        assert_eq!(
+            json!({
+                "name": "Polygon1",
+                "type": "polygon",
+                "points": [
+                    { "x": 0.0, "y": 0.0 },
+                    { "x": 1.0, "y": 0.0 },
+                    { "x": 0.0, "y": 1.0 }
+                ],
+                "rgb": { "r": 0.5, "g": 0.5, "b": 0.5 }
+            }),
+            result
+        );
+    }
+
+    type Point = (f32, f32);
+
+    struct PointCreator {}
+
+    impl CreateTrianglePoint<Point> for PointCreator {
+        fn create_triangle_point(&self, x: f32, y: f32, _r: f32, _g: f32, _b: f32) -> Point {
+            (x, y)
+        }
+    }
+
+    struct TriangleConstructor {}
+
+    impl ConstructGeometryTriangle<[Point; 3], Point> for TriangleConstructor {
+        fn construct_geometry_triangle(
+            &self,
+            triangle_point_1: Point,
+            triangle_point_2: Point,
+            triangle_point_3: Point,
+        ) -> [Point; 3] {
+            [triangle_point_1, triangle_point_2, triangle_point_3]
+        }
+    }
+}
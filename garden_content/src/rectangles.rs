@@ -1,12 +1,16 @@
 use std::{cell::RefCell, marker::PhantomData, rc::Rc};
 
 use garden::{GetHeight, GetName, GetWidth};
+use garden_json::{convert_f32_to_json, ConvertToJson};
+use serde_json::{json, Value};
 
 use crate::{
-    triangles::CreateGeometryTriangles, ConstructObject, CreateObject, CreateTwoDPoint,
-    Get2DCoordiantes, GetB, GetContent, GetContentInstanceData, GetG, GetNumberOfObjects,
-    GetNumberOfVertices, GetPosition, GetR, GetRgb, GetRgbValues, GetScale, GetVertexData, Rgb,
-    ScaleObjectInstance, StoreObject,
+    meshes::IndexedMesh,
+    transforms::{convert_object_instance_to_json, GetTransform, SetTransform, Transform},
+    triangles::CreateGeometryTriangles,
+    ConstructObject, GetB, GetContent, GetContentInstanceData, GetG, GetIndexData,
+    GetNumberOfObjects, GetNumberOfVertices, GetR, GetRgb, GetRgbValues, GetVertexData,
+    GetVertexDataVersion, Rgb, StoreObject,
 };
 
 pub struct Rectangle<TRgb> {
@@ -71,6 +75,18 @@ impl<TRgb: GetB> GetB for Rectangle<TRgb> {
 
 impl<TRgb: GetRgbValues> GetRgbValues for Rectangle<TRgb> {}
 
+impl<TRgb: ConvertToJson> ConvertToJson for Rectangle<TRgb> {
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "type": "rectangle",
+            "width": convert_f32_to_json(self.width),
+            "height": convert_f32_to_json(self.height),
+            "rgb": self.rgb.convert_to_json()
+        })
+    }
+}
+
 pub struct RectangleParameters<TRgb> {
     name: String,
     width: f32,
@@ -108,175 +124,197 @@ impl<TRgb> ConstructObject<Rectangle<TRgb>, RectangleParameters<TRgb>> for Recta
     }
 }
 
-pub struct RectangleInstanceParameters<TRectangle, TTwoDPoint> {
+pub struct RectangleInstanceParameters<TRectangle> {
     name: String,
     rectangle: Rc<RefCell<TRectangle>>,
-    scale: f32,
-    position: TTwoDPoint,
+    transform: Transform,
     width: f32,
     height: f32,
 }
 
-impl<TRectangle, TTwoDPoint> RectangleInstanceParameters<TRectangle, TTwoDPoint> {
+impl<TRectangle> RectangleInstanceParameters<TRectangle> {
     pub fn new(
         name: String,
         rectangle: Rc<RefCell<TRectangle>>,
-        scale: f32,
-        position: TTwoDPoint,
+        transform: Transform,
         width: f32,
         height: f32,
     ) -> Self {
         Self {
             name,
             rectangle,
-            scale,
-            position,
+            transform,
             width,
             height,
         }
     }
 }
 
-pub struct RectangleInstance<TPosition, TRectangle, TGeometryTriangle> {
+pub struct RectangleInstance<TRectangle, TGeometryTriangle> {
     name: String,
     rectangle: Rc<RefCell<TRectangle>>,
-    scale: f32,
-    position: TPosition,
+    transform: Transform,
     number_of_vertices: i32,
     vertex_data: Vec<f32>,
+    index_data: Vec<u32>,
     geometry_triangles: Vec<TGeometryTriangle>,
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle>
-    RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
-{
+impl<TRectangle, TGeometryTriangle> RectangleInstance<TRectangle, TGeometryTriangle> {
     pub fn new(
         name: String,
         rectangle: Rc<RefCell<TRectangle>>,
-        scale: f32,
-        position: TPosition,
+        transform: Transform,
         number_of_vertices: i32,
         vertex_data: Vec<f32>,
+        index_data: Vec<u32>,
         geometry_triangles: Vec<TGeometryTriangle>,
     ) -> Self {
         Self {
             name,
             rectangle,
-            scale,
-            position,
+            transform,
             vertex_data,
+            index_data,
             number_of_vertices,
             geometry_triangles,
         }
     }
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetName
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
-{
+impl<TRectangle, TGeometryTriangle> GetName for RectangleInstance<TRectangle, TGeometryTriangle> {
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetVertexData
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetVertexData
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_vertex_data(&self) -> Vec<f32> {
         self.vertex_data.clone()
     }
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetNumberOfVertices
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetIndexData
+    for RectangleInstance<TRectangle, TGeometryTriangle>
+{
+    fn get_index_data(&self) -> Vec<u32> {
+        self.index_data.clone()
+    }
+}
+
+impl<TRectangle, TGeometryTriangle> GetNumberOfVertices
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_number_of_vertices(&self) -> i32 {
         self.number_of_vertices
     }
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetNumberOfObjects
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetNumberOfObjects
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_number_of_objects(&self) -> i32 {
         2
     }
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetContentInstanceData
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetContentInstanceData
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetScale
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetVertexDataVersion
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
-    fn get_scale(&self) -> f32 {
-        self.scale
+    fn get_vertex_data_version(&self) -> u64 {
+        0
     }
 }
 
-impl<TPosition, TRectangle: GetWidth, TGeometryTriangle> GetWidth
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetTransform
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
-    fn get_width(&self) -> f32 {
-        self.rectangle.borrow().get_width()
+    fn get_transform(&self) -> Transform {
+        self.transform
     }
 }
 
-impl<TPosition, TRectangle: GetHeight, TGeometryTriangle> GetHeight
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> SetTransform
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
-    fn get_height(&self) -> f32 {
-        self.rectangle.borrow().get_height()
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+impl<TRectangle: GetWidth, TGeometryTriangle> GetWidth
+    for RectangleInstance<TRectangle, TGeometryTriangle>
+{
+    fn get_width(&self) -> f32 {
+        self.rectangle.borrow().get_width()
     }
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetPosition<TPosition>
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle: GetHeight, TGeometryTriangle> GetHeight
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
-    fn get_position(&self) -> &TPosition {
-        &self.position
+    fn get_height(&self) -> f32 {
+        self.rectangle.borrow().get_height()
     }
 }
 
-impl<TPosition, TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetR
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetR
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_r(&self) -> f32 {
         self.rectangle.borrow().get_rgb().get_r()
     }
 }
 
-impl<TPosition, TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetG
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetG
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_g(&self) -> f32 {
         self.rectangle.borrow().get_rgb().get_g()
     }
 }
 
-impl<TPosition, TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetB
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetB
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_b(&self) -> f32 {
         self.rectangle.borrow().get_rgb().get_b()
     }
 }
 
-impl<TPosition, TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetRgbValues
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle: GetRgb<Rgb>, TGeometryTriangle> GetRgbValues
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
 }
 
-impl<TPosition, TRectangle, TGeometryTriangle> GetRectangle<TRectangle>
-    for RectangleInstance<TPosition, TRectangle, TGeometryTriangle>
+impl<TRectangle, TGeometryTriangle> GetRectangle<TRectangle>
+    for RectangleInstance<TRectangle, TGeometryTriangle>
 {
     fn get_rectangle(&self) -> Rc<RefCell<TRectangle>> {
         Rc::clone(&self.rectangle)
     }
 }
 
+impl<TRectangle: GetName, TGeometryTriangle> ConvertToJson
+    for RectangleInstance<TRectangle, TGeometryTriangle>
+{
+    fn convert_to_json(&self) -> Value {
+        convert_object_instance_to_json(
+            &self.name,
+            self.rectangle.borrow().get_name(),
+            "rectangle",
+            &self.transform,
+        )
+    }
+}
+
 pub struct RectangleInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle> {
     geometry_triangles_creator: Rc<TGeometryTrianglesCreator>,
     geometry_triangle_type: PhantomData<TGeometryTriangle>,
@@ -295,118 +333,48 @@ impl<TGeometryTrianglesCreator, TGeometryTriangle>
 
 impl<
         TRectangle: GetRgbValues,
-        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TRectangle, TTwoDPoint>,
-        TTwoDPoint: Get2DCoordiantes,
+        TGeometryTrianglesCreator: CreateGeometryTriangles<TGeometryTriangle, TRectangle>,
         TGeometryTriangle: GetNumberOfVertices + GetVertexData,
     >
     ConstructObject<
-        RectangleInstance<TTwoDPoint, TRectangle, TGeometryTriangle>,
-        RectangleInstanceParameters<TRectangle, TTwoDPoint>,
+        RectangleInstance<TRectangle, TGeometryTriangle>,
+        RectangleInstanceParameters<TRectangle>,
     > for RectangleInstanceConstructor<TGeometryTrianglesCreator, TGeometryTriangle>
 {
     fn construct_object(
         &self,
-        parameters: RectangleInstanceParameters<TRectangle, TTwoDPoint>,
-    ) -> RectangleInstance<TTwoDPoint, TRectangle, TGeometryTriangle> {
-        let mut vertex_data = vec![];
-
-        let mut number_of_vertices = 0;
+        parameters: RectangleInstanceParameters<TRectangle>,
+    ) -> RectangleInstance<TRectangle, TGeometryTriangle> {
+        let mut triangle_vertex_data = vec![];
 
         let geometry_triangles = self.geometry_triangles_creator.create_geometry_triangles(
             &parameters.rectangle.borrow(),
-            &parameters.position,
             parameters.width,
             parameters.height,
         );
 
         for geometry_triangle in geometry_triangles.iter() {
-            number_of_vertices += geometry_triangle.get_number_of_vertices();
-            vertex_data.append(&mut geometry_triangle.get_vertex_data());
+            triangle_vertex_data.append(&mut geometry_triangle.get_vertex_data());
         }
 
+        let indexed_mesh = IndexedMesh::from_triangle_vertex_data(&triangle_vertex_data);
+
+        let number_of_vertices = indexed_mesh.get_number_of_vertices();
+
+        let (vertex_data, index_data) = indexed_mesh.into_data();
+
         RectangleInstance::new(
             parameters.name,
             parameters.rectangle,
-            parameters.scale,
-            parameters.position,
+            parameters.transform,
             number_of_vertices,
             vertex_data,
+            index_data,
             geometry_triangles,
         )
     }
 }
 
-pub struct RectangleInstanceScaler<
-    TRectangleInstanceCreator,
-    TTwoDPointCreator,
-    TTwoDPoint,
-    TRectangle,
-> {
-    rectangle_instance_creator: Rc<TRectangleInstanceCreator>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
-    two_d_point_type: PhantomData<TTwoDPoint>,
-    rectangle_type: PhantomData<TRectangle>,
-}
-
-impl<TRectangleInstanceCreator, TTwoDPointCreator, TTwoDPoint, TRectangle>
-    RectangleInstanceScaler<TRectangleInstanceCreator, TTwoDPointCreator, TTwoDPoint, TRectangle>
-{
-    pub fn new(
-        rectangle_instance_creator: Rc<TRectangleInstanceCreator>,
-        two_d_point_creator: Rc<TTwoDPointCreator>,
-    ) -> Self {
-        Self {
-            rectangle_instance_creator: rectangle_instance_creator,
-            two_d_point_creator: two_d_point_creator,
-            two_d_point_type: PhantomData,
-            rectangle_type: PhantomData,
-        }
-    }
-}
-
-impl<
-        'ri,
-        'r,
-        TRectangleInstance: GetName
-            + GetScale
-            + GetPosition<TTwoDPoint>
-            + GetWidth
-            + GetHeight
-            + GetRgbValues
-            + GetRectangle<TRectangle>,
-        TRectangleInstanceCreator: CreateObject<TRectangleInstance, RectangleInstanceParameters<TRectangle, TTwoDPoint>>,
-        TTwoDPointCreator: CreateTwoDPoint<TTwoDPoint>,
-        TTwoDPoint: Get2DCoordiantes,
-        TRectangle,
-    > ScaleObjectInstance<TRectangleInstance>
-    for RectangleInstanceScaler<
-        TRectangleInstanceCreator,
-        TTwoDPointCreator,
-        TTwoDPoint,
-        TRectangle,
-    >
-{
-    fn scale_object_instance(
-        &self,
-        rectangle_instance: Rc<RefCell<TRectangleInstance>>,
-        x: f32,
-        y: f32,
-    ) -> Rc<RefCell<TRectangleInstance>> {
-        self.rectangle_instance_creator
-            .create_object(RectangleInstanceParameters::new(
-                rectangle_instance.borrow().get_name().to_string(),
-                rectangle_instance.borrow().get_rectangle(),
-                rectangle_instance.borrow().get_scale(),
-                self.two_d_point_creator.create_two_d_point(
-                    rectangle_instance.borrow().get_position().get_x() / x,
-                    rectangle_instance.borrow().get_position().get_y() / y,
-                ),
-                rectangle_instance.borrow().get_width() / x,
-                rectangle_instance.borrow().get_height() / y,
-            ))
-    }
-}
-
 pub trait GetRectangle<TRectangle> {
     fn get_rectangle(&self) -> Rc<RefCell<TRectangle>>;
 }
@@ -422,14 +390,14 @@ impl<TContent> ContentProvider<TContent> {
 }
 
 impl<'c, TContent: GetName> GetContent<TContent> for ContentProvider<TContent> {
-    fn get_content(&self, content_name: String) -> Rc<RefCell<TContent>> {
+    fn get_content(&self, content_name: String) -> Option<Rc<RefCell<TContent>>> {
         for content in self.content.iter() {
             if content.borrow().get_name() == content_name {
-                return Rc::clone(&content);
+                return Some(Rc::clone(&content));
             }
         }
 
-        todo!()
+        None
     }
 }
 
@@ -445,11 +413,13 @@ mod tests {
     use std::rc::Rc;
 
     use garden::{GetHeight, GetName, GetWidth};
+    use garden_json::ConvertToJson;
     use mockall::mock;
+    use serde_json::{json, Value};
 
     use crate::{
-        Get2DCoordiantes, GetB, GetG, GetNumberOfObjects, GetNumberOfVertices, GetR, GetRgb,
-        GetScale, GetVertexData, GetX, GetY,
+        transforms::{GetTransform, SetTransform, Transform},
+        GetB, GetG, GetNumberOfObjects, GetNumberOfVertices, GetR, GetRgb, GetVertexData,
     };
 
     use crate::rectangles::{Rectangle, RectangleInstance};
@@ -533,9 +503,7 @@ mod tests {
 
         let rectangle = Rc::new(RefCell::new(MockRectangle::new()));
 
-        let scale = 0.0;
-
-        let position = MockRectanglePosition::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 0;
 
@@ -546,10 +514,10 @@ mod tests {
         let rectangle_instance = RectangleInstance::new(
             name.to_string(),
             rectangle,
-            scale,
-            position,
+            transform,
             number_of_vertices,
             vertex_data,
+            vec![],
             geometry_triangles,
         );
 
@@ -564,9 +532,7 @@ mod tests {
 
         let rectangle = Rc::new(RefCell::new(MockRectangle::new()));
 
-        let scale = 0.0;
-
-        let position = MockRectanglePosition::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 0;
 
@@ -577,10 +543,10 @@ mod tests {
         let rectangle_instance = RectangleInstance::new(
             name.to_string(),
             rectangle,
-            scale,
-            position,
+            transform,
             number_of_vertices,
             vertex_data.clone(),
+            vec![],
             geometry_triangles,
         );
 
@@ -596,9 +562,7 @@ mod tests {
 
         let rectangle = Rc::new(RefCell::new(MockRectangle::new()));
 
-        let scale = 0.0;
-
-        let position = MockRectanglePosition::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 123;
 
@@ -609,10 +573,10 @@ mod tests {
         let rectangle_instance = RectangleInstance::new(
             name.to_string(),
             rectangle,
-            scale,
-            position,
+            transform,
             number_of_vertices,
             vertex_data.clone(),
+            vec![],
             geometry_triangles,
         );
 
@@ -628,9 +592,7 @@ mod tests {
 
         let rectangle = Rc::new(RefCell::new(MockRectangle::new()));
 
-        let scale = 0.0;
-
-        let position = MockRectanglePosition::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 0;
 
@@ -641,10 +603,10 @@ mod tests {
         let rectangle_instance = RectangleInstance::new(
             name.to_string(),
             rectangle,
-            scale,
-            position,
+            transform,
             number_of_vertices,
             vertex_data.clone(),
+            vec![],
             geometry_triangles,
         );
 
@@ -654,14 +616,12 @@ mod tests {
     }
 
     #[test]
-    fn when_a_rectangle_instance_gets_its_scale_then_the_scale_is_returned() {
+    fn when_a_rectangle_instance_gets_its_transform_then_the_transform_is_returned() {
         let name = "";
 
         let rectangle = Rc::new(RefCell::new(MockRectangle::new()));
 
-        let scale = 1.23;
-
-        let position = MockRectanglePosition::new();
+        let transform = Transform::new(1.0, 2.0, 30.0, 4.0, 5.0);
 
         let number_of_vertices = 0;
 
@@ -672,16 +632,66 @@ mod tests {
         let rectangle_instance = RectangleInstance::new(
             name.to_string(),
             rectangle,
-            scale,
-            position,
+            transform,
             number_of_vertices,
             vertex_data.clone(),
+            vec![],
             geometry_triangles,
         );
 
-        let result = rectangle_instance.get_scale();
+        let result = rectangle_instance.get_transform();
+
+        assert_eq!(transform, result);
+    }
+
+    #[test]
+    fn when_a_rectangle_instance_with_a_transform_gets_its_vertex_data_then_the_vertex_data_is_untransformed(
+    ) {
+        let name = "";
+
+        let rectangle = Rc::new(RefCell::new(MockRectangle::new()));
+
+        let mut rectangle_instance = RectangleInstance::new(
+            name.to_string(),
+            rectangle,
+            Transform::new(0.0, 0.0, 0.0, 1.0, 1.0),
+            10,
+            vec![1.0, 0.5, 0.1, 0.2, 0.3, -1.0, -0.5, 0.1, 0.2, 0.3],
+            vec![],
+            Vec::<MockGeometryTriangle>::new(),
+        );
+
+        rectangle_instance.set_transform(Transform::new(3.0, 4.0, 0.0, 2.0, -1.0));
+
+        let result = rectangle_instance.get_vertex_data();
+
+        assert_eq!(
+            vec![1.0, 0.5, 0.1, 0.2, 0.3, -1.0, -0.5, 0.1, 0.2, 0.3],
+            result
+        );
+    }
+
+    #[test]
+    fn when_a_rectangle_is_converted_to_json_then_the_json_is_returned() {
+        let mut rgb = MockRectangleRgb::new();
+
+        rgb.expect_convert_to_json()
+            .returning(|| json!({ "r": 0.1, "g": 0.2, "b": 0.3 }));
+
+        let rectangle = Rectangle::<MockRectangleRgb>::new("Rectangle1".to_string(), 2.5, 4.0, rgb);
+
+        let result = rectangle.convert_to_json();
 
-        assert_eq!(scale, result);
+        assert_eq!(
+            json!({
+                "name": "Rectangle1",
+                "type": "rectangle",
+                "width": 2.5,
+                "height": 4.0,
+                "rgb": { "r": 0.1, "g": 0.2, "b": 0.3 }
+            }),
+            result
+        );
     }
 
     mock! {
@@ -695,17 +705,9 @@ mod tests {
         impl GetB for RectangleRgb{
             fn get_b(&self) -> f32;
         }
-    }
-
-    mock! {
-        RectanglePosition {}
-        impl GetX for RectanglePosition {
-            fn get_x(&self) -> f32;
-        }
-        impl GetY for RectanglePosition {
-            fn get_y(&self) -> f32;
+        impl ConvertToJson for RectangleRgb{
+            fn convert_to_json(&self) -> Value;
         }
-        impl Get2DCoordiantes for RectanglePosition {}
     }
 
     mock! {
@@ -0,0 +1,199 @@
+use garden_json::{convert_f32_to_json, ConvertToJson};
+use garden_maths::{matrices::Mat3, vectors::Vec2};
+use serde_json::{json, Value};
+
+use crate::{GetX, GetY};
+
+pub trait GetTransform {
+    fn get_transform(&self) -> Transform;
+}
+
+pub trait SetTransform {
+    fn set_transform(&mut self, transform: Transform);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    x: f32,
+    y: f32,
+    rotation: f32,
+    scale_x: f32,
+    scale_y: f32,
+}
+
+impl Transform {
+    pub fn new(x: f32, y: f32, rotation: f32, scale_x: f32, scale_y: f32) -> Self {
+        Self {
+            x,
+            y,
+            rotation,
+            scale_x,
+            scale_y,
+        }
+    }
+
+    pub fn get_rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    pub fn get_scale_x(&self) -> f32 {
+        self.scale_x
+    }
+
+    pub fn get_scale_y(&self) -> f32 {
+        self.scale_y
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+    }
+
+    pub fn set_scale(&mut self, scale_x: f32, scale_y: f32) {
+        self.scale_x = scale_x;
+        self.scale_y = scale_y;
+    }
+
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_scale_rotation_translation(
+            Vec2::new(self.scale_x, self.scale_y),
+            self.rotation.to_radians(),
+            Vec2::new(self.x, self.y),
+        )
+    }
+
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        self.to_mat3().transform_point(Vec2::new(x, y)).into()
+    }
+}
+
+impl GetX for Transform {
+    fn get_x(&self) -> f32 {
+        self.x
+    }
+}
+
+impl GetY for Transform {
+    fn get_y(&self) -> f32 {
+        self.y
+    }
+}
+
+impl ConvertToJson for Transform {
+    fn convert_to_json(&self) -> Value {
+        let scale = if self.scale_x == self.scale_y {
+            convert_f32_to_json(self.scale_x)
+        } else {
+            json!({
+                "x": convert_f32_to_json(self.scale_x),
+                "y": convert_f32_to_json(self.scale_y)
+            })
+        };
+
+        let mut json = json!({
+            "scale": scale,
+            "position": {
+                "x": convert_f32_to_json(self.x),
+                "y": convert_f32_to_json(self.y)
+            }
+        });
+
+        if self.rotation != 0.0 {
+            json["rotation"] = convert_f32_to_json(self.rotation);
+        }
+
+        json
+    }
+}
+
+pub fn convert_object_instance_to_json(
+    name: &str,
+    content_name: &str,
+    object_instance_type: &str,
+    transform: &Transform,
+) -> Value {
+    let mut json = json!({
+        "name": name,
+        "contentName": content_name,
+        "type": object_instance_type
+    });
+
+    if let (Some(object), Value::Object(transform)) =
+        (json.as_object_mut(), transform.convert_to_json())
+    {
+        object.extend(transform);
+    }
+
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::transforms::{convert_object_instance_to_json, Transform};
+
+    fn assert_approximately_equal(expected: (f32, f32), result: (f32, f32)) {
+        assert!(
+            (expected.0 - result.0).abs() < 0.0001 && (expected.1 - result.1).abs() < 0.0001,
+            "expected {expected:?}, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn when_a_transform_transforms_a_point_then_it_is_scaled_rotated_and_translated_in_that_order()
+    {
+        let transform = Transform::new(10.0, -5.0, 90.0, 2.0, 3.0);
+
+        assert_approximately_equal((7.0, -3.0), transform.transform_point(1.0, 1.0));
+        assert_approximately_equal((10.0, -7.0), transform.transform_point(-1.0, 0.0));
+    }
+
+    #[test]
+    fn when_an_identity_transform_transforms_a_point_then_the_point_is_unchanged() {
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
+
+        assert_eq!((0.5, 1.0), transform.transform_point(0.5, 1.0));
+    }
+
+    #[test]
+    fn when_an_object_instance_is_converted_to_json_then_rotation_and_non_uniform_scale_are_only_written_when_used(
+    ) {
+        let mut transform = Transform::new(1.5, -2.0, 0.0, 2.0, 2.0);
+
+        let unrotated_result =
+            convert_object_instance_to_json("Instance1", "Rectangle1", "rectangle", &transform);
+
+        transform.set_rotation(45.0);
+        transform.set_scale(2.0, 0.5);
+
+        let rotated_result =
+            convert_object_instance_to_json("Instance1", "Rectangle1", "rectangle", &transform);
+
+        assert_eq!(
+            json!({
+                "name": "Instance1",
+                "contentName": "Rectangle1",
+                "type": "rectangle",
+                "scale": 2.0,
+                "position": { "x": 1.5, "y": -2.0 }
+            }),
+            unrotated_result
+        );
+        assert_eq!(
+            json!({
+                "name": "Instance1",
+                "contentName": "Rectangle1",
+                "type": "rectangle",
+                "scale": { "x": 2.0, "y": 0.5 },
+                "position": { "x": 1.5, "y": -2.0 },
+                "rotation": 45.0
+            }),
+            rotated_result
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use std::{cell::RefCell, marker::PhantomData, rc::Rc};
 
 use garden::GetName;
+use garden_json::ConvertToJson;
+use serde_json::{json, Value};
 
 use crate::{
-    ConstructObject, CreateObject, CreateTrianglePoint, Get2DCoordiantes, GetContentInstanceData,
-    GetNumberOfObjects, GetNumberOfVertices, GetPosition, GetRgbValues, GetScale,
-    GetTrianglePointProperties, GetVertexData, ScaleObjectInstance, TranslateTwoDPoint,
+    transforms::{convert_object_instance_to_json, GetTransform, SetTransform, Transform},
+    ConstructObject, CreateTrianglePoint, GetContentInstanceData, GetIndexData, GetNumberOfObjects,
+    GetNumberOfVertices, GetRgbValues, GetVertexData, GetVertexDataVersion, VERTEX_STRIDE,
 };
 
 pub trait GetPoint1<TPoint> {
@@ -92,6 +94,18 @@ impl<TTrianglePoint> GetPoint3<TTrianglePoint> for Triangle<TTrianglePoint> {
 
 impl<TTrianglePoint> GetTrianglePoints<TTrianglePoint> for Triangle<TTrianglePoint> {}
 
+impl<TTrianglePoint: ConvertToJson> ConvertToJson for Triangle<TTrianglePoint> {
+    fn convert_to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "type": "triangle",
+            "point1": self.point_1.convert_to_json(),
+            "point2": self.point_2.convert_to_json(),
+            "point3": self.point_3.convert_to_json()
+        })
+    }
+}
+
 pub struct TriangleParameters<TTrianglePoint> {
     name: String,
     point_1: TTrianglePoint,
@@ -158,209 +172,125 @@ impl<TTrianglePoint: GetVertexData + GetNumberOfVertices>
     }
 }
 
-pub trait CalculateTriangleInstancePoint<TTrianglePoint, TTwoDPoint> {
-    fn calculate_triangle_instance_point(
-        &self,
-        point: &TTrianglePoint,
-        position: &TTwoDPoint,
-        scale: f32,
-    ) -> TTrianglePoint;
-}
-
-pub struct TriangleInstancePointCalculator<TTrianglePointCreator> {
-    triangle_point_creator: Rc<TTrianglePointCreator>,
-}
-
-impl<TTrianglePointCreator> TriangleInstancePointCalculator<TTrianglePointCreator> {
-    pub fn new(triangle_point_creator: Rc<TTrianglePointCreator>) -> Self {
-        Self {
-            triangle_point_creator,
-        }
-    }
-}
-
-impl<
-        TTrianglePoint: GetRgbValues + Get2DCoordiantes,
-        TTwoDPoint: Get2DCoordiantes,
-        TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
-    > CalculateTriangleInstancePoint<TTrianglePoint, TTwoDPoint>
-    for TriangleInstancePointCalculator<TTrianglePointCreator>
-{
-    fn calculate_triangle_instance_point(
-        &self,
-        point: &TTrianglePoint,
-        position: &TTwoDPoint,
-        scale: f32,
-    ) -> TTrianglePoint {
-        self.triangle_point_creator.create_triangle_point(
-            point.get_x() * scale + position.get_x(),
-            point.get_y() * scale + position.get_y(),
-            point.get_r(),
-            point.get_g(),
-            point.get_b(),
-        )
-    }
-}
-
-pub struct TriangleInstanceParameters<TTriangle, TPosition, TTrianglePoint> {
+pub struct TriangleInstanceParameters<TTriangle> {
     name: String,
     triangle: Rc<RefCell<TTriangle>>,
-    scale: f32,
-    position: TPosition,
-    point_1: TTrianglePoint,
-    point_2: TTrianglePoint,
-    point_3: TTrianglePoint,
+    transform: Transform,
 }
 
-impl<TTriangle, TPosition, TTrianglePoint>
-    TriangleInstanceParameters<TTriangle, TPosition, TTrianglePoint>
-{
-    pub fn new(
-        name: String,
-        triangle: Rc<RefCell<TTriangle>>,
-        scale: f32,
-        position: TPosition,
-        point_1: TTrianglePoint,
-        point_2: TTrianglePoint,
-        point_3: TTrianglePoint,
-    ) -> Self {
+impl<TTriangle> TriangleInstanceParameters<TTriangle> {
+    pub fn new(name: String, triangle: Rc<RefCell<TTriangle>>, transform: Transform) -> Self {
         Self {
             name,
             triangle,
-            scale,
-            position,
-            point_1,
-            point_2,
-            point_3,
+            transform,
         }
     }
 }
 
-pub struct TriangleInstance<TPosition, TTrianglePoint, TTriangle> {
+pub struct TriangleInstance<TTrianglePoint, TTriangle> {
     name: String,
     triangle: Rc<RefCell<TTriangle>>,
-    scale: f32,
-    position: TPosition,
-    point_1: TTrianglePoint,
-    point_2: TTrianglePoint,
-    point_3: TTrianglePoint,
+    transform: Transform,
     number_of_vertices: i32,
     vertex_data: Vec<f32>,
+    triangle_point_type: PhantomData<TTrianglePoint>,
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> TriangleInstance<TPosition, TTrianglePoint, TTriangle> {
+impl<TTrianglePoint, TTriangle> TriangleInstance<TTrianglePoint, TTriangle> {
     pub fn new(
         name: String,
         triangle: Rc<RefCell<TTriangle>>,
-        scale: f32,
-        position: TPosition,
-        point_1: TTrianglePoint,
-        point_2: TTrianglePoint,
-        point_3: TTrianglePoint,
+        transform: Transform,
         number_of_vertices: i32,
         vertex_data: Vec<f32>,
     ) -> Self {
         Self {
             name,
             triangle,
-            scale,
-            position,
-            point_1,
-            point_2,
-            point_3,
+            transform,
             number_of_vertices,
             vertex_data,
+            triangle_point_type: PhantomData,
         }
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetName
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
+impl<TTrianglePoint, TTriangle> GetName for TriangleInstance<TTrianglePoint, TTriangle> {
     fn get_name(&self) -> &str {
         &self.name
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetScale
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
-    fn get_scale(&self) -> f32 {
-        self.scale
+impl<TTrianglePoint, TTriangle> GetTransform for TriangleInstance<TTrianglePoint, TTriangle> {
+    fn get_transform(&self) -> Transform {
+        self.transform
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetPoint1<TTrianglePoint>
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
-    fn get_point_1(&self) -> &TTrianglePoint {
-        &self.point_1
+impl<TTrianglePoint, TTriangle> SetTransform for TriangleInstance<TTrianglePoint, TTriangle> {
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetPoint2<TTrianglePoint>
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
-    fn get_point_2(&self) -> &TTrianglePoint {
-        &self.point_2
+impl<TTrianglePoint, TTriangle> GetVertexData for TriangleInstance<TTrianglePoint, TTriangle> {
+    fn get_vertex_data(&self) -> Vec<f32> {
+        self.vertex_data.clone()
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetPoint3<TTrianglePoint>
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
-    fn get_point_3(&self) -> &TTrianglePoint {
-        &self.point_3
+impl<TTrianglePoint, TTriangle> GetIndexData for TriangleInstance<TTrianglePoint, TTriangle> {
+    fn get_index_data(&self) -> Vec<u32> {
+        (0..(self.vertex_data.len() / VERTEX_STRIDE) as u32).collect()
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetTrianglePoints<TTrianglePoint>
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
+impl<TTrianglePoint, TTriangle> GetNumberOfVertices
+    for TriangleInstance<TTrianglePoint, TTriangle>
 {
-}
-
-impl<TPosition, TTrianglePoint, TTriangle> GetPosition<TPosition>
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
-    fn get_position(&self) -> &TPosition {
-        &self.position
+    fn get_number_of_vertices(&self) -> i32 {
+        self.number_of_vertices
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetVertexData
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
-{
-    fn get_vertex_data(&self) -> Vec<f32> {
-        self.vertex_data.clone()
+impl<TTrianglePoint, TTriangle> GetNumberOfObjects for TriangleInstance<TTrianglePoint, TTriangle> {
+    fn get_number_of_objects(&self) -> i32 {
+        1
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetNumberOfVertices
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
+impl<TTrianglePoint, TTriangle> GetContentInstanceData
+    for TriangleInstance<TTrianglePoint, TTriangle>
 {
-    fn get_number_of_vertices(&self) -> i32 {
-        self.number_of_vertices
-    }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetNumberOfObjects
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
+impl<TTrianglePoint, TTriangle> GetVertexDataVersion
+    for TriangleInstance<TTrianglePoint, TTriangle>
 {
-    fn get_number_of_objects(&self) -> i32 {
-        1
+    fn get_vertex_data_version(&self) -> u64 {
+        0
     }
 }
 
-impl<TPosition, TTrianglePoint, TTriangle> GetContentInstanceData
-    for TriangleInstance<TPosition, TTrianglePoint, TTriangle>
+impl<TTrianglePoint, TTriangle> GetTriangle<TTriangle>
+    for TriangleInstance<TTrianglePoint, TTriangle>
 {
+    fn get_triangle(&self) -> Rc<RefCell<TTriangle>> {
+        Rc::clone(&self.triangle)
+    }
 }
 
-impl<TTriangle, TTwoDPoint, TTrianglePoint> GetTriangle<TTriangle>
-    for TriangleInstance<TTwoDPoint, TTrianglePoint, TTriangle>
+impl<TTrianglePoint, TTriangle: GetName> ConvertToJson
+    for TriangleInstance<TTrianglePoint, TTriangle>
 {
-    fn get_triangle(&self) -> Rc<RefCell<TTriangle>> {
-        Rc::clone(&self.triangle)
+    fn convert_to_json(&self) -> Value {
+        convert_object_instance_to_json(
+            &self.name,
+            self.triangle.borrow().get_name(),
+            "triangle",
+            &self.transform,
+        )
     }
 }
 
@@ -390,15 +320,14 @@ impl<TTriangleInstanceVertexDataGenerator, TTriangleInstanceVertexCounter>
 }
 
 impl<
-        TTriangle,
-        TPosition,
+        TTriangle: GetTrianglePoints<TTrianglePoint>,
         TTrianglePoint,
         TTriangleInstanceVertexDataGenerator: GenerateTriangleInstanceVertexData<TTrianglePoint>,
         TTriangleInstanceVertexCounter: CountTriangleInstanceVertices<TTrianglePoint>,
     >
     ConstructObject<
-        TriangleInstance<TPosition, TTrianglePoint, TTriangle>,
-        TriangleInstanceParameters<TTriangle, TPosition, TTrianglePoint>,
+        TriangleInstance<TTrianglePoint, TTriangle>,
+        TriangleInstanceParameters<TTriangle>,
     >
     for TriangleInstanceConstructor<
         TTriangleInstanceVertexDataGenerator,
@@ -407,32 +336,28 @@ impl<
 {
     fn construct_object(
         &self,
-        parameters: TriangleInstanceParameters<TTriangle, TPosition, TTrianglePoint>,
-    ) -> TriangleInstance<TPosition, TTrianglePoint, TTriangle> {
+        parameters: TriangleInstanceParameters<TTriangle>,
+    ) -> TriangleInstance<TTrianglePoint, TTriangle> {
         let vertex_data = self
             .triangle_instance_vertex_data_generator
             .generate_triangle_instance_vertex_data(
-                &parameters.point_1,
-                &parameters.point_2,
-                &parameters.point_3,
+                parameters.triangle.borrow().get_point_1(),
+                parameters.triangle.borrow().get_point_2(),
+                parameters.triangle.borrow().get_point_3(),
             );
 
         let number_of_vertices = self
             .triangle_instance_vertex_counter
             .count_triangle_instance_vertices(
-                &parameters.point_1,
-                &parameters.point_2,
-                &parameters.point_3,
+                parameters.triangle.borrow().get_point_1(),
+                parameters.triangle.borrow().get_point_2(),
+                parameters.triangle.borrow().get_point_3(),
             );
 
         TriangleInstance::new(
             parameters.name,
             parameters.triangle,
-            parameters.scale,
-            parameters.position,
-            parameters.point_1,
-            parameters.point_2,
-            parameters.point_3,
+            parameters.transform,
             number_of_vertices,
             vertex_data,
         )
@@ -507,161 +432,6 @@ impl<TTrianglePoint: GetNumberOfVertices> CountTriangleInstanceVertices<TTriangl
     }
 }
 
-pub trait CreateTriangleInstancePoint<TTrianglePoint> {
-    fn create_triangle_instance_point(
-        &self,
-        triangle_point: &TTrianglePoint,
-        x: f32,
-        y: f32,
-    ) -> TTrianglePoint;
-}
-
-pub struct TriangleInstancePointCreator<TTrianglePointCreator> {
-    triangle_point_creator: Rc<TTrianglePointCreator>,
-}
-
-impl<TTrianglePointCreator> TriangleInstancePointCreator<TTrianglePointCreator> {
-    pub fn new(triangle_point_creator: Rc<TTrianglePointCreator>) -> Self {
-        Self {
-            triangle_point_creator,
-        }
-    }
-}
-
-impl<
-        TTrianglePoint: GetTrianglePointProperties,
-        TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
-    > CreateTriangleInstancePoint<TTrianglePoint>
-    for TriangleInstancePointCreator<TTrianglePointCreator>
-{
-    fn create_triangle_instance_point(
-        &self,
-        triangle_point: &TTrianglePoint,
-        x: f32,
-        y: f32,
-    ) -> TTrianglePoint {
-        self.triangle_point_creator.create_triangle_point(
-            triangle_point.get_x() / x,
-            triangle_point.get_y() / y,
-            triangle_point.get_r(),
-            triangle_point.get_g(),
-            triangle_point.get_b(),
-        )
-    }
-}
-
-pub struct TriangleInstanceScaler<
-    TTriangleInstanceCreator,
-    TTriangleInstancePointCreator,
-    TTwoDPointTranslator,
-    TTwoDPoint,
-    TTrianglePoint,
-    TTriangle,
-> {
-    triangle_instance_creator: Rc<TTriangleInstanceCreator>,
-    triangle_instance_point_creator: Rc<TTriangleInstancePointCreator>,
-    two_d_point_translator: Rc<TTwoDPointTranslator>,
-    two_d_point_type: PhantomData<TTwoDPoint>,
-    triangle_point_type: PhantomData<TTrianglePoint>,
-    triangle_type: PhantomData<TTriangle>,
-}
-
-impl<
-        TTriangleInstanceCreator,
-        TTriangleInstancePointCreator,
-        TTwoDPointTranslator,
-        TTwoDPoint,
-        TTrianglePoint,
-        TTriangle,
-    >
-    TriangleInstanceScaler<
-        TTriangleInstanceCreator,
-        TTriangleInstancePointCreator,
-        TTwoDPointTranslator,
-        TTwoDPoint,
-        TTrianglePoint,
-        TTriangle,
-    >
-{
-    pub fn new(
-        triangle_instance_creator: Rc<TTriangleInstanceCreator>,
-        triangle_instance_point_creator: Rc<TTriangleInstancePointCreator>,
-        two_d_point_translator: Rc<TTwoDPointTranslator>,
-    ) -> Self {
-        Self {
-            triangle_instance_creator: triangle_instance_creator,
-            triangle_instance_point_creator: triangle_instance_point_creator,
-            two_d_point_translator: two_d_point_translator,
-            two_d_point_type: PhantomData,
-            triangle_point_type: PhantomData,
-            triangle_type: PhantomData,
-        }
-    }
-}
-
-impl<
-        TTriangleInstanceCreator: CreateObject<
-            TTriangleInstance,
-            TriangleInstanceParameters<TTriangle, TTwoDPoint, TTrianglePoint>,
-        >,
-        TTriangleInstance: GetContentInstanceData
-            + GetPosition<TTwoDPoint>
-            + GetTrianglePoints<TTrianglePoint>
-            + GetName
-            + GetScale
-            + GetTriangle<TTriangle>,
-        TTriangleInstancePointCreator: CreateTriangleInstancePoint<TTrianglePoint>,
-        TPositionTranslator: TranslateTwoDPoint<TTwoDPoint>,
-        TTwoDPoint,
-        TTrianglePoint,
-        TTriangle,
-    > ScaleObjectInstance<TTriangleInstance>
-    for TriangleInstanceScaler<
-        TTriangleInstanceCreator,
-        TTriangleInstancePointCreator,
-        TPositionTranslator,
-        TTwoDPoint,
-        TTrianglePoint,
-        TTriangle,
-    >
-{
-    fn scale_object_instance(
-        &self,
-        triangle_instance: Rc<RefCell<TTriangleInstance>>,
-        x: f32,
-        y: f32,
-    ) -> Rc<RefCell<TTriangleInstance>> {
-        let new_position = self.two_d_point_translator.translate_two_d_point(
-            triangle_instance.borrow().get_position(),
-            x,
-            y,
-        );
-
-        let new_point_1 = self
-            .triangle_instance_point_creator
-            .create_triangle_instance_point(triangle_instance.borrow().get_point_1(), x, y);
-
-        let new_point_2 = self
-            .triangle_instance_point_creator
-            .create_triangle_instance_point(triangle_instance.borrow().get_point_2(), x, y);
-
-        let new_point_3 = self
-            .triangle_instance_point_creator
-            .create_triangle_instance_point(triangle_instance.borrow().get_point_3(), x, y);
-
-        self.triangle_instance_creator
-            .create_object(TriangleInstanceParameters::new(
-                triangle_instance.borrow().get_name().to_string(),
-                triangle_instance.borrow().get_triangle(),
-                triangle_instance.borrow().get_scale(),
-                new_position,
-                new_point_1,
-                new_point_2,
-                new_point_3,
-            ))
-    }
-}
-
 pub trait GetTriangle<TTriangle> {
     fn get_triangle(&self) -> Rc<RefCell<TTriangle>>;
 }
@@ -754,11 +524,10 @@ impl<TTrianglePoint: GetVertexData + GetNumberOfVertices>
     }
 }
 
-pub trait CreateGeometryTriangles<TGeometryTriangle, TObject, TPosition> {
+pub trait CreateGeometryTriangles<TGeometryTriangle, TObject> {
     fn create_geometry_triangles(
         &self,
         object: &TObject,
-        position: &TPosition,
         width: f32,
         height: f32,
     ) -> Vec<TGeometryTriangle>;
@@ -791,12 +560,11 @@ impl<TGeometryTriangleConstructor, TTrianglePointCreator, TTrianglePoint>
 
 impl<
         TObject: GetRgbValues,
-        TPosition: Get2DCoordiantes,
         TGeometryTriangle,
         TGeometryTriangleConstructor: ConstructGeometryTriangle<TGeometryTriangle, TTrianglePoint>,
         TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint>,
         TTrianglePoint,
-    > CreateGeometryTriangles<TGeometryTriangle, TObject, TPosition>
+    > CreateGeometryTriangles<TGeometryTriangle, TObject>
     for GeometryTrianglesCreator<
         TGeometryTriangleConstructor,
         TTrianglePointCreator,
@@ -806,7 +574,6 @@ impl<
     fn create_geometry_triangles(
         &self,
         object: &TObject,
-        position: &TPosition,
         width: f32,
         height: f32,
     ) -> Vec<TGeometryTriangle> {
@@ -816,24 +583,24 @@ impl<
         let y = height / 2.0;
 
         let geometry_triangle_1_point_1 = self.triangle_point_creator.create_triangle_point(
-            position.get_x() + x,
-            position.get_y() + y,
+            x,
+            y,
             object.get_r(),
             object.get_g(),
             object.get_b(),
         );
 
         let geometry_triangle_1_point_2 = self.triangle_point_creator.create_triangle_point(
-            position.get_x() - x,
-            position.get_y() + y,
+            -x,
+            y,
             object.get_r(),
             object.get_g(),
             object.get_b(),
         );
 
         let geometry_triangle_1_point_3 = self.triangle_point_creator.create_triangle_point(
-            position.get_x() - x,
-            position.get_y() - y,
+            -x,
+            -y,
             object.get_r(),
             object.get_g(),
             object.get_b(),
@@ -850,24 +617,24 @@ impl<
         geometry_triangles.push(geometry_triangle_1);
 
         let geometry_triangle_2_point_1 = self.triangle_point_creator.create_triangle_point(
-            position.get_x() + x,
-            position.get_y() + y,
+            x,
+            y,
             object.get_r(),
             object.get_g(),
             object.get_b(),
         );
 
         let geometry_triangle_2_point_2 = self.triangle_point_creator.create_triangle_point(
-            position.get_x() - x,
-            position.get_y() - y,
+            -x,
+            -y,
             object.get_r(),
             object.get_g(),
             object.get_b(),
         );
 
         let geometry_triangle_2_point_3 = self.triangle_point_creator.create_triangle_point(
-            position.get_x() + x,
-            position.get_y() - y,
+            x,
+            -y,
             object.get_r(),
             object.get_g(),
             object.get_b(),
@@ -895,10 +662,11 @@ mod tests {
     use garden::GetName;
     use mockall::mock;
 
+    use crate::transforms::Transform;
     use crate::triangles::{Triangle, TriangleInstance, TriangleParameters};
     use crate::{
-        ConstructObject, GetContentInstanceData, GetNumberOfObjects, GetNumberOfVertices,
-        GetVertexData, Scale, StoreObject,
+        ConstructObject, GetContentInstanceData, GetIndexData, GetNumberOfObjects,
+        GetNumberOfVertices, GetVertexData, StoreObject,
     };
     use crate::{GetX, GetY};
 
@@ -1007,35 +775,20 @@ mod tests {
             0,
         )));
 
-        let scale = 0.0;
-
-        let position = MockVertexObject::new();
-
-        let point_1 = MockVertexObject::new();
-
-        let point_2 = MockVertexObject::new();
-
-        let point_3 = MockVertexObject::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 0;
 
         let vertex_data = vec![];
 
-        let triangle_instance = TriangleInstance::<
-            MockVertexObject,
-            MockVertexObject,
-            Triangle<MockVertexObject>,
-        >::new(
-            name.to_string(),
-            triangle,
-            scale,
-            position,
-            point_1,
-            point_2,
-            point_3,
-            number_of_vertices,
-            vertex_data,
-        );
+        let triangle_instance =
+            TriangleInstance::<MockVertexObject, Triangle<MockVertexObject>>::new(
+                name.to_string(),
+                triangle,
+                transform,
+                number_of_vertices,
+                vertex_data,
+            );
 
         let result = triangle_instance.get_name();
 
@@ -1061,13 +814,7 @@ mod tests {
             0,
         )));
 
-        let scale = 0.0;
-
-        let point_1 = MockVertexObject::new();
-
-        let point_2 = MockVertexObject::new();
-
-        let point_3 = MockVertexObject::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 0;
 
@@ -1075,16 +822,10 @@ mod tests {
             0.0, 0.5, 1.0, 0.0, 0.0, 0.5, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
         ];
 
-        let position = MockVertexObject::new();
-
-        let triangle_instance = TriangleInstance::new(
+        let triangle_instance = TriangleInstance::<MockVertexObject, _>::new(
             name.to_string(),
             triangle,
-            scale,
-            position,
-            point_1,
-            point_2,
-            point_3,
+            transform,
             number_of_vertices,
             vertex_data.clone(),
         );
@@ -1114,28 +855,16 @@ mod tests {
             0,
         )));
 
-        let scale = 0.0;
-
-        let position = MockVertexObject::new();
-
-        let point_1 = MockVertexObject::new();
-
-        let point_2 = MockVertexObject::new();
-
-        let point_3 = MockVertexObject::new();
+        let transform = Transform::new(0.0, 0.0, 0.0, 1.0, 1.0);
 
         let number_of_vertices = 15;
 
         let vertex_data = vec![];
 
-        let triangle_instance = TriangleInstance::new(
+        let triangle_instance = TriangleInstance::<MockVertexObject, _>::new(
             name.to_string(),
             triangle,
-            scale,
-            position,
-            point_1,
-            point_2,
-            point_3,
+            transform,
             number_of_vertices,
             vertex_data,
         );
@@ -1153,8 +882,8 @@ mod tests {
         impl GetNumberOfVertices for VertexObject {
             fn get_number_of_vertices(&self) -> i32;
         }
-        impl Scale for VertexObject {
-            fn scale(&mut self, x: f32, y: f32);
+        impl GetIndexData for VertexObject {
+            fn get_index_data(&self) -> Vec<u32>;
         }
         impl GetNumberOfObjects for VertexObject {
             fn get_number_of_objects(&self) -> i32;
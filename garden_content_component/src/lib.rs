@@ -1,117 +1,433 @@
+use std::{
+    cell::{Cell, RefCell},
+    fs,
+    rc::Rc,
+    time::{Duration, Instant, SystemTime},
+};
+
 use garden::{
-    gl, GetHeight, GetWidth, Initialise, OnCreateGlutinVbo, OnDraw, RunEndComponent,
-    RunFullComponent, RunLoop,
+    gl, input::Input, AddComponent, Create, DrawComponent, FrameTime, RunComponentLifecycle,
+    UpdateComponent,
+};
+use garden_content::{
+    Content, GetIndexDataPtr, GetIndexRanges, GetNumberOfIndices, GetNumberOfVertices,
+    GetObjectInstanceTransforms, GetVertexDataPtr, GetVertexDataVersion,
 };
-use garden_content::{Content, GetNumberOfObjects, GetNumberOfVertices, GetVertexDataPtr};
 use garden_content_loading::compose_content_loader;
+use garden_json::{JsonFileReader, JsonToF32Converter, JsonToStringConverter, ReadJsonDocuments};
 use garden_json_component::JsonComponent;
-use garden_loading::Load;
+use garden_loading::{Load, LoadError};
+use garden_maths::trigonometry::CalculateTrigonometry;
 use garden_maths_component::MathsComponent;
-use garden_winit::AddComponent;
 
-pub fn add_content<TGameInstanceBuilder: AddComponent, TScene: GetWidth + GetHeight>(
+pub fn add_content<TGameInstanceBuilder: AddComponent>(
     game_instance_builder: &mut TGameInstanceBuilder,
-    scene: &TScene,
     json_component: &JsonComponent,
     maths_component: &MathsComponent,
-) {
-    let component = compose_component(scene, json_component, maths_component);
+    file_path: &str,
+) -> Result<Rc<RefCell<Content>>, LoadError> {
+    let content_creator = compose_content_creator(json_component, maths_component, file_path);
+
+    let content = Rc::new(RefCell::new(content_creator.create()?));
+
+    let component = ContentComponent::new(Rc::clone(&content), None::<NeverWatcher>);
 
     game_instance_builder.add(component);
+
+    Ok(content)
 }
 
-fn compose_component<TScene: GetWidth + GetHeight>(
-    scene: &TScene,
+pub fn add_watched_content<TGameInstanceBuilder: AddComponent>(
+    game_instance_builder: &mut TGameInstanceBuilder,
     json_component: &JsonComponent,
     maths_component: &MathsComponent,
-) -> ContentComponent<Content> {
-    let content_loader = compose_content_loader(
+    file_path: &str,
+) -> Result<Rc<RefCell<Content>>, LoadError> {
+    let content_creator = compose_content_creator(json_component, maths_component, file_path);
+
+    let content = Rc::new(RefCell::new(content_creator.create()?));
+
+    let content_file_watcher =
+        ContentFileWatcher::new(file_path, Duration::from_millis(500), content_creator);
+
+    let component = ContentComponent::new(Rc::clone(&content), Some(content_file_watcher));
+
+    game_instance_builder.add(component);
+
+    Ok(content)
+}
+
+fn compose_content_creator(
+    json_component: &JsonComponent,
+    maths_component: &MathsComponent,
+    file_path: &str,
+) -> ContentCreator<impl CalculateTrigonometry + 'static> {
+    ContentCreator::new(
         json_component.get_json_to_f32_converter(),
         json_component.get_json_to_string_converter(),
         maths_component.get_trigonometry_calculator(),
-    );
-    let mut content = content_loader.load();
-
-    content.scale_object_instances(scene.get_width(), scene.get_height());
+        file_path,
+    )
+}
 
-    let content_component = ContentComponent::new(content);
+pub struct ContentCreator<TTrigonometryCalculator> {
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    trigonometry_calculator: Rc<TTrigonometryCalculator>,
+    file_path: String,
+}
 
-    content_component
+impl<TTrigonometryCalculator> ContentCreator<TTrigonometryCalculator> {
+    pub fn new(
+        json_to_f32_converter: Rc<JsonToF32Converter>,
+        json_to_string_converter: Rc<JsonToStringConverter>,
+        trigonometry_calculator: Rc<TTrigonometryCalculator>,
+        file_path: &str,
+    ) -> Self {
+        Self {
+            json_to_f32_converter,
+            json_to_string_converter,
+            trigonometry_calculator,
+            file_path: file_path.to_string(),
+        }
+    }
 }
 
-pub struct ContentInitialiser {}
+impl<TTrigonometryCalculator: CalculateTrigonometry + 'static> Create<Result<Content, LoadError>>
+    for ContentCreator<TTrigonometryCalculator>
+{
+    fn create(&self) -> Result<Content, LoadError> {
+        let content_loader = compose_content_loader(
+            Rc::clone(&self.json_to_f32_converter),
+            Rc::clone(&self.json_to_string_converter),
+            Rc::clone(&self.trigonometry_calculator),
+            JsonFileReader::new(&self.file_path),
+        );
 
-impl ContentInitialiser {
-    fn new() -> Self {
-        Self {}
+        content_loader.load()
     }
 }
 
-impl Initialise for ContentInitialiser {
-    fn initialise(&self) {}
+pub trait WatchContent<TContent> {
+    fn watch_content(&self) -> Option<Result<TContent, LoadError>>;
 }
 
-pub struct ContentLoopRunner {}
+pub struct ContentFileWatcher<TContentCreator> {
+    file_path: String,
+    poll_interval: Duration,
+    content_creator: TContentCreator,
+    last_polled: Cell<Instant>,
+    modified_times: RefCell<Vec<(String, Option<SystemTime>)>>,
+}
 
-impl ContentLoopRunner {
-    fn new() -> Self {
-        Self {}
+impl<TContentCreator> ContentFileWatcher<TContentCreator> {
+    pub fn new(file_path: &str, poll_interval: Duration, content_creator: TContentCreator) -> Self {
+        let content_file_watcher = Self {
+            file_path: file_path.to_string(),
+            poll_interval,
+            content_creator,
+            last_polled: Cell::new(Instant::now()),
+            modified_times: RefCell::new(vec![]),
+        };
+
+        content_file_watcher
+            .modified_times
+            .replace(content_file_watcher.get_modified_times());
+
+        content_file_watcher
     }
-}
 
-impl RunLoop for ContentLoopRunner {
-    fn run_loop(&self) {}
+    fn get_modified_times(&self) -> Vec<(String, Option<SystemTime>)> {
+        let mut file_paths = vec![self.file_path.clone()];
+
+        if let Ok(json_documents) = JsonFileReader::new(&self.file_path).read_json_documents() {
+            for json_document in json_documents {
+                if json_document.get_file_path() != self.file_path {
+                    file_paths.push(json_document.get_file_path().to_string());
+                }
+            }
+        }
+
+        file_paths
+            .into_iter()
+            .map(|file_path| {
+                let modified_time = fs::metadata(&file_path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok();
+
+                (file_path, modified_time)
+            })
+            .collect()
+    }
 }
 
-pub struct ContentComponent<TContent> {
-    content: TContent,
+impl<TContent, TContentCreator: Create<Result<TContent, LoadError>>> WatchContent<TContent>
+    for ContentFileWatcher<TContentCreator>
+{
+    fn watch_content(&self) -> Option<Result<TContent, LoadError>> {
+        if self.last_polled.get().elapsed() < self.poll_interval {
+            return None;
+        }
+
+        self.last_polled.set(Instant::now());
+
+        let modified_times = self.get_modified_times();
+
+        if modified_times == *self.modified_times.borrow() {
+            return None;
+        }
+
+        self.modified_times.replace(modified_times);
+
+        Some(self.content_creator.create())
+    }
 }
 
-impl<TContent> ContentComponent<TContent> {
-    fn new(content: TContent) -> Self {
-        Self { content }
+pub struct NeverWatcher {}
+
+impl<TContent> WatchContent<TContent> for NeverWatcher {
+    fn watch_content(&self) -> Option<Result<TContent, LoadError>> {
+        None
     }
 }
 
-impl<TContent> Initialise for ContentComponent<TContent> {
-    fn initialise(&self) {}
+pub struct ContentComponent<TContent, TContentWatcher> {
+    content: Rc<RefCell<TContent>>,
+    content_watcher: Option<TContentWatcher>,
+    vbo_outdated: bool,
+    vbo_vertex_data_version: u64,
+    model_location: gl::types::GLint,
 }
 
-impl<TContent> RunLoop for ContentComponent<TContent> {
-    fn run_loop(&self) {}
+impl<TContent, TContentWatcher> ContentComponent<TContent, TContentWatcher> {
+    fn new(content: Rc<RefCell<TContent>>, content_watcher: Option<TContentWatcher>) -> Self {
+        Self {
+            content,
+            content_watcher,
+            vbo_outdated: false,
+            vbo_vertex_data_version: 0,
+            model_location: -1,
+        }
+    }
 }
 
-impl<TContent> RunEndComponent for ContentComponent<TContent> {
-    fn run_end_component(self) {}
+impl<TContent, TContentWatcher> RunComponentLifecycle
+    for ContentComponent<TContent, TContentWatcher>
+{
 }
 
-impl<TContent: GetNumberOfObjects> OnDraw for ContentComponent<TContent> {
-    unsafe fn on_draw(&self, gl: &garden::gl::Gl) {
-        for n in 0..self.content.get_number_of_objects() {
-            gl.DrawArrays(
-                gl::TRIANGLES,
-                ((n as i32) * 5) - (2 * n as i32),
-                ((n as i32) * 5) - (2 * n as i32) + 3,
-            );
+impl<TContent, TContentWatcher: WatchContent<TContent>> UpdateComponent
+    for ContentComponent<TContent, TContentWatcher>
+{
+    fn run_loop(&mut self, _frame_time: &FrameTime, _input: &Input) {
+        let content_watcher = match &self.content_watcher {
+            Some(content_watcher) => content_watcher,
+            None => return,
+        };
+
+        match content_watcher.watch_content() {
+            Some(Ok(content)) => {
+                self.content.replace(content);
+                self.vbo_outdated = true;
+            }
+            Some(Err(error)) => {
+                eprintln!("Failed to reload content, keeping the last good content: {error}")
+            }
+            None => (),
         }
     }
 }
 
-impl<TContent: GetNumberOfVertices + GetVertexDataPtr> OnCreateGlutinVbo
-    for ContentComponent<TContent>
+impl<
+        TContent: GetNumberOfVertices
+            + GetVertexDataPtr
+            + GetVertexDataVersion
+            + GetNumberOfIndices
+            + GetIndexDataPtr
+            + GetIndexRanges
+            + GetObjectInstanceTransforms,
+        TContentWatcher,
+    > DrawComponent for ContentComponent<TContent, TContentWatcher>
 {
-    unsafe fn on_create_glutin_vbo(&self, gl: &gl::Gl) {
+    unsafe fn on_create_glutin_vbo(&mut self, gl: &gl::Gl) {
+        let content = self.content.borrow();
+
         gl.BufferData(
             gl::ARRAY_BUFFER,
-            (self.content.get_number_of_vertices() * std::mem::size_of::<f32>() as i32)
+            (content.get_number_of_vertices() * std::mem::size_of::<f32>() as i32)
                 as gl::types::GLsizeiptr,
-            self.content.get_vertex_data_ptr() as *const _,
-            gl::STATIC_DRAW,
+            content.get_vertex_data_ptr() as *const _,
+            gl::DYNAMIC_DRAW,
         );
+
+        gl.BufferData(
+            gl::ELEMENT_ARRAY_BUFFER,
+            (content.get_number_of_indices() * std::mem::size_of::<u32>() as i32)
+                as gl::types::GLsizeiptr,
+            content.get_index_data_ptr() as *const _,
+            gl::DYNAMIC_DRAW,
+        );
+
+        self.vbo_vertex_data_version = content.get_vertex_data_version();
+
+        let mut program = 0;
+        gl.GetIntegerv(gl::CURRENT_PROGRAM, &mut program);
+
+        self.model_location =
+            gl.GetUniformLocation(program as gl::types::GLuint, c"u_model".as_ptr());
+    }
+
+    unsafe fn on_draw(&mut self, gl: &garden::gl::Gl, _interpolation: f32) {
+        if self.vbo_outdated
+            || self.vbo_vertex_data_version != self.content.borrow().get_vertex_data_version()
+        {
+            self.on_create_glutin_vbo(gl);
+            self.vbo_outdated = false;
+        }
+
+        let content = self.content.borrow();
+
+        for (index_range, transform) in content
+            .get_index_ranges()
+            .iter()
+            .zip(content.get_object_instance_transforms())
+        {
+            if index_range.get_count() > 0 {
+                gl.UniformMatrix3fv(
+                    self.model_location,
+                    1,
+                    gl::FALSE,
+                    transform.to_mat3().to_cols_array().as_ptr(),
+                );
+                gl.DrawElements(
+                    gl::TRIANGLES,
+                    index_range.get_count(),
+                    gl::UNSIGNED_INT,
+                    (index_range.get_first() as usize * std::mem::size_of::<u32>()) as *const _,
+                );
+            }
+        }
     }
 }
 
-impl<TContent: GetNumberOfVertices + GetVertexDataPtr + GetNumberOfObjects> RunFullComponent
-    for ContentComponent<TContent>
-{
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        env, fs,
+        path::PathBuf,
+        process,
+        rc::Rc,
+        time::{Duration, SystemTime},
+    };
+
+    use garden::{input::Input, Create, FrameTime, UpdateComponent};
+    use garden_loading::LoadError;
+    use mockall::mock;
+
+    use crate::{ContentComponent, ContentFileWatcher, WatchContent};
+
+    fn create_test_directory(test_name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!(
+            "garden_content_component_{test_name}_{}",
+            process::id()
+        ));
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
+    mock! {
+        ContentWatcher {}
+        impl WatchContent<String> for ContentWatcher {
+            fn watch_content(&self) -> Option<Result<String, LoadError>>;
+        }
+    }
+
+    mock! {
+        ContentCreator {}
+        impl Create<Result<String, LoadError>> for ContentCreator {
+            fn create(&self) -> Result<String, LoadError>;
+        }
+    }
+
+    #[test]
+    fn when_a_content_component_runs_a_loop_and_the_content_is_reloaded_then_the_content_is_replaced(
+    ) {
+        let mut content_watcher = MockContentWatcher::new();
+        content_watcher
+            .expect_watch_content()
+            .times(1)
+            .returning(|| Some(Ok("new content".to_string())));
+
+        let mut content_component = ContentComponent::new(
+            Rc::new(RefCell::new("old content".to_string())),
+            Some(content_watcher),
+        );
+
+        content_component.run_loop(
+            &FrameTime::new(1.0 / 60.0, 1.0 / 60.0, 1),
+            &Input::new(800.0, 600.0),
+        );
+
+        assert_eq!("new content", *content_component.content.borrow());
+        assert!(content_component.vbo_outdated);
+    }
+
+    #[test]
+    fn when_a_content_component_runs_a_loop_and_the_content_fails_to_reload_then_the_last_good_content_is_kept(
+    ) {
+        let mut content_watcher = MockContentWatcher::new();
+        content_watcher
+            .expect_watch_content()
+            .times(1)
+            .returning(|| Some(Err(LoadError::missing_field().within("objects"))));
+
+        let mut content_component = ContentComponent::new(
+            Rc::new(RefCell::new("old content".to_string())),
+            Some(content_watcher),
+        );
+
+        content_component.run_loop(
+            &FrameTime::new(1.0 / 60.0, 1.0 / 60.0, 1),
+            &Input::new(800.0, 600.0),
+        );
+
+        assert_eq!("old content", *content_component.content.borrow());
+        assert!(!content_component.vbo_outdated);
+    }
+
+    #[test]
+    fn when_a_content_file_watcher_watches_content_then_content_is_only_created_after_the_file_changes(
+    ) {
+        let directory = create_test_directory("watch_test");
+        let file_path = directory.join("content.json");
+        fs::write(&file_path, r#"{ "objects": [] }"#).unwrap();
+
+        let mut content_creator = MockContentCreator::new();
+        content_creator
+            .expect_create()
+            .times(1)
+            .returning(|| Ok("new content".to_string()));
+
+        let content_file_watcher =
+            ContentFileWatcher::new(file_path.to_str().unwrap(), Duration::ZERO, content_creator);
+
+        let unchanged_result: Option<Result<String, LoadError>> =
+            content_file_watcher.watch_content();
+
+        fs::File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+
+        let changed_result = content_file_watcher.watch_content();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(None, unchanged_result);
+        assert_eq!(Some(Ok("new content".to_string())), changed_result);
+    }
 }
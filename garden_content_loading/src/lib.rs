@@ -2,55 +2,98 @@ use garden::{GetHeight, GetName, GetWidth};
 use garden_content::{
     circles::{
         Circle, CircleConstructor, CircleGeometryTrianglesCreator, CircleInstanceConstructor,
-        CircleInstanceParameters, CircleInstanceScaler, CircleParameters, GetDiameter,
+        CircleInstanceParameters, CircleParameters, CircleTessellation, GetDiameter,
+    },
+    curves::{
+        Curve, CurveConstructor, CurveGeometryTrianglesCreator, CurveInstanceConstructor,
+        CurveInstanceParameters, CurveParameters, CurveShape,
     },
     equilateral_triangles::{
         CalculateEquilateralTrianglePoint, EquilateralTrianglePointCalculator,
     },
+    polygons::{
+        Polygon, PolygonConstructor, PolygonGeometryTrianglesCreator, PolygonInstanceConstructor,
+        PolygonInstanceParameters, PolygonParameters,
+    },
     rectangles::{
         ContentProvider, Rectangle, RectangleConstructor, RectangleInstanceConstructor,
-        RectangleInstanceParameters, RectangleInstanceScaler, RectangleParameters,
+        RectangleInstanceParameters, RectangleParameters,
     },
+    transforms::Transform,
     triangles::{
-        CalculateTriangleInstancePoint, GeometryTriangleConstructor, GeometryTrianglesCreator,
-        GetTrianglePoints, Triangle, TriangleConstructor, TriangleInstanceConstructor,
-        TriangleInstanceParameters, TriangleInstancePointCalculator, TriangleInstancePointCreator,
-        TriangleInstanceScaler, TriangleInstanceVertexCounter, TriangleInstanceVertexDataGenerator,
-        TriangleParameters,
+        GeometryTriangleConstructor, GeometryTrianglesCreator, Triangle, TriangleConstructor,
+        TriangleInstanceConstructor, TriangleInstanceParameters, TriangleInstanceVertexCounter,
+        TriangleInstanceVertexDataGenerator, TriangleParameters,
     },
-    Content, CreateObject, CreateRgb, CreateTrianglePoint, CreateTwoDPoint, Get2DCoordiantes,
-    GetContent, GetNumberOfVertices, GetRgbValues, GetTrianglePointProperties, GetVertexData,
-    ObjectCreator, ObjectInstanceRunner, Rgb, RgbCreator, RunObjectInstance, Store, TrianglePoint,
+    Content, ContentObject, CreateObject, CreateTrianglePoint, Get2DCoordiantes, GetContent,
+    GetNumberOfVertices, GetTrianglePointProperties, GetVertexData, ObjectCreator,
+    ObjectInstanceRunner, Rgb, RgbCreator, RunObjectInstance, Store, TrianglePoint,
     TrianglePointConstructor, TrianglePointCreator, TwoDPoint, TwoDPointCreator,
-    TwoDPointTranslator,
 };
-use garden_json::{ConvertJsonToValue, JsonToF32Converter, JsonToStringConverter};
-use garden_loading::Load;
-use garden_maths::trigonometry::CalculateTrigonometry;
-use serde_json::Value;
-use std::{cell::RefCell, collections::HashMap, fs, marker::PhantomData, rc::Rc};
+use garden_json::{
+    create_object_json_schema, create_object_json_schema_with_optional_properties,
+    ConvertJsonDocumentsToValue, ConvertJsonToValue, ConvertToJson, GetJsonSchema, JsonDocument,
+    JsonToF32Converter, JsonToStringConverter, JsonToU64Converter, ReadJsonDocuments,
+};
+use garden_loading::{Load, LoadError};
+use garden_maths::{
+    triangulation::{EarClippingTriangulator, TriangulatePolygon},
+    trigonometry::{CalculateTrigonometry, MAXIMUM_CIRCLE_SEGMENTS, MINIMUM_CIRCLE_SEGMENTS},
+    vectors::Vec2,
+};
+use serde_json::{json, Value};
+use std::{cell::RefCell, collections::HashMap, marker::PhantomData, rc::Rc};
+
+pub trait RegisterContentType {
+    fn register_content_type<
+        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>> + GetJsonSchema + 'static,
+        TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<Box<dyn RunObjectInstance>> + GetJsonSchema + 'static,
+    >(
+        &mut self,
+        value_type: &str,
+        json_to_object_converter: TJsonToObjectConverter,
+        json_to_object_instance_runner_converter: TJsonToObjectInstanceRunnerConverter,
+    );
+}
 
-pub struct ContentLoader<TJsonToContentConverter> {
+pub struct ContentLoader<TJsonToContentConverter, TJsonDocumentsReader> {
     json_to_content_converter: TJsonToContentConverter,
+    json_documents_reader: TJsonDocumentsReader,
 }
 
-impl<'a, TJsonToContentConverter> ContentLoader<TJsonToContentConverter> {
-    pub fn new(json_to_content_converter: TJsonToContentConverter) -> Self {
+impl<TJsonToContentConverter, TJsonDocumentsReader>
+    ContentLoader<TJsonToContentConverter, TJsonDocumentsReader>
+{
+    pub fn new(
+        json_to_content_converter: TJsonToContentConverter,
+        json_documents_reader: TJsonDocumentsReader,
+    ) -> Self {
         Self {
             json_to_content_converter,
+            json_documents_reader,
         }
     }
 }
 
-impl<'a, TJsonToContentConverter: ConvertJsonToValue<TContent>, TContent> Load<TContent>
-    for ContentLoader<TJsonToContentConverter>
+impl<
+        TJsonToContentConverter: ConvertJsonDocumentsToValue<TContent>,
+        TJsonDocumentsReader: ReadJsonDocuments,
+        TContent,
+    > Load<TContent> for ContentLoader<TJsonToContentConverter, TJsonDocumentsReader>
 {
-    fn load(self) -> TContent {
-        let file_contents = fs::read_to_string("content.json").unwrap();
+    fn load(self) -> Result<TContent, LoadError> {
+        let json_documents = self.json_documents_reader.read_json_documents()?;
 
-        let json: Value = serde_json::from_str(file_contents.as_str()).unwrap();
+        self.json_to_content_converter
+            .convert_json_documents_to_value(&json_documents)
+    }
+}
 
-        self.json_to_content_converter.convert_json_to_value(&json)
+impl<TJsonToContentConverter: GetJsonSchema, TJsonDocumentsReader> GetJsonSchema
+    for ContentLoader<TJsonToContentConverter, TJsonDocumentsReader>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_content_converter.get_json_schema()
     }
 }
 
@@ -60,8 +103,7 @@ pub struct JsonToContentConverter<TJsonToObjectConverter, TJsonToObjectInstanceR
 }
 
 impl<
-        'a,
-        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>,
+        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>,
         TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<Box<dyn RunObjectInstance>>,
     > JsonToContentConverter<TJsonToObjectConverter, TJsonToObjectInstanceRunnerConverter>
 {
@@ -74,40 +116,178 @@ impl<
             json_to_object_instance_runner_converter,
         }
     }
+
+    fn convert_objects(
+        &self,
+        json: &Value,
+        objects: &mut Vec<Box<Rc<RefCell<dyn ContentObject>>>>,
+    ) -> Result<(), LoadError> {
+        let object_json_array = match &json["content"]["objects"] {
+            Value::Null => return Ok(()),
+            Value::Array(object_json_array) => object_json_array,
+            _ => {
+                return Err(LoadError::wrong_field_type("array")
+                    .within("objects")
+                    .within("content"))
+            }
+        };
+
+        for (index, object_json) in object_json_array.iter().enumerate() {
+            objects.push(
+                self.json_to_object_converter
+                    .convert_json_to_value(object_json)
+                    .map_err(|error| {
+                        error
+                            .within(&index.to_string())
+                            .within("objects")
+                            .within("content")
+                    })?,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn convert_object_instance_runners(
+        &self,
+        json: &Value,
+        object_instance_runners: &mut Vec<Box<dyn RunObjectInstance>>,
+    ) -> Result<(), LoadError> {
+        let object_instance_json_array = match &json["objects"] {
+            Value::Null => return Ok(()),
+            Value::Array(object_instance_json_array) => object_instance_json_array,
+            _ => return Err(LoadError::wrong_field_type("array").within("objects")),
+        };
+
+        for (index, object_instance_json) in object_instance_json_array.iter().enumerate() {
+            object_instance_runners.push(
+                self.json_to_object_instance_runner_converter
+                    .convert_json_to_value(object_instance_json)
+                    .map_err(|error| error.within(&index.to_string()).within("objects"))?,
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl<
-        'a,
-        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>,
+        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>,
         TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<Box<dyn RunObjectInstance>>,
     > ConvertJsonToValue<Content>
     for JsonToContentConverter<TJsonToObjectConverter, TJsonToObjectInstanceRunnerConverter>
 {
-    fn convert_json_to_value(&self, json: &Value) -> Content {
-        let mut objects = Vec::<Box<Rc<RefCell<dyn GetName>>>>::new();
+    fn convert_json_to_value(&self, json: &Value) -> Result<Content, LoadError> {
+        let mut objects = Vec::<Box<Rc<RefCell<dyn ContentObject>>>>::new();
 
-        if let Some(object_json_array) = json["content"]["objects"].as_array() {
-            for object_json in object_json_array {
-                objects.push(
-                    self.json_to_object_converter
-                        .convert_json_to_value(object_json),
-                );
-            }
+        self.convert_objects(json, &mut objects)?;
+
+        let mut object_instance_runners = Vec::<Box<dyn RunObjectInstance>>::new();
+
+        self.convert_object_instance_runners(json, &mut object_instance_runners)?;
+
+        Ok(Content::new(objects, object_instance_runners))
+    }
+}
+
+impl<
+        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>,
+        TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<Box<dyn RunObjectInstance>>,
+    > ConvertJsonDocumentsToValue<Content>
+    for JsonToContentConverter<TJsonToObjectConverter, TJsonToObjectInstanceRunnerConverter>
+{
+    fn convert_json_documents_to_value(
+        &self,
+        json_documents: &[JsonDocument],
+    ) -> Result<Content, LoadError> {
+        let mut object_file_paths = HashMap::<String, String>::new();
+
+        let mut objects = Vec::<Box<Rc<RefCell<dyn ContentObject>>>>::new();
+
+        for json_document in json_documents {
+            let json = json_document.get_json();
+            let file_path = json_document.get_file_path();
+
+            check_for_duplicate_names(
+                &json["content"]["objects"],
+                file_path,
+                &mut object_file_paths,
+            )?;
+
+            self.convert_objects(json, &mut objects)
+                .map_err(|error| error.in_file(file_path))?;
         }
 
+        let mut object_instance_file_paths = HashMap::<String, String>::new();
+
         let mut object_instance_runners = Vec::<Box<dyn RunObjectInstance>>::new();
 
-        if let Some(object_instance_json_array) = json["objects"].as_array() {
-            for object_instance_json in object_instance_json_array {
-                object_instance_runners.push(
-                    self.json_to_object_instance_runner_converter
-                        .convert_json_to_value(object_instance_json),
-                );
-            }
+        for json_document in json_documents {
+            let json = json_document.get_json();
+            let file_path = json_document.get_file_path();
+
+            check_for_duplicate_names(
+                &json["objects"],
+                file_path,
+                &mut object_instance_file_paths,
+            )?;
+
+            self.convert_object_instance_runners(json, &mut object_instance_runners)
+                .map_err(|error| error.in_file(file_path))?;
         }
 
-        Content::new(objects, object_instance_runners)
+        Ok(Content::new(objects, object_instance_runners))
+    }
+}
+
+impl<
+        TJsonToObjectConverter: GetJsonSchema,
+        TJsonToObjectInstanceRunnerConverter: GetJsonSchema,
+    > GetJsonSchema
+    for JsonToContentConverter<TJsonToObjectConverter, TJsonToObjectInstanceRunnerConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "content": {
+                    "type": "object",
+                    "properties": {
+                        "objects": {
+                            "type": "array",
+                            "items": self.json_to_object_converter.get_json_schema()
+                        }
+                    }
+                },
+                "objects": {
+                    "type": "array",
+                    "items": self.json_to_object_instance_runner_converter.get_json_schema()
+                }
+            }
+        })
+    }
+}
+
+fn check_for_duplicate_names(
+    json: &Value,
+    file_path: &str,
+    file_paths_by_name: &mut HashMap<String, String>,
+) -> Result<(), LoadError> {
+    if let Some(json_array) = json.as_array() {
+        for name in json_array.iter().filter_map(|json| json["name"].as_str()) {
+            if let Some(first_file_path) = file_paths_by_name.get(name) {
+                return Err(LoadError::DuplicateName {
+                    name: name.to_string(),
+                    file_path: file_path.to_string(),
+                    first_file_path: first_file_path.to_string(),
+                });
+            }
+
+            file_paths_by_name.insert(name.to_string(), file_path.to_string());
+        }
     }
+
+    Ok(())
 }
 
 pub struct JsonToTriangleConverter<
@@ -169,26 +349,64 @@ impl<
         TTriangle,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TTriangle>> {
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TTriangle>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
         let point_1 = self
             .json_to_triangle_point_converter
-            .convert_json_to_value(&json["point1"]);
+            .convert_json_to_value(&json["point1"])
+            .map_err(|error| error.within("point1"))?;
 
         let point_2 = self
             .json_to_triangle_point_converter
-            .convert_json_to_value(&json["point2"]);
+            .convert_json_to_value(&json["point2"])
+            .map_err(|error| error.within("point2"))?;
 
         let point_3 = self
             .json_to_triangle_point_converter
-            .convert_json_to_value(&json["point3"]);
+            .convert_json_to_value(&json["point3"])
+            .map_err(|error| error.within("point3"))?;
 
         let parameters = TriangleParameters::new(name, point_1, point_2, point_3);
 
-        self.triangle_creator.create_object(parameters)
+        Ok(self.triangle_creator.create_object(parameters))
+    }
+}
+
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToTrianglePointConverter: GetJsonSchema,
+        TTriangleCreator,
+        TTrianglePoint,
+        TTriangle,
+    > GetJsonSchema
+    for JsonToTriangleConverter<
+        TJsonToStringConverter,
+        TJsonToTrianglePointConverter,
+        TTriangleCreator,
+        TTrianglePoint,
+        TTriangle,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("name", self.json_to_string_converter.get_json_schema()),
+            (
+                "point1",
+                self.json_to_triangle_point_converter.get_json_schema(),
+            ),
+            (
+                "point2",
+                self.json_to_triangle_point_converter.get_json_schema(),
+            ),
+            (
+                "point3",
+                self.json_to_triangle_point_converter.get_json_schema(),
+            ),
+        ])
     }
 }
 
@@ -274,18 +492,21 @@ impl<
         TRgb,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TTriangle>> {
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TTriangle>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
         let size = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["size"]);
+            .convert_json_to_value(&json["size"])
+            .map_err(|error| error.within("size"))?;
 
         let rgb = self
             .json_to_rgb_converter
-            .convert_json_to_value(&json["rgb"]);
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
 
         let point_1 = self
             .equilateral_triangle_point_calculator
@@ -301,7 +522,37 @@ impl<
 
         let parameters = TriangleParameters::new(name, point_1, point_2, point_3);
 
-        self.triangle_creator.create_object(parameters)
+        Ok(self.triangle_creator.create_object(parameters))
+    }
+}
+
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+        TEquilateralTrianglePointCalculator,
+        TTriangleCreator,
+        TTrianglePoint,
+        TTriangle,
+        TRgb,
+    > GetJsonSchema
+    for JsonToEquilateralTriangleConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToRgbConverter,
+        TEquilateralTrianglePointCalculator,
+        TTriangleCreator,
+        TTrianglePoint,
+        TTriangle,
+        TRgb,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("name", self.json_to_string_converter.get_json_schema()),
+            ("size", self.json_to_f32_converter.get_json_schema()),
+            ("rgb", self.json_to_rgb_converter.get_json_schema()),
+        ])
     }
 }
 
@@ -319,11 +570,26 @@ impl<TJsonToTriangleConverter> JsonToBoxedTriangleConverter<TJsonToTriangleConve
 
 impl<
         TJsonToTriangleConverter: ConvertJsonToValue<Rc<RefCell<Triangle<TrianglePoint<TwoDPoint, Rgb>>>>>,
-    > ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>
+    > ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>
+    for JsonToBoxedTriangleConverter<TJsonToTriangleConverter>
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Box<Rc<RefCell<dyn ContentObject>>>, LoadError> {
+        let triangle = self
+            .json_to_triangle_converter
+            .convert_json_to_value(json)?;
+
+        Ok(Box::new(triangle))
+    }
+}
+
+impl<TJsonToTriangleConverter: GetJsonSchema> GetJsonSchema
     for JsonToBoxedTriangleConverter<TJsonToTriangleConverter>
 {
-    fn convert_json_to_value(&self, json: &Value) -> Box<Rc<RefCell<dyn GetName>>> {
-        Box::new(self.json_to_triangle_converter.convert_json_to_value(json))
+    fn get_json_schema(&self) -> Value {
+        self.json_to_triangle_converter.get_json_schema()
     }
 }
 
@@ -382,26 +648,55 @@ impl<
         TRectangleCreator,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TRectangle>> {
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TRectangle>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
         let width = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["width"]);
+            .convert_json_to_value(&json["width"])
+            .map_err(|error| error.within("width"))?;
 
         let height = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["height"]);
+            .convert_json_to_value(&json["height"])
+            .map_err(|error| error.within("height"))?;
 
         let rgb = self
             .json_to_rgb_converter
-            .convert_json_to_value(&json["rgb"]);
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
 
         let parameters = RectangleParameters::new(name, width, height, rgb);
 
-        self.rectangle_creator.create_object(parameters)
+        Ok(self.rectangle_creator.create_object(parameters))
+    }
+}
+
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+        TRgb,
+        TRectangleCreator,
+    > GetJsonSchema
+    for JsonToRectangleConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TRectangleCreator,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("name", self.json_to_string_converter.get_json_schema()),
+            ("width", self.json_to_f32_converter.get_json_schema()),
+            ("height", self.json_to_f32_converter.get_json_schema()),
+            ("rgb", self.json_to_rgb_converter.get_json_schema()),
+        ])
     }
 }
 
@@ -460,22 +755,49 @@ impl<
         TRectangleCreator,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TRectangle>> {
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TRectangle>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
         let size = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["size"]);
+            .convert_json_to_value(&json["size"])
+            .map_err(|error| error.within("size"))?;
 
         let rgb = self
             .json_to_rgb_converter
-            .convert_json_to_value(&json["rgb"]);
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
 
         let parameters = RectangleParameters::new(name, size, size, rgb);
 
-        self.rectangle_creator.create_object(parameters)
+        Ok(self.rectangle_creator.create_object(parameters))
+    }
+}
+
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+        TRgb,
+        TRectangleCreator,
+    > GetJsonSchema
+    for JsonToSquareConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TRectangleCreator,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("name", self.json_to_string_converter.get_json_schema()),
+            ("size", self.json_to_f32_converter.get_json_schema()),
+            ("rgb", self.json_to_rgb_converter.get_json_schema()),
+        ])
     }
 }
 
@@ -497,12 +819,27 @@ impl<TJsonToRectangleConverter, TRectangle>
 
 impl<
         TJsonToRectangleConverter: ConvertJsonToValue<Rc<RefCell<TRectangle>>>,
-        TRectangle: GetName + 'static,
-    > ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>
+        TRectangle: ContentObject + 'static,
+    > ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>
+    for JsonToBoxedRectangleConverter<TJsonToRectangleConverter, TRectangle>
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Box<Rc<RefCell<dyn ContentObject>>>, LoadError> {
+        let rectangle = self
+            .json_to_rectangle_converter
+            .convert_json_to_value(json)?;
+
+        Ok(Box::new(rectangle))
+    }
+}
+
+impl<TJsonToRectangleConverter: GetJsonSchema, TRectangle> GetJsonSchema
     for JsonToBoxedRectangleConverter<TJsonToRectangleConverter, TRectangle>
 {
-    fn convert_json_to_value(&self, json: &Value) -> Box<Rc<RefCell<dyn GetName>>> {
-        Box::new(self.json_to_rectangle_converter.convert_json_to_value(json))
+    fn get_json_schema(&self) -> Value {
+        self.json_to_rectangle_converter.get_json_schema()
     }
 }
 
@@ -514,8 +851,6 @@ pub struct JsonToTriangleInstanceConverter<
     TTriangleProvider,
     TTriangle,
     TTwoDPoint,
-    TTrianglePoint,
-    TTriangleInstancePointCalculator,
 > {
     json_to_string_converter: Rc<TJsonToStringConverter>,
     json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
@@ -524,8 +859,6 @@ pub struct JsonToTriangleInstanceConverter<
     triangle_provider: Rc<RefCell<TTriangleProvider>>,
     triangle_type: PhantomData<TTriangle>,
     two_d_point_type: PhantomData<TTwoDPoint>,
-    triangle_point_type: PhantomData<TTrianglePoint>,
-    triangle_instance_point_calculator: Rc<TTriangleInstancePointCalculator>,
 }
 
 impl<
@@ -536,8 +869,6 @@ impl<
         TTriangleProvider,
         TTriangle,
         TTwoDPoint,
-        TTrianglePoint,
-        TTriangleInstancePointCalculator,
     >
     JsonToTriangleInstanceConverter<
         TJsonToStringConverter,
@@ -547,8 +878,6 @@ impl<
         TTriangleProvider,
         TTriangle,
         TTwoDPoint,
-        TTrianglePoint,
-        TTriangleInstancePointCalculator,
     >
 {
     fn new(
@@ -557,7 +886,6 @@ impl<
         json_to_f32_converter: Rc<TJsonToF32Converter>,
         triangle_instance_creator: Rc<TTriangleInstanceCreator>,
         triangle_provider: Rc<RefCell<TTriangleProvider>>,
-        triangle_instance_point_calculator: Rc<TTriangleInstancePointCalculator>,
     ) -> Self {
         Self {
             json_to_string_converter: json_to_string_converter,
@@ -567,8 +895,6 @@ impl<
             triangle_provider: triangle_provider,
             triangle_type: PhantomData,
             two_d_point_type: PhantomData,
-            triangle_point_type: PhantomData,
-            triangle_instance_point_calculator: triangle_instance_point_calculator,
         }
     }
 }
@@ -577,16 +903,11 @@ impl<
         TJsonToStringConverter: ConvertJsonToValue<String>,
         TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint>,
         TJsonToF32Converter: ConvertJsonToValue<f32>,
-        TTriangleInstanceCreator: CreateObject<
-            TTriangleInstance,
-            TriangleInstanceParameters<TTriangle, TTwoDPoint, TTrianglePoint>,
-        >,
+        TTriangleInstanceCreator: CreateObject<TTriangleInstance, TriangleInstanceParameters<TTriangle>>,
         TTriangleInstance,
         TTriangleProvider: GetContent<TTriangle>,
-        TTriangle: GetTrianglePoints<TTrianglePoint>,
+        TTriangle,
         TTwoDPoint: Get2DCoordiantes,
-        TTrianglePoint: GetRgbValues + Get2DCoordiantes,
-        TTriangleInstancePointCalculator: CalculateTriangleInstancePoint<TTrianglePoint, TTwoDPoint>,
     > ConvertJsonToValue<Rc<RefCell<TTriangleInstance>>>
     for JsonToTriangleInstanceConverter<
         TJsonToStringConverter,
@@ -596,85 +917,96 @@ impl<
         TTriangleProvider,
         TTriangle,
         TTwoDPoint,
-        TTrianglePoint,
-        TTriangleInstancePointCalculator,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TTriangleInstance>> {
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Rc<RefCell<TTriangleInstance>>, LoadError> {
         let content_name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["contentName"]);
+            .convert_json_to_value(&json["contentName"])
+            .map_err(|error| error.within("contentName"))?;
 
         let triangle = self
             .triangle_provider
             .borrow_mut()
-            .get_content(content_name);
-
-        let scale = self
-            .json_to_f32_converter
-            .convert_json_to_value(&json["scale"]);
-
-        let position = self
-            .json_to_two_d_point_converter
-            .convert_json_to_value(&json["position"]);
-
-        let point_1 = self
-            .triangle_instance_point_calculator
-            .calculate_triangle_instance_point(
-                triangle.borrow_mut().get_point_1(),
-                &position,
-                scale,
-            );
-
-        let point_2 = self
-            .triangle_instance_point_calculator
-            .calculate_triangle_instance_point(
-                triangle.borrow_mut().get_point_2(),
-                &position,
-                scale,
-            );
+            .get_content(content_name.clone())
+            .ok_or_else(|| LoadError::unknown_content(content_name).within("contentName"))?;
 
-        let point_3 = self
-            .triangle_instance_point_calculator
-            .calculate_triangle_instance_point(
-                triangle.borrow_mut().get_point_3(),
-                &position,
-                scale,
-            );
+        let transform = convert_json_to_transform(
+            self.json_to_f32_converter.as_ref(),
+            self.json_to_two_d_point_converter.as_ref(),
+            json,
+        )?;
 
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
-        let position = self
-            .json_to_two_d_point_converter
-            .convert_json_to_value(&json["position"]);
+        Ok(self
+            .triangle_instance_creator
+            .create_object(TriangleInstanceParameters::new(name, triangle, transform)))
+    }
+}
 
-        self.triangle_instance_creator
-            .create_object(TriangleInstanceParameters::new(
-                name, triangle, scale, position, point_1, point_2, point_3,
-            ))
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToTwoDPointConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TTriangleInstanceCreator,
+        TTriangleProvider,
+        TTriangle,
+        TTwoDPoint,
+    > GetJsonSchema
+    for JsonToTriangleInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter,
+        TJsonToF32Converter,
+        TTriangleInstanceCreator,
+        TTriangleProvider,
+        TTriangle,
+        TTwoDPoint,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_instance_json_schema(
+            self.json_to_string_converter.get_json_schema(),
+            self.json_to_f32_converter.get_json_schema(),
+            self.json_to_two_d_point_converter.get_json_schema(),
+        )
     }
 }
 
 pub struct JsonToCircleConverter<
     TJsonToStringConverter,
     TJsonToF32Converter,
+    TJsonToU64Converter,
     TJsonToRgbConverter,
     TRgb,
     TCircleCreator,
 > {
     json_to_string_converter: Rc<TJsonToStringConverter>,
     json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_u64_converter: Rc<TJsonToU64Converter>,
     json_to_rgb_converter: Rc<TJsonToRgbConverter>,
     rgb_type: PhantomData<TRgb>,
     circle_creator: Rc<TCircleCreator>,
 }
 
-impl<TJsonToStringConverter, TJsonToF32Converter, TJsonToRgbConverter, TRgb, TCircleCreator>
+impl<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCircleCreator,
+    >
     JsonToCircleConverter<
         TJsonToStringConverter,
         TJsonToF32Converter,
+        TJsonToU64Converter,
         TJsonToRgbConverter,
         TRgb,
         TCircleCreator,
@@ -683,12 +1015,14 @@ impl<TJsonToStringConverter, TJsonToF32Converter, TJsonToRgbConverter, TRgb, TCi
     fn new(
         json_to_string_converter: Rc<TJsonToStringConverter>,
         json_to_f32_converter: Rc<TJsonToF32Converter>,
+        json_to_u64_converter: Rc<TJsonToU64Converter>,
         json_to_rgb_converter: Rc<TJsonToRgbConverter>,
         circle_creator: Rc<TCircleCreator>,
     ) -> Self {
         Self {
             json_to_string_converter: json_to_string_converter,
             json_to_f32_converter: json_to_f32_converter,
+            json_to_u64_converter: json_to_u64_converter,
             json_to_rgb_converter: json_to_rgb_converter,
             rgb_type: PhantomData,
             circle_creator: circle_creator,
@@ -699,6 +1033,7 @@ impl<TJsonToStringConverter, TJsonToF32Converter, TJsonToRgbConverter, TRgb, TCi
 impl<
         TJsonToStringConverter: ConvertJsonToValue<String>,
         TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToU64Converter: ConvertJsonToValue<u64>,
         TJsonToRgbConverter: ConvertJsonToValue<TRgb>,
         TRgb,
         TCircleCreator: CreateObject<TCircle, CircleParameters<TRgb>>,
@@ -707,27 +1042,71 @@ impl<
     for JsonToCircleConverter<
         TJsonToStringConverter,
         TJsonToF32Converter,
+        TJsonToU64Converter,
         TJsonToRgbConverter,
         TRgb,
         TCircleCreator,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TCircle>> {
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TCircle>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
         let diameter = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["diameter"]);
+            .convert_json_to_value(&json["diameter"])
+            .map_err(|error| error.within("diameter"))?;
 
         let rgb = self
             .json_to_rgb_converter
-            .convert_json_to_value(&json["rgb"]);
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
+
+        let mut parameters = CircleParameters::new(name, diameter, rgb);
+
+        if let Some(tessellation) = convert_json_to_circle_tessellation(
+            self.json_to_u64_converter.as_ref(),
+            self.json_to_f32_converter.as_ref(),
+            json,
+        )? {
+            parameters.set_circle_tessellation(tessellation);
+        }
 
-        let parameters = CircleParameters::new(name, diameter, rgb);
+        Ok(self.circle_creator.create_object(parameters))
+    }
+}
 
-        self.circle_creator.create_object(parameters)
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToU64Converter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+        TRgb,
+        TCircleCreator,
+    > GetJsonSchema
+    for JsonToCircleConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCircleCreator,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema_with_optional_properties(
+            vec![
+                ("name", self.json_to_string_converter.get_json_schema()),
+                ("diameter", self.json_to_f32_converter.get_json_schema()),
+                ("rgb", self.json_to_rgb_converter.get_json_schema()),
+            ],
+            vec![
+                ("segments", self.json_to_u64_converter.get_json_schema()),
+                ("tolerance", self.json_to_f32_converter.get_json_schema()),
+            ],
+        )
     }
 }
 
@@ -747,12 +1126,25 @@ impl<TJsonToCircleConverter, TCircle> JsonToBoxedCircleConverter<TJsonToCircleCo
 
 impl<
         TJsonToCircleConverter: ConvertJsonToValue<Rc<RefCell<TCircle>>>,
-        TCircle: GetName + 'static,
-    > ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>
+        TCircle: ContentObject + 'static,
+    > ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>
+    for JsonToBoxedCircleConverter<TJsonToCircleConverter, TCircle>
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Box<Rc<RefCell<dyn ContentObject>>>, LoadError> {
+        let circle = self.json_to_circle_converter.convert_json_to_value(json)?;
+
+        Ok(Box::new(circle))
+    }
+}
+
+impl<TJsonToCircleConverter: GetJsonSchema, TCircle> GetJsonSchema
     for JsonToBoxedCircleConverter<TJsonToCircleConverter, TCircle>
 {
-    fn convert_json_to_value(&self, json: &Value) -> Box<Rc<RefCell<dyn GetName>>> {
-        Box::new(self.json_to_circle_converter.convert_json_to_value(json))
+    fn get_json_schema(&self) -> Value {
+        self.json_to_circle_converter.get_json_schema()
     }
 }
 
@@ -816,9 +1208,9 @@ impl<
         TJsonToStringConverter: ConvertJsonToValue<String>,
         TJsonToF32Converter: ConvertJsonToValue<f32>,
         TJsonToPositionConverter: ConvertJsonToValue<TTwoDPoint>,
-        TCircleInstanceCreator: CreateObject<TCircleInstance, CircleInstanceParameters<TCircle, TTwoDPoint>>,
+        TCircleInstanceCreator: CreateObject<TCircleInstance, CircleInstanceParameters<TCircle>>,
         TCircleProvider: GetContent<TCircle>,
-        TTwoDPoint,
+        TTwoDPoint: Get2DCoordiantes,
         TCircleInstance,
         TCircle: GetDiameter,
     > ConvertJsonToValue<Rc<RefCell<TCircleInstance>>>
@@ -832,1024 +1224,2263 @@ impl<
         TCircle,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TCircleInstance>> {
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Rc<RefCell<TCircleInstance>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
         let content_name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["contentName"]);
+            .convert_json_to_value(&json["contentName"])
+            .map_err(|error| error.within("contentName"))?;
 
-        let scale = self
-            .json_to_f32_converter
-            .convert_json_to_value(&json["scale"]);
-
-        let position = self
-            .json_to_position_converter
-            .convert_json_to_value(&json["position"]);
+        let transform = convert_json_to_transform(
+            self.json_to_f32_converter.as_ref(),
+            self.json_to_position_converter.as_ref(),
+            json,
+        )?;
 
-        let circle = self.circle_provider.borrow_mut().get_content(content_name);
+        let circle = self
+            .circle_provider
+            .borrow_mut()
+            .get_content(content_name.clone())
+            .ok_or_else(|| LoadError::unknown_content(content_name).within("contentName"))?;
 
         let diameter = circle.borrow().get_diameter();
 
-        self.circle_instance_creator
+        Ok(self
+            .circle_instance_creator
             .create_object(CircleInstanceParameters::new(
-                name, circle, scale, position, diameter, /*rgb*/
-            ))
-    }
-}
-
-pub struct JsonToBoxedObjectInstanceRunnerConverter<
-    TJsonToObjectInstanceRunnerConverter,
-    TObjectInstanceRunner,
-> {
-    json_to_object_instance_runner_converter: TJsonToObjectInstanceRunnerConverter,
-    phantom_data: PhantomData<TObjectInstanceRunner>,
-}
-
-impl<TJsonToObjectInstanceRunnerConverter, TObjectInstanceRunner>
-    JsonToBoxedObjectInstanceRunnerConverter<
-        TJsonToObjectInstanceRunnerConverter,
-        TObjectInstanceRunner,
-    >
-{
-    fn new(json_to_object_instance_runner_converter: TJsonToObjectInstanceRunnerConverter) -> Self {
-        Self {
-            json_to_object_instance_runner_converter: json_to_object_instance_runner_converter,
-            phantom_data: PhantomData,
-        }
+                name, circle, transform, diameter, /*rgb*/
+            )))
     }
 }
 
 impl<
-        TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<TObjectInstanceRunner>,
-        TObjectInstanceRunner: RunObjectInstance + 'static,
-    > ConvertJsonToValue<Box<dyn RunObjectInstance>>
-    for JsonToBoxedObjectInstanceRunnerConverter<
-        TJsonToObjectInstanceRunnerConverter,
-        TObjectInstanceRunner,
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToPositionConverter: GetJsonSchema,
+        TCircleInstanceCreator,
+        TCircleProvider,
+        TTwoDPoint,
+        TCircle,
+    > GetJsonSchema
+    for JsonToCircleInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TCircleInstanceCreator,
+        TCircleProvider,
+        TTwoDPoint,
+        TCircle,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Box<dyn RunObjectInstance> {
-        Box::new(
-            self.json_to_object_instance_runner_converter
-                .convert_json_to_value(json),
+    fn get_json_schema(&self) -> Value {
+        create_object_instance_json_schema(
+            self.json_to_string_converter.get_json_schema(),
+            self.json_to_f32_converter.get_json_schema(),
+            self.json_to_position_converter.get_json_schema(),
         )
     }
 }
 
-pub struct JsonToRectangleInstanceConverter<
+#[derive(Clone, Copy)]
+enum CurveType {
+    Ellipse,
+    Arc,
+    Sector,
+    Ring,
+}
+
+const CURVE_TYPES: [CurveType; 4] = [
+    CurveType::Ellipse,
+    CurveType::Arc,
+    CurveType::Sector,
+    CurveType::Ring,
+];
+
+impl CurveType {
+    fn get_name(&self) -> &'static str {
+        match self {
+            CurveType::Ellipse => "ellipse",
+            CurveType::Arc => "arc",
+            CurveType::Sector => "sector",
+            CurveType::Ring => "ring",
+        }
+    }
+
+    fn get_field_names(&self) -> &'static [&'static str] {
+        match self {
+            CurveType::Ellipse => &["radiusX", "radiusY"],
+            CurveType::Arc => &["innerRadius", "outerRadius", "startAngle", "endAngle"],
+            CurveType::Sector => &["radius", "startAngle", "endAngle"],
+            CurveType::Ring => &["innerRadius", "outerRadius"],
+        }
+    }
+}
+
+pub struct JsonToCurveConverter<
     TJsonToStringConverter,
     TJsonToF32Converter,
-    TJsonToPositionConverter,
-    TRectangleInstanceCreator,
-    TRectangleProvider,
-    TTwoDPoint,
-    TRectangle,
+    TJsonToU64Converter,
+    TJsonToRgbConverter,
+    TRgb,
+    TCurveCreator,
 > {
+    curve_type: CurveType,
     json_to_string_converter: Rc<TJsonToStringConverter>,
     json_to_f32_converter: Rc<TJsonToF32Converter>,
-    json_to_position_converter: Rc<TJsonToPositionConverter>,
-    rectangle_instance_creator: Rc<TRectangleInstanceCreator>,
-    rectangle_provider: Rc<RefCell<TRectangleProvider>>,
-    two_d_point_type: PhantomData<TTwoDPoint>,
-    rectangle_type: PhantomData<TRectangle>,
+    json_to_u64_converter: Rc<TJsonToU64Converter>,
+    json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+    rgb_type: PhantomData<TRgb>,
+    curve_creator: Rc<TCurveCreator>,
 }
 
 impl<
-        'r,
         TJsonToStringConverter,
         TJsonToF32Converter,
-        TJsonToPositionConverter,
-        TRectangleInstanceCreator,
-        TRectangleProvider,
-        TTwoDPoint,
-        TRectangle,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCurveCreator,
     >
-    JsonToRectangleInstanceConverter<
+    JsonToCurveConverter<
         TJsonToStringConverter,
         TJsonToF32Converter,
-        TJsonToPositionConverter,
-        TRectangleInstanceCreator,
-        TRectangleProvider,
-        TTwoDPoint,
-        TRectangle,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCurveCreator,
     >
 {
     fn new(
+        curve_type: CurveType,
         json_to_string_converter: Rc<TJsonToStringConverter>,
         json_to_f32_converter: Rc<TJsonToF32Converter>,
-        json_to_position_converter: Rc<TJsonToPositionConverter>,
-        rectangle_instance_creator: Rc<TRectangleInstanceCreator>,
-        rectangle_provider: Rc<RefCell<TRectangleProvider>>,
+        json_to_u64_converter: Rc<TJsonToU64Converter>,
+        json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+        curve_creator: Rc<TCurveCreator>,
     ) -> Self {
         Self {
-            json_to_string_converter: json_to_string_converter,
-            json_to_f32_converter: json_to_f32_converter,
-            json_to_position_converter: json_to_position_converter,
-            rectangle_instance_creator: rectangle_instance_creator,
-            rectangle_provider: rectangle_provider,
-            two_d_point_type: PhantomData,
-            rectangle_type: PhantomData,
+            curve_type,
+            json_to_string_converter,
+            json_to_f32_converter,
+            json_to_u64_converter,
+            json_to_rgb_converter,
+            rgb_type: PhantomData,
+            curve_creator,
+        }
+    }
+}
+
+impl<
+        TJsonToStringConverter,
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCurveCreator,
+    >
+    JsonToCurveConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCurveCreator,
+    >
+{
+    fn convert_json_to_curve_shape(&self, json: &Value) -> Result<CurveShape, LoadError> {
+        let mut values = HashMap::new();
+
+        for field_name in self.curve_type.get_field_names() {
+            let value = self
+                .json_to_f32_converter
+                .convert_json_to_value(&json[*field_name])
+                .map_err(|error| error.within(field_name))?;
+
+            values.insert(*field_name, value);
+        }
+
+        for field_name in ["radiusX", "radiusY", "radius", "outerRadius"] {
+            if let Some(&radius) = values.get(field_name) {
+                if radius <= 0.0 {
+                    return Err(LoadError::invalid_value(
+                        radius.to_string(),
+                        "must be > 0".to_string(),
+                    )
+                    .within(field_name));
+                }
+            }
+        }
+
+        if let Some(&inner_radius) = values.get("innerRadius") {
+            let outer_radius = values["outerRadius"];
+
+            if inner_radius < 0.0 || inner_radius >= outer_radius {
+                return Err(LoadError::invalid_value(
+                    inner_radius.to_string(),
+                    format!("must be >= 0 and < outerRadius ({outer_radius})"),
+                )
+                .within("innerRadius"));
+            }
+        }
+
+        if let Some(&end_angle) = values.get("endAngle") {
+            let start_angle = values["startAngle"];
+
+            if end_angle <= start_angle || end_angle - start_angle > 360.0 {
+                return Err(LoadError::invalid_value(
+                    end_angle.to_string(),
+                    format!("must be > startAngle ({start_angle}) and <= startAngle + 360"),
+                )
+                .within("endAngle"));
+            }
         }
+
+        Ok(match self.curve_type {
+            CurveType::Ellipse => CurveShape::Ellipse {
+                radius_x: values["radiusX"],
+                radius_y: values["radiusY"],
+            },
+            CurveType::Arc => CurveShape::Arc {
+                inner_radius: values["innerRadius"],
+                outer_radius: values["outerRadius"],
+                start_angle: values["startAngle"],
+                end_angle: values["endAngle"],
+            },
+            CurveType::Sector => CurveShape::Sector {
+                radius: values["radius"],
+                start_angle: values["startAngle"],
+                end_angle: values["endAngle"],
+            },
+            CurveType::Ring => CurveShape::Ring {
+                inner_radius: values["innerRadius"],
+                outer_radius: values["outerRadius"],
+            },
+        })
     }
 }
 
 impl<
         TJsonToStringConverter: ConvertJsonToValue<String>,
         TJsonToF32Converter: ConvertJsonToValue<f32>,
-        TJsonToPositionConverter: ConvertJsonToValue<TTwoDPoint>,
-        TRectangleInstanceCreator: CreateObject<TRectangleInstance, RectangleInstanceParameters<TRectangle, TTwoDPoint>>,
-        TRectangleProvider: GetContent<TRectangle>,
-        TTwoDPoint,
-        TRectangleInstance,
-        TRectangle: GetWidth + GetHeight,
-    > ConvertJsonToValue<Rc<RefCell<TRectangleInstance>>>
-    for JsonToRectangleInstanceConverter<
+        TJsonToU64Converter: ConvertJsonToValue<u64>,
+        TJsonToRgbConverter: ConvertJsonToValue<TRgb>,
+        TRgb,
+        TCurveCreator: CreateObject<TCurve, CurveParameters<TRgb>>,
+        TCurve,
+    > ConvertJsonToValue<Rc<RefCell<TCurve>>>
+    for JsonToCurveConverter<
         TJsonToStringConverter,
         TJsonToF32Converter,
-        TJsonToPositionConverter,
-        TRectangleInstanceCreator,
-        TRectangleProvider,
-        TTwoDPoint,
-        TRectangle,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCurveCreator,
     >
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rc<RefCell<TRectangleInstance>> {
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TCurve>>, LoadError> {
         let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["name"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
-        let content_name = self
-            .json_to_string_converter
-            .convert_json_to_value(&json["contentName"]);
+        let shape = self.convert_json_to_curve_shape(json)?;
 
-        let scale = self
-            .json_to_f32_converter
-            .convert_json_to_value(&json["scale"]);
+        let rgb = self
+            .json_to_rgb_converter
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
 
-        let position = self
-            .json_to_position_converter
-            .convert_json_to_value(&json["position"]);
+        let mut parameters = CurveParameters::new(name, shape, rgb);
 
-        let rectangle = self
-            .rectangle_provider
-            .borrow_mut()
-            .get_content(content_name);
+        if let Some(tessellation) = convert_json_to_circle_tessellation(
+            self.json_to_u64_converter.as_ref(),
+            self.json_to_f32_converter.as_ref(),
+            json,
+        )? {
+            parameters.set_circle_tessellation(tessellation);
+        }
 
-        let width = rectangle.borrow().get_width();
-        let height = rectangle.borrow().get_height();
+        Ok(self.curve_creator.create_object(parameters))
+    }
+}
 
-        self.rectangle_instance_creator
-            .create_object(RectangleInstanceParameters::new(
-                name, rectangle, scale, position, width, height, /*rgb*/
-            ))
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToU64Converter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+        TRgb,
+        TCurveCreator,
+    > GetJsonSchema
+    for JsonToCurveConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToRgbConverter,
+        TRgb,
+        TCurveCreator,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        let mut properties = vec![("name", self.json_to_string_converter.get_json_schema())];
+
+        for field_name in self.curve_type.get_field_names() {
+            properties.push((field_name, self.json_to_f32_converter.get_json_schema()));
+        }
+
+        properties.push(("rgb", self.json_to_rgb_converter.get_json_schema()));
+
+        create_object_json_schema_with_optional_properties(
+            properties,
+            vec![
+                ("segments", self.json_to_u64_converter.get_json_schema()),
+                ("tolerance", self.json_to_f32_converter.get_json_schema()),
+            ],
+        )
     }
 }
 
-pub struct JsonToObjectInstanceRunnerConverter<
-    TJsonToObjectInstanceConverter,
-    TObjectInstanceScaler,
-> {
-    json_to_object_instance_converter: TJsonToObjectInstanceConverter,
-    object_instance_scaler: Rc<TObjectInstanceScaler>,
+pub struct JsonToBoxedCurveConverter<TJsonToCurveConverter, TCurve> {
+    json_to_curve_converter: TJsonToCurveConverter,
+    curve_type: PhantomData<TCurve>,
 }
 
-impl<TJsonToObjectInstanceConverter, TObjectInstanceScaler>
-    JsonToObjectInstanceRunnerConverter<TJsonToObjectInstanceConverter, TObjectInstanceScaler>
-{
-    fn new(
-        json_to_object_instance_converter: TJsonToObjectInstanceConverter,
-        object_instance_scaler: Rc<TObjectInstanceScaler>,
-    ) -> Self {
+impl<TJsonToCurveConverter, TCurve> JsonToBoxedCurveConverter<TJsonToCurveConverter, TCurve> {
+    fn new(json_to_curve_converter: TJsonToCurveConverter) -> Self {
         Self {
-            json_to_object_instance_converter,
-            object_instance_scaler,
+            json_to_curve_converter,
+            curve_type: PhantomData,
         }
     }
 }
 
 impl<
-        TJsonToObjectInstanceConverter: ConvertJsonToValue<Rc<RefCell<TObjectInstance>>>,
-        TObjectInstanceScaler,
-        TObjectInstance,
-    > ConvertJsonToValue<ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler>>
-    for JsonToObjectInstanceRunnerConverter<TJsonToObjectInstanceConverter, TObjectInstanceScaler>
+        TJsonToCurveConverter: ConvertJsonToValue<Rc<RefCell<TCurve>>>,
+        TCurve: ContentObject + 'static,
+    > ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>
+    for JsonToBoxedCurveConverter<TJsonToCurveConverter, TCurve>
 {
     fn convert_json_to_value(
         &self,
         json: &Value,
-    ) -> ObjectInstanceRunner<TObjectInstance, TObjectInstanceScaler> {
-        ObjectInstanceRunner::new(
-            self.json_to_object_instance_converter
-                .convert_json_to_value(json),
-            Rc::clone(&self.object_instance_scaler),
-        )
+    ) -> Result<Box<Rc<RefCell<dyn ContentObject>>>, LoadError> {
+        let curve = self.json_to_curve_converter.convert_json_to_value(json)?;
+
+        Ok(Box::new(curve))
     }
 }
 
-pub struct TypedJsonToValueConverter<TJsonToStringConverter, TBox> {
+impl<TJsonToCurveConverter: GetJsonSchema, TCurve> GetJsonSchema
+    for JsonToBoxedCurveConverter<TJsonToCurveConverter, TCurve>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_curve_converter.get_json_schema()
+    }
+}
+
+pub struct JsonToCurveInstanceConverter<
+    TJsonToStringConverter,
+    TJsonToF32Converter,
+    TJsonToPositionConverter,
+    TCurveInstanceCreator,
+    TCurveProvider,
+    TTwoDPoint,
+    TCurve,
+> {
     json_to_string_converter: Rc<TJsonToStringConverter>,
-    converter_map: HashMap<String, Box<dyn ConvertJsonToValue<TBox>>>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_position_converter: Rc<TJsonToPositionConverter>,
+    curve_instance_creator: Rc<TCurveInstanceCreator>,
+    curve_provider: Rc<RefCell<TCurveProvider>>,
+    two_d_point_type: PhantomData<TTwoDPoint>,
+    curve_type: PhantomData<TCurve>,
 }
 
-impl<TJsonToStringConverter, TBox> TypedJsonToValueConverter<TJsonToStringConverter, TBox> {
+impl<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TCurveInstanceCreator,
+        TCurveProvider,
+        TTwoDPoint,
+        TCurve,
+    >
+    JsonToCurveInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TCurveInstanceCreator,
+        TCurveProvider,
+        TTwoDPoint,
+        TCurve,
+    >
+{
     fn new(
         json_to_string_converter: Rc<TJsonToStringConverter>,
-        converter_map: HashMap<String, Box<dyn ConvertJsonToValue<TBox>>>,
+        json_to_f32_converter: Rc<TJsonToF32Converter>,
+        json_to_position_converter: Rc<TJsonToPositionConverter>,
+        curve_instance_creator: Rc<TCurveInstanceCreator>,
+        curve_provider: Rc<RefCell<TCurveProvider>>,
     ) -> Self {
         Self {
             json_to_string_converter,
-            converter_map,
+            json_to_f32_converter,
+            json_to_position_converter,
+            curve_instance_creator,
+            curve_provider,
+            two_d_point_type: PhantomData,
+            curve_type: PhantomData,
         }
     }
 }
 
-impl<TJsonToStringConverter: ConvertJsonToValue<String>, TBox> ConvertJsonToValue<TBox>
-    for TypedJsonToValueConverter<TJsonToStringConverter, TBox>
+impl<
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToPositionConverter: ConvertJsonToValue<TTwoDPoint>,
+        TCurveInstanceCreator: CreateObject<TCurveInstance, CurveInstanceParameters<TCurve>>,
+        TCurveProvider: GetContent<TCurve>,
+        TTwoDPoint: Get2DCoordiantes,
+        TCurveInstance,
+        TCurve,
+    > ConvertJsonToValue<Rc<RefCell<TCurveInstance>>>
+    for JsonToCurveInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TCurveInstanceCreator,
+        TCurveProvider,
+        TTwoDPoint,
+        TCurve,
+    >
 {
-    fn convert_json_to_value(&self, json: &Value) -> TBox {
-        let value_type = self
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Rc<RefCell<TCurveInstance>>, LoadError> {
+        let name = self
             .json_to_string_converter
-            .convert_json_to_value(&json["type"]);
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
-        match self.converter_map.get(&value_type) {
-            Some(converter) => converter.convert_json_to_value(json),
-            None => todo!("JSON converter for type {value_type} not found."),
-        }
-    }
-}
+        let content_name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["contentName"])
+            .map_err(|error| error.within("contentName"))?;
 
-pub struct JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter> {
-    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-    json_to_rgb_converter: Rc<TJsonToRgbConverter>,
-}
+        let transform = convert_json_to_transform(
+            self.json_to_f32_converter.as_ref(),
+            self.json_to_position_converter.as_ref(),
+            json,
+        )?;
 
-impl<TJsonToTwoDPointConverter, TJsonToRgbConverter>
-    JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter>
-{
-    pub fn new(
-        json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-        json_to_rgb_converter: Rc<TJsonToRgbConverter>,
-    ) -> Self {
-        Self {
-            json_to_two_d_point_converter,
-            json_to_rgb_converter,
-        }
+        let curve = self
+            .curve_provider
+            .borrow_mut()
+            .get_content(content_name.clone())
+            .ok_or_else(|| LoadError::unknown_content(content_name).within("contentName"))?;
+
+        Ok(self
+            .curve_instance_creator
+            .create_object(CurveInstanceParameters::new(name, curve, transform)))
     }
 }
 
 impl<
-        TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint>,
-        TJsonToRgbConverter: ConvertJsonToValue<TRgb>,
-        TTwoDPoint: GetVertexData + GetNumberOfVertices,
-        TRgb: GetVertexData + GetNumberOfVertices,
-    > ConvertJsonToValue<TrianglePoint<TTwoDPoint, TRgb>>
-    for JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter>
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToPositionConverter: GetJsonSchema,
+        TCurveInstanceCreator,
+        TCurveProvider,
+        TTwoDPoint,
+        TCurve,
+    > GetJsonSchema
+    for JsonToCurveInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TCurveInstanceCreator,
+        TCurveProvider,
+        TTwoDPoint,
+        TCurve,
+    >
 {
-    fn convert_json_to_value(&self, json: &Value) -> TrianglePoint<TTwoDPoint, TRgb> {
-        let two_d_point = self
-            .json_to_two_d_point_converter
-            .convert_json_to_value(&json["twoDPoint"]);
-
-        let rgb = self
-            .json_to_rgb_converter
-            .convert_json_to_value(&json["rgb"]);
+    fn get_json_schema(&self) -> Value {
+        create_object_instance_json_schema(
+            self.json_to_string_converter.get_json_schema(),
+            self.json_to_f32_converter.get_json_schema(),
+            self.json_to_position_converter.get_json_schema(),
+        )
+    }
+}
 
-        let mut vertex_data = vec![];
+pub struct JsonToPolygonConverter<
+    TJsonToStringConverter,
+    TJsonToTwoDPointConverter,
+    TJsonToRgbConverter,
+    TTwoDPoint,
+    TRgb,
+    TPolygonTriangulator,
+    TPolygonCreator,
+> {
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+    two_d_point_type: PhantomData<TTwoDPoint>,
+    rgb_type: PhantomData<TRgb>,
+    polygon_triangulator: Rc<TPolygonTriangulator>,
+    polygon_creator: Rc<TPolygonCreator>,
+}
 
-        vertex_data.append(&mut two_d_point.get_vertex_data());
-        vertex_data.append(&mut rgb.get_vertex_data());
+impl<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter,
+        TJsonToRgbConverter,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    >
+    JsonToPolygonConverter<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter,
+        TJsonToRgbConverter,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    >
+{
+    fn new(
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+        json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+        polygon_triangulator: Rc<TPolygonTriangulator>,
+        polygon_creator: Rc<TPolygonCreator>,
+    ) -> Self {
+        Self {
+            json_to_string_converter,
+            json_to_two_d_point_converter,
+            json_to_rgb_converter,
+            two_d_point_type: PhantomData,
+            rgb_type: PhantomData,
+            polygon_triangulator,
+            polygon_creator,
+        }
+    }
+}
 
-        let number_of_vertices =
-            two_d_point.get_number_of_vertices() + rgb.get_number_of_vertices();
+impl<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint>,
+        TJsonToRgbConverter,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    >
+    JsonToPolygonConverter<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter,
+        TJsonToRgbConverter,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    >
+{
+    fn convert_json_to_points(&self, json: &Value) -> Result<Vec<TTwoDPoint>, LoadError> {
+        let points_json = match json {
+            Value::Null => return Err(LoadError::missing_field()),
+            Value::Array(points_json) => points_json,
+            _ => return Err(LoadError::wrong_field_type("array")),
+        };
+
+        let mut points = vec![];
+
+        for (index, point_json) in points_json.iter().enumerate() {
+            let point = self
+                .json_to_two_d_point_converter
+                .convert_json_to_value(point_json)
+                .map_err(|error| error.within(&index.to_string()))?;
+
+            points.push(point);
+        }
 
-        TrianglePoint::new(two_d_point, rgb, number_of_vertices, vertex_data)
+        Ok(points)
     }
 }
 
-pub struct JsonToTwoDPointConverter<TJsonToF32Converter> {
-    json_to_f32_converter: Rc<TJsonToF32Converter>,
-}
+impl<
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint>,
+        TJsonToRgbConverter: ConvertJsonToValue<TRgb>,
+        TTwoDPoint: Get2DCoordiantes,
+        TRgb,
+        TPolygonTriangulator: TriangulatePolygon,
+        TPolygonCreator: CreateObject<TPolygon, PolygonParameters<TTwoDPoint, TRgb>>,
+        TPolygon,
+    > ConvertJsonToValue<Rc<RefCell<TPolygon>>>
+    for JsonToPolygonConverter<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter,
+        TJsonToRgbConverter,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    >
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rc<RefCell<TPolygon>>, LoadError> {
+        let name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
-impl<TJsonToF32Converter> JsonToTwoDPointConverter<TJsonToF32Converter> {
-    pub fn new(json_to_f32_converter: Rc<TJsonToF32Converter>) -> Self {
-        Self {
-            json_to_f32_converter,
-        }
+        let points = self
+            .convert_json_to_points(&json["points"])
+            .map_err(|error| error.within("points"))?;
+
+        let rgb = self
+            .json_to_rgb_converter
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
+
+        let triangles = self
+            .polygon_triangulator
+            .triangulate_polygon(
+                &points
+                    .iter()
+                    .map(|point| Vec2::new(point.get_x(), point.get_y()))
+                    .collect::<Vec<Vec2>>(),
+            )
+            .map_err(|error| LoadError::invalid_geometry(error.to_string()).within("points"))?;
+
+        Ok(self
+            .polygon_creator
+            .create_object(PolygonParameters::new(name, points, rgb, triangles)))
     }
 }
 
-impl<TJsonToF32Converter: ConvertJsonToValue<f32>> ConvertJsonToValue<TwoDPoint>
-    for JsonToTwoDPointConverter<TJsonToF32Converter>
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToTwoDPointConverter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    > GetJsonSchema
+    for JsonToPolygonConverter<
+        TJsonToStringConverter,
+        TJsonToTwoDPointConverter,
+        TJsonToRgbConverter,
+        TTwoDPoint,
+        TRgb,
+        TPolygonTriangulator,
+        TPolygonCreator,
+    >
 {
-    fn convert_json_to_value(&self, json: &Value) -> TwoDPoint {
-        TwoDPoint::new(
-            self.json_to_f32_converter.convert_json_to_value(&json["x"]),
-            self.json_to_f32_converter.convert_json_to_value(&json["y"]),
-        )
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("name", self.json_to_string_converter.get_json_schema()),
+            (
+                "points",
+                json!({
+                    "type": "array",
+                    "items": self.json_to_two_d_point_converter.get_json_schema()
+                }),
+            ),
+            ("rgb", self.json_to_rgb_converter.get_json_schema()),
+        ])
     }
 }
 
-pub struct JsonToRgbConverter<TJsonToF32Converter> {
-    json_to_f32_converter: Rc<TJsonToF32Converter>,
+pub struct JsonToBoxedPolygonConverter<TJsonToPolygonConverter, TPolygon> {
+    json_to_polygon_converter: TJsonToPolygonConverter,
+    polygon_type: PhantomData<TPolygon>,
 }
 
-impl<TJsonToF32Converter> JsonToRgbConverter<TJsonToF32Converter> {
-    pub fn new(json_to_f32_converter: Rc<TJsonToF32Converter>) -> Self {
+impl<TJsonToPolygonConverter, TPolygon>
+    JsonToBoxedPolygonConverter<TJsonToPolygonConverter, TPolygon>
+{
+    fn new(json_to_polygon_converter: TJsonToPolygonConverter) -> Self {
         Self {
-            json_to_f32_converter,
+            json_to_polygon_converter,
+            polygon_type: PhantomData,
         }
     }
 }
 
-impl<TJsonToF32Converter: ConvertJsonToValue<f32>> ConvertJsonToValue<Rgb>
-    for JsonToRgbConverter<TJsonToF32Converter>
+impl<
+        TJsonToPolygonConverter: ConvertJsonToValue<Rc<RefCell<TPolygon>>>,
+        TPolygon: ContentObject + 'static,
+    > ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>
+    for JsonToBoxedPolygonConverter<TJsonToPolygonConverter, TPolygon>
 {
-    fn convert_json_to_value(&self, json: &Value) -> Rgb {
-        Rgb::new(
-            self.json_to_f32_converter.convert_json_to_value(&json["r"]),
-            self.json_to_f32_converter.convert_json_to_value(&json["g"]),
-            self.json_to_f32_converter.convert_json_to_value(&json["b"]),
-        )
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Box<Rc<RefCell<dyn ContentObject>>>, LoadError> {
+        let polygon = self.json_to_polygon_converter.convert_json_to_value(json)?;
+
+        Ok(Box::new(polygon))
     }
 }
 
-pub fn compose_rectangles<
-    TJsonToStringConverter: ConvertJsonToValue<String> + 'static,
-    TJsonToF32Converter: ConvertJsonToValue<f32> + 'static,
-    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + 'static,
-    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + 'static,
-    TTwoDPointCreator: CreateTwoDPoint<TTwoDPoint> + 'static,
-    TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
-    TTwoDPoint: Get2DCoordiantes + 'static,
-    TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
->(
-    object_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>>,
-    >,
-    object_instance_runner_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>,
-    >,
+impl<TJsonToPolygonConverter: GetJsonSchema, TPolygon> GetJsonSchema
+    for JsonToBoxedPolygonConverter<TJsonToPolygonConverter, TPolygon>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_polygon_converter.get_json_schema()
+    }
+}
+
+pub struct JsonToPolygonInstanceConverter<
+    TJsonToStringConverter,
+    TJsonToF32Converter,
+    TJsonToPositionConverter,
+    TPolygonInstanceCreator,
+    TPolygonProvider,
+    TTwoDPoint,
+    TPolygon,
+> {
     json_to_string_converter: Rc<TJsonToStringConverter>,
     json_to_f32_converter: Rc<TJsonToF32Converter>,
-    json_to_rgb_converter: Rc<TJsontoRgbConverter>,
-    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
-    triangle_point_creator: Rc<TTrianglePointCreator>,
-) {
-    let rectangle_provider = ContentProvider::<Rectangle<Rgb>>::new(vec![]);
+    json_to_position_converter: Rc<TJsonToPositionConverter>,
+    polygon_instance_creator: Rc<TPolygonInstanceCreator>,
+    polygon_provider: Rc<RefCell<TPolygonProvider>>,
+    two_d_point_type: PhantomData<TTwoDPoint>,
+    polygon_type: PhantomData<TPolygon>,
+}
 
-    let rectangle_provider_ref_cell = Rc::new(RefCell::new(rectangle_provider));
+impl<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TPolygonInstanceCreator,
+        TPolygonProvider,
+        TTwoDPoint,
+        TPolygon,
+    >
+    JsonToPolygonInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TPolygonInstanceCreator,
+        TPolygonProvider,
+        TTwoDPoint,
+        TPolygon,
+    >
+{
+    fn new(
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        json_to_f32_converter: Rc<TJsonToF32Converter>,
+        json_to_position_converter: Rc<TJsonToPositionConverter>,
+        polygon_instance_creator: Rc<TPolygonInstanceCreator>,
+        polygon_provider: Rc<RefCell<TPolygonProvider>>,
+    ) -> Self {
+        Self {
+            json_to_string_converter,
+            json_to_f32_converter,
+            json_to_position_converter,
+            polygon_instance_creator,
+            polygon_provider,
+            two_d_point_type: PhantomData,
+            polygon_type: PhantomData,
+        }
+    }
+}
 
-    let rectangle_constructor = Rc::new(RectangleConstructor::new());
+impl<
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToPositionConverter: ConvertJsonToValue<TTwoDPoint>,
+        TPolygonInstanceCreator: CreateObject<TPolygonInstance, PolygonInstanceParameters<TPolygon>>,
+        TPolygonProvider: GetContent<TPolygon>,
+        TTwoDPoint: Get2DCoordiantes,
+        TPolygonInstance,
+        TPolygon,
+    > ConvertJsonToValue<Rc<RefCell<TPolygonInstance>>>
+    for JsonToPolygonInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TPolygonInstanceCreator,
+        TPolygonProvider,
+        TTwoDPoint,
+        TPolygon,
+    >
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Rc<RefCell<TPolygonInstance>>, LoadError> {
+        let name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
-    let rectangle_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&rectangle_constructor),
-        Rc::clone(&rectangle_provider_ref_cell),
-    ));
+        let content_name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["contentName"])
+            .map_err(|error| error.within("contentName"))?;
 
-    let json_to_rectangle_converter = JsonToRectangleConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_rgb_converter),
-        Rc::clone(&rectangle_creator),
-    );
+        let transform = convert_json_to_transform(
+            self.json_to_f32_converter.as_ref(),
+            self.json_to_position_converter.as_ref(),
+            json,
+        )?;
 
-    let json_to_boxed_rectangle_converter =
-        JsonToBoxedRectangleConverter::new(json_to_rectangle_converter);
+        let polygon = self
+            .polygon_provider
+            .borrow_mut()
+            .get_content(content_name.clone())
+            .ok_or_else(|| LoadError::unknown_content(content_name).within("contentName"))?;
 
-    let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
+        Ok(self
+            .polygon_instance_creator
+            .create_object(PolygonInstanceParameters::new(name, polygon, transform)))
+    }
+}
 
-    let geometry_triangles_creator = Rc::new(GeometryTrianglesCreator::new(
-        Rc::clone(&geometry_triangle_constructor),
-        Rc::clone(&triangle_point_creator),
-    ));
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToPositionConverter: GetJsonSchema,
+        TPolygonInstanceCreator,
+        TPolygonProvider,
+        TTwoDPoint,
+        TPolygon,
+    > GetJsonSchema
+    for JsonToPolygonInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TPolygonInstanceCreator,
+        TPolygonProvider,
+        TTwoDPoint,
+        TPolygon,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_instance_json_schema(
+            self.json_to_string_converter.get_json_schema(),
+            self.json_to_f32_converter.get_json_schema(),
+            self.json_to_position_converter.get_json_schema(),
+        )
+    }
+}
 
-    let rectangle_instance_constructor = Rc::new(RectangleInstanceConstructor::new(Rc::clone(
-        &geometry_triangles_creator,
-    )));
+pub struct JsonToBoxedObjectInstanceRunnerConverter<
+    TJsonToObjectInstanceRunnerConverter,
+    TObjectInstanceRunner,
+> {
+    json_to_object_instance_runner_converter: TJsonToObjectInstanceRunnerConverter,
+    phantom_data: PhantomData<TObjectInstanceRunner>,
+}
 
-    let rectangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+impl<TJsonToObjectInstanceRunnerConverter, TObjectInstanceRunner>
+    JsonToBoxedObjectInstanceRunnerConverter<
+        TJsonToObjectInstanceRunnerConverter,
+        TObjectInstanceRunner,
+    >
+{
+    pub fn new(
+        json_to_object_instance_runner_converter: TJsonToObjectInstanceRunnerConverter,
+    ) -> Self {
+        Self {
+            json_to_object_instance_runner_converter: json_to_object_instance_runner_converter,
+            phantom_data: PhantomData,
+        }
+    }
+}
 
-    let rectangle_instance_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&rectangle_instance_constructor),
-        Rc::clone(&rectangle_instance_store),
-    ));
+impl<
+        TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<TObjectInstanceRunner>,
+        TObjectInstanceRunner: RunObjectInstance + 'static,
+    > ConvertJsonToValue<Box<dyn RunObjectInstance>>
+    for JsonToBoxedObjectInstanceRunnerConverter<
+        TJsonToObjectInstanceRunnerConverter,
+        TObjectInstanceRunner,
+    >
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<Box<dyn RunObjectInstance>, LoadError> {
+        let object_instance_runner = self
+            .json_to_object_instance_runner_converter
+            .convert_json_to_value(json)?;
 
-    let json_to_rectangle_instance_converter = JsonToRectangleInstanceConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&rectangle_instance_creator),
-        Rc::clone(&rectangle_provider_ref_cell),
-    );
-
-    let rectangle_instance_scaler = Rc::new(RectangleInstanceScaler::new(
-        Rc::clone(&rectangle_instance_creator),
-        Rc::clone(&two_d_point_creator),
-    ));
-
-    let json_to_rectangle_instance_runner_converter = JsonToObjectInstanceRunnerConverter::new(
-        json_to_rectangle_instance_converter,
-        Rc::clone(&rectangle_instance_scaler),
-    );
-
-    let json_to_boxed_rectangle_instance_runner_converter =
-        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_rectangle_instance_runner_converter);
-
-    let b = Box::new(json_to_boxed_rectangle_converter);
-    object_converters.insert("rectangle".to_string(), b);
+        Ok(Box::new(object_instance_runner))
+    }
+}
 
-    object_instance_runner_converters.insert(
-        "rectangle".to_string(),
-        Box::new(json_to_boxed_rectangle_instance_runner_converter),
-    );
+impl<TJsonToObjectInstanceRunnerConverter: GetJsonSchema, TObjectInstanceRunner> GetJsonSchema
+    for JsonToBoxedObjectInstanceRunnerConverter<
+        TJsonToObjectInstanceRunnerConverter,
+        TObjectInstanceRunner,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_object_instance_runner_converter
+            .get_json_schema()
+    }
 }
 
-pub fn compose_squares<
-    TJsonToStringConverter: ConvertJsonToValue<String> + 'static,
-    TJsonToF32Converter: ConvertJsonToValue<f32> + 'static,
-    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + 'static,
-    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + 'static,
-    TTwoDPointCreator: CreateTwoDPoint<TTwoDPoint> + 'static,
-    TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
-    TTwoDPoint: Get2DCoordiantes + 'static,
-    TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
->(
-    object_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>>,
-    >,
-    object_instance_runner_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>,
-    >,
+pub struct JsonToRectangleInstanceConverter<
+    TJsonToStringConverter,
+    TJsonToF32Converter,
+    TJsonToPositionConverter,
+    TRectangleInstanceCreator,
+    TRectangleProvider,
+    TTwoDPoint,
+    TRectangle,
+> {
     json_to_string_converter: Rc<TJsonToStringConverter>,
     json_to_f32_converter: Rc<TJsonToF32Converter>,
-    json_to_rgb_converter: Rc<TJsontoRgbConverter>,
-    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
-    triangle_point_creator: Rc<TTrianglePointCreator>,
-) {
-    let rectangle_provider = ContentProvider::<Rectangle<Rgb>>::new(vec![]);
-
-    let rectangle_provider_ref_cell = Rc::new(RefCell::new(rectangle_provider));
-
-    let rectangle_constructor = Rc::new(RectangleConstructor::new());
-
-    let rectangle_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&rectangle_constructor),
-        Rc::clone(&rectangle_provider_ref_cell),
-    ));
+    json_to_position_converter: Rc<TJsonToPositionConverter>,
+    rectangle_instance_creator: Rc<TRectangleInstanceCreator>,
+    rectangle_provider: Rc<RefCell<TRectangleProvider>>,
+    two_d_point_type: PhantomData<TTwoDPoint>,
+    rectangle_type: PhantomData<TRectangle>,
+}
 
-    let json_to_square_converter = JsonToSquareConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_rgb_converter),
-        Rc::clone(&rectangle_creator),
-    );
+impl<
+        'r,
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TRectangleInstanceCreator,
+        TRectangleProvider,
+        TTwoDPoint,
+        TRectangle,
+    >
+    JsonToRectangleInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TRectangleInstanceCreator,
+        TRectangleProvider,
+        TTwoDPoint,
+        TRectangle,
+    >
+{
+    fn new(
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        json_to_f32_converter: Rc<TJsonToF32Converter>,
+        json_to_position_converter: Rc<TJsonToPositionConverter>,
+        rectangle_instance_creator: Rc<TRectangleInstanceCreator>,
+        rectangle_provider: Rc<RefCell<TRectangleProvider>>,
+    ) -> Self {
+        Self {
+            json_to_string_converter: json_to_string_converter,
+            json_to_f32_converter: json_to_f32_converter,
+            json_to_position_converter: json_to_position_converter,
+            rectangle_instance_creator: rectangle_instance_creator,
+            rectangle_provider: rectangle_provider,
+            two_d_point_type: PhantomData,
+            rectangle_type: PhantomData,
+        }
+    }
+}
 
-    let json_to_boxed_rectangle_converter =
-        JsonToBoxedRectangleConverter::new(json_to_square_converter);
+impl<
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToPositionConverter: ConvertJsonToValue<TTwoDPoint>,
+        TRectangleInstanceCreator: CreateObject<TRectangleInstance, RectangleInstanceParameters<TRectangle>>,
+        TRectangleProvider: GetContent<TRectangle>,
+        TTwoDPoint: Get2DCoordiantes,
+        TRectangleInstance,
+        TRectangle: GetWidth + GetHeight,
+    > ConvertJsonToValue<Rc<RefCell<TRectangleInstance>>>
+    for JsonToRectangleInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TRectangleInstanceCreator,
+        TRectangleProvider,
+        TTwoDPoint,
+        TRectangle,
+    >
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<Rc<RefCell<TRectangleInstance>>, LoadError> {
+        let name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["name"])
+            .map_err(|error| error.within("name"))?;
 
-    let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
+        let content_name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["contentName"])
+            .map_err(|error| error.within("contentName"))?;
 
-    let geometry_triangles_creator = Rc::new(GeometryTrianglesCreator::new(
-        Rc::clone(&geometry_triangle_constructor),
-        Rc::clone(&triangle_point_creator),
-    ));
+        let transform = convert_json_to_transform(
+            self.json_to_f32_converter.as_ref(),
+            self.json_to_position_converter.as_ref(),
+            json,
+        )?;
 
-    let rectangle_instance_constructor = Rc::new(RectangleInstanceConstructor::new(Rc::clone(
-        &geometry_triangles_creator,
-    )));
+        let rectangle = self
+            .rectangle_provider
+            .borrow_mut()
+            .get_content(content_name.clone())
+            .ok_or_else(|| LoadError::unknown_content(content_name).within("contentName"))?;
 
-    let rectangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+        let width = rectangle.borrow().get_width();
+        let height = rectangle.borrow().get_height();
 
-    let rectangle_instance_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&rectangle_instance_constructor),
-        Rc::clone(&rectangle_instance_store),
-    ));
+        Ok(self
+            .rectangle_instance_creator
+            .create_object(RectangleInstanceParameters::new(
+                name, rectangle, transform, width, height, /*rgb*/
+            )))
+    }
+}
 
-    let json_to_rectangle_instance_converter = JsonToRectangleInstanceConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&rectangle_instance_creator),
-        Rc::clone(&rectangle_provider_ref_cell),
-    );
+impl<
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToPositionConverter: GetJsonSchema,
+        TRectangleInstanceCreator,
+        TRectangleProvider,
+        TTwoDPoint,
+        TRectangle,
+    > GetJsonSchema
+    for JsonToRectangleInstanceConverter<
+        TJsonToStringConverter,
+        TJsonToF32Converter,
+        TJsonToPositionConverter,
+        TRectangleInstanceCreator,
+        TRectangleProvider,
+        TTwoDPoint,
+        TRectangle,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_instance_json_schema(
+            self.json_to_string_converter.get_json_schema(),
+            self.json_to_f32_converter.get_json_schema(),
+            self.json_to_position_converter.get_json_schema(),
+        )
+    }
+}
 
-    let rectangle_instance_scaler = Rc::new(RectangleInstanceScaler::new(
-        Rc::clone(&rectangle_instance_creator),
-        Rc::clone(&two_d_point_creator),
-    ));
+pub struct JsonToObjectInstanceRunnerConverter<TJsonToObjectInstanceConverter> {
+    json_to_object_instance_converter: TJsonToObjectInstanceConverter,
+}
 
-    let json_to_rectangle_instance_runner_converter = JsonToObjectInstanceRunnerConverter::new(
-        json_to_rectangle_instance_converter,
-        Rc::clone(&rectangle_instance_scaler),
-    );
+impl<TJsonToObjectInstanceConverter>
+    JsonToObjectInstanceRunnerConverter<TJsonToObjectInstanceConverter>
+{
+    pub fn new(json_to_object_instance_converter: TJsonToObjectInstanceConverter) -> Self {
+        Self {
+            json_to_object_instance_converter,
+        }
+    }
+}
 
-    let json_to_boxed_rectangle_instance_runner_converter =
-        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_rectangle_instance_runner_converter);
+impl<
+        TJsonToObjectInstanceConverter: ConvertJsonToValue<Rc<RefCell<TObjectInstance>>>,
+        TObjectInstance: GetName,
+    > ConvertJsonToValue<ObjectInstanceRunner<TObjectInstance>>
+    for JsonToObjectInstanceRunnerConverter<TJsonToObjectInstanceConverter>
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<ObjectInstanceRunner<TObjectInstance>, LoadError> {
+        let object_instance = self
+            .json_to_object_instance_converter
+            .convert_json_to_value(json)?;
 
-    let b = Box::new(json_to_boxed_rectangle_converter);
-    object_converters.insert("square".to_string(), b);
+        Ok(ObjectInstanceRunner::new(object_instance))
+    }
+}
 
-    object_instance_runner_converters.insert(
-        "square".to_string(),
-        Box::new(json_to_boxed_rectangle_instance_runner_converter),
-    );
+impl<TJsonToObjectInstanceConverter: GetJsonSchema> GetJsonSchema
+    for JsonToObjectInstanceRunnerConverter<TJsonToObjectInstanceConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_object_instance_converter.get_json_schema()
+    }
 }
 
-pub fn compose_triangles<
-    TJsonToStringConverter: ConvertJsonToValue<String> + 'static,
-    TJsonToTrianglePointConverter: ConvertJsonToValue<TrianglePoint<TwoDPoint, Rgb>> + 'static,
-    TTwoDPointCreator: CreateTwoDPoint<TwoDPoint> + 'static,
-    TRgbCreator: CreateRgb<Rgb> + 'static,
-    TJsonToTwoDPointConverter: ConvertJsonToValue<TwoDPoint> + 'static,
-    TJsonToF32Converter: ConvertJsonToValue<f32> + 'static,
+fn convert_json_to_transform<
+    TJsonToF32Converter: ConvertJsonToValue<f32>,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint>,
+    TTwoDPoint: Get2DCoordiantes,
 >(
-    object_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>>,
-    >,
-    object_instance_runner_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>,
-    >,
-    json_to_string_converter: Rc<TJsonToStringConverter>,
-    json_to_triangle_point_converter: Rc<TJsonToTrianglePointConverter>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
-    rgb_creator: Rc<TRgbCreator>,
-    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-    json_to_f32_converter: Rc<TJsonToF32Converter>,
-) {
-    let triangle_provider = ContentProvider::<Triangle<TrianglePoint<TwoDPoint, Rgb>>>::new(vec![]);
+    json_to_f32_converter: &TJsonToF32Converter,
+    json_to_two_d_point_converter: &TJsonToTwoDPointConverter,
+    json: &Value,
+) -> Result<Transform, LoadError> {
+    let (scale_x, scale_y) = match &json["scale"] {
+        Value::Object(_) => {
+            let scale = json_to_two_d_point_converter
+                .convert_json_to_value(&json["scale"])
+                .map_err(|error| error.within("scale"))?;
+
+            (scale.get_x(), scale.get_y())
+        }
+        scale => {
+            let scale = json_to_f32_converter
+                .convert_json_to_value(scale)
+                .map_err(|error| error.within("scale"))?;
 
-    let triangle_provider_ref_cell = Rc::new(RefCell::new(triangle_provider));
+            (scale, scale)
+        }
+    };
 
-    let triangle_constructor = Rc::new(TriangleConstructor::new());
+    let position = json_to_two_d_point_converter
+        .convert_json_to_value(&json["position"])
+        .map_err(|error| error.within("position"))?;
 
-    let triangle_creator = ObjectCreator::new(
-        Rc::clone(&triangle_constructor),
-        Rc::clone(&triangle_provider_ref_cell),
-    );
+    let rotation = match &json["rotation"] {
+        Value::Null => 0.0,
+        rotation => json_to_f32_converter
+            .convert_json_to_value(rotation)
+            .map_err(|error| error.within("rotation"))?,
+    };
 
-    let json_to_triangle_converter = JsonToTriangleConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_triangle_point_converter),
-        triangle_creator,
-    );
+    Ok(Transform::new(
+        position.get_x(),
+        position.get_y(),
+        rotation,
+        scale_x,
+        scale_y,
+    ))
+}
 
-    let json_to_boxed_triangle_converter =
-        JsonToBoxedTriangleConverter::new(json_to_triangle_converter);
+fn convert_json_to_circle_tessellation<
+    TJsonToU64Converter: ConvertJsonToValue<u64>,
+    TJsonToF32Converter: ConvertJsonToValue<f32>,
+>(
+    json_to_u64_converter: &TJsonToU64Converter,
+    json_to_f32_converter: &TJsonToF32Converter,
+    json: &Value,
+) -> Result<Option<CircleTessellation>, LoadError> {
+    if !json["segments"].is_null() {
+        if !json["tolerance"].is_null() {
+            return Err(LoadError::unknown_field().within("tolerance"));
+        }
 
-    let triangle_instance_vertex_data_generator =
-        Rc::new(TriangleInstanceVertexDataGenerator::new());
+        let segments = json_to_u64_converter
+            .convert_json_to_value(&json["segments"])
+            .map_err(|error| error.within("segments"))?;
 
-    let triangle_instance_vertex_counter = Rc::new(TriangleInstanceVertexCounter::new());
+        if segments < MINIMUM_CIRCLE_SEGMENTS as u64 || segments > MAXIMUM_CIRCLE_SEGMENTS as u64 {
+            return Err(LoadError::invalid_value(
+                segments.to_string(),
+                format!("must be between {MINIMUM_CIRCLE_SEGMENTS} and {MAXIMUM_CIRCLE_SEGMENTS}"),
+            )
+            .within("segments"));
+        }
 
-    let triangle_instance_constructor = Rc::new(TriangleInstanceConstructor::new(
-        Rc::clone(&triangle_instance_vertex_data_generator),
-        Rc::clone(&triangle_instance_vertex_counter),
-    ));
+        return Ok(Some(CircleTessellation::Segments(segments as u32)));
+    }
 
-    let triangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+    if !json["tolerance"].is_null() {
+        let tolerance = json_to_f32_converter
+            .convert_json_to_value(&json["tolerance"])
+            .map_err(|error| error.within("tolerance"))?;
 
-    let triangle_instance_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&triangle_instance_constructor),
-        Rc::clone(&triangle_instance_store),
-    ));
+        if tolerance <= 0.0 {
+            return Err(
+                LoadError::invalid_value(tolerance.to_string(), "must be > 0".to_string())
+                    .within("tolerance"),
+            );
+        }
 
-    let triangle_point_constructor = Rc::new(TrianglePointConstructor::new());
+        return Ok(Some(CircleTessellation::Tolerance(tolerance)));
+    }
 
-    let triangle_point_creator = Rc::new(TrianglePointCreator::new(
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&rgb_creator),
-        Rc::clone(&triangle_point_constructor),
-    ));
+    Ok(None)
+}
 
-    let triangle_instance_point_creator = Rc::new(TriangleInstancePointCreator::new(Rc::clone(
-        &triangle_point_creator,
-    )));
+fn create_object_instance_json_schema(
+    string_json_schema: Value,
+    f32_json_schema: Value,
+    position_json_schema: Value,
+) -> Value {
+    create_object_json_schema_with_optional_properties(
+        vec![
+            ("name", string_json_schema.clone()),
+            ("contentName", string_json_schema),
+            (
+                "scale",
+                json!({ "anyOf": [f32_json_schema.clone(), position_json_schema.clone()] }),
+            ),
+            ("position", position_json_schema),
+        ],
+        vec![("rotation", f32_json_schema)],
+    )
+}
 
-    let triangle_instance_point_calculator = Rc::new(TriangleInstancePointCalculator::new(
-        Rc::clone(&triangle_point_creator),
-    ));
+pub struct TypedJsonToValueConverter<TJsonToStringConverter, TBox> {
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    converter_map: HashMap<String, Box<dyn ConvertJsonToValue<TBox>>>,
+    json_schema_map: HashMap<String, Value>,
+}
 
-    let json_to_triangle_instance_converter = JsonToTriangleInstanceConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&triangle_instance_creator),
-        Rc::clone(&triangle_provider_ref_cell),
-        Rc::clone(&triangle_instance_point_calculator),
-    );
+impl<TJsonToStringConverter, TBox> TypedJsonToValueConverter<TJsonToStringConverter, TBox> {
+    fn new(
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        converter_map: HashMap<String, Box<dyn ConvertJsonToValue<TBox>>>,
+        json_schema_map: HashMap<String, Value>,
+    ) -> Self {
+        Self {
+            json_to_string_converter,
+            converter_map,
+            json_schema_map,
+        }
+    }
+}
 
-    let two_d_point_translator = Rc::new(TwoDPointTranslator::new());
+impl<TJsonToStringConverter, TBox> GetJsonSchema
+    for TypedJsonToValueConverter<TJsonToStringConverter, TBox>
+{
+    fn get_json_schema(&self) -> Value {
+        let mut value_types = self.json_schema_map.keys().collect::<Vec<&String>>();
 
-    let triangle_instance_scaler = Rc::new(TriangleInstanceScaler::new(
-        Rc::clone(&triangle_instance_creator),
-        Rc::clone(&triangle_instance_point_creator),
-        Rc::clone(&two_d_point_translator),
-    ));
+        value_types.sort();
 
-    let json_to_triangle_instance_runner_converter = JsonToObjectInstanceRunnerConverter::new(
-        json_to_triangle_instance_converter,
-        Rc::clone(&triangle_instance_scaler),
-    );
+        let json_schemas = value_types
+            .into_iter()
+            .map(|value_type| self.json_schema_map[value_type].clone())
+            .collect::<Vec<Value>>();
 
-    let json_to_boxed_triangle_instance_runner_converter =
-        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_triangle_instance_runner_converter);
+        json!({ "oneOf": json_schemas })
+    }
+}
 
-    let c = Box::new(json_to_boxed_triangle_converter);
-    object_converters.insert("triangle".to_string(), c);
+impl<TJsonToStringConverter: ConvertJsonToValue<String>, TBox> ConvertJsonToValue<TBox>
+    for TypedJsonToValueConverter<TJsonToStringConverter, TBox>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<TBox, LoadError> {
+        let value_type = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["type"])
+            .map_err(|error| error.within("type"))?;
 
-    object_instance_runner_converters.insert(
-        "triangle".to_string(),
-        Box::new(json_to_boxed_triangle_instance_runner_converter),
-    );
+        match self.converter_map.get(&value_type) {
+            Some(converter) => converter.convert_json_to_value(json),
+            None => Err(LoadError::unknown_type(value_type).within("type")),
+        }
+    }
 }
 
-pub fn compose_equilateral_triangles<
-    TJsonToStringConverter: ConvertJsonToValue<String> + 'static,
-    TJsonToTrianglePointConverter: ConvertJsonToValue<TrianglePoint<TwoDPoint, Rgb>> + 'static,
-    TTwoDPointCreator: CreateTwoDPoint<TwoDPoint> + 'static,
-    TRgbCreator: CreateRgb<Rgb> + 'static,
-    TJsonToTwoDPointConverter: ConvertJsonToValue<TwoDPoint> + 'static,
-    TJsonToF32Converter: ConvertJsonToValue<f32> + 'static,
-    TJsonToRgbConverter: ConvertJsonToValue<Rgb> + 'static,
->(
-    object_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>>,
-    >,
-    object_instance_runner_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>,
-    >,
+pub struct ContentLoaderBuilder<TJsonToStringConverter> {
     json_to_string_converter: Rc<TJsonToStringConverter>,
-    json_to_triangle_point_converter: Rc<TJsonToTrianglePointConverter>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
-    rgb_creator: Rc<TRgbCreator>,
+    object_converters:
+        HashMap<String, Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>>>>,
+    object_instance_runner_converters:
+        HashMap<String, Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>>,
+    object_json_schemas: HashMap<String, Value>,
+    object_instance_runner_json_schemas: HashMap<String, Value>,
+}
+
+impl<TJsonToStringConverter> ContentLoaderBuilder<TJsonToStringConverter> {
+    pub fn new(json_to_string_converter: Rc<TJsonToStringConverter>) -> Self {
+        Self {
+            json_to_string_converter,
+            object_converters: HashMap::new(),
+            object_instance_runner_converters: HashMap::new(),
+            object_json_schemas: HashMap::new(),
+            object_instance_runner_json_schemas: HashMap::new(),
+        }
+    }
+}
+
+impl<TJsonToStringConverter: ConvertJsonToValue<String>>
+    ContentLoaderBuilder<TJsonToStringConverter>
+{
+    pub fn build_json_to_content_converter(
+        self,
+    ) -> JsonToContentConverter<
+        TypedJsonToValueConverter<TJsonToStringConverter, Box<Rc<RefCell<dyn ContentObject>>>>,
+        TypedJsonToValueConverter<TJsonToStringConverter, Box<dyn RunObjectInstance>>,
+    > {
+        let json_to_object_converter = TypedJsonToValueConverter::new(
+            Rc::clone(&self.json_to_string_converter),
+            self.object_converters,
+            self.object_json_schemas,
+        );
+
+        let json_to_object_instance_runner_converter = TypedJsonToValueConverter::new(
+            Rc::clone(&self.json_to_string_converter),
+            self.object_instance_runner_converters,
+            self.object_instance_runner_json_schemas,
+        );
+
+        JsonToContentConverter::new(
+            json_to_object_converter,
+            json_to_object_instance_runner_converter,
+        )
+    }
+
+    pub fn build_content_loader<TJsonDocumentsReader>(
+        self,
+        json_documents_reader: TJsonDocumentsReader,
+    ) -> ContentLoader<
+        JsonToContentConverter<
+            TypedJsonToValueConverter<TJsonToStringConverter, Box<Rc<RefCell<dyn ContentObject>>>>,
+            TypedJsonToValueConverter<TJsonToStringConverter, Box<dyn RunObjectInstance>>,
+        >,
+        TJsonDocumentsReader,
+    > {
+        ContentLoader::new(
+            self.build_json_to_content_converter(),
+            json_documents_reader,
+        )
+    }
+}
+
+impl<TJsonToStringConverter> RegisterContentType for ContentLoaderBuilder<TJsonToStringConverter> {
+    fn register_content_type<
+        TJsonToObjectConverter: ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>> + GetJsonSchema + 'static,
+        TJsonToObjectInstanceRunnerConverter: ConvertJsonToValue<Box<dyn RunObjectInstance>> + GetJsonSchema + 'static,
+    >(
+        &mut self,
+        value_type: &str,
+        json_to_object_converter: TJsonToObjectConverter,
+        json_to_object_instance_runner_converter: TJsonToObjectInstanceRunnerConverter,
+    ) {
+        self.object_json_schemas.insert(
+            value_type.to_string(),
+            create_typed_json_schema(value_type, json_to_object_converter.get_json_schema()),
+        );
+
+        self.object_instance_runner_json_schemas.insert(
+            value_type.to_string(),
+            create_typed_json_schema(
+                value_type,
+                json_to_object_instance_runner_converter.get_json_schema(),
+            ),
+        );
+
+        self.object_converters
+            .insert(value_type.to_string(), Box::new(json_to_object_converter));
+
+        self.object_instance_runner_converters.insert(
+            value_type.to_string(),
+            Box::new(json_to_object_instance_runner_converter),
+        );
+    }
+}
+
+fn create_typed_json_schema(value_type: &str, mut json_schema: Value) -> Value {
+    json_schema["properties"]["type"] = json!({ "const": value_type });
+
+    if let Some(required) = json_schema["required"].as_array_mut() {
+        required.insert(0, json!("type"));
+    }
+
+    json_schema
+}
+
+pub struct JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter> {
     json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-    json_to_f32_converter: Rc<TJsonToF32Converter>,
     json_to_rgb_converter: Rc<TJsonToRgbConverter>,
-) {
-    let triangle_provider = ContentProvider::<Triangle<TrianglePoint<TwoDPoint, Rgb>>>::new(vec![]);
-
-    let triangle_provider_ref_cell = Rc::new(RefCell::new(triangle_provider));
+}
 
-    let triangle_constructor = Rc::new(TriangleConstructor::new());
+impl<TJsonToTwoDPointConverter, TJsonToRgbConverter>
+    JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter>
+{
+    pub fn new(
+        json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+        json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+    ) -> Self {
+        Self {
+            json_to_two_d_point_converter,
+            json_to_rgb_converter,
+        }
+    }
+}
 
-    let equilateral_triangle_point_calculator = Rc::new(EquilateralTrianglePointCalculator::new());
+impl<
+        TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint>,
+        TJsonToRgbConverter: ConvertJsonToValue<TRgb>,
+        TTwoDPoint: GetVertexData + GetNumberOfVertices,
+        TRgb: GetVertexData + GetNumberOfVertices,
+    > ConvertJsonToValue<TrianglePoint<TTwoDPoint, TRgb>>
+    for JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter>
+{
+    fn convert_json_to_value(
+        &self,
+        json: &Value,
+    ) -> Result<TrianglePoint<TTwoDPoint, TRgb>, LoadError> {
+        let two_d_point = self
+            .json_to_two_d_point_converter
+            .convert_json_to_value(&json["twoDPoint"])
+            .map_err(|error| error.within("twoDPoint"))?;
 
-    let triangle_creator = ObjectCreator::new(
-        Rc::clone(&triangle_constructor),
-        Rc::clone(&triangle_provider_ref_cell),
-    );
+        let rgb = self
+            .json_to_rgb_converter
+            .convert_json_to_value(&json["rgb"])
+            .map_err(|error| error.within("rgb"))?;
 
-    let json_to_equilateral_triangle_converter = JsonToEquilateralTriangleConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_rgb_converter),
-        Rc::clone(&equilateral_triangle_point_calculator),
-        triangle_creator,
-    );
+        let mut vertex_data = vec![];
 
-    let json_to_boxed_triangle_converter =
-        JsonToBoxedTriangleConverter::new(json_to_equilateral_triangle_converter);
+        vertex_data.append(&mut two_d_point.get_vertex_data());
+        vertex_data.append(&mut rgb.get_vertex_data());
 
-    let triangle_instance_vertex_data_generator =
-        Rc::new(TriangleInstanceVertexDataGenerator::new());
+        let number_of_vertices =
+            two_d_point.get_number_of_vertices() + rgb.get_number_of_vertices();
 
-    let triangle_instance_vertex_counter = Rc::new(TriangleInstanceVertexCounter::new());
+        Ok(TrianglePoint::new(
+            two_d_point,
+            rgb,
+            number_of_vertices,
+            vertex_data,
+        ))
+    }
+}
 
-    let triangle_instance_constructor = Rc::new(TriangleInstanceConstructor::new(
-        Rc::clone(&triangle_instance_vertex_data_generator),
-        Rc::clone(&triangle_instance_vertex_counter),
-    ));
+impl<TJsonToTwoDPointConverter: GetJsonSchema, TJsonToRgbConverter: GetJsonSchema> GetJsonSchema
+    for JsonToTrianglePointConverter<TJsonToTwoDPointConverter, TJsonToRgbConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            (
+                "twoDPoint",
+                self.json_to_two_d_point_converter.get_json_schema(),
+            ),
+            ("rgb", self.json_to_rgb_converter.get_json_schema()),
+        ])
+    }
+}
 
-    let triangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+pub struct JsonToTwoDPointConverter<TJsonToF32Converter> {
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+}
 
-    let triangle_instance_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&triangle_instance_constructor),
-        Rc::clone(&triangle_instance_store),
-    ));
+impl<TJsonToF32Converter> JsonToTwoDPointConverter<TJsonToF32Converter> {
+    pub fn new(json_to_f32_converter: Rc<TJsonToF32Converter>) -> Self {
+        Self {
+            json_to_f32_converter,
+        }
+    }
+}
 
-    let triangle_point_constructor = Rc::new(TrianglePointConstructor::new());
+impl<TJsonToF32Converter: ConvertJsonToValue<f32>> ConvertJsonToValue<TwoDPoint>
+    for JsonToTwoDPointConverter<TJsonToF32Converter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<TwoDPoint, LoadError> {
+        let x = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["x"])
+            .map_err(|error| error.within("x"))?;
 
-    let triangle_point_creator = Rc::new(TrianglePointCreator::new(
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&rgb_creator),
-        Rc::clone(&triangle_point_constructor),
-    ));
+        let y = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["y"])
+            .map_err(|error| error.within("y"))?;
 
-    let triangle_instance_point_creator = Rc::new(TriangleInstancePointCreator::new(Rc::clone(
-        &triangle_point_creator,
-    )));
+        Ok(TwoDPoint::new(x, y))
+    }
+}
 
-    let triangle_instance_point_calculator = Rc::new(TriangleInstancePointCalculator::new(
-        Rc::clone(&triangle_point_creator),
-    ));
+impl<TJsonToF32Converter: GetJsonSchema> GetJsonSchema
+    for JsonToTwoDPointConverter<TJsonToF32Converter>
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("x", self.json_to_f32_converter.get_json_schema()),
+            ("y", self.json_to_f32_converter.get_json_schema()),
+        ])
+    }
+}
 
-    let json_to_triangle_instance_converter = JsonToTriangleInstanceConverter::new(
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&triangle_instance_creator),
-        Rc::clone(&triangle_provider_ref_cell),
-        Rc::clone(&triangle_instance_point_calculator),
-    );
+pub struct JsonToRgbConverter<TJsonToF32Converter> {
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+}
 
-    let two_d_point_translator = Rc::new(TwoDPointTranslator::new());
+impl<TJsonToF32Converter> JsonToRgbConverter<TJsonToF32Converter> {
+    pub fn new(json_to_f32_converter: Rc<TJsonToF32Converter>) -> Self {
+        Self {
+            json_to_f32_converter,
+        }
+    }
+}
 
-    let triangle_instance_scaler = Rc::new(TriangleInstanceScaler::new(
-        Rc::clone(&triangle_instance_creator),
-        Rc::clone(&triangle_instance_point_creator),
-        Rc::clone(&two_d_point_translator),
-    ));
+impl<TJsonToF32Converter: ConvertJsonToValue<f32>> ConvertJsonToValue<Rgb>
+    for JsonToRgbConverter<TJsonToF32Converter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<Rgb, LoadError> {
+        let r = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["r"])
+            .map_err(|error| error.within("r"))?;
 
-    let json_to_triangle_instance_runner_converter = JsonToObjectInstanceRunnerConverter::new(
-        json_to_triangle_instance_converter,
-        Rc::clone(&triangle_instance_scaler),
-    );
+        let g = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["g"])
+            .map_err(|error| error.within("g"))?;
 
-    let json_to_boxed_triangle_instance_runner_converter =
-        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_triangle_instance_runner_converter);
+        let b = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["b"])
+            .map_err(|error| error.within("b"))?;
 
-    let c = Box::new(json_to_boxed_triangle_converter);
-    object_converters.insert("equilateral-triangle".to_string(), c);
+        Ok(Rgb::new(r, g, b))
+    }
+}
 
-    object_instance_runner_converters.insert(
-        "equilateral-triangle".to_string(),
-        Box::new(json_to_boxed_triangle_instance_runner_converter),
-    );
+impl<TJsonToF32Converter: GetJsonSchema> GetJsonSchema for JsonToRgbConverter<TJsonToF32Converter> {
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("r", self.json_to_f32_converter.get_json_schema()),
+            ("g", self.json_to_f32_converter.get_json_schema()),
+            ("b", self.json_to_f32_converter.get_json_schema()),
+        ])
+    }
 }
 
-pub fn compose_circles<
-    TJsonToStringConverter: ConvertJsonToValue<String> + 'static,
-    TJsonToF32Converter: ConvertJsonToValue<f32> + 'static,
-    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + 'static,
-    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + 'static,
-    TTwoDPointCreator: CreateTwoDPoint<TTwoDPoint> + 'static,
+pub fn compose_rectangles<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + GetJsonSchema + 'static,
     TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
-    TTwoDPoint: Get2DCoordiantes + 'static,
+    TTwoDPoint: Get2DCoordiantes + ConvertToJson + 'static,
     TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
-    TTrigonometryCalculator: CalculateTrigonometry + 'static,
 >(
-    object_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>>,
-    >,
-    object_instance_runner_converters: &mut HashMap<
-        String,
-        Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>,
-    >,
+    content_type_registrar: &mut TContentTypeRegistrar,
     json_to_string_converter: Rc<TJsonToStringConverter>,
     json_to_f32_converter: Rc<TJsonToF32Converter>,
     json_to_rgb_converter: Rc<TJsontoRgbConverter>,
     json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
-    two_d_point_creator: Rc<TTwoDPointCreator>,
     triangle_point_creator: Rc<TTrianglePointCreator>,
-    trigonometry_calculator: Rc<TTrigonometryCalculator>,
 ) {
-    let circle_provider = ContentProvider::<Circle<Rgb>>::new(vec![]);
+    let rectangle_provider = ContentProvider::<Rectangle<Rgb>>::new(vec![]);
 
-    let circle_provider_ref_cell = Rc::new(RefCell::new(circle_provider));
+    let rectangle_provider_ref_cell = Rc::new(RefCell::new(rectangle_provider));
 
-    let circle_constructor = Rc::new(CircleConstructor::new());
+    let rectangle_constructor = Rc::new(RectangleConstructor::new());
 
-    let circle_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&circle_constructor),
-        Rc::clone(&circle_provider_ref_cell),
+    let rectangle_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&rectangle_constructor),
+        Rc::clone(&rectangle_provider_ref_cell),
     ));
 
-    let json_to_circle_converter = JsonToCircleConverter::new(
+    let json_to_rectangle_converter = JsonToRectangleConverter::new(
         Rc::clone(&json_to_string_converter),
         Rc::clone(&json_to_f32_converter),
         Rc::clone(&json_to_rgb_converter),
-        Rc::clone(&circle_creator),
+        Rc::clone(&rectangle_creator),
     );
 
-    let json_to_boxed_circle_converter = JsonToBoxedCircleConverter::new(json_to_circle_converter);
+    let json_to_boxed_rectangle_converter =
+        JsonToBoxedRectangleConverter::new(json_to_rectangle_converter);
 
     let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
 
-    let geometry_triangles_creator = Rc::new(CircleGeometryTrianglesCreator::new(
+    let geometry_triangles_creator = Rc::new(GeometryTrianglesCreator::new(
         Rc::clone(&geometry_triangle_constructor),
         Rc::clone(&triangle_point_creator),
-        Rc::clone(&trigonometry_calculator),
     ));
 
-    let circle_instance_constructor = Rc::new(CircleInstanceConstructor::new(Rc::clone(
+    let rectangle_instance_constructor = Rc::new(RectangleInstanceConstructor::new(Rc::clone(
         &geometry_triangles_creator,
     )));
 
-    let circle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+    let rectangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
 
-    let circle_instance_creator = Rc::new(ObjectCreator::new(
-        Rc::clone(&circle_instance_constructor),
-        Rc::clone(&circle_instance_store),
+    let rectangle_instance_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&rectangle_instance_constructor),
+        Rc::clone(&rectangle_instance_store),
     ));
 
-    let json_to_circle_instance_converter = JsonToCircleInstanceConverter::new(
+    let json_to_rectangle_instance_converter = JsonToRectangleInstanceConverter::new(
         Rc::clone(&json_to_string_converter),
         Rc::clone(&json_to_f32_converter),
         Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&circle_instance_creator),
-        Rc::clone(&circle_provider_ref_cell),
-    );
-
-    let circle_instance_scaler = Rc::new(CircleInstanceScaler::new(
-        Rc::clone(&circle_instance_creator),
-        Rc::clone(&two_d_point_creator),
-    ));
-
-    let json_to_circle_instance_runner_converter = JsonToObjectInstanceRunnerConverter::new(
-        json_to_circle_instance_converter,
-        Rc::clone(&circle_instance_scaler),
+        Rc::clone(&rectangle_instance_creator),
+        Rc::clone(&rectangle_provider_ref_cell),
     );
 
-    let json_to_boxed_circle_instance_runner_converter =
-        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_circle_instance_runner_converter);
+    let json_to_rectangle_instance_runner_converter =
+        JsonToObjectInstanceRunnerConverter::new(json_to_rectangle_instance_converter);
 
-    let b = Box::new(json_to_boxed_circle_converter);
-    object_converters.insert("circle".to_string(), b);
+    let json_to_boxed_rectangle_instance_runner_converter =
+        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_rectangle_instance_runner_converter);
 
-    object_instance_runner_converters.insert(
-        "circle".to_string(),
-        Box::new(json_to_boxed_circle_instance_runner_converter),
+    content_type_registrar.register_content_type(
+        "rectangle",
+        json_to_boxed_rectangle_converter,
+        json_to_boxed_rectangle_instance_runner_converter,
     );
 }
 
-pub fn compose_json_to_content_converter<
-    TTrigonometryCalculator: CalculateTrigonometry + 'static,
+pub fn compose_squares<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + GetJsonSchema + 'static,
+    TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
+    TTwoDPoint: Get2DCoordiantes + ConvertToJson + 'static,
+    TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
 >(
-    json_to_f32_converter: Rc<JsonToF32Converter>,
-    json_to_string_converter: Rc<JsonToStringConverter>,
-    trigonometry_calculator: Rc<TTrigonometryCalculator>,
-) -> JsonToContentConverter<
-    TypedJsonToValueConverter<JsonToStringConverter, Box<Rc<RefCell<dyn GetName>>>>,
-    TypedJsonToValueConverter<JsonToStringConverter, Box<dyn RunObjectInstance>>,
-> {
-    let json_to_rgb_converter = Rc::new(JsonToRgbConverter::new(Rc::clone(&json_to_f32_converter)));
+    content_type_registrar: &mut TContentTypeRegistrar,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_rgb_converter: Rc<TJsontoRgbConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    triangle_point_creator: Rc<TTrianglePointCreator>,
+) {
+    let rectangle_provider = ContentProvider::<Rectangle<Rgb>>::new(vec![]);
 
-    let two_d_point_creator = Rc::new(TwoDPointCreator::new());
+    let rectangle_provider_ref_cell = Rc::new(RefCell::new(rectangle_provider));
 
-    let rgb_creator = Rc::new(RgbCreator::new());
+    let rectangle_constructor = Rc::new(RectangleConstructor::new());
+
+    let rectangle_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&rectangle_constructor),
+        Rc::clone(&rectangle_provider_ref_cell),
+    ));
 
-    let mut object_instance_runner_converters =
-        HashMap::<String, Box<dyn ConvertJsonToValue<Box<dyn RunObjectInstance>>>>::new();
+    let json_to_square_converter = JsonToSquareConverter::new(
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&rectangle_creator),
+    );
 
-    let mut object_converters =
-        HashMap::<String, Box<dyn ConvertJsonToValue<Box<Rc<RefCell<dyn GetName>>>>>>::new();
+    let json_to_boxed_rectangle_converter =
+        JsonToBoxedRectangleConverter::new(json_to_square_converter);
 
-    let json_to_two_d_point_converter = Rc::new(JsonToTwoDPointConverter::new(Rc::clone(
-        &json_to_f32_converter,
-    )));
+    let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
 
-    let json_to_triangle_point_converter = Rc::new(JsonToTrianglePointConverter::new(
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&json_to_rgb_converter),
+    let geometry_triangles_creator = Rc::new(GeometryTrianglesCreator::new(
+        Rc::clone(&geometry_triangle_constructor),
+        Rc::clone(&triangle_point_creator),
     ));
 
-    let triangle_point_constructor = Rc::new(TrianglePointConstructor::new());
+    let rectangle_instance_constructor = Rc::new(RectangleInstanceConstructor::new(Rc::clone(
+        &geometry_triangles_creator,
+    )));
 
-    let triangle_point_creator = Rc::new(TrianglePointCreator::new(
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&rgb_creator),
-        Rc::clone(&triangle_point_constructor),
+    let rectangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+
+    let rectangle_instance_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&rectangle_instance_constructor),
+        Rc::clone(&rectangle_instance_store),
     ));
 
-    compose_rectangles(
-        &mut object_converters,
-        &mut object_instance_runner_converters,
+    let json_to_rectangle_instance_converter = JsonToRectangleInstanceConverter::new(
         Rc::clone(&json_to_string_converter),
         Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_rgb_converter),
         Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&triangle_point_creator),
+        Rc::clone(&rectangle_instance_creator),
+        Rc::clone(&rectangle_provider_ref_cell),
     );
 
-    compose_squares(
-        &mut object_converters,
-        &mut object_instance_runner_converters,
-        Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_rgb_converter),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&triangle_point_creator),
+    let json_to_rectangle_instance_runner_converter =
+        JsonToObjectInstanceRunnerConverter::new(json_to_rectangle_instance_converter);
+
+    let json_to_boxed_rectangle_instance_runner_converter =
+        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_rectangle_instance_runner_converter);
+
+    content_type_registrar.register_content_type(
+        "square",
+        json_to_boxed_rectangle_converter,
+        json_to_boxed_rectangle_instance_runner_converter,
     );
+}
 
-    compose_triangles(
-        &mut object_converters,
-        &mut object_instance_runner_converters,
+pub fn compose_triangles<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToTrianglePointConverter: ConvertJsonToValue<TrianglePoint<TwoDPoint, Rgb>> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TwoDPoint> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+>(
+    content_type_registrar: &mut TContentTypeRegistrar,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_triangle_point_converter: Rc<TJsonToTrianglePointConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+) {
+    let triangle_provider = ContentProvider::<Triangle<TrianglePoint<TwoDPoint, Rgb>>>::new(vec![]);
+
+    let triangle_provider_ref_cell = Rc::new(RefCell::new(triangle_provider));
+
+    let triangle_constructor = Rc::new(TriangleConstructor::new());
+
+    let triangle_creator = ObjectCreator::new(
+        Rc::clone(&triangle_constructor),
+        Rc::clone(&triangle_provider_ref_cell),
+    );
+
+    let json_to_triangle_converter = JsonToTriangleConverter::new(
         Rc::clone(&json_to_string_converter),
         Rc::clone(&json_to_triangle_point_converter),
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&&rgb_creator),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&json_to_f32_converter),
+        triangle_creator,
     );
 
-    compose_equilateral_triangles(
-        &mut object_converters,
-        &mut object_instance_runner_converters,
+    let json_to_boxed_triangle_converter =
+        JsonToBoxedTriangleConverter::new(json_to_triangle_converter);
+
+    let triangle_instance_vertex_data_generator =
+        Rc::new(TriangleInstanceVertexDataGenerator::new());
+
+    let triangle_instance_vertex_counter = Rc::new(TriangleInstanceVertexCounter::new());
+
+    let triangle_instance_constructor = Rc::new(TriangleInstanceConstructor::new(
+        Rc::clone(&triangle_instance_vertex_data_generator),
+        Rc::clone(&triangle_instance_vertex_counter),
+    ));
+
+    let triangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+
+    let triangle_instance_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&triangle_instance_constructor),
+        Rc::clone(&triangle_instance_store),
+    ));
+
+    let json_to_triangle_instance_converter = JsonToTriangleInstanceConverter::new(
         Rc::clone(&json_to_string_converter),
-        Rc::clone(&json_to_triangle_point_converter),
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&&rgb_creator),
         Rc::clone(&json_to_two_d_point_converter),
         Rc::clone(&json_to_f32_converter),
-        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&triangle_instance_creator),
+        Rc::clone(&triangle_provider_ref_cell),
     );
 
-    compose_circles(
-        &mut object_converters,
-        &mut object_instance_runner_converters,
+    let json_to_triangle_instance_runner_converter =
+        JsonToObjectInstanceRunnerConverter::new(json_to_triangle_instance_converter);
+
+    let json_to_boxed_triangle_instance_runner_converter =
+        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_triangle_instance_runner_converter);
+
+    content_type_registrar.register_content_type(
+        "triangle",
+        json_to_boxed_triangle_converter,
+        json_to_boxed_triangle_instance_runner_converter,
+    );
+}
+
+pub fn compose_equilateral_triangles<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToTrianglePointConverter: ConvertJsonToValue<TrianglePoint<TwoDPoint, Rgb>> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TwoDPoint> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+    TJsonToRgbConverter: ConvertJsonToValue<Rgb> + GetJsonSchema + 'static,
+>(
+    content_type_registrar: &mut TContentTypeRegistrar,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_triangle_point_converter: Rc<TJsonToTrianglePointConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+) {
+    let triangle_provider = ContentProvider::<Triangle<TrianglePoint<TwoDPoint, Rgb>>>::new(vec![]);
+
+    let triangle_provider_ref_cell = Rc::new(RefCell::new(triangle_provider));
+
+    let triangle_constructor = Rc::new(TriangleConstructor::new());
+
+    let equilateral_triangle_point_calculator = Rc::new(EquilateralTrianglePointCalculator::new());
+
+    let triangle_creator = ObjectCreator::new(
+        Rc::clone(&triangle_constructor),
+        Rc::clone(&triangle_provider_ref_cell),
+    );
+
+    let json_to_equilateral_triangle_converter = JsonToEquilateralTriangleConverter::new(
         Rc::clone(&json_to_string_converter),
         Rc::clone(&json_to_f32_converter),
         Rc::clone(&json_to_rgb_converter),
-        Rc::clone(&json_to_two_d_point_converter),
-        Rc::clone(&two_d_point_creator),
-        Rc::clone(&triangle_point_creator),
-        Rc::clone(&trigonometry_calculator),
+        Rc::clone(&equilateral_triangle_point_calculator),
+        triangle_creator,
     );
 
-    let json_to_object_converter =
-        TypedJsonToValueConverter::new(Rc::clone(&json_to_string_converter), object_converters);
+    let json_to_boxed_triangle_converter =
+        JsonToBoxedTriangleConverter::new(json_to_equilateral_triangle_converter);
+
+    let triangle_instance_vertex_data_generator =
+        Rc::new(TriangleInstanceVertexDataGenerator::new());
+
+    let triangle_instance_vertex_counter = Rc::new(TriangleInstanceVertexCounter::new());
+
+    let triangle_instance_constructor = Rc::new(TriangleInstanceConstructor::new(
+        Rc::clone(&triangle_instance_vertex_data_generator),
+        Rc::clone(&triangle_instance_vertex_counter),
+    ));
+
+    let triangle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
 
-    let json_to_object_instance_runner_converter = TypedJsonToValueConverter::new(
+    let triangle_instance_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&triangle_instance_constructor),
+        Rc::clone(&triangle_instance_store),
+    ));
+
+    let json_to_triangle_instance_converter = JsonToTriangleInstanceConverter::new(
         Rc::clone(&json_to_string_converter),
-        object_instance_runner_converters,
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&triangle_instance_creator),
+        Rc::clone(&triangle_provider_ref_cell),
     );
 
-    let json_to_content_converter = JsonToContentConverter::new(
-        json_to_object_converter,
-        json_to_object_instance_runner_converter,
-    );
+    let json_to_triangle_instance_runner_converter =
+        JsonToObjectInstanceRunnerConverter::new(json_to_triangle_instance_converter);
+
+    let json_to_boxed_triangle_instance_runner_converter =
+        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_triangle_instance_runner_converter);
 
-    json_to_content_converter
+    content_type_registrar.register_content_type(
+        "equilateral-triangle",
+        json_to_boxed_triangle_converter,
+        json_to_boxed_triangle_instance_runner_converter,
+    );
 }
 
-pub fn compose_content_loader<TTrigonometryCalculator: CalculateTrigonometry + 'static>(
-    json_to_f32_converter: Rc<JsonToF32Converter>,
-    json_to_string_converter: Rc<JsonToStringConverter>,
+pub fn compose_circles<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + GetJsonSchema + 'static,
+    TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
+    TTwoDPoint: Get2DCoordiantes + ConvertToJson + 'static,
+    TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
+    TTrigonometryCalculator: CalculateTrigonometry + 'static,
+>(
+    content_type_registrar: &mut TContentTypeRegistrar,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_rgb_converter: Rc<TJsontoRgbConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    triangle_point_creator: Rc<TTrianglePointCreator>,
     trigonometry_calculator: Rc<TTrigonometryCalculator>,
-) -> ContentLoader<
-    JsonToContentConverter<
-        TypedJsonToValueConverter<JsonToStringConverter, Box<Rc<RefCell<dyn GetName>>>>,
-        TypedJsonToValueConverter<JsonToStringConverter, Box<dyn RunObjectInstance>>,
-    >,
-> {
-    let json_to_content_converter = compose_json_to_content_converter(
-        json_to_f32_converter,
-        json_to_string_converter,
-        trigonometry_calculator,
-    );
+) {
+    let circle_provider = ContentProvider::<Circle<Rgb>>::new(vec![]);
 
-    ContentLoader::<
-        JsonToContentConverter<
-            TypedJsonToValueConverter<JsonToStringConverter, Box<Rc<RefCell<dyn GetName>>>>,
-            TypedJsonToValueConverter<JsonToStringConverter, Box<dyn RunObjectInstance>>,
-        >,
-    >::new(json_to_content_converter)
-}
+    let circle_provider_ref_cell = Rc::new(RefCell::new(circle_provider));
 
-#[cfg(test)]
-mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    let circle_constructor = Rc::new(CircleConstructor::new());
 
-    use garden_content::{
-        rectangles::{
-            Rectangle, RectangleInstance, RectangleInstanceConstructor, RectangleInstanceScaler,
-        },
-        triangles::{
-            GeometryTriangleConstructor, GeometryTrianglesCreator, Triangle, TriangleInstance,
-            TriangleInstanceConstructor, TriangleInstancePointCreator, TriangleInstanceScaler,
-            TriangleInstanceVertexCounter, TriangleInstanceVertexDataGenerator,
-        },
-        Content, GetVertexData, ObjectCreator, ObjectInstanceRunner, Rgb, RgbCreator, Store,
-        TrianglePoint, TrianglePointConstructor, TrianglePointCreator, TwoDPoint, TwoDPointCreator,
-        TwoDPointTranslator,
-    };
-    use garden_json::{ConvertJsonToValue, JsonToF32Converter, JsonToStringConverter};
-    use serde_json::json;
+    let circle_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&circle_constructor),
+        Rc::clone(&circle_provider_ref_cell),
+    ));
 
-    use crate::compose_json_to_content_converter;
+    let json_to_u64_converter = Rc::new(JsonToU64Converter::new());
 
-    #[test]
-    fn when_a_json_to_content_converter_converts_json_to_content_then_the_content_is_converted() {
-        let json_to_content_converter = compose_json_to_content_converter(
-            Rc::new(JsonToF32Converter::new()),
-            Rc::new(JsonToStringConverter::new()),
+    let json_to_circle_converter = JsonToCircleConverter::new(
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_u64_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&circle_creator),
+    );
+
+    let json_to_boxed_circle_converter = JsonToBoxedCircleConverter::new(json_to_circle_converter);
+
+    let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
+
+    let geometry_triangles_creator = Rc::new(CircleGeometryTrianglesCreator::new(
+        Rc::clone(&geometry_triangle_constructor),
+        Rc::clone(&triangle_point_creator),
+        Rc::clone(&trigonometry_calculator),
+    ));
+
+    let circle_instance_constructor = Rc::new(CircleInstanceConstructor::new(Rc::clone(
+        &geometry_triangles_creator,
+    )));
+
+    let circle_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+
+    let circle_instance_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&circle_instance_constructor),
+        Rc::clone(&circle_instance_store),
+    ));
+
+    let json_to_circle_instance_converter = JsonToCircleInstanceConverter::new(
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&circle_instance_creator),
+        Rc::clone(&circle_provider_ref_cell),
+    );
+
+    let json_to_circle_instance_runner_converter =
+        JsonToObjectInstanceRunnerConverter::new(json_to_circle_instance_converter);
+
+    let json_to_boxed_circle_instance_runner_converter =
+        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_circle_instance_runner_converter);
+
+    content_type_registrar.register_content_type(
+        "circle",
+        json_to_boxed_circle_converter,
+        json_to_boxed_circle_instance_runner_converter,
+    );
+}
+
+pub fn compose_curves<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + GetJsonSchema + 'static,
+    TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
+    TTwoDPoint: Get2DCoordiantes + ConvertToJson + 'static,
+    TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
+    TTrigonometryCalculator: CalculateTrigonometry + 'static,
+>(
+    content_type_registrar: &mut TContentTypeRegistrar,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_rgb_converter: Rc<TJsontoRgbConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    triangle_point_creator: Rc<TTrianglePointCreator>,
+    trigonometry_calculator: Rc<TTrigonometryCalculator>,
+) {
+    let json_to_u64_converter = Rc::new(JsonToU64Converter::new());
+
+    let curve_constructor = Rc::new(CurveConstructor::new());
+
+    let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
+
+    let geometry_triangles_creator = Rc::new(CurveGeometryTrianglesCreator::new(
+        Rc::clone(&geometry_triangle_constructor),
+        Rc::clone(&triangle_point_creator),
+        Rc::clone(&trigonometry_calculator),
+    ));
+
+    let curve_instance_constructor = Rc::new(CurveInstanceConstructor::new(Rc::clone(
+        &geometry_triangles_creator,
+    )));
+
+    for curve_type in CURVE_TYPES {
+        let curve_provider = ContentProvider::<Curve<Rgb>>::new(vec![]);
+
+        let curve_provider_ref_cell = Rc::new(RefCell::new(curve_provider));
+
+        let curve_creator = Rc::new(ObjectCreator::new(
+            Rc::clone(&curve_constructor),
+            Rc::clone(&curve_provider_ref_cell),
+        ));
+
+        let json_to_curve_converter = JsonToCurveConverter::new(
+            curve_type,
+            Rc::clone(&json_to_string_converter),
+            Rc::clone(&json_to_f32_converter),
+            Rc::clone(&json_to_u64_converter),
+            Rc::clone(&json_to_rgb_converter),
+            Rc::clone(&curve_creator),
+        );
+
+        let json_to_boxed_curve_converter = JsonToBoxedCurveConverter::new(json_to_curve_converter);
+
+        let curve_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+
+        let curve_instance_creator = Rc::new(ObjectCreator::new(
+            Rc::clone(&curve_instance_constructor),
+            Rc::clone(&curve_instance_store),
+        ));
+
+        let json_to_curve_instance_converter = JsonToCurveInstanceConverter::new(
+            Rc::clone(&json_to_string_converter),
+            Rc::clone(&json_to_f32_converter),
+            Rc::clone(&json_to_two_d_point_converter),
+            Rc::clone(&curve_instance_creator),
+            Rc::clone(&curve_provider_ref_cell),
+        );
+
+        let json_to_curve_instance_runner_converter =
+            JsonToObjectInstanceRunnerConverter::new(json_to_curve_instance_converter);
+
+        let json_to_boxed_curve_instance_runner_converter =
+            JsonToBoxedObjectInstanceRunnerConverter::new(json_to_curve_instance_runner_converter);
+
+        content_type_registrar.register_content_type(
+            curve_type.get_name(),
+            json_to_boxed_curve_converter,
+            json_to_boxed_curve_instance_runner_converter,
         );
+    }
+}
+
+pub fn compose_polygons<
+    TContentTypeRegistrar: RegisterContentType,
+    TJsonToStringConverter: ConvertJsonToValue<String> + GetJsonSchema + 'static,
+    TJsonToF32Converter: ConvertJsonToValue<f32> + GetJsonSchema + 'static,
+    TJsontoRgbConverter: ConvertJsonToValue<Rgb> + GetJsonSchema + 'static,
+    TJsonToTwoDPointConverter: ConvertJsonToValue<TTwoDPoint> + GetJsonSchema + 'static,
+    TTrianglePointCreator: CreateTrianglePoint<TTrianglePoint> + 'static,
+    TTwoDPoint: Get2DCoordiantes + ConvertToJson + 'static,
+    TTrianglePoint: GetTrianglePointProperties + GetVertexData + GetNumberOfVertices + 'static,
+>(
+    content_type_registrar: &mut TContentTypeRegistrar,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_rgb_converter: Rc<TJsontoRgbConverter>,
+    json_to_two_d_point_converter: Rc<TJsonToTwoDPointConverter>,
+    triangle_point_creator: Rc<TTrianglePointCreator>,
+) {
+    let polygon_provider = ContentProvider::<Polygon<TTwoDPoint, Rgb>>::new(vec![]);
+
+    let polygon_provider_ref_cell = Rc::new(RefCell::new(polygon_provider));
+
+    let polygon_constructor = Rc::new(PolygonConstructor::new());
+
+    let polygon_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&polygon_constructor),
+        Rc::clone(&polygon_provider_ref_cell),
+    ));
+
+    let polygon_triangulator = Rc::new(EarClippingTriangulator::new());
+
+    let json_to_polygon_converter = JsonToPolygonConverter::new(
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&polygon_triangulator),
+        Rc::clone(&polygon_creator),
+    );
+
+    let json_to_boxed_polygon_converter =
+        JsonToBoxedPolygonConverter::new(json_to_polygon_converter);
+
+    let geometry_triangle_constructor = Rc::new(GeometryTriangleConstructor::new());
+
+    let geometry_triangles_creator = Rc::new(PolygonGeometryTrianglesCreator::new(
+        Rc::clone(&geometry_triangle_constructor),
+        Rc::clone(&triangle_point_creator),
+    ));
+
+    let polygon_instance_constructor = Rc::new(PolygonInstanceConstructor::new(Rc::clone(
+        &geometry_triangles_creator,
+    )));
+
+    let polygon_instance_store = Rc::new(RefCell::new(Store::new(vec![])));
+
+    let polygon_instance_creator = Rc::new(ObjectCreator::new(
+        Rc::clone(&polygon_instance_constructor),
+        Rc::clone(&polygon_instance_store),
+    ));
+
+    let json_to_polygon_instance_converter = JsonToPolygonInstanceConverter::new(
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&polygon_instance_creator),
+        Rc::clone(&polygon_provider_ref_cell),
+    );
+
+    let json_to_polygon_instance_runner_converter =
+        JsonToObjectInstanceRunnerConverter::new(json_to_polygon_instance_converter);
+
+    let json_to_boxed_polygon_instance_runner_converter =
+        JsonToBoxedObjectInstanceRunnerConverter::new(json_to_polygon_instance_runner_converter);
+
+    content_type_registrar.register_content_type(
+        "polygon",
+        json_to_boxed_polygon_converter,
+        json_to_boxed_polygon_instance_runner_converter,
+    );
+}
+
+pub fn compose_content_loader_builder<TTrigonometryCalculator: CalculateTrigonometry + 'static>(
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    trigonometry_calculator: Rc<TTrigonometryCalculator>,
+) -> ContentLoaderBuilder<JsonToStringConverter> {
+    let json_to_rgb_converter = Rc::new(JsonToRgbConverter::new(Rc::clone(&json_to_f32_converter)));
+
+    let two_d_point_creator = Rc::new(TwoDPointCreator::new());
+
+    let rgb_creator = Rc::new(RgbCreator::new());
+
+    let mut content_loader_builder =
+        ContentLoaderBuilder::new(Rc::clone(&json_to_string_converter));
+
+    let json_to_two_d_point_converter = Rc::new(JsonToTwoDPointConverter::new(Rc::clone(
+        &json_to_f32_converter,
+    )));
+
+    let json_to_triangle_point_converter = Rc::new(JsonToTrianglePointConverter::new(
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&json_to_rgb_converter),
+    ));
+
+    let triangle_point_constructor = Rc::new(TrianglePointConstructor::new());
+
+    let triangle_point_creator = Rc::new(TrianglePointCreator::new(
+        Rc::clone(&two_d_point_creator),
+        Rc::clone(&rgb_creator),
+        Rc::clone(&triangle_point_constructor),
+    ));
+
+    compose_rectangles(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&triangle_point_creator),
+    );
+
+    compose_squares(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&triangle_point_creator),
+    );
+
+    compose_triangles(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_triangle_point_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&json_to_f32_converter),
+    );
+
+    compose_equilateral_triangles(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_triangle_point_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+    );
+
+    compose_circles(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&triangle_point_creator),
+        Rc::clone(&trigonometry_calculator),
+    );
+
+    compose_curves(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&triangle_point_creator),
+        Rc::clone(&trigonometry_calculator),
+    );
+
+    compose_polygons(
+        &mut content_loader_builder,
+        Rc::clone(&json_to_string_converter),
+        Rc::clone(&json_to_f32_converter),
+        Rc::clone(&json_to_rgb_converter),
+        Rc::clone(&json_to_two_d_point_converter),
+        Rc::clone(&triangle_point_creator),
+    );
+
+    content_loader_builder
+}
+
+pub fn compose_json_to_content_converter<
+    TTrigonometryCalculator: CalculateTrigonometry + 'static,
+>(
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    trigonometry_calculator: Rc<TTrigonometryCalculator>,
+) -> JsonToContentConverter<
+    TypedJsonToValueConverter<JsonToStringConverter, Box<Rc<RefCell<dyn ContentObject>>>>,
+    TypedJsonToValueConverter<JsonToStringConverter, Box<dyn RunObjectInstance>>,
+> {
+    compose_content_loader_builder(
+        json_to_f32_converter,
+        json_to_string_converter,
+        trigonometry_calculator,
+    )
+    .build_json_to_content_converter()
+}
+
+pub fn compose_content_loader<
+    TTrigonometryCalculator: CalculateTrigonometry + 'static,
+    TJsonDocumentsReader: ReadJsonDocuments,
+>(
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    trigonometry_calculator: Rc<TTrigonometryCalculator>,
+    json_documents_reader: TJsonDocumentsReader,
+) -> ContentLoader<
+    JsonToContentConverter<
+        TypedJsonToValueConverter<JsonToStringConverter, Box<Rc<RefCell<dyn ContentObject>>>>,
+        TypedJsonToValueConverter<JsonToStringConverter, Box<dyn RunObjectInstance>>,
+    >,
+    TJsonDocumentsReader,
+> {
+    compose_content_loader_builder(
+        json_to_f32_converter,
+        json_to_string_converter,
+        trigonometry_calculator,
+    )
+    .build_content_loader(json_documents_reader)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use garden::GetName;
+    use garden_content::{
+        rectangles::{Rectangle, RectangleInstance},
+        transforms::{GetTransform, SetTransform, Transform},
+        triangles::{GeometryTriangle, Triangle, TriangleInstance},
+        Content, ContentObject, GetContentInstanceData, GetIndexData, GetIndexRanges,
+        GetNumberOfObjects, GetNumberOfVertices, GetObjectInstanceTransforms, GetVertexData,
+        GetVertexDataVersion, IndexRange, ObjectInstanceRunner, Rgb, RunObjectInstance,
+        TrianglePoint, TwoDPoint,
+    };
+    use garden_json::{
+        create_object_json_schema, ConvertJsonDocumentsToValue, ConvertJsonToValue, ConvertToJson,
+        GetJsonSchema, JsonDocument, JsonReader, JsonSchemaValidator, JsonToF32Converter,
+        JsonToStringConverter, ValidateJson,
+    };
+    use garden_loading::{Load, LoadError};
+    use garden_maths::trigonometry::{
+        AdjacentCalculator, DegreesToRadiansConverter, OppositeCalculator, TrigonometryCalculator,
+    };
+    use serde_json::{json, Value};
+
+    use crate::{
+        compose_content_loader, compose_content_loader_builder, compose_json_to_content_converter,
+        ContentLoaderBuilder, RegisterContentType,
+    };
+
+    fn compose_test_json_to_content_converter(
+    ) -> impl ConvertJsonToValue<Content> + ConvertJsonDocumentsToValue<Content> + GetJsonSchema
+    {
+        compose_json_to_content_converter(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(TrigonometryCalculator::new(
+                Rc::new(DegreesToRadiansConverter::new()),
+                Rc::new(AdjacentCalculator::new()),
+                Rc::new(OppositeCalculator::new()),
+            )),
+        )
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_json_to_content_then_the_content_is_converted() {
+        let json_to_content_converter = compose_test_json_to_content_converter();
 
         let json = json!({
             "content": {
@@ -2000,12 +3631,8 @@ mod tests {
                 )))),
             ],
             vec![
-                Box::new(ObjectInstanceRunner::new(
-                    Rc::new(RefCell::new(TriangleInstance::<
-                        TwoDPoint,
-                        TrianglePoint<TwoDPoint, Rgb>,
-                        Triangle<TrianglePoint<TwoDPoint, Rgb>>,
-                    >::new(
+                Box::new(ObjectInstanceRunner::new(Rc::new(RefCell::new(
+                    TriangleInstance::<TrianglePoint<TwoDPoint, Rgb>, _>::new(
                         "Triangle1-a".to_string(),
                         Rc::new(RefCell::new(Triangle::new(
                             "triangle".to_string(),
@@ -2030,52 +3657,16 @@ mod tests {
                             vec![],
                             0,
                         ))),
-                        0.5,
-                        TwoDPoint::new(-5.0, -5.0),
-                        TrianglePoint::<TwoDPoint, Rgb>::new(
-                            TwoDPoint::new(-5.5, -5.5),
-                            Rgb::new(1.0, 0.0, 0.0),
-                            5,
-                            vec![-5.5, -5.5, 1.0, 0.0, 0.0],
-                        ),
-                        TrianglePoint::<TwoDPoint, Rgb>::new(
-                            TwoDPoint::new(-5.0, -4.5),
-                            Rgb::new(0.0, 1.0, 0.0),
-                            5,
-                            vec![-5.0, -4.5, 0.0, 1.0, 0.0],
-                        ),
-                        TrianglePoint::<TwoDPoint, Rgb>::new(
-                            TwoDPoint::new(-4.5, -5.5),
-                            Rgb::new(0.0, 0.0, 1.0),
-                            5,
-                            vec![-4.5, -5.5, 0.0, 0.0, 1.0],
-                        ),
+                        Transform::new(-5.0, -5.0, 0.0, 0.5, 0.5),
                         15,
                         vec![
-                            -5.5, -5.5, 1.0, 0.0, 0.0, -5.0, -4.5, 0.0, 1.0, 0.0, -4.5, -5.5, 0.0,
+                            -1.0, -1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, -1.0, 0.0,
                             0.0, 1.0,
                         ],
-                    ))),
-                    Rc::new(TriangleInstanceScaler::new(
-                        Rc::new(ObjectCreator::new(
-                            Rc::new(TriangleInstanceConstructor::new(
-                                Rc::new(TriangleInstanceVertexDataGenerator::new()),
-                                Rc::new(TriangleInstanceVertexCounter::new()),
-                            )),
-                            Rc::new(RefCell::new(Store::new(vec![]))),
-                        )),
-                        Rc::new(TriangleInstancePointCreator::new(Rc::new(
-                            TrianglePointCreator::new(
-                                Rc::new(TwoDPointCreator::new()),
-                                Rc::new(RgbCreator::new()),
-                                Rc::new(TrianglePointConstructor::new()),
-                            ),
-                        ))),
-                        Rc::new(TwoDPointTranslator::new()),
-                    )),
-                )),
-                Box::new(ObjectInstanceRunner::new(
-                    Rc::new(RefCell::new(TriangleInstance::new(
+                    ),
+                )))),
+                Box::new(ObjectInstanceRunner::new(Rc::new(RefCell::new(
+                    TriangleInstance::<TrianglePoint<TwoDPoint, Rgb>, _>::new(
                         "Triangle1-b".to_string(),
                         Rc::new(RefCell::new(Triangle::new(
                             "triangle".to_string(),
@@ -2100,127 +3691,1457 @@ mod tests {
                             vec![],
                             0,
                         ))),
-                        0.5,
-                        TwoDPoint::new(0.5, 0.5),
-                        TrianglePoint::<TwoDPoint, Rgb>::new(
-                            TwoDPoint::new(2.0, 2.0),
-                            Rgb::new(1.0, 0.0, 0.0),
-                            5,
-                            vec![2.0, 2.0, 1.0, 0.0, 0.0],
-                        ),
-                        TrianglePoint::<TwoDPoint, Rgb>::new(
-                            TwoDPoint::new(5.0, 8.0),
-                            Rgb::new(0.0, 1.0, 0.0),
-                            5,
-                            vec![5.0, 8.0, 0.0, 1.0, 0.0],
-                        ),
-                        TrianglePoint::<TwoDPoint, Rgb>::new(
-                            TwoDPoint::new(8.0, 2.0),
-                            Rgb::new(0.0, 0.0, 1.0),
-                            5,
-                            vec![8.0, 2.0, 0.0, 0.0, 1.0],
-                        ),
+                        Transform::new(5.0, 5.0, 0.0, 3.0, 3.0),
                         15,
                         vec![
-                            2.0, 2.0, 1.0, 0.0, 0.0, 5.0, 8.0, 0.0, 1.0, 0.0, 8.0, 2.0, 0.0, 0.0,
-                            1.0,
+                            -1.0, -1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, -1.0, 0.0,
+                            0.0, 1.0,
                         ],
-                    ))),
-                    Rc::new(TriangleInstanceScaler::new(
-                        Rc::new(ObjectCreator::new(
-                            Rc::new(TriangleInstanceConstructor::new(
-                                Rc::new(TriangleInstanceVertexDataGenerator::new()),
-                                Rc::new(TriangleInstanceVertexCounter::new()),
-                            )),
-                            Rc::new(RefCell::new(Store::new(vec![]))),
-                        )),
-                        Rc::new(TriangleInstancePointCreator::new(Rc::new(
-                            TrianglePointCreator::new(
-                                Rc::new(TwoDPointCreator::new()),
-                                Rc::new(RgbCreator::new()),
-                                Rc::new(TrianglePointConstructor::new()),
-                            ),
-                        ))),
-                        Rc::new(TwoDPointTranslator::new()),
-                    )),
-                )),
-                Box::new(ObjectInstanceRunner::new(
-                    Rc::new(RefCell::new(RectangleInstance::new(
+                    ),
+                )))),
+                Box::new(ObjectInstanceRunner::new(Rc::new(RefCell::new(
+                    RectangleInstance::<_, GeometryTriangle<TrianglePoint<TwoDPoint, Rgb>>>::new(
                         "Rectangle1-a".to_string(),
                         Rc::new(RefCell::new(Rectangle::new(
                             "Rectangle1".to_string(),
-                            2.5,
+                            2.0,
                             5.0,
                             Rgb::new(0.0, 0.0, 1.0),
                         ))),
-                        1.0,
-                        TwoDPoint::new(-5.0, 5.0),
-                        30,
+                        Transform::new(-5.0, 5.0, 0.0, 1.0, 1.0),
+                        20,
                         vec![
-                            -4.0, 7.5, 0.0, 0.0, 1.0, -6.0, 7.5, 0.0, 0.0, 1.0, -6.0, 2.5, 0.0,
-                            0.0, 1.0, -4.0, 7.5, 0.0, 0.0, 1.0, -6.0, 2.5, 0.0, 0.0, 1.0, -4.0,
-                            2.5, 0.0, 0.0, 1.0,
+                            1.0, 2.5, 0.0, 0.0, 1.0, -1.0, 2.5, 0.0, 0.0, 1.0, -1.0, -2.5, 0.0,
+                            0.0, 1.0, 1.0, -2.5, 0.0, 0.0, 1.0,
                         ],
+                        vec![0, 1, 2, 0, 2, 3],
                         vec![],
-                    ))),
-                    Rc::new(RectangleInstanceScaler::new(
-                        Rc::new(ObjectCreator::new(
-                            Rc::new(RectangleInstanceConstructor::new(Rc::new(
-                                GeometryTrianglesCreator::new(
-                                    Rc::new(GeometryTriangleConstructor::new()),
-                                    Rc::new(TrianglePointCreator::new(
-                                        Rc::new(TwoDPointCreator::new()),
-                                        Rc::new(RgbCreator::new()),
-                                        Rc::new(TrianglePointConstructor::new()),
-                                    )),
-                                ),
-                            ))),
-                            Rc::new(RefCell::new(Store::new(vec![]))),
-                        )),
-                        Rc::new(TwoDPointCreator::new()),
-                    )),
-                )),
-                Box::new(ObjectInstanceRunner::new(
-                    Rc::new(RefCell::new(RectangleInstance::new(
+                    ),
+                )))),
+                Box::new(ObjectInstanceRunner::new(Rc::new(RefCell::new(
+                    RectangleInstance::<_, GeometryTriangle<TrianglePoint<TwoDPoint, Rgb>>>::new(
                         "Rectangle2-a".to_string(),
                         Rc::new(RefCell::new(Rectangle::new(
-                            "Rectangle1".to_string(),
+                            "Rectangle2".to_string(),
                             3.0,
                             2.0,
                             Rgb::new(1.0, 0.0, 0.0),
                         ))),
-                        1.0,
-                        TwoDPoint::new(5.0, -5.0),
-                        30,
+                        Transform::new(5.0, -5.0, 0.0, 1.0, 1.0),
+                        20,
                         vec![
-                            6.5, -4.0, 1.0, 0.0, 0.0, 3.5, -4.0, 1.0, 0.0, 0.0, 3.5, -6.0, 1.0,
-                            0.0, 0.0, 6.5, -4.0, 1.0, 0.0, 0.0, 3.5, -6.0, 1.0, 0.0, 0.0, 6.5,
-                            -6.0, 1.0, 0.0, 0.0,
+                            1.5, 1.0, 1.0, 0.0, 0.0, -1.5, 1.0, 1.0, 0.0, 0.0, -1.5, -1.0, 1.0,
+                            0.0, 0.0, 1.5, -1.0, 1.0, 0.0, 0.0,
                         ],
+                        vec![0, 1, 2, 0, 2, 3],
                         vec![],
-                    ))),
-                    Rc::new(RectangleInstanceScaler::new(
-                        Rc::new(ObjectCreator::new(
-                            Rc::new(RectangleInstanceConstructor::new(Rc::new(
-                                GeometryTrianglesCreator::new(
-                                    Rc::new(GeometryTriangleConstructor::new()),
-                                    Rc::new(TrianglePointCreator::new(
-                                        Rc::new(TwoDPointCreator::new()),
-                                        Rc::new(RgbCreator::new()),
-                                        Rc::new(TrianglePointConstructor::new()),
-                                    )),
-                                ),
-                            ))),
-                            Rc::new(RefCell::new(Store::new(vec![]))),
-                        )),
-                        Rc::new(TwoDPointCreator::new()),
-                    )),
-                )),
+                    ),
+                )))),
             ],
         );
 
-        let result = json_to_content_converter.convert_json_to_value(&json);
+        let result = json_to_content_converter
+            .convert_json_to_value(&json)
+            .unwrap();
 
         assert_eq!(expected_result.get_vertex_data(), result.get_vertex_data());
+        assert_eq!(expected_result.get_index_data(), result.get_index_data());
+        assert_eq!(
+            expected_result.get_object_instance_transforms(),
+            result.get_object_instance_transforms()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_an_object_with_an_unknown_type_then_an_unknown_type_error_is_returned(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Hexagon1",
+                        "type": "hexagon"
+                    }
+                ]
+            },
+            "objects": []
+        });
+
+        let result = json_to_content_converter.convert_json_to_value(&json);
+
+        assert_eq!(
+            Some(LoadError::UnknownType {
+                path: "/content/objects/0/type".to_string(),
+                value_type: "hexagon".to_string(),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_an_object_with_a_missing_field_then_a_missing_field_error_is_returned(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 5.0,
+                        "rgb": {
+                            "r": 0.0,
+                            "b": 1.0
+                        }
+                    }
+                ]
+            },
+            "objects": []
+        });
+
+        let result = json_to_content_converter.convert_json_to_value(&json);
+
+        assert_eq!(
+            Some(LoadError::MissingField {
+                path: "/content/objects/0/rgb/g".to_string(),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_objects_that_are_not_an_array_then_a_wrong_field_type_error_is_returned(
+    ) {
+        let cases = vec![
+            (
+                json!({ "content": { "objects": {} }, "objects": [] }),
+                "/content/objects",
+            ),
+            (
+                json!({ "content": { "objects": [] }, "objects": "Rectangle1-a" }),
+                "/objects",
+            ),
+        ];
+
+        for (json, path) in cases {
+            let result = compose_test_json_to_content_converter().convert_json_to_value(&json);
+
+            assert_eq!(
+                Some(LoadError::WrongFieldType {
+                    path: path.to_string(),
+                    expected_type: "array",
+                }),
+                result.err()
+            );
+        }
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_an_object_instance_with_a_wrong_field_type_then_a_wrong_field_type_error_is_returned(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 5.0,
+                        "rgb": {
+                            "r": 0.0,
+                            "g": 0.0,
+                            "b": 1.0
+                        }
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Rectangle1-a",
+                    "contentName": "Rectangle1",
+                    "type": "rectangle",
+                    "scale": 1.0,
+                    "position": {
+                        "x": -5.0,
+                        "y": "five"
+                    }
+                }
+            ]
+        });
+
+        let result = json_to_content_converter.convert_json_to_value(&json);
+
+        assert_eq!(
+            Some(LoadError::WrongFieldType {
+                path: "/objects/0/position/y".to_string(),
+                expected_type: "number",
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_an_object_instance_with_an_unknown_content_name_then_an_unknown_content_error_is_returned(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let json = json!({
+            "content": {
+                "objects": []
+            },
+            "objects": [
+                {
+                    "name": "Rectangle1-a",
+                    "contentName": "Rectangle1",
+                    "type": "rectangle",
+                    "scale": 1.0,
+                    "position": {
+                        "x": -5.0,
+                        "y": 5.0
+                    }
+                }
+            ]
+        });
+
+        let result = json_to_content_converter.convert_json_to_value(&json);
+
+        assert_eq!(
+            Some(LoadError::UnknownContent {
+                path: "/objects/0/contentName".to_string(),
+                content_name: "Rectangle1".to_string(),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_json_documents_then_instances_can_use_objects_from_other_documents(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let json_documents = vec![
+            JsonDocument::new(
+                "level-1.json".to_string(),
+                json!({
+                    "objects": [
+                        {
+                            "name": "Rectangle1-a",
+                            "contentName": "Rectangle1",
+                            "type": "rectangle",
+                            "scale": 1.0,
+                            "position": {
+                                "x": 0.0,
+                                "y": 0.0
+                            }
+                        }
+                    ]
+                }),
+            ),
+            JsonDocument::new(
+                "shapes.json".to_string(),
+                json!({
+                    "content": {
+                        "objects": [
+                            {
+                                "name": "Rectangle1",
+                                "type": "rectangle",
+                                "width": 2.0,
+                                "height": 4.0,
+                                "rgb": {
+                                    "r": 0.0,
+                                    "g": 0.0,
+                                    "b": 1.0
+                                }
+                            }
+                        ]
+                    }
+                }),
+            ),
+        ];
+
+        let result = json_to_content_converter
+            .convert_json_documents_to_value(&json_documents)
+            .unwrap();
+
+        assert_eq!(1, result.get_objects().as_ref().unwrap().len());
+        assert_eq!(
+            vec![
+                1.0, 2.0, 0.0, 0.0, 1.0, -1.0, 2.0, 0.0, 0.0, 1.0, -1.0, -2.0, 0.0, 0.0, 1.0, 1.0,
+                -2.0, 0.0, 0.0, 1.0,
+            ],
+            result.get_vertex_data()
+        );
+        assert_eq!(vec![0, 1, 2, 0, 2, 3], result.get_index_data());
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_json_documents_with_a_duplicate_object_name_then_a_duplicate_name_error_is_returned(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let rectangle_json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 4.0,
+                        "rgb": {
+                            "r": 0.0,
+                            "g": 0.0,
+                            "b": 1.0
+                        }
+                    }
+                ]
+            }
+        });
+
+        let json_documents = vec![
+            JsonDocument::new("shapes.json".to_string(), rectangle_json.clone()),
+            JsonDocument::new("more-shapes.json".to_string(), rectangle_json),
+        ];
+
+        let result = json_to_content_converter.convert_json_documents_to_value(&json_documents);
+
+        assert_eq!(
+            Some(LoadError::DuplicateName {
+                name: "Rectangle1".to_string(),
+                file_path: "more-shapes.json".to_string(),
+                first_file_path: "shapes.json".to_string(),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_converts_an_invalid_json_document_then_the_error_includes_the_file_path(
+    ) {
+        let json_to_content_converter = compose_test_json_to_content_converter();
+
+        let json_documents = vec![JsonDocument::new(
+            "shapes.json".to_string(),
+            json!({
+                "content": {
+                    "objects": [
+                        {
+                            "name": "Rectangle1",
+                            "type": "rectangle",
+                            "height": 4.0
+                        }
+                    ]
+                }
+            }),
+        )];
+
+        let result = json_to_content_converter.convert_json_documents_to_value(&json_documents);
+
+        assert_eq!(
+            Some(LoadError::InFile {
+                file_path: "shapes.json".to_string(),
+                error: Box::new(LoadError::MissingField {
+                    path: "/content/objects/0/width".to_string(),
+                }),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_content_loader_loads_from_a_reader_then_the_content_is_loaded() {
+        let content_loader = compose_content_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(TrigonometryCalculator::new(
+                Rc::new(DegreesToRadiansConverter::new()),
+                Rc::new(AdjacentCalculator::new()),
+                Rc::new(OppositeCalculator::new()),
+            )),
+            JsonReader::new(
+                "fixture",
+                r#"{
+                    "content": {
+                        "objects": [
+                            {
+                                "name": "Rectangle1",
+                                "type": "rectangle",
+                                "width": 2.0,
+                                "height": 4.0,
+                                "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                            }
+                        ]
+                    },
+                    "objects": []
+                }"#
+                .as_bytes(),
+            ),
+        );
+
+        let result = content_loader.load().unwrap();
+
+        assert_eq!(1, result.get_objects().as_ref().unwrap().len());
+    }
+
+    #[test]
+    fn when_mixed_triangle_rectangle_and_circle_content_is_converted_then_each_object_instance_has_its_own_index_range(
+    ) {
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Triangle1",
+                        "type": "triangle",
+                        "point1": {
+                            "twoDPoint": { "x": -1.0, "y": -1.0 },
+                            "rgb": { "r": 1.0, "g": 0.0, "b": 0.0 }
+                        },
+                        "point2": {
+                            "twoDPoint": { "x": 0.0, "y": 1.0 },
+                            "rgb": { "r": 0.0, "g": 1.0, "b": 0.0 }
+                        },
+                        "point3": {
+                            "twoDPoint": { "x": 1.0, "y": -1.0 },
+                            "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                        }
+                    },
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 5.0,
+                        "rgb": { "r": 0.1, "g": 0.2, "b": 0.3 }
+                    },
+                    {
+                        "name": "Circle1",
+                        "type": "circle",
+                        "diameter": 3.0,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 }
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Rectangle1-a",
+                    "contentName": "Rectangle1",
+                    "type": "rectangle",
+                    "scale": 1.0,
+                    "position": { "x": -5.0, "y": 5.0 }
+                },
+                {
+                    "name": "Circle1-a",
+                    "contentName": "Circle1",
+                    "type": "circle",
+                    "scale": 1.0,
+                    "position": { "x": 5.0, "y": -5.0 }
+                },
+                {
+                    "name": "Triangle1-a",
+                    "contentName": "Triangle1",
+                    "type": "triangle",
+                    "scale": 1.0,
+                    "position": { "x": -5.0, "y": -5.0 }
+                },
+                {
+                    "name": "Triangle1-b",
+                    "contentName": "Triangle1",
+                    "type": "triangle",
+                    "scale": 1.0,
+                    "position": { "x": 5.0, "y": 5.0 }
+                }
+            ]
+        });
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        let result = content.get_index_ranges();
+
+        assert_eq!(content.get_vertex_data().len(), (371 * 5) as usize);
+        assert_eq!(
+            [
+                IndexRange::new(0, 6),
+                IndexRange::new(6, 1080),
+                IndexRange::new(1086, 3),
+                IndexRange::new(1089, 3)
+            ],
+            result
+        );
+        assert_eq!(
+            vec![365, 366, 367, 368, 369, 370],
+            content.get_index_data()[1086..].to_vec()
+        );
+    }
+
+    #[test]
+    fn when_a_circle_with_a_segment_count_is_converted_then_the_circle_has_one_triangle_per_segment(
+    ) {
+        let json = create_circle_content_json(json!({ "segments": 8 }));
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        assert_eq!(8, content.get_number_of_objects());
+        assert_eq!(9 * 5, content.get_vertex_data().len());
+        assert_eq!([IndexRange::new(0, 24)], content.get_index_ranges());
+        assert_eq!(
+            json!(8),
+            content.get_objects().as_ref().unwrap()[0]
+                .borrow()
+                .convert_to_json()["segments"]
+        );
+    }
+
+    #[test]
+    fn when_a_circle_with_a_tolerance_is_converted_then_the_fewest_triangles_within_the_tolerance_are_created(
+    ) {
+        let json = create_circle_content_json(json!({ "tolerance": 0.01 }));
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        assert_eq!(28, content.get_number_of_objects());
+    }
+
+    #[test]
+    fn when_a_scaled_circle_with_a_tolerance_is_converted_then_the_segment_count_uses_the_largest_scale(
+    ) {
+        for scale in [json!(10.0), json!({ "x": 10.0, "y": 1.0 })] {
+            let mut json = create_circle_content_json(json!({ "tolerance": 0.01 }));
+
+            json["objects"][0]["scale"] = scale;
+
+            let content = compose_test_json_to_content_converter()
+                .convert_json_to_value(&json)
+                .unwrap();
+
+            assert_eq!(87, content.get_number_of_objects());
+        }
+    }
+
+    #[test]
+    fn when_a_circle_with_a_tolerance_is_rescaled_then_the_segment_count_uses_the_new_scale() {
+        let json = create_circle_content_json(json!({ "tolerance": 0.01 }));
+
+        let mut content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        content
+            .set_object_instance_transform("Circle1-a", Transform::new(0.0, 0.0, 0.0, 10.0, 1.0));
+
+        assert_eq!(87, content.get_number_of_objects());
+
+        content.set_object_instance_transform("Circle1-a", Transform::new(0.0, 0.0, 0.0, 1.0, 1.0));
+
+        assert_eq!(28, content.get_number_of_objects());
+    }
+
+    #[test]
+    fn when_a_circle_with_an_invalid_tessellation_policy_is_converted_then_an_error_is_returned() {
+        let cases = vec![
+            (
+                json!({ "segments": 2 }),
+                LoadError::InvalidValue {
+                    path: "/content/objects/0/segments".to_string(),
+                    value: "2".to_string(),
+                    reason: "must be between 3 and 360".to_string(),
+                },
+            ),
+            (
+                json!({ "tolerance": 0.0 }),
+                LoadError::InvalidValue {
+                    path: "/content/objects/0/tolerance".to_string(),
+                    value: "0".to_string(),
+                    reason: "must be > 0".to_string(),
+                },
+            ),
+            (
+                json!({ "segments": 8, "tolerance": 0.01 }),
+                LoadError::UnknownField {
+                    path: "/content/objects/0/tolerance".to_string(),
+                },
+            ),
+        ];
+
+        for (tessellation, expected_error) in cases {
+            let json = create_circle_content_json(tessellation);
+
+            let result = compose_test_json_to_content_converter().convert_json_to_value(&json);
+
+            assert_eq!(Some(expected_error), result.err());
+        }
+    }
+
+    #[test]
+    fn when_ellipse_arc_sector_and_ring_content_is_converted_then_each_instance_is_triangulated() {
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Ellipse1",
+                        "type": "ellipse",
+                        "radiusX": 2.0,
+                        "radiusY": 1.0,
+                        "rgb": { "r": 1.0, "g": 0.0, "b": 0.0 },
+                        "segments": 16
+                    },
+                    {
+                        "name": "Arc1",
+                        "type": "arc",
+                        "innerRadius": 1.0,
+                        "outerRadius": 2.0,
+                        "startAngle": 0.0,
+                        "endAngle": 90.0,
+                        "rgb": { "r": 0.0, "g": 1.0, "b": 0.0 },
+                        "segments": 16
+                    },
+                    {
+                        "name": "Sector1",
+                        "type": "sector",
+                        "radius": 2.0,
+                        "startAngle": 45.0,
+                        "endAngle": 135.0,
+                        "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 },
+                        "segments": 16
+                    },
+                    {
+                        "name": "Ring1",
+                        "type": "ring",
+                        "innerRadius": 1.0,
+                        "outerRadius": 2.0,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 },
+                        "segments": 16
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Ellipse1-a",
+                    "contentName": "Ellipse1",
+                    "type": "ellipse",
+                    "scale": 1.0,
+                    "position": { "x": 0.0, "y": 0.0 }
+                },
+                {
+                    "name": "Arc1-a",
+                    "contentName": "Arc1",
+                    "type": "arc",
+                    "scale": 1.0,
+                    "position": { "x": 0.0, "y": 0.0 }
+                },
+                {
+                    "name": "Sector1-a",
+                    "contentName": "Sector1",
+                    "type": "sector",
+                    "scale": 1.0,
+                    "position": { "x": 0.0, "y": 0.0 }
+                },
+                {
+                    "name": "Ring1-a",
+                    "contentName": "Ring1",
+                    "type": "ring",
+                    "scale": 1.0,
+                    "position": { "x": 0.0, "y": 0.0 }
+                }
+            ]
+        });
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        assert_eq!(65 * 5, content.get_vertex_data().len());
+        assert_eq!(
+            [
+                IndexRange::new(0, 48),
+                IndexRange::new(48, 24),
+                IndexRange::new(72, 12),
+                IndexRange::new(84, 96)
+            ],
+            content.get_index_ranges()
+        );
+        assert_eq!(
+            json,
+            json!({
+                "content": {
+                    "objects": content
+                        .get_objects()
+                        .as_ref()
+                        .unwrap()
+                        .iter()
+                        .map(|object| object.borrow().convert_to_json())
+                        .collect::<Vec<Value>>()
+                },
+                "objects": content
+                    .get_object_instance_runners()
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .map(|object_instance_runner| object_instance_runner.convert_to_json())
+                    .collect::<Vec<Value>>()
+            })
+        );
+    }
+
+    #[test]
+    fn when_a_scaled_curve_with_a_tolerance_is_converted_then_the_segment_count_uses_the_largest_scale(
+    ) {
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Ring1",
+                        "type": "ring",
+                        "innerRadius": 1.0,
+                        "outerRadius": 1.5,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 },
+                        "tolerance": 0.01
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Ring1-a",
+                    "contentName": "Ring1",
+                    "type": "ring",
+                    "scale": { "x": 1.0, "y": 10.0 },
+                    "position": { "x": 0.0, "y": 0.0 }
+                }
+            ]
+        });
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        assert_eq!(174, content.get_number_of_objects());
+    }
+
+    #[test]
+    fn when_a_curve_with_a_tolerance_is_rescaled_then_the_segment_count_uses_the_new_scale() {
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Ring1",
+                        "type": "ring",
+                        "innerRadius": 1.0,
+                        "outerRadius": 1.5,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 },
+                        "tolerance": 0.01
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Ring1-a",
+                    "contentName": "Ring1",
+                    "type": "ring",
+                    "scale": 1.0,
+                    "position": { "x": 0.0, "y": 0.0 }
+                }
+            ]
+        });
+
+        let mut content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        assert_eq!(56, content.get_number_of_objects());
+
+        content.set_object_instance_transform("Ring1-a", Transform::new(0.0, 0.0, 0.0, 1.0, 10.0));
+
+        assert_eq!(174, content.get_number_of_objects());
+    }
+
+    #[test]
+    fn when_a_curve_with_invalid_dimensions_is_converted_then_an_error_is_returned() {
+        let cases = vec![
+            (
+                json!({ "type": "ellipse", "radiusX": 0.0, "radiusY": 1.0 }),
+                LoadError::InvalidValue {
+                    path: "/content/objects/0/radiusX".to_string(),
+                    value: "0".to_string(),
+                    reason: "must be > 0".to_string(),
+                },
+            ),
+            (
+                json!({ "type": "ring", "innerRadius": 2.0, "outerRadius": 1.0 }),
+                LoadError::InvalidValue {
+                    path: "/content/objects/0/innerRadius".to_string(),
+                    value: "2".to_string(),
+                    reason: "must be >= 0 and < outerRadius (1)".to_string(),
+                },
+            ),
+            (
+                json!({ "type": "sector", "radius": 1.0, "startAngle": 90.0, "endAngle": 45.0 }),
+                LoadError::InvalidValue {
+                    path: "/content/objects/0/endAngle".to_string(),
+                    value: "45".to_string(),
+                    reason: "must be > startAngle (90) and <= startAngle + 360".to_string(),
+                },
+            ),
+            (
+                json!({ "type": "arc", "innerRadius": 1.0, "outerRadius": 2.0, "startAngle": 0.0 }),
+                LoadError::MissingField {
+                    path: "/content/objects/0/endAngle".to_string(),
+                },
+            ),
+        ];
+
+        for (mut curve_json, expected_error) in cases {
+            curve_json["name"] = json!("Curve1");
+            curve_json["rgb"] = json!({ "r": 1.0, "g": 1.0, "b": 1.0 });
+
+            let json = json!({
+                "content": { "objects": [curve_json] },
+                "objects": []
+            });
+
+            let result = compose_test_json_to_content_converter().convert_json_to_value(&json);
+
+            assert_eq!(Some(expected_error), result.err());
+        }
+    }
+
+    #[test]
+    fn when_a_concave_polygon_is_converted_then_it_is_triangulated_whatever_its_winding() {
+        let counter_clockwise_points = json!([
+            { "x": 0.0, "y": 0.0 },
+            { "x": 4.0, "y": 0.0 },
+            { "x": 4.0, "y": 1.0 },
+            { "x": 1.0, "y": 1.0 },
+            { "x": 1.0, "y": 4.0 },
+            { "x": 0.0, "y": 4.0 }
+        ]);
+
+        let mut clockwise_points = counter_clockwise_points.clone();
+
+        clockwise_points.as_array_mut().unwrap().reverse();
+
+        for points in [counter_clockwise_points, clockwise_points] {
+            let json = json!({
+                "content": {
+                    "objects": [
+                        {
+                            "name": "Polygon1",
+                            "type": "polygon",
+                            "points": points,
+                            "rgb": { "r": 0.0, "g": 1.0, "b": 0.0 }
+                        }
+                    ]
+                },
+                "objects": [
+                    {
+                        "name": "Polygon1-a",
+                        "contentName": "Polygon1",
+                        "type": "polygon",
+                        "scale": 1.0,
+                        "position": { "x": 0.0, "y": 0.0 }
+                    }
+                ]
+            });
+
+            let content = compose_test_json_to_content_converter()
+                .convert_json_to_value(&json)
+                .unwrap();
+
+            assert_eq!(4, content.get_number_of_objects());
+            assert_eq!(6 * 5, content.get_vertex_data().len());
+            assert_eq!([IndexRange::new(0, 12)], content.get_index_ranges());
+            assert_eq!(
+                json["content"]["objects"][0],
+                content.get_objects().as_ref().unwrap()[0]
+                    .borrow()
+                    .convert_to_json()
+            );
+        }
+    }
+
+    #[test]
+    fn when_a_degenerate_polygon_is_converted_then_an_error_is_returned() {
+        let cases = vec![
+            (
+                json!([{ "x": 0.0, "y": 0.0 }, { "x": 1.0, "y": 0.0 }]),
+                LoadError::InvalidGeometry {
+                    path: "/content/objects/0/points".to_string(),
+                    reason: "a polygon needs at least 3 points, found 2".to_string(),
+                },
+            ),
+            (
+                json!([
+                    { "x": 0.0, "y": 0.0 },
+                    { "x": 1.0, "y": 1.0 },
+                    { "x": 2.0, "y": 2.0 }
+                ]),
+                LoadError::InvalidGeometry {
+                    path: "/content/objects/0/points".to_string(),
+                    reason: "the points enclose no area".to_string(),
+                },
+            ),
+            (
+                json!([
+                    { "x": 0.0, "y": 0.0 },
+                    { "x": 2.0, "y": 2.0 },
+                    { "x": 2.0, "y": 0.0 },
+                    { "x": 0.0, "y": 2.0 }
+                ]),
+                LoadError::InvalidGeometry {
+                    path: "/content/objects/0/points".to_string(),
+                    reason: "edge 0 crosses edge 2".to_string(),
+                },
+            ),
+            (
+                json!([{ "x": 0.0, "y": 0.0 }, { "x": 1.0 }, { "x": 0.0, "y": 1.0 }]),
+                LoadError::MissingField {
+                    path: "/content/objects/0/points/1/y".to_string(),
+                },
+            ),
+            (
+                json!({ "x": 0.0, "y": 0.0 }),
+                LoadError::WrongFieldType {
+                    path: "/content/objects/0/points".to_string(),
+                    expected_type: "array",
+                },
+            ),
+        ];
+
+        for (points, expected_error) in cases {
+            let json = json!({
+                "content": {
+                    "objects": [
+                        {
+                            "name": "Polygon1",
+                            "type": "polygon",
+                            "points": points,
+                            "rgb": { "r": 1.0, "g": 1.0, "b": 1.0 }
+                        }
+                    ]
+                },
+                "objects": []
+            });
+
+            let result = compose_test_json_to_content_converter().convert_json_to_value(&json);
+
+            assert_eq!(Some(expected_error), result.err());
+        }
+    }
+
+    fn create_circle_content_json(tessellation: Value) -> Value {
+        let mut circle_json = json!({
+            "name": "Circle1",
+            "type": "circle",
+            "diameter": 3.0,
+            "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 }
+        });
+
+        for (key, value) in tessellation.as_object().unwrap() {
+            circle_json[key] = value.clone();
+        }
+
+        json!({
+            "content": { "objects": [circle_json] },
+            "objects": [
+                {
+                    "name": "Circle1-a",
+                    "contentName": "Circle1",
+                    "type": "circle",
+                    "scale": 1.0,
+                    "position": { "x": 0.0, "y": 0.0 }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn when_content_is_converted_to_json_and_back_then_the_content_is_unchanged() {
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Triangle1",
+                        "type": "triangle",
+                        "point1": {
+                            "twoDPoint": { "x": -1.0, "y": -1.0 },
+                            "rgb": { "r": 1.0, "g": 0.0, "b": 0.0 }
+                        },
+                        "point2": {
+                            "twoDPoint": { "x": 0.0, "y": 1.0 },
+                            "rgb": { "r": 0.0, "g": 1.0, "b": 0.0 }
+                        },
+                        "point3": {
+                            "twoDPoint": { "x": 1.0, "y": -1.0 },
+                            "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                        }
+                    },
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 5.0,
+                        "rgb": { "r": 0.1, "g": 0.2, "b": 0.3 }
+                    },
+                    {
+                        "name": "Square1",
+                        "type": "square",
+                        "size": 4.0,
+                        "rgb": { "r": 0.5, "g": 0.5, "b": 0.5 }
+                    },
+                    {
+                        "name": "Circle1",
+                        "type": "circle",
+                        "diameter": 3.0,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 }
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Triangle1-a",
+                    "contentName": "Triangle1",
+                    "type": "triangle",
+                    "scale": 0.5,
+                    "position": { "x": -5.0, "y": -5.0 }
+                },
+                {
+                    "name": "Rectangle1-a",
+                    "contentName": "Rectangle1",
+                    "type": "rectangle",
+                    "scale": 1.5,
+                    "position": { "x": -5.0, "y": 5.0 }
+                },
+                {
+                    "name": "Square1-a",
+                    "contentName": "Square1",
+                    "type": "square",
+                    "scale": 1.0,
+                    "position": { "x": 2.0, "y": 2.0 }
+                },
+                {
+                    "name": "Circle1-a",
+                    "contentName": "Circle1",
+                    "type": "circle",
+                    "scale": 2.0,
+                    "position": { "x": 5.0, "y": -5.0 }
+                }
+            ]
+        });
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        let saved_json = content.convert_to_json();
+
+        let reloaded_content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&saved_json)
+            .unwrap();
+
+        assert_eq!(saved_json, reloaded_content.convert_to_json());
+        assert_eq!(
+            content.get_vertex_data(),
+            reloaded_content.get_vertex_data()
+        );
+        assert_eq!(
+            json!({
+                "name": "Rectangle1",
+                "type": "rectangle",
+                "width": 2.0,
+                "height": 5.0,
+                "rgb": { "r": 0.1, "g": 0.2, "b": 0.3 }
+            }),
+            saved_json["content"]["objects"][1]
+        );
+        assert_eq!(
+            json!({
+                "name": "Rectangle1-a",
+                "contentName": "Rectangle1",
+                "type": "rectangle",
+                "scale": 1.5,
+                "position": { "x": -5.0, "y": 5.0 }
+            }),
+            saved_json["objects"][1]
+        );
+    }
+
+    #[test]
+    fn when_an_object_instance_has_a_rotation_and_a_non_uniform_scale_then_both_are_loaded_and_saved(
+    ) {
+        let json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Rectangle1",
+                        "type": "rectangle",
+                        "width": 2.0,
+                        "height": 4.0,
+                        "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Rectangle1-a",
+                    "contentName": "Rectangle1",
+                    "type": "rectangle",
+                    "scale": { "x": 2.0, "y": 1.0 },
+                    "position": { "x": 1.0, "y": 1.0 },
+                    "rotation": 90.0
+                }
+            ]
+        });
+
+        let content = compose_test_json_to_content_converter()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        let transform = content.get_object_instance_transforms()[0];
+
+        let positions = content
+            .get_vertex_data()
+            .chunks(5)
+            .flat_map(|vertex| {
+                let (x, y) = transform.transform_point(vertex[0], vertex[1]);
+
+                [x, y]
+            })
+            .collect::<Vec<f32>>();
+
+        let expected_positions = [-1.0, 3.0, -1.0, -1.0, 3.0, -1.0, 3.0, 3.0];
+
+        assert_eq!(expected_positions.len(), positions.len());
+        for (expected, result) in expected_positions.iter().zip(positions.iter()) {
+            assert!((expected - result).abs() < 0.0001);
+        }
+        assert_eq!(json["objects"], content.convert_to_json()["objects"]);
+    }
+
+    #[test]
+    fn when_a_content_type_is_registered_then_the_content_loader_loads_it_with_the_built_in_types()
+    {
+        let mut content_loader_builder = compose_content_loader_builder(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(TrigonometryCalculator::new(
+                Rc::new(DegreesToRadiansConverter::new()),
+                Rc::new(AdjacentCalculator::new()),
+                Rc::new(OppositeCalculator::new()),
+            )),
+        );
+
+        content_loader_builder.register_content_type(
+            "star",
+            JsonToStarConverter {},
+            JsonToStarInstanceConverter {},
+        );
+
+        let content_loader = content_loader_builder.build_content_loader(JsonReader::new(
+            "fixture",
+            r#"{
+                "content": {
+                    "objects": [
+                        { "name": "Star1", "type": "star" },
+                        {
+                            "name": "Rectangle1",
+                            "type": "rectangle",
+                            "width": 2.0,
+                            "height": 4.0,
+                            "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                        }
+                    ]
+                },
+                "objects": [
+                    { "name": "Star1-a", "type": "star", "points": 5.0 }
+                ]
+            }"#
+            .as_bytes(),
+        ));
+
+        let result = content_loader.load().unwrap();
+
+        assert_eq!(2, result.get_objects().as_ref().unwrap().len());
+        assert_eq!(vec![5.0], result.get_vertex_data());
+        assert_eq!(
+            json!({ "name": "Star1", "type": "star" }),
+            result.convert_to_json()["content"]["objects"][0]
+        );
+    }
+
+    #[test]
+    fn when_a_content_loader_builder_has_no_registered_types_then_an_unknown_type_error_is_returned(
+    ) {
+        let json_to_content_converter =
+            ContentLoaderBuilder::new(Rc::new(JsonToStringConverter::new()))
+                .build_json_to_content_converter();
+
+        let json = json!({
+            "content": {
+                "objects": [
+                    { "name": "Star1", "type": "star" }
+                ]
+            }
+        });
+
+        let result = json_to_content_converter.convert_json_to_value(&json);
+
+        assert_eq!(
+            Err(LoadError::UnknownType {
+                path: "/content/objects/0/type".to_string(),
+                value_type: "star".to_string(),
+            }),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_content_converter_gets_its_json_schema_then_every_registered_type_is_described(
+    ) {
+        let result = compose_test_json_to_content_converter().get_json_schema();
+
+        let object_types = result["properties"]["content"]["properties"]["objects"]["items"]
+            ["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|json_schema| json_schema["properties"]["type"]["const"].clone())
+            .collect::<Vec<Value>>();
+
+        assert_eq!(
+            vec![
+                json!("arc"),
+                json!("circle"),
+                json!("ellipse"),
+                json!("equilateral-triangle"),
+                json!("polygon"),
+                json!("rectangle"),
+                json!("ring"),
+                json!("sector"),
+                json!("square"),
+                json!("triangle")
+            ],
+            object_types
+        );
+        assert_eq!(
+            json!({
+                "type": "object",
+                "properties": {
+                    "type": { "const": "circle" },
+                    "name": { "type": "string" },
+                    "diameter": { "type": "number" },
+                    "rgb": {
+                        "type": "object",
+                        "properties": {
+                            "r": { "type": "number" },
+                            "g": { "type": "number" },
+                            "b": { "type": "number" }
+                        },
+                        "required": ["r", "g", "b"],
+                        "additionalProperties": false
+                    },
+                    "segments": { "type": "integer" },
+                    "tolerance": { "type": "number" }
+                },
+                "required": ["type", "name", "diameter", "rgb"],
+                "additionalProperties": false
+            }),
+            result["properties"]["content"]["properties"]["objects"]["items"]["oneOf"][1]
+        );
+        assert_eq!(
+            json!(["type", "name", "contentName", "scale", "position"]),
+            result["properties"]["objects"]["items"]["oneOf"][0]["required"]
+        );
+    }
+
+    #[test]
+    fn when_content_json_is_validated_against_the_json_schema_then_valid_content_passes_and_invalid_content_fails(
+    ) {
+        let json_schema_validator =
+            JsonSchemaValidator::new(compose_test_json_to_content_converter().get_json_schema());
+
+        let mut json = json!({
+            "content": {
+                "objects": [
+                    {
+                        "name": "Triangle1",
+                        "type": "triangle",
+                        "point1": {
+                            "twoDPoint": { "x": -1.0, "y": -1.0 },
+                            "rgb": { "r": 1.0, "g": 0.0, "b": 0.0 }
+                        },
+                        "point2": {
+                            "twoDPoint": { "x": 0.0, "y": 1.0 },
+                            "rgb": { "r": 0.0, "g": 1.0, "b": 0.0 }
+                        },
+                        "point3": {
+                            "twoDPoint": { "x": 1.0, "y": -1.0 },
+                            "rgb": { "r": 0.0, "g": 0.0, "b": 1.0 }
+                        }
+                    },
+                    {
+                        "name": "Circle1",
+                        "type": "circle",
+                        "diameter": 3.0,
+                        "rgb": { "r": 1.0, "g": 1.0, "b": 0.0 }
+                    }
+                ]
+            },
+            "objects": [
+                {
+                    "name": "Circle1-a",
+                    "contentName": "Circle1",
+                    "type": "circle",
+                    "scale": 2.0,
+                    "position": { "x": 5.0, "y": -5.0 }
+                }
+            ]
+        });
+
+        assert_eq!(Ok(()), json_schema_validator.validate_json(&json));
+
+        json["content"]["objects"][1]["diamter"] = json!(3.0);
+
+        assert_eq!(
+            Err(LoadError::unknown_field()
+                .within("diamter")
+                .within("1")
+                .within("objects")
+                .within("content")),
+            json_schema_validator.validate_json(&json)
+        );
+    }
+
+    struct Star {
+        name: String,
+    }
+
+    impl GetName for Star {
+        fn get_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    impl ConvertToJson for Star {
+        fn convert_to_json(&self) -> Value {
+            json!({ "name": self.name, "type": "star" })
+        }
+    }
+
+    struct JsonToStarConverter {}
+
+    impl ConvertJsonToValue<Box<Rc<RefCell<dyn ContentObject>>>> for JsonToStarConverter {
+        fn convert_json_to_value(
+            &self,
+            json: &Value,
+        ) -> Result<Box<Rc<RefCell<dyn ContentObject>>>, LoadError> {
+            let name = JsonToStringConverter::new()
+                .convert_json_to_value(&json["name"])
+                .map_err(|error| error.within("name"))?;
+
+            Ok(Box::new(Rc::new(RefCell::new(Star { name }))))
+        }
+    }
+
+    impl GetJsonSchema for JsonToStarConverter {
+        fn get_json_schema(&self) -> Value {
+            create_object_json_schema(vec![("name", json!({ "type": "string" }))])
+        }
+    }
+
+    struct StarInstance {
+        name: String,
+        points: f32,
+    }
+
+    impl GetVertexData for StarInstance {
+        fn get_vertex_data(&self) -> Vec<f32> {
+            vec![self.points]
+        }
+    }
+
+    impl GetNumberOfVertices for StarInstance {
+        fn get_number_of_vertices(&self) -> i32 {
+            1
+        }
+    }
+
+    impl GetNumberOfObjects for StarInstance {
+        fn get_number_of_objects(&self) -> i32 {
+            1
+        }
+    }
+
+    impl GetIndexData for StarInstance {
+        fn get_index_data(&self) -> Vec<u32> {
+            vec![]
+        }
+    }
+
+    impl GetContentInstanceData for StarInstance {}
+
+    impl GetVertexDataVersion for StarInstance {
+        fn get_vertex_data_version(&self) -> u64 {
+            0
+        }
+    }
+
+    impl GetName for StarInstance {
+        fn get_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    impl GetTransform for StarInstance {
+        fn get_transform(&self) -> Transform {
+            Transform::new(0.0, 0.0, 0.0, 1.0, 1.0)
+        }
+    }
+
+    impl SetTransform for StarInstance {
+        fn set_transform(&mut self, _transform: Transform) {}
+    }
+
+    impl ConvertToJson for StarInstance {
+        fn convert_to_json(&self) -> Value {
+            json!({ "name": self.name, "type": "star", "points": self.points })
+        }
+    }
+
+    impl RunObjectInstance for StarInstance {}
+
+    struct JsonToStarInstanceConverter {}
+
+    impl GetJsonSchema for JsonToStarInstanceConverter {
+        fn get_json_schema(&self) -> Value {
+            create_object_json_schema(vec![
+                ("name", json!({ "type": "string" })),
+                ("points", json!({ "type": "number" })),
+            ])
+        }
+    }
+
+    impl ConvertJsonToValue<Box<dyn RunObjectInstance>> for JsonToStarInstanceConverter {
+        fn convert_json_to_value(
+            &self,
+            json: &Value,
+        ) -> Result<Box<dyn RunObjectInstance>, LoadError> {
+            let name = JsonToStringConverter::new()
+                .convert_json_to_value(&json["name"])
+                .map_err(|error| error.within("name"))?;
+
+            let points = JsonToF32Converter::new()
+                .convert_json_to_value(&json["points"])
+                .map_err(|error| error.within("points"))?;
+
+            Ok(Box::new(StarInstance { name, points }))
+        }
     }
 }
@@ -1,4 +1,4 @@
-use garden::{Create, GetName, Run};
+use garden::{Create, FrameTime, GetName, Run};
 
 pub struct EndSystem<TEngineEnder> {
     component_enders: Vec<Box<dyn Run>>,
@@ -98,8 +98,95 @@ pub fn create_game_name_provider<'a>(name: &'a str) -> GameNameProvider<'a> {
     GameNameProvider::<'a>::new(name)
 }
 
+pub const DEFAULT_TICKS_PER_SECOND: f64 = 60.0;
+
+pub const DEFAULT_MAX_TICKS_PER_STEP: u32 = 5;
+
+pub trait StepFixedTimestep {
+    fn step_fixed_timestep<TRunTick: FnMut(&FrameTime)>(
+        &mut self,
+        elapsed_seconds: f64,
+        run_tick: TRunTick,
+    ) -> f32;
+}
+
+pub trait GetTickDuration {
+    fn get_tick_duration(&self) -> f64;
+}
+
+pub struct FixedTimestep {
+    tick_duration: f64,
+    max_ticks_per_step: u32,
+    accumulated_seconds: f64,
+    number_of_ticks: u64,
+}
+
+pub fn assert_valid_tick_rate(ticks_per_second: f64) {
+    assert!(
+        ticks_per_second.is_finite() && ticks_per_second > 0.0,
+        "the tick rate must be a finite number of ticks per second greater than 0, found {ticks_per_second}"
+    );
+}
+
+impl FixedTimestep {
+    pub fn new(ticks_per_second: f64, max_ticks_per_step: u32) -> Self {
+        assert_valid_tick_rate(ticks_per_second);
+
+        Self {
+            tick_duration: 1.0 / ticks_per_second,
+            max_ticks_per_step,
+            accumulated_seconds: 0.0,
+            number_of_ticks: 0,
+        }
+    }
+}
+
+impl GetTickDuration for FixedTimestep {
+    fn get_tick_duration(&self) -> f64 {
+        self.tick_duration
+    }
+}
+
+impl StepFixedTimestep for FixedTimestep {
+    fn step_fixed_timestep<TRunTick: FnMut(&FrameTime)>(
+        &mut self,
+        elapsed_seconds: f64,
+        mut run_tick: TRunTick,
+    ) -> f32 {
+        self.accumulated_seconds += elapsed_seconds.max(0.0);
+
+        let mut number_of_ticks_this_step = 0;
+
+        while self.accumulated_seconds >= self.tick_duration {
+            if number_of_ticks_this_step == self.max_ticks_per_step {
+                self.accumulated_seconds %= self.tick_duration;
+                break;
+            }
+
+            self.number_of_ticks += 1;
+
+            run_tick(&FrameTime::new(
+                self.tick_duration as f32,
+                self.number_of_ticks as f64 * self.tick_duration,
+                self.number_of_ticks,
+            ));
+
+            self.accumulated_seconds -= self.tick_duration;
+            number_of_ticks_this_step += 1;
+        }
+
+        (self.accumulated_seconds / self.tick_duration) as f32
+    }
+}
+
+pub fn create_fixed_timestep(ticks_per_second: f64) -> FixedTimestep {
+    FixedTimestep::new(ticks_per_second, DEFAULT_MAX_TICKS_PER_STEP)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::panic;
+
     use crate::End;
     use mockall::{mock, Sequence};
 
@@ -193,4 +280,80 @@ mod tests {
 
         assert_eq!(name, result);
     }
+
+    #[test]
+    fn when_a_fixed_timestep_steps_less_than_a_tick_then_no_ticks_run_and_it_interpolates() {
+        let mut fixed_timestep = FixedTimestep::new(10.0, 5);
+        let mut ticks = Vec::new();
+
+        let interpolation = fixed_timestep
+            .step_fixed_timestep(0.05, |frame_time| ticks.push(frame_time.get_tick()));
+
+        assert!(ticks.is_empty());
+        assert!((interpolation - 0.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn when_a_fixed_timestep_steps_several_ticks_then_each_tick_gets_the_fixed_delta() {
+        let mut fixed_timestep = FixedTimestep::new(10.0, 5);
+        let mut frame_times = Vec::new();
+
+        let interpolation = fixed_timestep.step_fixed_timestep(0.35, |frame_time| {
+            frame_times.push((
+                frame_time.get_tick(),
+                frame_time.get_delta_seconds(),
+                frame_time.get_elapsed_seconds(),
+            ))
+        });
+
+        assert_eq!(3, frame_times.len());
+        for (index, (tick, delta_seconds, elapsed_seconds)) in frame_times.into_iter().enumerate() {
+            assert_eq!(index as u64 + 1, tick);
+            assert!((delta_seconds - 0.1).abs() < 0.0001);
+            assert!((elapsed_seconds - 0.1 * tick as f64).abs() < 0.0001);
+        }
+        assert!((interpolation - 0.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn when_a_fixed_timestep_steps_across_calls_then_the_remainder_carries_over() {
+        let mut fixed_timestep = FixedTimestep::new(10.0, 5);
+        let mut number_of_ticks = 0;
+
+        fixed_timestep.step_fixed_timestep(0.06, |_| number_of_ticks += 1);
+        fixed_timestep.step_fixed_timestep(0.06, |_| number_of_ticks += 1);
+
+        assert_eq!(1, number_of_ticks);
+    }
+
+    #[test]
+    fn when_a_fixed_timestep_falls_far_behind_then_it_caps_the_ticks_and_drops_the_backlog() {
+        let mut fixed_timestep = FixedTimestep::new(10.0, 5);
+        let mut number_of_ticks = 0;
+
+        let interpolation = fixed_timestep.step_fixed_timestep(10.05, |_| number_of_ticks += 1);
+
+        assert_eq!(5, number_of_ticks);
+        assert!(interpolation < 1.0);
+
+        fixed_timestep.step_fixed_timestep(0.1, |_| number_of_ticks += 1);
+
+        assert_eq!(6, number_of_ticks);
+    }
+
+    #[test]
+    fn when_a_fixed_timestep_is_created_then_it_uses_the_tick_rate() {
+        let fixed_timestep = create_fixed_timestep(DEFAULT_TICKS_PER_SECOND);
+
+        assert!((fixed_timestep.get_tick_duration() - 1.0 / 60.0).abs() < 0.000001);
+    }
+
+    #[test]
+    fn when_a_fixed_timestep_is_created_with_an_invalid_tick_rate_then_it_panics() {
+        for ticks_per_second in [0.0, -60.0, f64::NAN, f64::INFINITY] {
+            let result = panic::catch_unwind(|| FixedTimestep::new(ticks_per_second, 5));
+
+            assert!(result.is_err(), "{ticks_per_second} was accepted");
+        }
+    }
 }
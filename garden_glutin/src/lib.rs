@@ -1,14 +1,29 @@
 use std::{
+    cell::{Cell, RefCell},
     ffi::{c_void, CStr, CString},
+    io,
     num::NonZeroU32,
     ops::Deref,
+    rc::Rc,
+    time::Instant,
 };
 
-use garden_games::{EndEngine, StartEngine};
+use garden_games::{
+    assert_valid_tick_rate, create_fixed_timestep, EndEngine, FixedTimestep, StartEngine,
+    StepFixedTimestep, DEFAULT_TICKS_PER_SECOND,
+};
 
-use garden::{gl, Create, RunFullComponent};
+use garden::{
+    camera::{Camera, GetViewProjection},
+    gl,
+    input::{EndInputTick, HandleInputEvent, Input, RunInputEventStream},
+    window::{FullscreenMode, WindowSettings},
+    Create, RunFullComponent, RunLoopSystem, SetTickRate,
+};
+use garden_maths::matrices::Mat3;
 use garden_winit::{
-    create_game_instance_builder, CreateLoopSystem, GameInstanceBuilder, RunLoopSystem,
+    create_game_instance_builder, ConvertWindowEventToInputEvent, CreateLoopSystem,
+    GameInstanceBuilder, SetCamera, SetWindowSettings, WindowEventToInputEventConverter,
 };
 use glutin::{
     config::{Config, ConfigTemplateBuilder},
@@ -26,7 +41,8 @@ use winit::{
     dpi::PhysicalSize,
     event::{Event, WindowEvent},
     event_loop::{ControlFlow, EventLoop, EventLoopBuilder, EventLoopWindowTarget},
-    window::{Window, WindowBuilder},
+    platform::run_return::EventLoopExtRunReturn,
+    window::{Fullscreen, Window, WindowBuilder},
 };
 
 use glutin::surface::{Surface, SwapInterval};
@@ -51,14 +67,13 @@ pub fn generate_game_instance_builder_and_event_loop<'a>(
     EventLoop<()>,
 ) {
     let event_loop = EventLoopBuilder::new().build();
-    let game_instance_builder = generate_game_instance_builder(game_name, &event_loop);
+    let game_instance_builder = generate_game_instance_builder(game_name);
 
     (game_instance_builder, event_loop)
 }
 
 fn generate_game_instance_builder<'a>(
     game_name: &'a str,
-    event_loop: &EventLoop<()>,
 ) -> GameInstanceBuilder<
     'a,
     EngineStarterCreator,
@@ -102,6 +117,7 @@ fn generate_game_instance_builder<'a>(
         game_name,
         EngineStarterCreator::new(),
         LoopSystemCreator::new(
+            create_default_window_settings(game_name),
             DisplayCreator::new(),
             ContextAttributesCreator::new(),
             FallbackContextAttributesCreator::new(),
@@ -113,10 +129,63 @@ fn generate_game_instance_builder<'a>(
             RedrawEventsClearedEventCreator::new(),
         ),
         EngineEnderCreator::new(),
-        &event_loop,
     )
 }
 
+fn create_default_window_settings(game_name: &str) -> WindowSettings {
+    let mut window_settings = WindowSettings::new();
+    window_settings.set_title(game_name);
+
+    window_settings
+}
+
+fn create_window_builder(
+    window_settings: &WindowSettings,
+    window_target: &EventLoopWindowTarget<()>,
+) -> WindowBuilder {
+    let mut window_builder = WindowBuilder::new()
+        .with_title(window_settings.get_title().unwrap_or_default())
+        .with_resizable(window_settings.is_resizable())
+        .with_fullscreen(create_fullscreen(
+            window_settings.get_fullscreen_mode(),
+            window_target,
+        ));
+
+    if let Some((width, height)) = window_settings.get_size() {
+        window_builder = window_builder.with_inner_size(PhysicalSize::new(width, height));
+    }
+
+    if let Some((width, height)) = window_settings.get_min_size() {
+        window_builder = window_builder.with_min_inner_size(PhysicalSize::new(width, height));
+    }
+
+    window_builder
+}
+
+fn create_fullscreen(
+    fullscreen_mode: FullscreenMode,
+    window_target: &EventLoopWindowTarget<()>,
+) -> Option<Fullscreen> {
+    match fullscreen_mode {
+        FullscreenMode::Windowed => None,
+        FullscreenMode::Borderless => Some(Fullscreen::Borderless(None)),
+        FullscreenMode::Exclusive => {
+            let video_mode = window_target.primary_monitor().and_then(|monitor| {
+                monitor.video_modes().max_by_key(|video_mode| {
+                    let size = video_mode.size();
+
+                    size.width * size.height
+                })
+            });
+
+            match video_mode {
+                Some(video_mode) => Some(Fullscreen::Exclusive(video_mode)),
+                None => Some(Fullscreen::Borderless(None)),
+            }
+        }
+    }
+}
+
 pub struct EngineStarter {}
 
 impl EngineStarter {
@@ -194,7 +263,9 @@ pub trait RunResumedEvent<TRenderer> {
         gl_display: &Display,
         state: &mut Option<(PossiblyCurrentContext, GlWindow)>,
         renderer: &mut Option<TRenderer>,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        camera: &Rc<RefCell<Camera>>,
+        window_settings: &WindowSettings,
+        components: &mut [Box<dyn RunFullComponent>],
     );
 }
 
@@ -220,13 +291,15 @@ impl<TGlWindowCreator: CreateGlWindow> RunResumedEvent<Renderer>
         gl_display: &Display,
         state: &mut Option<(PossiblyCurrentContext, GlWindow)>,
         renderer: &mut Option<Renderer>,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        camera: &Rc<RefCell<Camera>>,
+        window_settings: &WindowSettings,
+        components: &mut [Box<dyn RunFullComponent>],
     ) {
         #[cfg(target_os = "android")]
         println!("Android window available");
 
         let window = window.take().unwrap_or_else(|| {
-            let window_builder = WindowBuilder::new().with_transparent(true);
+            let window_builder = create_window_builder(window_settings, window_target);
             glutin_winit::finalize_window(window_target, window_builder, gl_config).unwrap()
         });
 
@@ -236,11 +309,28 @@ impl<TGlWindowCreator: CreateGlWindow> RunResumedEvent<Renderer>
             .make_current(&gl_window.surface)
             .unwrap();
 
-        renderer.get_or_insert_with(|| generate_renderer(gl_display, components));
+        let (width, height): (u32, u32) = gl_window.window.inner_size().into();
+
+        renderer
+            .get_or_insert_with(|| {
+                generate_renderer(
+                    gl_display,
+                    components,
+                    Rc::clone(camera),
+                    window_settings.get_clear_colour(),
+                )
+            })
+            .resize(width as i32, height as i32);
+
+        let swap_interval = if window_settings.is_vsync_enabled() {
+            SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+        } else {
+            SwapInterval::DontWait
+        };
 
         if let Err(res) = gl_window
             .surface
-            .set_swap_interval(&gl_context, SwapInterval::Wait(NonZeroU32::new(1).unwrap()))
+            .set_swap_interval(&gl_context, swap_interval)
         {
             eprintln!("Error setting vsync: {:?}", res);
         }
@@ -322,7 +412,8 @@ pub trait RunRedrawEventsClearedEvent<TRenderer> {
         &mut self,
         state: &mut Option<(PossiblyCurrentContext, GlWindow)>,
         renderer: &mut Option<TRenderer>,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
+        interpolation: f32,
     );
 }
 
@@ -339,10 +430,11 @@ impl<TRenderer: Render> RunRedrawEventsClearedEvent<TRenderer> for RedrawEventsC
         &mut self,
         state: &mut Option<(PossiblyCurrentContext, GlWindow)>,
         renderer: &mut Option<TRenderer>,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
+        interpolation: f32,
     ) {
         if let Some((gl_context, gl_window)) = state {
-            renderer.as_ref().unwrap().draw(components);
+            renderer.as_ref().unwrap().draw(components, interpolation);
             gl_window.window.request_redraw();
 
             gl_window.surface.swap_buffers(gl_context).unwrap();
@@ -400,14 +492,20 @@ pub trait RunEvents {
     fn run_resumed_event(
         &mut self,
         window_target: &EventLoopWindowTarget<()>,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
     );
 
     fn run_window_resized_event(&mut self, size: PhysicalSize<u32>);
 
     fn run_window_close_requested_event(&mut self, control_flow: &mut ControlFlow);
 
-    fn run_redraw_events_cleared_event(&mut self, components: &Vec<Box<dyn RunFullComponent>>);
+    fn get_window_size(&self) -> Option<PhysicalSize<u32>>;
+
+    fn run_redraw_events_cleared_event(
+        &mut self,
+        components: &mut [Box<dyn RunFullComponent>],
+        interpolation: f32,
+    );
 }
 
 pub struct EventRunner<
@@ -421,6 +519,8 @@ pub struct EventRunner<
     gl_config: Config,
     not_current_gl_context: Option<NotCurrentContext>,
     renderer: Option<TRenderer>,
+    camera: Rc<RefCell<Camera>>,
+    window_settings: WindowSettings,
     state: Option<(PossiblyCurrentContext, GlWindow)>,
     gl_display: Display,
     resumed_event: TResumedEvent,
@@ -449,6 +549,8 @@ impl<
         gl_config: Config,
         not_current_gl_context: Option<NotCurrentContext>,
         renderer: Option<TRenderer>,
+        camera: Rc<RefCell<Camera>>,
+        window_settings: WindowSettings,
         state: Option<(PossiblyCurrentContext, GlWindow)>,
         gl_display: Display,
         resumed_event: TResumedEvent,
@@ -461,6 +563,8 @@ impl<
             gl_config,
             not_current_gl_context,
             renderer,
+            camera,
+            window_settings,
             state,
             gl_display,
             resumed_event,
@@ -489,7 +593,7 @@ impl<
     fn run_resumed_event(
         &mut self,
         window_target: &EventLoopWindowTarget<()>,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
     ) {
         self.resumed_event.run_resumed_event(
             &mut self.window,
@@ -499,6 +603,8 @@ impl<
             &self.gl_display,
             &mut self.state,
             &mut self.renderer,
+            &self.camera,
+            &self.window_settings,
             components,
         )
     }
@@ -516,54 +622,141 @@ impl<
             .run_window_close_requested_event(control_flow)
     }
 
-    fn run_redraw_events_cleared_event(&mut self, components: &Vec<Box<dyn RunFullComponent>>) {
+    fn get_window_size(&self) -> Option<PhysicalSize<u32>> {
+        match &self.state {
+            Some((_, gl_window)) => Some(gl_window.window.inner_size()),
+            None => self.window.as_ref().map(|window| window.inner_size()),
+        }
+    }
+
+    fn run_redraw_events_cleared_event(
+        &mut self,
+        components: &mut [Box<dyn RunFullComponent>],
+        interpolation: f32,
+    ) {
         self.redraw_events_cleared_event
-            .run_redraw_events_cleared_event(&mut self.state, &mut self.renderer, components)
+            .run_redraw_events_cleared_event(
+                &mut self.state,
+                &mut self.renderer,
+                components,
+                interpolation,
+            )
     }
 }
 
 pub struct LoopSystem<TEventRunner> {
+    event_loop: EventLoop<()>,
     event_runner: TEventRunner,
-    components: Vec<Box<dyn RunFullComponent>>,
+    fixed_timestep: FixedTimestep,
+    window_event_to_input_event_converter: WindowEventToInputEventConverter,
+    input: Input,
 }
 
-impl<TEventRunner> LoopSystem<TEventRunner> {
-    fn new(event_runner: TEventRunner) -> Self {
+impl<TEventRunner: RunEvents> LoopSystem<TEventRunner> {
+    fn new(
+        event_loop: EventLoop<()>,
+        event_runner: TEventRunner,
+        fixed_timestep: FixedTimestep,
+    ) -> Self {
+        let (width, height) = event_runner
+            .get_window_size()
+            .map_or((0.0, 0.0), |size| (size.width as f32, size.height as f32));
+
         Self {
+            event_loop,
             event_runner,
-            components: Vec::new(),
+            fixed_timestep,
+            window_event_to_input_event_converter: WindowEventToInputEventConverter::new(),
+            input: Input::new(width, height),
         }
     }
 }
 
-impl<TEventRunner: 'static + RunEvents> RunLoopSystem for LoopSystem<TEventRunner> {
+impl<TEventRunner: RunEvents> RunLoopSystem for LoopSystem<TEventRunner> {
     fn run_loop_system(
         mut self,
-        event_loop: EventLoop<()>,
-        components: Vec<Box<dyn RunFullComponent>>,
-    ) {
-        self.components = components;
-
-        event_loop.run(move |event, window_target, control_flow| {
-            control_flow.set_wait();
-            match event {
-                Event::Resumed => {
-                    self.event_runner
-                        .run_resumed_event(window_target, &self.components);
-                }
-                Event::WindowEvent { event, .. } => match event {
-                    WindowEvent::Resized(size) => self.event_runner.run_window_resized_event(size),
-                    WindowEvent::CloseRequested => self
-                        .event_runner
-                        .run_window_close_requested_event(control_flow),
+        components: &mut [Box<dyn RunFullComponent>],
+        input_event_stream: &mut dyn RunInputEventStream,
+    ) -> io::Result<u64> {
+        let mut last_frame_instant: Option<Instant> = None;
+        let mut number_of_ticks = 0;
+
+        self.event_loop
+            .run_return(|event, window_target, control_flow| {
+                control_flow.set_wait();
+                match event {
+                    Event::Resumed => {
+                        self.event_runner
+                            .run_resumed_event(window_target, components);
+
+                        if let Some(input_event) =
+                            self.event_runner.get_window_size().and_then(|size| {
+                                self.window_event_to_input_event_converter
+                                    .convert_window_event_to_input_event(&WindowEvent::Resized(
+                                        size,
+                                    ))
+                            })
+                        {
+                            input_event_stream.handle_input_event(&input_event);
+                        }
+                    }
+                    Event::WindowEvent { event, .. } => {
+                        if let Some(input_event) = self
+                            .window_event_to_input_event_converter
+                            .convert_window_event_to_input_event(&event)
+                        {
+                            input_event_stream.handle_input_event(&input_event);
+                        }
+
+                        match event {
+                            WindowEvent::Resized(size) => {
+                                self.event_runner.run_window_resized_event(size)
+                            }
+                            WindowEvent::CloseRequested => self
+                                .event_runner
+                                .run_window_close_requested_event(control_flow),
+                            _ => (),
+                        }
+                    }
+                    Event::RedrawEventsCleared => {
+                        let now = Instant::now();
+                        let elapsed_seconds =
+                            last_frame_instant
+                                .replace(now)
+                                .map_or(0.0, |last_frame_instant| {
+                                    now.duration_since(last_frame_instant).as_secs_f64()
+                                });
+
+                        let input = &mut self.input;
+                        let interpolation = self.fixed_timestep.step_fixed_timestep(
+                            elapsed_seconds,
+                            |frame_time| {
+                                for input_event in
+                                    input_event_stream.read_input_events(frame_time.get_tick())
+                                {
+                                    input.handle_input_event(&input_event);
+                                }
+
+                                for component in components.iter_mut() {
+                                    component.run_loop(frame_time, input);
+                                }
+
+                                input.end_input_tick();
+
+                                number_of_ticks = frame_time.get_tick();
+                            },
+                        );
+
+                        self.event_runner
+                            .run_redraw_events_cleared_event(components, interpolation)
+                    }
                     _ => (),
-                },
-                Event::RedrawEventsCleared => self
-                    .event_runner
-                    .run_redraw_events_cleared_event(&self.components),
-                _ => (),
-            }
-        })
+                }
+            });
+
+        input_event_stream.end_input_event_stream(number_of_ticks)?;
+
+        Ok(number_of_ticks)
     }
 }
 
@@ -594,7 +787,7 @@ impl GlWindow {
 }
 
 pub trait Render {
-    fn draw(&self, components: &Vec<Box<dyn RunFullComponent>>);
+    fn draw(&self, components: &mut [Box<dyn RunFullComponent>], interpolation: f32);
 }
 
 pub trait Resize {
@@ -605,6 +798,12 @@ pub struct Renderer {
     program: gl::types::GLuint,
     vao: gl::types::GLuint,
     vbo: gl::types::GLuint,
+    ebo: gl::types::GLuint,
+    view_projection_location: gl::types::GLint,
+    model_location: gl::types::GLint,
+    camera: Rc<RefCell<Camera>>,
+    clear_colour: (f32, f32, f32),
+    window_size: Cell<(i32, i32)>,
     gl: gl::Gl,
 }
 
@@ -613,30 +812,83 @@ impl Renderer {
         program: gl::types::GLuint,
         vao: gl::types::GLuint,
         vbo: gl::types::GLuint,
+        ebo: gl::types::GLuint,
+        view_projection_location: gl::types::GLint,
+        model_location: gl::types::GLint,
+        camera: Rc<RefCell<Camera>>,
+        clear_colour: (f32, f32, f32),
         gl: gl::Gl,
     ) -> Self {
         Self {
             program,
             vao,
             vbo,
+            ebo,
+            view_projection_location,
+            model_location,
+            camera,
+            clear_colour,
+            window_size: Cell::new((0, 0)),
             gl,
         }
     }
 }
 
 impl Render for Renderer {
-    fn draw(&self, components: &Vec<Box<dyn RunFullComponent>>) {
+    fn draw(&self, components: &mut [Box<dyn RunFullComponent>], interpolation: f32) {
         unsafe {
             self.gl.UseProgram(self.program);
 
+            let (window_width, window_height) = self.window_size.get();
+            let viewport = self
+                .camera
+                .borrow()
+                .get_viewport(window_width, window_height);
+
+            self.gl
+                .Viewport(viewport.x, viewport.y, viewport.width, viewport.height);
+
+            let view_projection = self
+                .camera
+                .borrow()
+                .get_view_projection(viewport.width as f32, viewport.height as f32)
+                .to_cols_array();
+
+            self.gl.UniformMatrix4fv(
+                self.view_projection_location,
+                1,
+                gl::FALSE,
+                view_projection.as_ptr(),
+            );
+
+            self.gl.UniformMatrix3fv(
+                self.model_location,
+                1,
+                gl::FALSE,
+                Mat3::IDENTITY.to_cols_array().as_ptr(),
+            );
+
             self.gl.BindVertexArray(self.vao);
             self.gl.BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            self.gl.BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.ebo);
+
+            self.gl.ClearColor(0.0, 0.0, 0.0, 1.0);
+            self.gl.Clear(gl::COLOR_BUFFER_BIT);
 
-            self.gl.ClearColor(0.1, 0.1, 0.1, 0.9);
+            self.gl.Enable(gl::SCISSOR_TEST);
+            self.gl
+                .Scissor(viewport.x, viewport.y, viewport.width, viewport.height);
+            let (red, green, blue) = self
+                .camera
+                .borrow()
+                .get_background_colour()
+                .unwrap_or(self.clear_colour);
+            self.gl.ClearColor(red, green, blue, 1.0);
             self.gl.Clear(gl::COLOR_BUFFER_BIT);
+            self.gl.Disable(gl::SCISSOR_TEST);
 
-            for component in components.iter() {
-                component.on_draw(&self.gl);
+            for component in components.iter_mut() {
+                component.on_draw(&self.gl, interpolation);
             }
         }
     }
@@ -644,9 +896,7 @@ impl Render for Renderer {
 
 impl Resize for Renderer {
     fn resize(&self, width: i32, height: i32) {
-        unsafe {
-            self.gl.Viewport(0, 0, width, height);
-        }
+        self.window_size.set((width, height));
     }
 }
 
@@ -663,6 +913,7 @@ impl Drop for Renderer {
         unsafe {
             self.gl.DeleteProgram(self.program);
             self.gl.DeleteBuffers(1, &self.vbo);
+            self.gl.DeleteBuffers(1, &self.ebo);
             self.gl.DeleteVertexArrays(1, &self.vao);
         }
     }
@@ -829,7 +1080,7 @@ pub trait CreateGLutin {
     unsafe fn create_glutin(
         self,
         gl: &gl::Gl,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
     ) -> gl::types::GLuint;
 }
 
@@ -845,7 +1096,7 @@ impl CreateGLutin for VaoCreator {
     unsafe fn create_glutin(
         self,
         gl: &gl::Gl,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
     ) -> gl::types::GLuint {
         let mut vao = std::mem::zeroed();
         gl.GenVertexArrays(1, &mut vao);
@@ -855,6 +1106,28 @@ impl CreateGLutin for VaoCreator {
     }
 }
 
+pub struct EboCreator {}
+
+impl EboCreator {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CreateGLutin for EboCreator {
+    unsafe fn create_glutin(
+        self,
+        gl: &gl::Gl,
+        _components: &mut [Box<dyn RunFullComponent>],
+    ) -> gl::types::GLuint {
+        let mut ebo = std::mem::zeroed();
+        gl.GenBuffers(1, &mut ebo);
+        gl.BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
+
+        ebo
+    }
+}
+
 pub struct VboCreator {}
 
 impl VboCreator {
@@ -867,13 +1140,13 @@ impl CreateGLutin for VboCreator {
     unsafe fn create_glutin(
         self,
         gl: &gl::Gl,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
     ) -> gl::types::GLuint {
         let mut vbo = std::mem::zeroed();
         gl.GenBuffers(1, &mut vbo);
         gl.BindBuffer(gl::ARRAY_BUFFER, vbo);
 
-        for component in components.iter() {
+        for component in components.iter_mut() {
             component.on_create_glutin_vbo(gl);
         }
 
@@ -885,7 +1158,9 @@ pub trait CreateRenderer<TRenderer> {
     fn create_renderer(
         self,
         display: &Display,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
+        camera: Rc<RefCell<Camera>>,
+        clear_colour: (f32, f32, f32),
     ) -> TRenderer;
 }
 
@@ -895,6 +1170,7 @@ pub struct RendererCreator<
     TProgramCreator,
     TVertexAttribSetup,
     TVaoCreator,
+    TEboCreator,
     TVboCreator,
 > {
     gl_creator: TGlCreator,
@@ -902,16 +1178,26 @@ pub struct RendererCreator<
     program_creator: TProgramCreator,
     vertex_attrib_setup: TVertexAttribSetup,
     vao_creator: TVaoCreator,
+    ebo_creator: TEboCreator,
     vbo_creator: TVboCreator,
 }
 
-impl<TGlCreator, TShaderCreator, TProgramCreator, TVertexAttribSetup, TVaoCreator, TVboCreator>
+impl<
+        TGlCreator,
+        TShaderCreator,
+        TProgramCreator,
+        TVertexAttribSetup,
+        TVaoCreator,
+        TEboCreator,
+        TVboCreator,
+    >
     RendererCreator<
         TGlCreator,
         TShaderCreator,
         TProgramCreator,
         TVertexAttribSetup,
         TVaoCreator,
+        TEboCreator,
         TVboCreator,
     >
 {
@@ -921,6 +1207,7 @@ impl<TGlCreator, TShaderCreator, TProgramCreator, TVertexAttribSetup, TVaoCreato
         program_creator: TProgramCreator,
         vertex_attrib_setup: TVertexAttribSetup,
         vao_creator: TVaoCreator,
+        ebo_creator: TEboCreator,
         vbo_creator: TVboCreator,
     ) -> Self {
         Self {
@@ -929,6 +1216,7 @@ impl<TGlCreator, TShaderCreator, TProgramCreator, TVertexAttribSetup, TVaoCreato
             program_creator,
             vertex_attrib_setup,
             vao_creator,
+            ebo_creator,
             vbo_creator,
         }
     }
@@ -940,6 +1228,7 @@ impl<
         TProgramCreator: CreateProgram,
         TVertexAttribSetup: SetupVertexAttrib,
         TVaoCreator: CreateGLutin,
+        TEboCreator: CreateGLutin,
         TVboCreator: CreateGLutin,
     > CreateRenderer<Renderer>
     for RendererCreator<
@@ -948,13 +1237,16 @@ impl<
         TProgramCreator,
         TVertexAttribSetup,
         TVaoCreator,
+        TEboCreator,
         TVboCreator,
     >
 {
     fn create_renderer(
         self,
         display: &Display,
-        components: &Vec<Box<dyn RunFullComponent>>,
+        components: &mut [Box<dyn RunFullComponent>],
+        camera: Rc<RefCell<Camera>>,
+        clear_colour: (f32, f32, f32),
     ) -> Renderer {
         unsafe {
             let gl = self.gl_creator.create_gl(display);
@@ -976,11 +1268,28 @@ impl<
 
             let vao = self.vao_creator.create_glutin(&gl, components);
 
+            let ebo = self.ebo_creator.create_glutin(&gl, components);
+
             let vbo = self.vbo_creator.create_glutin(&gl, components);
 
             self.vertex_attrib_setup.setup(&gl, program);
 
-            Renderer::new(program, vao, vbo, gl)
+            let view_projection_location =
+                gl.GetUniformLocation(program, b"u_view_projection\0".as_ptr() as *const _);
+
+            let model_location = gl.GetUniformLocation(program, b"u_model\0".as_ptr() as *const _);
+
+            Renderer::new(
+                program,
+                vao,
+                vbo,
+                ebo,
+                view_projection_location,
+                model_location,
+                camera,
+                clear_colour,
+                gl,
+            )
         }
     }
 }
@@ -991,6 +1300,7 @@ fn compose_renderer_creator() -> RendererCreator<
     ProgramCreator,
     VertexAttribSetup,
     VaoCreator,
+    EboCreator,
     VboCreator,
 > {
     RendererCreator::new(
@@ -999,12 +1309,18 @@ fn compose_renderer_creator() -> RendererCreator<
         ProgramCreator::new(),
         VertexAttribSetup::new(),
         VaoCreator::new(),
+        EboCreator::new(),
         VboCreator::new(),
     )
 }
 
-fn generate_renderer(display: &Display, components: &Vec<Box<dyn RunFullComponent>>) -> Renderer {
-    compose_renderer_creator().create_renderer(display, components)
+fn generate_renderer(
+    display: &Display,
+    components: &mut [Box<dyn RunFullComponent>],
+    camera: Rc<RefCell<Camera>>,
+    clear_colour: (f32, f32, f32),
+) -> Renderer {
+    compose_renderer_creator().create_renderer(display, components, camera, clear_colour)
 }
 
 fn get_gl_string(gl: &gl::Gl, variant: gl::types::GLenum) -> Option<&'static CStr> {
@@ -1015,7 +1331,11 @@ fn get_gl_string(gl: &gl::Gl, variant: gl::types::GLenum) -> Option<&'static CSt
 }
 
 pub trait CreateDisplay {
-    fn create_display(&self, event_loop: &EventLoop<()>) -> (Option<Window>, Config);
+    fn create_display(
+        &self,
+        event_loop: &EventLoop<()>,
+        window_settings: &WindowSettings,
+    ) -> (Option<Window>, Config);
 }
 
 pub struct DisplayCreator {}
@@ -1027,9 +1347,13 @@ impl<'a> DisplayCreator {
 }
 
 impl CreateDisplay for DisplayCreator {
-    fn create_display(&self, event_loop: &EventLoop<()>) -> (Option<Window>, Config) {
+    fn create_display(
+        &self,
+        event_loop: &EventLoop<()>,
+        window_settings: &WindowSettings,
+    ) -> (Option<Window>, Config) {
         let window_builder = if cfg!(wgl_backend) {
-            Some(WindowBuilder::new().with_transparent(true))
+            Some(create_window_builder(window_settings, event_loop))
         } else {
             None
         };
@@ -1131,6 +1455,7 @@ pub struct LoopSystemCreator<
     TWindowCloseRequestedEventCreator,
     TRedrawEventsClearedEventCreator,
 > {
+    window_settings: WindowSettings,
     display_creator: TDisplayCreator,
     context_attributes_creator: TContextAttributesCreator,
     fallback_context_attributes_creator: TFallbackContextAttributesCreator,
@@ -1140,6 +1465,8 @@ pub struct LoopSystemCreator<
     window_resized_event_creator: TWindowResizedEventCreator,
     window_close_requested_event_creator: TWindowCloseRequestedEventCreator,
     redraw_events_cleared_event_creator: TRedrawEventsClearedEventCreator,
+    ticks_per_second: f64,
+    camera: Rc<RefCell<Camera>>,
 }
 
 impl<
@@ -1164,6 +1491,7 @@ impl<
     >
 {
     fn new(
+        window_settings: WindowSettings,
         display_creator: TDisplayCreator,
         context_attributes_creator: TContextAttributesCreator,
         fallback_context_attributes_creator: TFallbackContextAttributesCreator,
@@ -1175,6 +1503,7 @@ impl<
         redraw_events_cleared_event_creator: TRedrawEventsClearedEventCreator,
     ) -> Self {
         Self {
+            window_settings,
             display_creator,
             context_attributes_creator,
             fallback_context_attributes_creator,
@@ -1184,7 +1513,96 @@ impl<
             window_resized_event_creator,
             window_close_requested_event_creator,
             redraw_events_cleared_event_creator,
+            ticks_per_second: DEFAULT_TICKS_PER_SECOND,
+            camera: Rc::new(RefCell::new(Camera::default())),
+        }
+    }
+}
+
+impl<
+        TDisplayCreator,
+        TContextAttributesCreator,
+        TFallbackContextAttributesCreator,
+        TNotCurrentGlContextCreator,
+        TResumedEventCreator,
+        TWindowResizedEventCreator,
+        TWindowCloseRequestedEventCreator,
+        TRedrawEventsClearedEventCreator,
+    > SetTickRate
+    for LoopSystemCreator<
+        TDisplayCreator,
+        TContextAttributesCreator,
+        TFallbackContextAttributesCreator,
+        TNotCurrentGlContextCreator,
+        TResumedEventCreator,
+        TWindowResizedEventCreator,
+        TWindowCloseRequestedEventCreator,
+        TRedrawEventsClearedEventCreator,
+    >
+{
+    fn set_tick_rate(&mut self, ticks_per_second: f64) {
+        assert_valid_tick_rate(ticks_per_second);
+
+        self.ticks_per_second = ticks_per_second;
+    }
+}
+
+impl<
+        TDisplayCreator,
+        TContextAttributesCreator,
+        TFallbackContextAttributesCreator,
+        TNotCurrentGlContextCreator,
+        TResumedEventCreator,
+        TWindowResizedEventCreator,
+        TWindowCloseRequestedEventCreator,
+        TRedrawEventsClearedEventCreator,
+    > SetCamera
+    for LoopSystemCreator<
+        TDisplayCreator,
+        TContextAttributesCreator,
+        TFallbackContextAttributesCreator,
+        TNotCurrentGlContextCreator,
+        TResumedEventCreator,
+        TWindowResizedEventCreator,
+        TWindowCloseRequestedEventCreator,
+        TRedrawEventsClearedEventCreator,
+    >
+{
+    fn set_camera(&mut self, camera: Rc<RefCell<Camera>>) {
+        self.camera = camera;
+    }
+}
+
+impl<
+        TDisplayCreator,
+        TContextAttributesCreator,
+        TFallbackContextAttributesCreator,
+        TNotCurrentGlContextCreator,
+        TResumedEventCreator,
+        TWindowResizedEventCreator,
+        TWindowCloseRequestedEventCreator,
+        TRedrawEventsClearedEventCreator,
+    > SetWindowSettings
+    for LoopSystemCreator<
+        TDisplayCreator,
+        TContextAttributesCreator,
+        TFallbackContextAttributesCreator,
+        TNotCurrentGlContextCreator,
+        TResumedEventCreator,
+        TWindowResizedEventCreator,
+        TWindowCloseRequestedEventCreator,
+        TRedrawEventsClearedEventCreator,
+    >
+{
+    fn set_window_settings(&mut self, mut window_settings: WindowSettings) {
+        if let (None, Some(title)) = (
+            window_settings.get_title(),
+            self.window_settings.get_title(),
+        ) {
+            window_settings.set_title(title);
         }
+
+        self.window_settings = window_settings;
     }
 }
 
@@ -1222,7 +1640,7 @@ impl<
 {
     fn create_loop_system(
         &self,
-        event_loop: &EventLoop<()>,
+        event_loop: EventLoop<()>,
     ) -> LoopSystem<
         EventRunner<
             ResumedEvent<GlWindowCreator>,
@@ -1232,7 +1650,9 @@ impl<
             Renderer,
         >,
     > {
-        let display = self.display_creator.create_display(event_loop);
+        let display = self
+            .display_creator
+            .create_display(&event_loop, &self.window_settings);
         let window = display.0;
         let gl_config = display.1;
 
@@ -1272,6 +1692,8 @@ impl<
             gl_config,
             not_current_gl_context,
             None,
+            Rc::clone(&self.camera),
+            self.window_settings.clone(),
             state,
             gl_display,
             resumed_event,
@@ -1280,7 +1702,11 @@ impl<
             redraw_events_cleared_event,
         );
 
-        LoopSystem::new(event_runner)
+        LoopSystem::new(
+            event_loop,
+            event_runner,
+            create_fixed_timestep(self.ticks_per_second),
+        )
     }
 }
 
@@ -1373,13 +1799,16 @@ const VERTEX_SHADER_SOURCE: &[u8] = b"
 #version 100
 precision mediump float;
 
+uniform mat4 u_view_projection;
+uniform mat3 u_model;
+
 attribute vec2 position;
 attribute vec3 color;
 
 varying vec3 v_color;
 
 void main() {
-    gl_Position = vec4(position, 0.0, 1.0);
+    gl_Position = u_view_projection * vec4((u_model * vec3(position, 1.0)).xy, 0.0, 1.0);
     v_color = color;
 }
 \0";
@@ -1,7 +1,13 @@
-use garden_content_component::add_content;
+use std::{cell::RefCell, env, process, rc::Rc};
+
+use garden::{camera::Camera, AddComponent};
+use garden_content_component::add_watched_content;
 use garden_glutin::generate_game_instance_builder_and_event_loop;
+use garden_json::{JsonFileReader, JsonToBoolConverter, JsonToU64Converter};
+use garden_loading::Load;
 use garden_scenes_component::GetScene;
-use garden_winit::{AddComponent, BuildGameInstance, RunGameInstance};
+use garden_window_loading::compose_window_settings_loader;
+use garden_winit::{BuildGameInstance, RunGameInstance, SetCamera, SetWindowSettings};
 
 fn main() {
     let game_instance_builder_and_event_loop =
@@ -9,23 +15,64 @@ fn main() {
 
     let mut game_instance_builder = game_instance_builder_and_event_loop.0;
 
+    let content_file_path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "content.json".to_string());
+
     let json_component = garden_json_component::compose_component();
 
-    let scene_component = garden_scenes_component::compose_component(&json_component);
+    let scene_component =
+        match garden_scenes_component::compose_component(&json_component, &content_file_path) {
+            Ok(scene_component) => scene_component,
+            Err(error) => {
+                eprintln!("Failed to load scene: {error}");
+                process::exit(1);
+            }
+        };
+
+    let scene = scene_component.get_scene();
+
+    game_instance_builder.set_camera(Rc::new(RefCell::new(Camera::from_scene(scene))));
+
+    let window_settings_loader = compose_window_settings_loader(
+        json_component.get_json_to_f32_converter(),
+        Rc::new(JsonToU64Converter::new()),
+        json_component.get_json_to_string_converter(),
+        Rc::new(JsonToBoolConverter::new()),
+        JsonFileReader::new(&content_file_path),
+    );
+
+    let mut window_settings = match window_settings_loader.load() {
+        Ok(window_settings) => window_settings,
+        Err(error) => {
+            eprintln!("Failed to load window settings: {error}");
+            process::exit(1);
+        }
+    };
+
+    window_settings.set_default_size_from_scene(scene);
+
+    game_instance_builder.set_window_settings(window_settings);
 
     let maths_component = garden_maths_component::compose_component();
 
-    add_content(
+    if let Err(error) = add_watched_content(
         &mut game_instance_builder,
-        scene_component.get_scene(),
         &json_component,
         &maths_component,
-    );
+        &content_file_path,
+    ) {
+        eprintln!("Failed to load content: {error}");
+        process::exit(1);
+    }
 
     game_instance_builder.add(scene_component);
 
     let game_instance =
-        game_instance_builder.build_game_instance(&game_instance_builder_and_event_loop.1);
+        game_instance_builder.build_game_instance(game_instance_builder_and_event_loop.1);
 
-    game_instance.run_game_instance(game_instance_builder_and_event_loop.1)
+    if let Err(error) = game_instance.run_game_instance() {
+        eprintln!("Failed to end the game: {error}");
+        process::exit(1);
+    }
 }
@@ -0,0 +1,572 @@
+use std::io;
+
+use garden::{
+    input::{EndInputTick, HandleInputEvent, Input, LiveInputEventStream, RunInputEventStream},
+    AddComponent, Create, RunFullComponent, RunLoopSystem, SetInputEventStream, SetTickRate,
+};
+use garden_games::{
+    assert_valid_tick_rate, create_end_system, create_fixed_timestep, create_start_system, End,
+    EndEngine, EndSystem, FixedTimestep, GetTickDuration, Start, StartEngine, StartSystem,
+    StepFixedTimestep, DEFAULT_TICKS_PER_SECOND,
+};
+
+pub trait CheckStopCondition {
+    fn check_stop_condition(&self, number_of_ticks: u64) -> bool;
+}
+
+impl<TFunction: Fn(u64) -> bool> CheckStopCondition for TFunction {
+    fn check_stop_condition(&self, number_of_ticks: u64) -> bool {
+        self(number_of_ticks)
+    }
+}
+
+pub struct TickCountStopCondition {
+    number_of_ticks: u64,
+}
+
+impl TickCountStopCondition {
+    pub fn new(number_of_ticks: u64) -> Self {
+        Self { number_of_ticks }
+    }
+}
+
+impl CheckStopCondition for TickCountStopCondition {
+    fn check_stop_condition(&self, number_of_ticks: u64) -> bool {
+        number_of_ticks >= self.number_of_ticks
+    }
+}
+
+pub struct HeadlessLoopSystem<TStopCondition, TFixedTimestep> {
+    stop_condition: TStopCondition,
+    fixed_timestep: TFixedTimestep,
+    input: Input,
+}
+
+impl<TStopCondition: CheckStopCondition, TFixedTimestep: StepFixedTimestep + GetTickDuration>
+    HeadlessLoopSystem<TStopCondition, TFixedTimestep>
+{
+    pub fn new(stop_condition: TStopCondition, fixed_timestep: TFixedTimestep) -> Self {
+        Self {
+            stop_condition,
+            fixed_timestep,
+            input: Input::new(0.0, 0.0),
+        }
+    }
+}
+
+impl<TStopCondition: CheckStopCondition, TFixedTimestep: StepFixedTimestep + GetTickDuration>
+    RunLoopSystem for HeadlessLoopSystem<TStopCondition, TFixedTimestep>
+{
+    fn run_loop_system(
+        mut self,
+        components: &mut [Box<dyn RunFullComponent>],
+        input_event_stream: &mut dyn RunInputEventStream,
+    ) -> io::Result<u64> {
+        let mut number_of_ticks = 0;
+
+        while !self.stop_condition.check_stop_condition(number_of_ticks) {
+            let tick_duration = self.fixed_timestep.get_tick_duration();
+
+            self.fixed_timestep
+                .step_fixed_timestep(tick_duration, |frame_time| {
+                    for input_event in input_event_stream.read_input_events(frame_time.get_tick()) {
+                        self.input.handle_input_event(&input_event);
+                    }
+
+                    for component in components.iter_mut() {
+                        component.run_loop(frame_time, &self.input);
+                    }
+
+                    self.input.end_input_tick();
+
+                    number_of_ticks += 1;
+                });
+        }
+
+        input_event_stream.end_input_event_stream(number_of_ticks)?;
+
+        Ok(number_of_ticks)
+    }
+}
+
+pub trait RunHeadlessEngine {
+    fn run_headless_engine(self) -> io::Result<u64>;
+}
+
+pub struct HeadlessEngine<TStartSystem, TLoopSystem, TEndSystem> {
+    start_system: TStartSystem,
+    loop_system: TLoopSystem,
+    end_system: TEndSystem,
+    components: Vec<Box<dyn RunFullComponent>>,
+    input_event_stream: Box<dyn RunInputEventStream>,
+}
+
+impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End>
+    HeadlessEngine<TStartSystem, TLoopSystem, TEndSystem>
+{
+    pub fn new(
+        start_system: TStartSystem,
+        loop_system: TLoopSystem,
+        end_system: TEndSystem,
+        components: Vec<Box<dyn RunFullComponent>>,
+        input_event_stream: Box<dyn RunInputEventStream>,
+    ) -> Self {
+        Self {
+            start_system,
+            loop_system,
+            end_system,
+            components,
+            input_event_stream,
+        }
+    }
+}
+
+impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End> RunHeadlessEngine
+    for HeadlessEngine<TStartSystem, TLoopSystem, TEndSystem>
+{
+    fn run_headless_engine(mut self) -> io::Result<u64> {
+        self.start_system.start();
+
+        for component in self.components.iter_mut() {
+            component.initialise();
+        }
+
+        let result = self
+            .loop_system
+            .run_loop_system(&mut self.components, self.input_event_stream.as_mut());
+
+        for component in self.components.into_iter() {
+            component.run_end_component();
+        }
+
+        self.end_system.end();
+
+        result
+    }
+}
+
+pub struct HeadlessEngineStarter {}
+
+impl HeadlessEngineStarter {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl StartEngine for HeadlessEngineStarter {
+    fn start_engine(self) {}
+}
+
+pub struct HeadlessEngineStarterCreator {}
+
+impl HeadlessEngineStarterCreator {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Create<HeadlessEngineStarter> for HeadlessEngineStarterCreator {
+    fn create(&self) -> HeadlessEngineStarter {
+        HeadlessEngineStarter::new()
+    }
+}
+
+pub struct HeadlessEngineEnder {}
+
+impl HeadlessEngineEnder {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl EndEngine for HeadlessEngineEnder {
+    fn end_engine(self) {}
+}
+
+pub struct HeadlessEngineEnderCreator {}
+
+impl HeadlessEngineEnderCreator {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Create<HeadlessEngineEnder> for HeadlessEngineEnderCreator {
+    fn create(&self) -> HeadlessEngineEnder {
+        HeadlessEngineEnder::new()
+    }
+}
+
+pub trait BuildHeadlessEngine<THeadlessEngine> {
+    fn build_headless_engine(self) -> THeadlessEngine;
+}
+
+pub struct HeadlessGameInstanceBuilder<TStopCondition> {
+    full_components: Vec<Box<dyn RunFullComponent>>,
+    input_event_stream: Box<dyn RunInputEventStream>,
+    stop_condition: TStopCondition,
+    ticks_per_second: f64,
+}
+
+impl<TStopCondition: CheckStopCondition> HeadlessGameInstanceBuilder<TStopCondition> {
+    fn new(
+        full_components: Vec<Box<dyn RunFullComponent>>,
+        stop_condition: TStopCondition,
+    ) -> Self {
+        Self {
+            full_components,
+            input_event_stream: Box::new(LiveInputEventStream::new()),
+            stop_condition,
+            ticks_per_second: DEFAULT_TICKS_PER_SECOND,
+        }
+    }
+}
+
+impl<TStopCondition> AddComponent for HeadlessGameInstanceBuilder<TStopCondition> {
+    fn add<TComponent: RunFullComponent + 'static>(&mut self, component: TComponent) {
+        let boxed_component = Box::new(component);
+
+        self.full_components.push(boxed_component);
+    }
+}
+
+impl<TStopCondition> SetInputEventStream for HeadlessGameInstanceBuilder<TStopCondition> {
+    fn set_input_event_stream<TInputEventStream: RunInputEventStream + 'static>(
+        &mut self,
+        input_event_stream: TInputEventStream,
+    ) {
+        self.input_event_stream = Box::new(input_event_stream);
+    }
+}
+
+impl<TStopCondition> SetTickRate for HeadlessGameInstanceBuilder<TStopCondition> {
+    fn set_tick_rate(&mut self, ticks_per_second: f64) {
+        assert_valid_tick_rate(ticks_per_second);
+
+        self.ticks_per_second = ticks_per_second;
+    }
+}
+
+impl<TStopCondition: CheckStopCondition>
+    BuildHeadlessEngine<
+        HeadlessEngine<
+            StartSystem<HeadlessEngineStarter>,
+            HeadlessLoopSystem<TStopCondition, FixedTimestep>,
+            EndSystem<HeadlessEngineEnder>,
+        >,
+    > for HeadlessGameInstanceBuilder<TStopCondition>
+{
+    fn build_headless_engine(
+        self,
+    ) -> HeadlessEngine<
+        StartSystem<HeadlessEngineStarter>,
+        HeadlessLoopSystem<TStopCondition, FixedTimestep>,
+        EndSystem<HeadlessEngineEnder>,
+    > {
+        HeadlessEngine::new(
+            create_start_system::<HeadlessEngineStarter, HeadlessEngineStarterCreator>(
+                HeadlessEngineStarterCreator::new(),
+            ),
+            HeadlessLoopSystem::new(
+                self.stop_condition,
+                create_fixed_timestep(self.ticks_per_second),
+            ),
+            create_end_system::<HeadlessEngineEnder, HeadlessEngineEnderCreator>(
+                HeadlessEngineEnderCreator::new(),
+            ),
+            self.full_components,
+            self.input_event_stream,
+        )
+    }
+}
+
+pub fn create_headless_game_instance_builder<TStopCondition: CheckStopCondition>(
+    stop_condition: TStopCondition,
+) -> HeadlessGameInstanceBuilder<TStopCondition> {
+    HeadlessGameInstanceBuilder::new(Vec::new(), stop_condition)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use garden::{
+        gl::Gl,
+        input::{
+            InputEvent, InputRecording, Key, QueryInput, RecordedInputEvent,
+            RecordingInputEventStream, ReplayInputEventStream,
+        },
+        DrawComponent, FrameTime, RunComponentLifecycle, UpdateComponent,
+    };
+
+    use super::*;
+
+    struct RecordingComponent {
+        calls: Rc<RefCell<Vec<&'static str>>>,
+        frame_times: Rc<RefCell<Vec<(u64, f32)>>>,
+    }
+
+    impl RecordingComponent {
+        fn new(calls: Rc<RefCell<Vec<&'static str>>>) -> Self {
+            Self {
+                calls,
+                frame_times: Rc::new(RefCell::new(Vec::new())),
+            }
+        }
+    }
+
+    impl RunComponentLifecycle for RecordingComponent {
+        fn initialise(&mut self) {
+            self.calls.borrow_mut().push("initialise");
+        }
+
+        fn run_end_component(self: Box<Self>) {
+            self.calls.borrow_mut().push("run_end_component");
+        }
+    }
+
+    impl UpdateComponent for RecordingComponent {
+        fn run_loop(&mut self, frame_time: &FrameTime, _input: &Input) {
+            self.calls.borrow_mut().push("run_loop");
+            self.frame_times
+                .borrow_mut()
+                .push((frame_time.get_tick(), frame_time.get_delta_seconds()));
+        }
+    }
+
+    impl DrawComponent for RecordingComponent {
+        unsafe fn on_create_glutin_vbo(&mut self, _gl: &Gl) {
+            self.calls.borrow_mut().push("on_create_glutin_vbo");
+        }
+
+        unsafe fn on_draw(&mut self, _gl: &Gl, _interpolation: f32) {
+            self.calls.borrow_mut().push("on_draw");
+        }
+    }
+
+    struct CountingComponent {
+        number_of_loops: u64,
+        number_of_loops_when_ended: Rc<RefCell<Option<u64>>>,
+    }
+
+    impl RunComponentLifecycle for CountingComponent {
+        fn run_end_component(self: Box<Self>) {
+            self.number_of_loops_when_ended
+                .replace(Some(self.number_of_loops));
+        }
+    }
+
+    impl UpdateComponent for CountingComponent {
+        fn run_loop(&mut self, _frame_time: &FrameTime, _input: &Input) {
+            self.number_of_loops += 1;
+        }
+    }
+
+    impl DrawComponent for CountingComponent {}
+
+    struct MovingComponent {
+        x: f32,
+        positions: Rc<RefCell<Vec<f32>>>,
+    }
+
+    impl RunComponentLifecycle for MovingComponent {}
+
+    impl UpdateComponent for MovingComponent {
+        fn run_loop(&mut self, frame_time: &FrameTime, input: &Input) {
+            if input.is_key_down(Key::Right) {
+                self.x += 60.0 * frame_time.get_delta_seconds();
+            }
+
+            if input.was_key_pressed(Key::Space) {
+                self.x = -self.x;
+            }
+
+            self.positions.borrow_mut().push(self.x);
+        }
+    }
+
+    impl DrawComponent for MovingComponent {}
+
+    #[test]
+    fn when_a_tick_count_stop_condition_is_checked_then_it_stops_once_the_count_is_reached() {
+        let stop_condition = TickCountStopCondition::new(3);
+
+        assert!(!stop_condition.check_stop_condition(2));
+        assert!(stop_condition.check_stop_condition(3));
+        assert!(stop_condition.check_stop_condition(4));
+    }
+
+    #[test]
+    fn when_a_headless_loop_system_runs_then_each_component_is_looped_once_per_tick() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut components: Vec<Box<dyn RunFullComponent>> = vec![
+            Box::new(RecordingComponent::new(calls.clone())),
+            Box::new(RecordingComponent::new(calls.clone())),
+        ];
+
+        let number_of_ticks = HeadlessLoopSystem::new(
+            TickCountStopCondition::new(3),
+            create_fixed_timestep(DEFAULT_TICKS_PER_SECOND),
+        )
+        .run_loop_system(&mut components, &mut LiveInputEventStream::new())
+        .unwrap();
+
+        assert_eq!(3, number_of_ticks);
+        assert_eq!(vec!["run_loop"; 6], *calls.borrow());
+    }
+
+    #[test]
+    fn when_a_headless_loop_system_is_already_stopped_then_no_components_are_looped() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut components: Vec<Box<dyn RunFullComponent>> =
+            vec![Box::new(RecordingComponent::new(calls.clone()))];
+
+        let number_of_ticks = HeadlessLoopSystem::new(
+            |_: u64| true,
+            create_fixed_timestep(DEFAULT_TICKS_PER_SECOND),
+        )
+        .run_loop_system(&mut components, &mut LiveInputEventStream::new())
+        .unwrap();
+
+        assert_eq!(0, number_of_ticks);
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn when_a_headless_engine_runs_then_components_are_initialised_looped_and_ended_without_drawing(
+    ) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut builder = create_headless_game_instance_builder(TickCountStopCondition::new(2));
+        builder.add(RecordingComponent::new(calls.clone()));
+
+        let number_of_ticks = builder
+            .build_headless_engine()
+            .run_headless_engine()
+            .unwrap();
+
+        assert_eq!(2, number_of_ticks);
+        assert_eq!(
+            vec!["initialise", "run_loop", "run_loop", "run_end_component"],
+            *calls.borrow()
+        );
+    }
+
+    #[test]
+    fn when_a_headless_engine_has_a_closure_stop_condition_then_it_stops_when_the_closure_says_so()
+    {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let observed_calls = calls.clone();
+        let mut builder = create_headless_game_instance_builder(move |_: u64| {
+            observed_calls
+                .borrow()
+                .iter()
+                .filter(|call| **call == "run_loop")
+                .count()
+                >= 5
+        });
+        builder.add(RecordingComponent::new(calls.clone()));
+
+        let number_of_ticks = builder
+            .build_headless_engine()
+            .run_headless_engine()
+            .unwrap();
+
+        assert_eq!(5, number_of_ticks);
+    }
+
+    #[test]
+    fn when_a_headless_engine_has_a_tick_rate_then_each_tick_gets_the_fixed_delta() {
+        let component = RecordingComponent::new(Rc::new(RefCell::new(Vec::new())));
+        let frame_times = component.frame_times.clone();
+        let mut builder = create_headless_game_instance_builder(TickCountStopCondition::new(3));
+        builder.set_tick_rate(30.0);
+        builder.add(component);
+
+        builder
+            .build_headless_engine()
+            .run_headless_engine()
+            .unwrap();
+
+        let frame_times = frame_times.borrow();
+        assert_eq!(
+            vec![1, 2, 3],
+            frame_times
+                .iter()
+                .map(|(tick, _)| *tick)
+                .collect::<Vec<u64>>()
+        );
+        for (_, delta_seconds) in frame_times.iter() {
+            assert!((delta_seconds - 1.0 / 30.0).abs() < 0.000001);
+        }
+    }
+
+    #[test]
+    fn when_a_headless_engine_runs_then_components_can_mutate_their_own_state_each_tick() {
+        let number_of_loops_when_ended = Rc::new(RefCell::new(None));
+        let mut builder = create_headless_game_instance_builder(TickCountStopCondition::new(4));
+        builder.add(CountingComponent {
+            number_of_loops: 0,
+            number_of_loops_when_ended: number_of_loops_when_ended.clone(),
+        });
+
+        builder
+            .build_headless_engine()
+            .run_headless_engine()
+            .unwrap();
+
+        assert_eq!(Some(4), *number_of_loops_when_ended.borrow());
+    }
+
+    #[test]
+    fn when_a_recorded_headless_session_is_replayed_then_the_components_end_up_identical() {
+        let input_recording = Rc::new(RefCell::new(None));
+        let saved_input_recording = input_recording.clone();
+        let recorded_positions = Rc::new(RefCell::new(Vec::new()));
+        let mut builder = create_headless_game_instance_builder(TickCountStopCondition::new(8));
+        builder.set_input_event_stream(RecordingInputEventStream::new(
+            ReplayInputEventStream::new(InputRecording::new(
+                vec![
+                    RecordedInputEvent::new(2, InputEvent::KeyPressed(Key::Right)),
+                    RecordedInputEvent::new(5, InputEvent::KeyPressed(Key::Space)),
+                    RecordedInputEvent::new(6, InputEvent::KeyReleased(Key::Right)),
+                ],
+                8,
+            )),
+            move |input_recording: &InputRecording| {
+                saved_input_recording.replace(Some(InputRecording::new(
+                    input_recording.get_recorded_input_events().to_vec(),
+                    input_recording.get_number_of_ticks(),
+                )));
+
+                Ok(())
+            },
+        ));
+        builder.add(MovingComponent {
+            x: 0.0,
+            positions: recorded_positions.clone(),
+        });
+        builder
+            .build_headless_engine()
+            .run_headless_engine()
+            .unwrap();
+
+        let input_recording = input_recording.take().unwrap();
+        let replayed_positions = Rc::new(RefCell::new(Vec::new()));
+        let mut builder = create_headless_game_instance_builder(TickCountStopCondition::new(
+            input_recording.get_number_of_ticks(),
+        ));
+        builder.set_input_event_stream(ReplayInputEventStream::new(input_recording));
+        builder.add(MovingComponent {
+            x: 0.0,
+            positions: replayed_positions.clone(),
+        });
+        builder
+            .build_headless_engine()
+            .run_headless_engine()
+            .unwrap();
+
+        assert_eq!(8, recorded_positions.borrow().len());
+        assert_eq!(-4.0, *recorded_positions.borrow().last().unwrap());
+        assert_eq!(*recorded_positions.borrow(), *replayed_positions.borrow());
+    }
+}
@@ -1,7 +1,475 @@
-use serde_json::Value;
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use garden_loading::LoadError;
+use serde_json::{json, Map, Number, Value};
 
 pub trait ConvertJsonToValue<TValue> {
-    fn convert_json_to_value(&self, json: &Value) -> TValue;
+    fn convert_json_to_value(&self, json: &Value) -> Result<TValue, LoadError>;
+}
+
+pub trait ConvertToJson {
+    fn convert_to_json(&self) -> Value;
+}
+
+pub trait ConvertJsonDocumentsToValue<TValue> {
+    fn convert_json_documents_to_value(
+        &self,
+        json_documents: &[JsonDocument],
+    ) -> Result<TValue, LoadError>;
+}
+
+pub trait ReadJsonDocuments {
+    fn read_json_documents(self) -> Result<Vec<JsonDocument>, LoadError>;
+}
+
+pub trait GetJsonSchema {
+    fn get_json_schema(&self) -> Value;
+}
+
+pub trait ValidateJson {
+    fn validate_json(&self, json: &Value) -> Result<(), LoadError>;
+}
+
+pub trait ValidateJsonDocuments {
+    fn validate_json_documents(&self, json_documents: &[JsonDocument]) -> Result<(), LoadError>;
+}
+
+pub struct JsonDocument {
+    file_path: String,
+    json: Value,
+}
+
+impl JsonDocument {
+    pub fn new(file_path: String, json: Value) -> Self {
+        Self { file_path, json }
+    }
+
+    pub fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    pub fn get_json(&self) -> &Value {
+        &self.json
+    }
+}
+
+pub struct JsonFileReader {
+    file_path: String,
+}
+
+impl JsonFileReader {
+    pub fn new(file_path: &str) -> Self {
+        Self {
+            file_path: file_path.to_string(),
+        }
+    }
+}
+
+impl ReadJsonDocuments for JsonFileReader {
+    fn read_json_documents(self) -> Result<Vec<JsonDocument>, LoadError> {
+        let json = read_json_file(&self.file_path)?;
+
+        let file_paths = match json.get("files") {
+            Some(Value::Array(file_paths)) => file_paths,
+            Some(_) => {
+                return Err(LoadError::wrong_field_type("array")
+                    .within("files")
+                    .in_file(&self.file_path))
+            }
+            None => return Ok(vec![JsonDocument::new(self.file_path, json)]),
+        };
+
+        let manifest_directory = Path::new(&self.file_path)
+            .parent()
+            .unwrap_or_else(|| Path::new(""));
+
+        let mut json_documents = vec![];
+
+        for (index, file_path) in file_paths.iter().enumerate() {
+            let file_path = file_path.as_str().ok_or_else(|| {
+                LoadError::wrong_field_type("string")
+                    .within(&index.to_string())
+                    .within("files")
+                    .in_file(&self.file_path)
+            })?;
+
+            let file_path = manifest_directory
+                .join(file_path)
+                .to_string_lossy()
+                .to_string();
+
+            let json = read_json_file(&file_path)?;
+
+            json_documents.push(JsonDocument::new(file_path, json));
+        }
+
+        Ok(json_documents)
+    }
+}
+
+pub struct JsonReader<TRead> {
+    source_name: String,
+    reader: TRead,
+}
+
+impl<TRead> JsonReader<TRead> {
+    pub fn new(source_name: &str, reader: TRead) -> Self {
+        Self {
+            source_name: source_name.to_string(),
+            reader,
+        }
+    }
+}
+
+impl<TRead: Read> ReadJsonDocuments for JsonReader<TRead> {
+    fn read_json_documents(self) -> Result<Vec<JsonDocument>, LoadError> {
+        let json = serde_json::from_reader(self.reader).map_err(|error| {
+            if error.is_io() {
+                LoadError::MissingFile {
+                    file_path: self.source_name.clone(),
+                    message: error.to_string(),
+                }
+            } else {
+                LoadError::BadJson {
+                    file_path: self.source_name.clone(),
+                    line: error.line(),
+                    column: error.column(),
+                    message: error.to_string(),
+                }
+            }
+        })?;
+
+        Ok(vec![JsonDocument::new(self.source_name, json)])
+    }
+}
+
+pub fn read_json_file(file_path: &str) -> Result<Value, LoadError> {
+    let file_contents = fs::read_to_string(file_path).map_err(|error| LoadError::MissingFile {
+        file_path: file_path.to_string(),
+        message: error.to_string(),
+    })?;
+
+    let extension = Path::new(file_path)
+        .extension()
+        .and_then(|extension| extension.to_str());
+
+    match extension {
+        Some("toml") => parse_toml(file_path, &file_contents),
+        Some("ron") => parse_ron(file_path, &file_contents),
+        Some("yaml") | Some("yml") => parse_yaml(file_path, &file_contents),
+        _ => parse_json(file_path, &file_contents),
+    }
+}
+
+pub fn write_json_file(file_path: &str, json: &Value) -> io::Result<()> {
+    let file_contents = serde_json::to_string_pretty(json)?;
+
+    fs::write(file_path, file_contents)
+}
+
+pub fn convert_f32_to_json(value: f32) -> Value {
+    value
+        .to_string()
+        .parse::<f64>()
+        .ok()
+        .and_then(Number::from_f64)
+        .map_or(Value::Null, Value::Number)
+}
+
+fn parse_json(file_path: &str, file_contents: &str) -> Result<Value, LoadError> {
+    serde_json::from_str(file_contents).map_err(|error| LoadError::BadJson {
+        file_path: file_path.to_string(),
+        line: error.line(),
+        column: error.column(),
+        message: error.to_string(),
+    })
+}
+
+#[cfg(feature = "toml")]
+fn parse_toml(file_path: &str, file_contents: &str) -> Result<Value, LoadError> {
+    toml::from_str(file_contents).map_err(|error| LoadError::BadSyntax {
+        file_path: file_path.to_string(),
+        format: "TOML",
+        message: error.to_string(),
+    })
+}
+
+#[cfg(not(feature = "toml"))]
+fn parse_toml(file_path: &str, _file_contents: &str) -> Result<Value, LoadError> {
+    Err(LoadError::UnsupportedFormat {
+        file_path: file_path.to_string(),
+        format: "TOML",
+        feature: "toml",
+    })
+}
+
+#[cfg(feature = "ron")]
+fn parse_ron(file_path: &str, file_contents: &str) -> Result<Value, LoadError> {
+    ron::from_str(file_contents).map_err(|error| LoadError::BadSyntax {
+        file_path: file_path.to_string(),
+        format: "RON",
+        message: error.to_string(),
+    })
+}
+
+#[cfg(not(feature = "ron"))]
+fn parse_ron(file_path: &str, _file_contents: &str) -> Result<Value, LoadError> {
+    Err(LoadError::UnsupportedFormat {
+        file_path: file_path.to_string(),
+        format: "RON",
+        feature: "ron",
+    })
+}
+
+#[cfg(feature = "yaml")]
+fn parse_yaml(file_path: &str, file_contents: &str) -> Result<Value, LoadError> {
+    serde_yaml::from_str(file_contents).map_err(|error| LoadError::BadSyntax {
+        file_path: file_path.to_string(),
+        format: "YAML",
+        message: error.to_string(),
+    })
+}
+
+#[cfg(not(feature = "yaml"))]
+fn parse_yaml(file_path: &str, _file_contents: &str) -> Result<Value, LoadError> {
+    Err(LoadError::UnsupportedFormat {
+        file_path: file_path.to_string(),
+        format: "YAML",
+        feature: "yaml",
+    })
+}
+
+pub fn create_object_json_schema(properties: Vec<(&str, Value)>) -> Value {
+    create_object_json_schema_with_optional_properties(properties, vec![])
+}
+
+pub fn create_object_json_schema_with_optional_properties(
+    properties: Vec<(&str, Value)>,
+    optional_properties: Vec<(&str, Value)>,
+) -> Value {
+    let required = properties
+        .iter()
+        .map(|(name, _)| Value::String(name.to_string()))
+        .collect::<Vec<Value>>();
+
+    let properties = properties
+        .into_iter()
+        .chain(optional_properties)
+        .map(|(name, json_schema)| (name.to_string(), json_schema))
+        .collect::<Map<String, Value>>();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false
+    })
+}
+
+pub fn create_enum_json_schema(names: &[&str]) -> Value {
+    json!({
+        "type": "string",
+        "enum": names
+    })
+}
+
+pub fn create_json_schema_document(json_schemas: Vec<Value>) -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "allOf": json_schemas
+    })
+}
+
+pub struct JsonSchemaValidator {
+    json_schema: Value,
+}
+
+impl JsonSchemaValidator {
+    pub fn new(json_schema: Value) -> Self {
+        Self { json_schema }
+    }
+}
+
+impl ValidateJson for JsonSchemaValidator {
+    fn validate_json(&self, json: &Value) -> Result<(), LoadError> {
+        validate_json_against_json_schema(&self.json_schema, json)
+    }
+}
+
+impl ValidateJsonDocuments for JsonSchemaValidator {
+    fn validate_json_documents(&self, json_documents: &[JsonDocument]) -> Result<(), LoadError> {
+        for json_document in json_documents {
+            self.validate_json(json_document.get_json())
+                .map_err(|error| error.in_file(json_document.get_file_path()))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_json_against_json_schema(json_schema: &Value, json: &Value) -> Result<(), LoadError> {
+    if let Some(expected_type) = json_schema["type"].as_str() {
+        let expected_type = match expected_type {
+            "object" if json.is_object() => None,
+            "array" if json.is_array() => None,
+            "number" if json.is_number() => None,
+            "integer" if json.is_u64() => None,
+            "string" if json.is_string() => None,
+            "boolean" if json.is_boolean() => None,
+            "object" => Some("object"),
+            "array" => Some("array"),
+            "number" => Some("number"),
+            "integer" => Some("integer"),
+            "string" => Some("string"),
+            "boolean" => Some("boolean"),
+            _ => None,
+        };
+
+        if let Some(expected_type) = expected_type {
+            return Err(LoadError::wrong_field_type(expected_type));
+        }
+    }
+
+    if let Some(value) = json_schema.get("const") {
+        if json != value {
+            return Err(LoadError::unknown_value(convert_json_to_error_value(json)));
+        }
+    }
+
+    if let Some(values) = json_schema["enum"].as_array() {
+        if !values.contains(json) {
+            return Err(LoadError::unknown_value(convert_json_to_error_value(json)));
+        }
+    }
+
+    if let Some(json_schemas) = json_schema["allOf"].as_array() {
+        for json_schema in json_schemas {
+            validate_json_against_json_schema(json_schema, json)?;
+        }
+    }
+
+    if let Some(json_schemas) = json_schema["anyOf"].as_array() {
+        let mut first_error = None;
+
+        for json_schema in json_schemas {
+            match validate_json_against_json_schema(json_schema, json) {
+                Ok(()) => {
+                    first_error = None;
+                    break;
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        if let Some(error) = first_error {
+            return Err(error);
+        }
+    }
+
+    if let Some(json_schemas) = json_schema["oneOf"].as_array() {
+        validate_json_against_one_of_json_schemas(json_schemas, json)?;
+    }
+
+    if let Some(object) = json.as_object() {
+        if let Some(required) = json_schema["required"].as_array() {
+            for name in required.iter().filter_map(|name| name.as_str()) {
+                if !object.contains_key(name) {
+                    return Err(LoadError::missing_field().within(name));
+                }
+            }
+        }
+
+        let properties = json_schema["properties"].as_object();
+
+        for (name, value) in object {
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(json_schema) => validate_json_against_json_schema(json_schema, value)
+                    .map_err(|error| error.within(name))?,
+                None => match &json_schema["additionalProperties"] {
+                    Value::Bool(false) => return Err(LoadError::unknown_field().within(name)),
+                    Value::Object(_) => validate_json_against_json_schema(
+                        &json_schema["additionalProperties"],
+                        value,
+                    )
+                    .map_err(|error| error.within(name))?,
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    if let (Some(array), Some(json_schema)) = (json.as_array(), json_schema.get("items")) {
+        for (index, value) in array.iter().enumerate() {
+            validate_json_against_json_schema(json_schema, value)
+                .map_err(|error| error.within(&index.to_string()))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_json_against_one_of_json_schemas(
+    json_schemas: &[Value],
+    json: &Value,
+) -> Result<(), LoadError> {
+    let results = json_schemas
+        .iter()
+        .map(|json_schema| validate_json_against_json_schema(json_schema, json))
+        .collect::<Vec<Result<(), LoadError>>>();
+
+    match results.iter().filter(|result| result.is_ok()).count() {
+        1 => return Ok(()),
+        0 => {}
+        number_of_matches => {
+            return Err(LoadError::invalid_value(
+                convert_json_to_error_value(json),
+                format!("matches {number_of_matches} schemas, expected exactly 1"),
+            ))
+        }
+    }
+
+    let type_json_schemas = json_schemas
+        .iter()
+        .map(|json_schema| &json_schema["properties"]["type"]["const"])
+        .collect::<Vec<&Value>>();
+
+    if let Some(index) = type_json_schemas
+        .iter()
+        .position(|value_type| !value_type.is_null() && **value_type == json["type"])
+    {
+        return results.into_iter().nth(index).unwrap();
+    }
+
+    if !type_json_schemas.is_empty()
+        && type_json_schemas
+            .iter()
+            .all(|value_type| value_type.is_string())
+    {
+        return Err(match &json["type"] {
+            Value::Null => LoadError::missing_field(),
+            Value::String(value_type) => LoadError::unknown_type(value_type.to_string()),
+            _ => LoadError::wrong_field_type("string"),
+        }
+        .within("type"));
+    }
+
+    results
+        .into_iter()
+        .find(|result| result.is_err())
+        .unwrap_or(Ok(()))
+}
+
+fn convert_json_to_error_value(json: &Value) -> String {
+    match json.as_str() {
+        Some(value) => value.to_string(),
+        None => json.to_string(),
+    }
 }
 
 pub struct JsonToF32Converter {}
@@ -13,8 +481,68 @@ impl JsonToF32Converter {
 }
 
 impl ConvertJsonToValue<f32> for JsonToF32Converter {
-    fn convert_json_to_value(&self, json: &Value) -> f32 {
-        json.as_f64().unwrap() as f32
+    fn convert_json_to_value(&self, json: &Value) -> Result<f32, LoadError> {
+        match json {
+            Value::Null => Err(LoadError::missing_field()),
+            Value::Number(number) => Ok(number.as_f64().unwrap() as f32),
+            _ => Err(LoadError::wrong_field_type("number")),
+        }
+    }
+}
+
+impl GetJsonSchema for JsonToF32Converter {
+    fn get_json_schema(&self) -> Value {
+        json!({ "type": "number" })
+    }
+}
+
+pub struct JsonToU64Converter {}
+
+impl JsonToU64Converter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ConvertJsonToValue<u64> for JsonToU64Converter {
+    fn convert_json_to_value(&self, json: &Value) -> Result<u64, LoadError> {
+        match json {
+            Value::Null => Err(LoadError::missing_field()),
+            Value::Number(number) => number
+                .as_u64()
+                .ok_or_else(|| LoadError::wrong_field_type("integer")),
+            _ => Err(LoadError::wrong_field_type("integer")),
+        }
+    }
+}
+
+impl GetJsonSchema for JsonToU64Converter {
+    fn get_json_schema(&self) -> Value {
+        json!({ "type": "integer" })
+    }
+}
+
+pub struct JsonToBoolConverter {}
+
+impl JsonToBoolConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ConvertJsonToValue<bool> for JsonToBoolConverter {
+    fn convert_json_to_value(&self, json: &Value) -> Result<bool, LoadError> {
+        match json {
+            Value::Null => Err(LoadError::missing_field()),
+            Value::Bool(bool) => Ok(*bool),
+            _ => Err(LoadError::wrong_field_type("boolean")),
+        }
+    }
+}
+
+impl GetJsonSchema for JsonToBoolConverter {
+    fn get_json_schema(&self) -> Value {
+        json!({ "type": "boolean" })
     }
 }
 
@@ -26,18 +554,42 @@ impl JsonToStringConverter {
     }
 }
 
+impl GetJsonSchema for JsonToStringConverter {
+    fn get_json_schema(&self) -> Value {
+        json!({ "type": "string" })
+    }
+}
+
 impl ConvertJsonToValue<String> for JsonToStringConverter {
-    fn convert_json_to_value(&self, json: &Value) -> String {
-        json.as_str().unwrap().to_string()
+    fn convert_json_to_value(&self, json: &Value) -> Result<String, LoadError> {
+        match json {
+            Value::Null => Err(LoadError::missing_field()),
+            Value::String(string) => Ok(string.to_string()),
+            _ => Err(LoadError::wrong_field_type("string")),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use garden_loading::LoadError;
     use rstest::rstest;
-    use serde_json::json;
+    use serde_json::{json, Value};
 
-    use crate::{ConvertJsonToValue, JsonToF32Converter, JsonToStringConverter};
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use crate::{
+        convert_f32_to_json, create_enum_json_schema, create_json_schema_document,
+        create_object_json_schema, create_object_json_schema_with_optional_properties,
+        read_json_file, write_json_file, ConvertJsonToValue, GetJsonSchema, JsonDocument,
+        JsonFileReader, JsonReader, JsonSchemaValidator, JsonToBoolConverter, JsonToF32Converter,
+        JsonToStringConverter, JsonToU64Converter, ReadJsonDocuments, ValidateJson,
+        ValidateJsonDocuments,
+    };
 
     #[rstest]
     #[case(1.23)]
@@ -52,7 +604,7 @@ mod tests {
 
         let result = json_to_f32_converter.convert_json_to_value(&f32_value);
 
-        assert_eq!(value, result);
+        assert_eq!(Ok(value), result);
     }
 
     #[rstest]
@@ -71,7 +623,7 @@ mod tests {
 
         let result = json_to_f32_converter.convert_json_to_value(&f32_value);
 
-        assert_eq!(expected_result, result);
+        assert_eq!(Ok(expected_result), result);
     }
 
     #[rstest]
@@ -87,7 +639,7 @@ mod tests {
 
         let result = json_to_string_converter.convert_json_to_value(&string_value);
 
-        assert_eq!(value, result);
+        assert_eq!(Ok(value), result);
     }
 
     #[rstest]
@@ -103,6 +655,481 @@ mod tests {
 
         let result = json_to_string_converter.convert_json_to_value(&string_value);
 
-        assert_eq!(value, result);
+        assert_eq!(Ok(value.to_string()), result);
+    }
+
+    #[rstest]
+    #[case(json!(true), Ok(true))]
+    #[case(json!(false), Ok(false))]
+    #[case(Value::Null, Err(LoadError::missing_field()))]
+    #[case(json!("true"), Err(LoadError::wrong_field_type("boolean")))]
+    #[case(json!(1), Err(LoadError::wrong_field_type("boolean")))]
+    fn when_a_json_to_bool_converter_converts_a_json_value_then_only_booleans_are_converted(
+        #[case] value: Value,
+        #[case] expected_result: Result<bool, LoadError>,
+    ) {
+        let json_to_bool_converter = JsonToBoolConverter::new();
+
+        let result = json_to_bool_converter.convert_json_to_value(&value);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn when_a_json_to_f32_converter_converts_a_missing_json_value_then_a_missing_field_error_is_returned(
+    ) {
+        let json_to_f32_converter = JsonToF32Converter::new();
+
+        let result = json_to_f32_converter.convert_json_to_value(&Value::Null);
+
+        assert_eq!(Err(LoadError::missing_field()), result);
+    }
+
+    #[rstest]
+    #[case(json!("1.23"))]
+    #[case(json!(true))]
+    #[case(json!({ "x": 1.23 }))]
+    fn when_a_json_to_f32_converter_converts_a_non_number_json_value_then_a_wrong_field_type_error_is_returned(
+        #[case] value: Value,
+    ) {
+        let json_to_f32_converter = JsonToF32Converter::new();
+
+        let result = json_to_f32_converter.convert_json_to_value(&value);
+
+        assert_eq!(Err(LoadError::wrong_field_type("number")), result);
+    }
+
+    #[rstest]
+    #[case(json!(0), Ok(0))]
+    #[case(json!(4294967296u64), Ok(4294967296))]
+    #[case(json!(1.5), Err(LoadError::wrong_field_type("integer")))]
+    #[case(json!(-1), Err(LoadError::wrong_field_type("integer")))]
+    #[case(json!("1"), Err(LoadError::wrong_field_type("integer")))]
+    #[case(Value::Null, Err(LoadError::missing_field()))]
+    fn when_a_json_to_u64_converter_converts_a_json_value_then_only_non_negative_integers_are_converted(
+        #[case] value: Value,
+        #[case] expected_result: Result<u64, LoadError>,
+    ) {
+        let json_to_u64_converter = JsonToU64Converter::new();
+
+        let result = json_to_u64_converter.convert_json_to_value(&value);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn when_a_json_to_string_converter_converts_a_missing_json_value_then_a_missing_field_error_is_returned(
+    ) {
+        let json_to_string_converter = JsonToStringConverter::new();
+
+        let result = json_to_string_converter.convert_json_to_value(&Value::Null);
+
+        assert_eq!(Err(LoadError::missing_field()), result);
+    }
+
+    #[rstest]
+    #[case(json!(1.23))]
+    #[case(json!(["val"]))]
+    fn when_a_json_to_string_converter_converts_a_non_string_json_value_then_a_wrong_field_type_error_is_returned(
+        #[case] value: Value,
+    ) {
+        let json_to_string_converter = JsonToStringConverter::new();
+
+        let result = json_to_string_converter.convert_json_to_value(&value);
+
+        assert_eq!(Err(LoadError::wrong_field_type("string")), result);
+    }
+
+    #[test]
+    fn when_a_missing_json_file_is_read_then_a_missing_file_error_is_returned() {
+        let result = read_json_file("does-not-exist.json");
+
+        assert!(matches!(
+            result,
+            Err(LoadError::MissingFile { file_path, .. }) if file_path == "does-not-exist.json"
+        ));
+    }
+
+    #[test]
+    fn when_a_json_file_reader_reads_a_manifest_then_each_listed_file_is_read_relative_to_the_manifest(
+    ) {
+        let directory = create_test_directory("manifest_test");
+        fs::create_dir_all(directory.join("levels")).unwrap();
+        fs::write(
+            directory.join("manifest.json"),
+            r#"{ "files": ["scene.json", "levels/level-1.json"] }"#,
+        )
+        .unwrap();
+        fs::write(directory.join("scene.json"), r#"{ "scene": {} }"#).unwrap();
+        fs::write(
+            directory.join("levels").join("level-1.json"),
+            r#"{ "objects": [] }"#,
+        )
+        .unwrap();
+
+        let json_file_reader =
+            JsonFileReader::new(directory.join("manifest.json").to_str().unwrap());
+
+        let result = json_file_reader.read_json_documents().unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(2, result.len());
+        assert_eq!(
+            directory.join("scene.json").to_str().unwrap(),
+            result[0].get_file_path()
+        );
+        assert_eq!(&json!({ "scene": {} }), result[0].get_json());
+        assert_eq!(
+            directory
+                .join("levels")
+                .join("level-1.json")
+                .to_str()
+                .unwrap(),
+            result[1].get_file_path()
+        );
+        assert_eq!(&json!({ "objects": [] }), result[1].get_json());
+    }
+
+    #[test]
+    fn when_a_json_reader_reads_json_then_a_single_document_is_read() {
+        let json_reader = JsonReader::new("fixture", r#"{ "objects": [] }"#.as_bytes());
+
+        let result = json_reader.read_json_documents().unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!("fixture", result[0].get_file_path());
+        assert_eq!(&json!({ "objects": [] }), result[0].get_json());
+    }
+
+    #[test]
+    fn when_a_json_reader_reads_invalid_json_then_a_bad_json_error_is_returned() {
+        let json_reader = JsonReader::new("fixture", r#"{ "objects": [ }"#.as_bytes());
+
+        let result = json_reader.read_json_documents();
+
+        assert!(matches!(
+            result,
+            Err(LoadError::BadJson { file_path, line: 1, .. }) if file_path == "fixture"
+        ));
+    }
+
+    fn create_test_directory(test_name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("garden_json_{test_name}_{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
+    fn write_test_file(directory: &Path, file_name: &str, file_contents: &str) -> String {
+        let file_path = directory.join(file_name);
+        fs::write(&file_path, file_contents).unwrap();
+
+        file_path.to_str().unwrap().to_string()
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn when_a_toml_file_is_read_then_it_is_read_as_json() {
+        let directory = create_test_directory("toml");
+        let file_path = write_test_file(
+            &directory,
+            "scene.toml",
+            "# A hand-written scene\n[scene]\nwidth = 100.0\nheight = 50.0\n",
+        );
+
+        let result = read_json_file(&file_path);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            Ok(json!({ "scene": { "width": 100.0, "height": 50.0 } })),
+            result
+        );
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn when_a_ron_file_is_read_then_it_is_read_as_json() {
+        let directory = create_test_directory("ron");
+        let file_path = write_test_file(
+            &directory,
+            "scene.ron",
+            "// A hand-written scene\n{ \"scene\": { \"width\": 100.0, \"height\": 50.0, }, }\n",
+        );
+
+        let result = read_json_file(&file_path);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            Ok(json!({ "scene": { "width": 100.0, "height": 50.0 } })),
+            result
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn when_a_yaml_file_is_read_then_it_is_read_as_json() {
+        let directory = create_test_directory("yaml");
+        let file_path = write_test_file(
+            &directory,
+            "scene.yaml",
+            "# A hand-written scene\nscene:\n  width: 100.0\n  height: 50.0\n",
+        );
+
+        let result = read_json_file(&file_path);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            Ok(json!({ "scene": { "width": 100.0, "height": 50.0 } })),
+            result
+        );
+    }
+
+    #[cfg(not(feature = "toml"))]
+    #[test]
+    fn when_a_toml_file_is_read_without_the_toml_feature_then_an_unsupported_format_error_is_returned(
+    ) {
+        let directory = create_test_directory("unsupported_toml");
+        let file_path = write_test_file(&directory, "unsupported.toml", "[scene]\n");
+
+        let result = read_json_file(&file_path);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            Err(LoadError::UnsupportedFormat {
+                file_path,
+                format: "TOML",
+                feature: "toml",
+            }),
+            result
+        );
+    }
+
+    #[rstest]
+    #[case(1.1, json!(1.1))]
+    #[case(-0.25, json!(-0.25))]
+    #[case(3.0, json!(3.0))]
+    fn when_an_f32_is_converted_to_json_then_it_keeps_its_shortest_representation(
+        #[case] value: f32,
+        #[case] expected_result: Value,
+    ) {
+        let result = convert_f32_to_json(value);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn when_a_json_file_is_written_then_it_can_be_read_back() {
+        let directory = create_test_directory("written_json");
+        let file_path = write_test_file(&directory, "written.json", "");
+        let json = json!({ "scene": { "width": 1.5, "height": 2.0 } });
+
+        write_json_file(&file_path, &json).unwrap();
+
+        let result = read_json_file(&file_path);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(Ok(json), result);
+    }
+
+    fn create_test_json_schema_validator() -> JsonSchemaValidator {
+        let json_to_f32_converter = JsonToF32Converter::new();
+        let json_to_string_converter = JsonToStringConverter::new();
+
+        let circle_json_schema = create_object_json_schema(vec![
+            ("name", json_to_string_converter.get_json_schema()),
+            ("type", json!({ "const": "circle" })),
+            ("diameter", json_to_f32_converter.get_json_schema()),
+        ]);
+
+        let square_json_schema = create_object_json_schema(vec![
+            ("name", json_to_string_converter.get_json_schema()),
+            ("type", json!({ "const": "square" })),
+            ("size", json_to_f32_converter.get_json_schema()),
+        ]);
+
+        JsonSchemaValidator::new(create_json_schema_document(vec![json!({
+            "type": "object",
+            "properties": {
+                "objects": {
+                    "type": "array",
+                    "items": { "oneOf": [circle_json_schema, square_json_schema] }
+                }
+            }
+        })]))
+    }
+
+    #[rstest]
+    #[case(
+        json!({ "objects": [{ "name": "Circle1", "type": "circle", "diameter": 2.0 }] }),
+        Ok(())
+    )]
+    #[case(
+        json!({ "objects": { "name": "Circle1" } }),
+        Err(LoadError::wrong_field_type("array").within("objects"))
+    )]
+    #[case(
+        json!({ "objects": [{ "name": "Circle1", "type": "circle" }] }),
+        Err(LoadError::missing_field().within("diameter").within("0").within("objects"))
+    )]
+    #[case(
+        json!({ "objects": [{ "name": "Circle1", "type": "circle", "diameter": "2" }] }),
+        Err(LoadError::wrong_field_type("number").within("diameter").within("0").within("objects"))
+    )]
+    #[case(
+        json!({ "objects": [{ "name": "Circle1", "type": "circle", "diameter": 2.0, "diamter": 2.0 }] }),
+        Err(LoadError::unknown_field().within("diamter").within("0").within("objects"))
+    )]
+    #[case(
+        json!({ "objects": [{ "name": "Star1", "type": "star" }] }),
+        Err(LoadError::unknown_type("star".to_string()).within("type").within("0").within("objects"))
+    )]
+    #[case(
+        json!({ "objects": [{ "name": "Circle1" }] }),
+        Err(LoadError::missing_field().within("type").within("0").within("objects"))
+    )]
+    fn when_a_json_schema_validator_validates_json_then_the_first_error_is_returned(
+        #[case] json: Value,
+        #[case] expected_result: Result<(), LoadError>,
+    ) {
+        let json_schema_validator = create_test_json_schema_validator();
+
+        let result = json_schema_validator.validate_json(&json);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[rstest]
+    #[case(json!({ "oneOf": [{ "type": "number" }, { "type": "string" }] }), json!(2.0), Ok(()))]
+    #[case(json!({ "oneOf": [{ "type": "number" }, { "type": "string" }] }), json!("2"), Ok(()))]
+    #[case(
+        json!({ "oneOf": [{ "type": "number" }, { "type": "string" }] }),
+        json!(true),
+        Err(LoadError::wrong_field_type("number"))
+    )]
+    #[case(
+        json!({ "oneOf": [{ "type": "number" }, { "type": "number" }] }),
+        json!(2.0),
+        Err(LoadError::invalid_value("2.0".to_string(), "matches 2 schemas, expected exactly 1".to_string()))
+    )]
+    #[case(json!({ "const": "circle" }), json!("circle"), Ok(()))]
+    #[case(
+        json!({ "const": "circle" }),
+        json!("square"),
+        Err(LoadError::unknown_value("square".to_string()))
+    )]
+    #[case(json!({ "const": 1 }), json!(2), Err(LoadError::unknown_value("2".to_string())))]
+    #[case(create_enum_json_schema(&["stretch", "expand"]), json!("expand"), Ok(()))]
+    #[case(
+        create_enum_json_schema(&["stretch", "expand"]),
+        json!("fit"),
+        Err(LoadError::unknown_value("fit".to_string()))
+    )]
+    #[case(
+        create_enum_json_schema(&["stretch", "expand"]),
+        json!(1.0),
+        Err(LoadError::wrong_field_type("string"))
+    )]
+    fn when_a_json_schema_validator_validates_one_of_const_and_enum_then_exactly_one_schema_must_match(
+        #[case] json_schema: Value,
+        #[case] json: Value,
+        #[case] expected_result: Result<(), LoadError>,
+    ) {
+        let json_schema_validator = JsonSchemaValidator::new(json_schema);
+
+        let result = json_schema_validator.validate_json(&json);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[rstest]
+    #[case(json!({ "jump": 1.0, "fall": 2.0 }), Ok(()))]
+    #[case(
+        json!({ "jump": 1.0, "fall": "2" }),
+        Err(LoadError::wrong_field_type("number").within("fall"))
+    )]
+    fn when_a_json_schema_validator_validates_additional_properties_then_each_value_is_validated(
+        #[case] json: Value,
+        #[case] expected_result: Result<(), LoadError>,
+    ) {
+        let json_schema_validator = JsonSchemaValidator::new(json!({
+            "type": "object",
+            "additionalProperties": { "type": "number" }
+        }));
+
+        let result = json_schema_validator.validate_json(&json);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[rstest]
+    #[case(json!({ "scale": 2.0 }), Ok(()))]
+    #[case(json!({ "scale": { "x": 2.0, "y": 0.5 }, "rotation": 45.0 }), Ok(()))]
+    #[case(json!({}), Err(LoadError::missing_field().within("scale")))]
+    #[case(
+        json!({ "scale": "2" }),
+        Err(LoadError::wrong_field_type("number").within("scale"))
+    )]
+    #[case(
+        json!({ "scale": 2.0, "rotation": "45" }),
+        Err(LoadError::wrong_field_type("number").within("rotation"))
+    )]
+    fn when_a_json_schema_validator_validates_optional_and_any_of_properties_then_any_matching_schema_is_accepted(
+        #[case] json: Value,
+        #[case] expected_result: Result<(), LoadError>,
+    ) {
+        let json_to_f32_converter = JsonToF32Converter::new();
+
+        let json_schema_validator =
+            JsonSchemaValidator::new(create_object_json_schema_with_optional_properties(
+                vec![(
+                    "scale",
+                    json!({ "anyOf": [
+                        json_to_f32_converter.get_json_schema(),
+                        create_object_json_schema(vec![
+                            ("x", json_to_f32_converter.get_json_schema()),
+                            ("y", json_to_f32_converter.get_json_schema()),
+                        ])
+                    ] }),
+                )],
+                vec![("rotation", json_to_f32_converter.get_json_schema())],
+            ));
+
+        let result = json_schema_validator.validate_json(&json);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn when_a_json_schema_validator_validates_json_documents_then_the_error_includes_the_file_path()
+    {
+        let json_schema_validator = create_test_json_schema_validator();
+
+        let json_documents = vec![
+            JsonDocument::new(
+                "shapes.json".to_string(),
+                json!({ "objects": [{ "name": "Square1", "type": "square", "size": 1.0 }] }),
+            ),
+            JsonDocument::new(
+                "level-1.json".to_string(),
+                json!({ "objects": [{ "name": "Square2", "type": "square" }] }),
+            ),
+        ];
+
+        let result = json_schema_validator.validate_json_documents(&json_documents);
+
+        assert_eq!(
+            Err(LoadError::missing_field()
+                .within("size")
+                .within("0")
+                .within("objects")
+                .in_file("level-1.json")),
+            result
+        );
     }
 }
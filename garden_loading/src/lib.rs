@@ -1,3 +1,382 @@
+use std::{error::Error, fmt};
+
 pub trait Load<T> {
-    fn load(self) -> T;
+    fn load(self) -> Result<T, LoadError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LoadError {
+    MissingFile {
+        file_path: String,
+        message: String,
+    },
+    BadJson {
+        file_path: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    BadSyntax {
+        file_path: String,
+        format: &'static str,
+        message: String,
+    },
+    UnsupportedFormat {
+        file_path: String,
+        format: &'static str,
+        feature: &'static str,
+    },
+    UnknownType {
+        path: String,
+        value_type: String,
+    },
+    UnknownContent {
+        path: String,
+        content_name: String,
+    },
+    MissingField {
+        path: String,
+    },
+    UnknownField {
+        path: String,
+    },
+    UnknownValue {
+        path: String,
+        value: String,
+    },
+    InvalidValue {
+        path: String,
+        value: String,
+        reason: String,
+    },
+    WrongFieldType {
+        path: String,
+        expected_type: &'static str,
+    },
+    InvalidGeometry {
+        path: String,
+        reason: String,
+    },
+    DuplicateName {
+        name: String,
+        file_path: String,
+        first_file_path: String,
+    },
+    InFile {
+        file_path: String,
+        error: Box<LoadError>,
+    },
+}
+
+impl LoadError {
+    pub fn unknown_type(value_type: String) -> Self {
+        LoadError::UnknownType {
+            path: String::new(),
+            value_type,
+        }
+    }
+
+    pub fn unknown_content(content_name: String) -> Self {
+        LoadError::UnknownContent {
+            path: String::new(),
+            content_name,
+        }
+    }
+
+    pub fn missing_field() -> Self {
+        LoadError::MissingField {
+            path: String::new(),
+        }
+    }
+
+    pub fn unknown_field() -> Self {
+        LoadError::UnknownField {
+            path: String::new(),
+        }
+    }
+
+    pub fn unknown_value(value: String) -> Self {
+        LoadError::UnknownValue {
+            path: String::new(),
+            value,
+        }
+    }
+
+    pub fn invalid_value(value: String, reason: String) -> Self {
+        LoadError::InvalidValue {
+            path: String::new(),
+            value,
+            reason,
+        }
+    }
+
+    pub fn wrong_field_type(expected_type: &'static str) -> Self {
+        LoadError::WrongFieldType {
+            path: String::new(),
+            expected_type,
+        }
+    }
+
+    pub fn invalid_geometry(reason: String) -> Self {
+        LoadError::InvalidGeometry {
+            path: String::new(),
+            reason,
+        }
+    }
+
+    pub fn within(mut self, segment: &str) -> Self {
+        if let Some(path) = self.get_path_mut() {
+            let escaped_segment = segment.replace('~', "~0").replace('/', "~1");
+
+            path.insert_str(0, &format!("/{escaped_segment}"));
+        }
+
+        self
+    }
+
+    pub fn in_file(self, file_path: &str) -> Self {
+        match self {
+            LoadError::MissingFile { .. }
+            | LoadError::BadJson { .. }
+            | LoadError::BadSyntax { .. }
+            | LoadError::UnsupportedFormat { .. }
+            | LoadError::DuplicateName { .. }
+            | LoadError::InFile { .. } => self,
+            _ => LoadError::InFile {
+                file_path: file_path.to_string(),
+                error: Box::new(self),
+            },
+        }
+    }
+
+    pub fn get_path(&self) -> Option<&str> {
+        match self {
+            LoadError::MissingFile { .. }
+            | LoadError::BadJson { .. }
+            | LoadError::BadSyntax { .. }
+            | LoadError::UnsupportedFormat { .. }
+            | LoadError::DuplicateName { .. } => None,
+            LoadError::InFile { error, .. } => error.get_path(),
+            LoadError::UnknownType { path, .. }
+            | LoadError::UnknownContent { path, .. }
+            | LoadError::MissingField { path }
+            | LoadError::UnknownField { path }
+            | LoadError::UnknownValue { path, .. }
+            | LoadError::InvalidValue { path, .. }
+            | LoadError::WrongFieldType { path, .. }
+            | LoadError::InvalidGeometry { path, .. } => Some(path),
+        }
+    }
+
+    fn get_path_mut(&mut self) -> Option<&mut String> {
+        match self {
+            LoadError::MissingFile { .. }
+            | LoadError::BadJson { .. }
+            | LoadError::BadSyntax { .. }
+            | LoadError::UnsupportedFormat { .. }
+            | LoadError::DuplicateName { .. }
+            | LoadError::InFile { .. } => None,
+            LoadError::UnknownType { path, .. }
+            | LoadError::UnknownContent { path, .. }
+            | LoadError::MissingField { path }
+            | LoadError::UnknownField { path }
+            | LoadError::UnknownValue { path, .. }
+            | LoadError::InvalidValue { path, .. }
+            | LoadError::WrongFieldType { path, .. }
+            | LoadError::InvalidGeometry { path, .. } => Some(path),
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::MissingFile { file_path, message } => {
+                write!(f, "could not read {file_path}: {message}")
+            }
+            LoadError::BadJson {
+                file_path, message, ..
+            } => write!(f, "invalid JSON in {file_path}: {message}"),
+            LoadError::BadSyntax {
+                file_path,
+                format,
+                message,
+            } => write!(f, "invalid {format} in {file_path}: {message}"),
+            LoadError::UnsupportedFormat {
+                file_path,
+                format,
+                feature,
+            } => write!(
+                f,
+                "could not read {file_path}: {format} support requires the \"{feature}\" feature"
+            ),
+            LoadError::UnknownType { path, value_type } => {
+                write!(f, "unknown type \"{value_type}\" at {path}")
+            }
+            LoadError::UnknownContent { path, content_name } => {
+                write!(f, "unknown content \"{content_name}\" at {path}")
+            }
+            LoadError::MissingField { path } => write!(f, "missing field at {path}"),
+            LoadError::UnknownField { path } => write!(f, "unknown field at {path}"),
+            LoadError::UnknownValue { path, value } => {
+                write!(f, "unknown value \"{value}\" at {path}")
+            }
+            LoadError::InvalidValue {
+                path,
+                value,
+                reason,
+            } => write!(f, "invalid value {value} at {path}: {reason}"),
+            LoadError::WrongFieldType {
+                path,
+                expected_type,
+            } => write!(f, "expected {expected_type} at {path}"),
+            LoadError::InvalidGeometry { path, reason } => {
+                write!(f, "invalid geometry at {path}: {reason}")
+            }
+            LoadError::DuplicateName {
+                name,
+                file_path,
+                first_file_path,
+            } => write!(
+                f,
+                "duplicate name \"{name}\" in {file_path}, first defined in {first_file_path}"
+            ),
+            LoadError::InFile { file_path, error } => write!(f, "{file_path}: {error}"),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::LoadError;
+
+    #[test]
+    fn when_a_load_error_is_placed_within_segments_then_the_path_is_a_json_pointer() {
+        let load_error = LoadError::missing_field()
+            .within("x")
+            .within("position")
+            .within("3")
+            .within("objects");
+
+        let result = load_error.get_path();
+
+        assert_eq!(Some("/objects/3/position/x"), result);
+    }
+
+    #[test]
+    fn when_a_load_error_is_placed_within_a_segment_containing_reserved_characters_then_they_are_escaped(
+    ) {
+        let load_error = LoadError::missing_field().within("a/b~c");
+
+        let result = load_error.get_path();
+
+        assert_eq!(Some("/a~1b~0c"), result);
+    }
+
+    #[test]
+    fn when_a_file_load_error_is_placed_within_a_segment_then_it_is_unchanged() {
+        let load_error = LoadError::MissingFile {
+            file_path: "content.json".to_string(),
+            message: "not found".to_string(),
+        };
+
+        let result = load_error.within("objects");
+
+        assert_eq!(
+            LoadError::MissingFile {
+                file_path: "content.json".to_string(),
+                message: "not found".to_string(),
+            },
+            result
+        );
+    }
+
+    #[test]
+    fn when_a_load_error_is_displayed_then_it_includes_the_path() {
+        let load_error = LoadError::wrong_field_type("number")
+            .within("x")
+            .within("position");
+
+        let result = load_error.to_string();
+
+        assert_eq!("expected number at /position/x", result);
+    }
+
+    #[test]
+    fn when_a_load_error_is_placed_in_a_file_then_it_is_displayed_with_the_file_path() {
+        let load_error = LoadError::missing_field()
+            .within("width")
+            .within("scene")
+            .in_file("level-1.json");
+
+        let result = load_error.to_string();
+
+        assert_eq!("level-1.json: missing field at /scene/width", result);
+        assert_eq!(Some("/scene/width"), load_error.get_path());
+    }
+
+    #[test]
+    fn when_a_file_load_error_is_placed_in_a_file_then_it_is_unchanged() {
+        let load_error = LoadError::MissingFile {
+            file_path: "level-1.json".to_string(),
+            message: "not found".to_string(),
+        };
+
+        let result = load_error.in_file("manifest.json");
+
+        assert_eq!(
+            LoadError::MissingFile {
+                file_path: "level-1.json".to_string(),
+                message: "not found".to_string(),
+            },
+            result
+        );
+    }
+
+    #[test]
+    fn when_an_unknown_field_error_is_displayed_then_it_includes_the_path() {
+        let load_error = LoadError::unknown_field().within("diamter").within("0");
+
+        let result = load_error.to_string();
+
+        assert_eq!("unknown field at /0/diamter", result);
+    }
+
+    #[test]
+    fn when_an_unknown_value_error_is_displayed_then_it_includes_the_value_and_path() {
+        let load_error = LoadError::unknown_value("Spce".to_string())
+            .within("jump")
+            .within("actions");
+
+        let result = load_error.to_string();
+
+        assert_eq!("unknown value \"Spce\" at /actions/jump", result);
+    }
+
+    #[test]
+    fn when_an_invalid_geometry_error_is_displayed_then_it_includes_the_reason_and_path() {
+        let load_error = LoadError::invalid_geometry("edge 0 crosses edge 2".to_string())
+            .within("points")
+            .within("0");
+
+        let result = load_error.to_string();
+
+        assert_eq!(
+            "invalid geometry at /0/points: edge 0 crosses edge 2",
+            result
+        );
+    }
+
+    #[test]
+    fn when_an_invalid_value_error_is_displayed_then_it_includes_the_value_reason_and_path() {
+        let load_error = LoadError::invalid_value("2".to_string(), "must be >= 3".to_string())
+            .within("segments")
+            .within("0");
+
+        let result = load_error.to_string();
+
+        assert_eq!("invalid value 2 at /0/segments: must be >= 3", result);
+    }
 }
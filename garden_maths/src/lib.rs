@@ -1 +1,4 @@
+pub mod matrices;
+pub mod triangulation;
 pub mod trigonometry;
+pub mod vectors;
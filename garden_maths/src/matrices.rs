@@ -0,0 +1,508 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::vectors::{Vec2, Vec3};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3 {
+    cols: [[f32; 3]; 3],
+}
+
+impl Mat3 {
+    pub const IDENTITY: Self = Self::from_cols(
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    );
+
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        Self {
+            cols: [
+                [x_axis.x, x_axis.y, x_axis.z],
+                [y_axis.x, y_axis.y, y_axis.z],
+                [z_axis.x, z_axis.y, z_axis.z],
+            ],
+        }
+    }
+
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self::from_cols(Vec3::X, Vec3::Y, translation.extend(1.0))
+    }
+
+    pub fn from_rotation(angle_radians: f32) -> Self {
+        let (sin, cos) = angle_radians.sin_cos();
+
+        Self::from_cols(Vec3::new(cos, sin, 0.0), Vec3::new(-sin, cos, 0.0), Vec3::Z)
+    }
+
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self::from_cols(Vec3::X * scale.x, Vec3::Y * scale.y, Vec3::Z)
+    }
+
+    pub fn from_scale_rotation_translation(
+        scale: Vec2,
+        angle_radians: f32,
+        translation: Vec2,
+    ) -> Self {
+        Self::from_translation(translation)
+            * Self::from_rotation(angle_radians)
+            * Self::from_scale(scale)
+    }
+
+    pub fn get_col(&self, index: usize) -> Vec3 {
+        let [x, y, z] = self.cols[index];
+
+        Vec3::new(x, y, z)
+    }
+
+    pub fn get_row(&self, index: usize) -> Vec3 {
+        Vec3::new(
+            self.cols[0][index],
+            self.cols[1][index],
+            self.cols[2][index],
+        )
+    }
+
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        (*self * point.extend(1.0)).truncate()
+    }
+
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        (*self * vector.extend(0.0)).truncate()
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(self.get_row(0), self.get_row(1), self.get_row(2))
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.get_col(2).dot(self.get_col(0).cross(self.get_col(1)))
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let x_axis = self.get_col(0);
+        let y_axis = self.get_col(1);
+        let z_axis = self.get_col(2);
+
+        Some(
+            Self::from_cols(
+                y_axis.cross(z_axis),
+                z_axis.cross(x_axis),
+                x_axis.cross(y_axis),
+            )
+            .transpose()
+                * (1.0 / determinant),
+        )
+    }
+
+    pub fn to_cols_array(&self) -> [f32; 9] {
+        let [x_axis, y_axis, z_axis] = self.cols;
+
+        [
+            x_axis[0], x_axis[1], x_axis[2], y_axis[0], y_axis[1], y_axis[2], z_axis[0], z_axis[1],
+            z_axis[2],
+        ]
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_cols(
+            self * other.get_col(0),
+            self * other.get_col(1),
+            self * other.get_col(2),
+        )
+    }
+}
+
+impl MulAssign for Mat3 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, vector: Vec3) -> Vec3 {
+        self.get_col(0) * vector.x + self.get_col(1) * vector.y + self.get_col(2) * vector.z
+    }
+}
+
+impl Mul<f32> for Mat3 {
+    type Output = Self;
+
+    fn mul(self, scalar: f32) -> Self {
+        Self::from_cols(
+            self.get_col(0) * scalar,
+            self.get_col(1) * scalar,
+            self.get_col(2) * scalar,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4 {
+    cols: [[f32; 4]; 4],
+}
+
+impl Mat4 {
+    pub const IDENTITY: Self = Self::from_cols_array([
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ]);
+
+    pub const fn from_cols_array(values: [f32; 16]) -> Self {
+        Self {
+            cols: [
+                [values[0], values[1], values[2], values[3]],
+                [values[4], values[5], values[6], values[7]],
+                [values[8], values[9], values[10], values[11]],
+                [values[12], values[13], values[14], values[15]],
+            ],
+        }
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        let mut mat4 = Self::IDENTITY;
+        mat4.cols[3] = [translation.x, translation.y, translation.z, 1.0];
+        mat4
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        let mut mat4 = Self::IDENTITY;
+        mat4.cols[0][0] = scale.x;
+        mat4.cols[1][1] = scale.y;
+        mat4.cols[2][2] = scale.z;
+        mat4
+    }
+
+    pub fn from_rotation_z(angle_radians: f32) -> Self {
+        Self::from(Mat3::from_rotation(angle_radians))
+    }
+
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+
+        Self::from_cols_array([
+            2.0 / width,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            2.0 / height,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            -2.0 / depth,
+            0.0,
+            -(right + left) / width,
+            -(top + bottom) / height,
+            -(far + near) / depth,
+            1.0,
+        ])
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        self.cols[col][row]
+    }
+
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        let [x, y, z, w] = self.transform([point.x, point.y, point.z, 1.0]);
+
+        Vec3::new(x, y, z) / w
+    }
+
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        let [x, y, z, _] = self.transform([vector.x, vector.y, vector.z, 0.0]);
+
+        Vec3::new(x, y, z)
+    }
+
+    fn transform(&self, vector: [f32; 4]) -> [f32; 4] {
+        let mut result = [0.0; 4];
+
+        for (row, value) in result.iter_mut().enumerate() {
+            *value = (0..4).map(|col| self.get(row, col) * vector[col]).sum();
+        }
+
+        result
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut transposed = *self;
+
+        for row in 0..4 {
+            for col in 0..4 {
+                transposed.cols[col][row] = self.get(col, row);
+            }
+        }
+
+        transposed
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let m = self.to_cols_array();
+        let cofactors = calculate_cofactors(&m);
+
+        m[0] * cofactors[0] + m[1] * cofactors[4] + m[2] * cofactors[8] + m[3] * cofactors[12]
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let cofactors = calculate_cofactors(&self.to_cols_array());
+
+        Some(Self::from_cols_array(
+            cofactors.map(|cofactor| cofactor / determinant),
+        ))
+    }
+
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let mut values = [0.0; 16];
+
+        for (col, column) in self.cols.iter().enumerate() {
+            values[col * 4..col * 4 + 4].copy_from_slice(column);
+        }
+
+        values
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Mat3> for Mat4 {
+    fn from(mat3: Mat3) -> Self {
+        let x_axis = mat3.get_col(0);
+        let y_axis = mat3.get_col(1);
+        let translation = mat3.get_col(2);
+
+        Self::from_cols_array([
+            x_axis.x,
+            x_axis.y,
+            0.0,
+            x_axis.z,
+            y_axis.x,
+            y_axis.y,
+            0.0,
+            y_axis.z,
+            0.0,
+            0.0,
+            1.0,
+            0.0,
+            translation.x,
+            translation.y,
+            0.0,
+            translation.z,
+        ])
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut result = Self::IDENTITY;
+
+        for col in 0..4 {
+            result.cols[col] = self.transform(other.cols[col]);
+        }
+
+        result
+    }
+}
+
+impl MulAssign for Mat4 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+fn calculate_cofactors(m: &[f32; 16]) -> [f32; 16] {
+    [
+        m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10],
+        -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10],
+        m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6],
+        -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6],
+        -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10],
+        m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10],
+        -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6],
+        m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6],
+        m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9],
+        -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9],
+        m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5],
+        -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5],
+        -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9],
+        m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9],
+        -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5],
+        m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        matrices::{Mat3, Mat4},
+        vectors::{Vec2, Vec3},
+    };
+
+    fn assert_approximately_equal(expected: &[f32], result: &[f32]) {
+        assert_eq!(expected.len(), result.len());
+
+        for (expected, result) in expected.iter().zip(result.iter()) {
+            assert!(
+                (expected - result).abs() < 0.0001,
+                "expected {expected:?}, got {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn when_a_mat3_is_composed_from_scale_rotation_and_translation_then_points_are_transformed_in_that_order(
+    ) {
+        let mat3 = Mat3::from_scale_rotation_translation(
+            Vec2::new(2.0, 3.0),
+            90.0_f32.to_radians(),
+            Vec2::new(10.0, -5.0),
+        );
+
+        let point = mat3.transform_point(Vec2::new(1.0, 1.0));
+        let vector = mat3.transform_vector(Vec2::new(1.0, 1.0));
+
+        assert_approximately_equal(&[7.0, -3.0], &[point.x, point.y]);
+        assert_approximately_equal(&[-3.0, 2.0], &[vector.x, vector.y]);
+    }
+
+    #[test]
+    fn when_a_mat3_is_inverted_then_multiplying_by_the_inverse_gives_the_identity() {
+        let mat3 = Mat3::from_scale_rotation_translation(
+            Vec2::new(2.0, 0.5),
+            30.0_f32.to_radians(),
+            Vec2::new(-4.0, 7.0),
+        );
+
+        let result = mat3 * mat3.inverse().unwrap();
+
+        assert_approximately_equal(&Mat3::IDENTITY.to_cols_array(), &result.to_cols_array());
+        assert_eq!(None, Mat3::from_scale(Vec2::new(0.0, 1.0)).inverse());
+    }
+
+    #[rstest]
+    #[case(Vec3::new(-400.0, -300.0, 0.0), Vec3::new(-1.0, -1.0, 0.0))]
+    #[case(Vec3::new(400.0, 300.0, 0.0), Vec3::new(1.0, 1.0, 0.0))]
+    #[case(Vec3::new(0.0, 0.0, -1.0), Vec3::new(0.0, 0.0, 1.0))]
+    #[case(Vec3::new(200.0, -150.0, 1.0), Vec3::new(0.5, -0.5, -1.0))]
+    fn when_an_orthographic_projection_transforms_a_point_then_it_is_mapped_to_normalised_device_coordinates(
+        #[case] point: Vec3,
+        #[case] expected_result: Vec3,
+    ) {
+        let projection = Mat4::orthographic(-400.0, 400.0, -300.0, 300.0, -1.0, 1.0);
+
+        let result = projection.transform_point(point);
+
+        assert_approximately_equal(
+            &[expected_result.x, expected_result.y, expected_result.z],
+            &[result.x, result.y, result.z],
+        );
+    }
+
+    #[test]
+    fn when_a_mat4_is_inverted_then_multiplying_by_the_inverse_gives_the_identity() {
+        let mat4 = Mat4::orthographic(-10.0, 30.0, -5.0, 15.0, 0.1, 100.0)
+            * Mat4::from_translation(Vec3::new(3.0, -2.0, 1.0))
+            * Mat4::from_rotation_z(45.0_f32.to_radians())
+            * Mat4::from_scale(Vec3::new(2.0, 3.0, 4.0));
+
+        let result = mat4.inverse().unwrap() * mat4;
+
+        assert_approximately_equal(&Mat4::IDENTITY.to_cols_array(), &result.to_cols_array());
+        assert_eq!(None, Mat4::from_scale(Vec3::new(1.0, 1.0, 0.0)).inverse());
+    }
+
+    #[test]
+    fn when_a_mat3_is_converted_to_a_mat4_then_it_transforms_points_in_the_xy_plane_the_same_way() {
+        let mat3 = Mat3::from_scale_rotation_translation(
+            Vec2::new(2.0, 0.5),
+            60.0_f32.to_radians(),
+            Vec2::new(1.0, 2.0),
+        );
+
+        let point = mat3.transform_point(Vec2::new(3.0, -1.0));
+        let result = Mat4::from(mat3).transform_point(Vec3::new(3.0, -1.0, 0.5));
+
+        assert_approximately_equal(&[point.x, point.y, 0.5], &[result.x, result.y, result.z]);
+    }
+}
@@ -0,0 +1,288 @@
+use std::{error::Error, fmt};
+
+use crate::vectors::Vec2;
+
+#[derive(Debug, PartialEq)]
+pub enum TriangulationError {
+    TooFewPoints { number_of_points: usize },
+    DuplicatePoint { index: usize, first_index: usize },
+    ZeroArea,
+    SelfIntersecting { edge: usize, other_edge: usize },
+    NoEarFound { remaining_points: Vec<usize> },
+}
+
+impl fmt::Display for TriangulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TriangulationError::TooFewPoints { number_of_points } => {
+                write!(
+                    f,
+                    "a polygon needs at least 3 points, found {number_of_points}"
+                )
+            }
+            TriangulationError::DuplicatePoint { index, first_index } => {
+                write!(f, "point {index} is the same as point {first_index}")
+            }
+            TriangulationError::ZeroArea => write!(f, "the points enclose no area"),
+            TriangulationError::SelfIntersecting { edge, other_edge } => {
+                write!(f, "edge {edge} crosses edge {other_edge}")
+            }
+            TriangulationError::NoEarFound { remaining_points } => {
+                write!(
+                    f,
+                    "no ear could be clipped from points {remaining_points:?}; the outline may overlap itself"
+                )
+            }
+        }
+    }
+}
+
+impl Error for TriangulationError {}
+
+pub trait TriangulatePolygon {
+    fn triangulate_polygon(&self, points: &[Vec2]) -> Result<Vec<[usize; 3]>, TriangulationError>;
+}
+
+pub struct EarClippingTriangulator {}
+
+impl EarClippingTriangulator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn check_points(points: &[Vec2]) -> Result<(), TriangulationError> {
+        if points.len() < 3 {
+            return Err(TriangulationError::TooFewPoints {
+                number_of_points: points.len(),
+            });
+        }
+
+        for index in 1..points.len() {
+            if let Some(first_index) = points[..index]
+                .iter()
+                .position(|point| *point == points[index])
+            {
+                return Err(TriangulationError::DuplicatePoint { index, first_index });
+            }
+        }
+
+        for edge in 0..points.len() {
+            for other_edge in edge + 2..points.len() {
+                if edge == 0 && other_edge == points.len() - 1 {
+                    continue;
+                }
+
+                if edges_intersect(
+                    points[edge],
+                    points[(edge + 1) % points.len()],
+                    points[other_edge],
+                    points[(other_edge + 1) % points.len()],
+                ) {
+                    return Err(TriangulationError::SelfIntersecting { edge, other_edge });
+                }
+            }
+        }
+
+        if calculate_signed_area(points) == 0.0 {
+            return Err(TriangulationError::ZeroArea);
+        }
+
+        Ok(())
+    }
+
+    fn is_ear(points: &[Vec2], remaining: &[usize], position: usize) -> bool {
+        let previous = remaining[(position + remaining.len() - 1) % remaining.len()];
+        let current = remaining[position];
+        let next = remaining[(position + 1) % remaining.len()];
+
+        let (a, b, c) = (points[previous], points[current], points[next]);
+
+        if (b - a).cross(c - b) <= 0.0 {
+            return false;
+        }
+
+        !remaining.iter().any(|&index| {
+            index != previous
+                && index != current
+                && index != next
+                && triangle_contains(a, b, c, points[index])
+        })
+    }
+}
+
+impl Default for EarClippingTriangulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriangulatePolygon for EarClippingTriangulator {
+    fn triangulate_polygon(&self, points: &[Vec2]) -> Result<Vec<[usize; 3]>, TriangulationError> {
+        Self::check_points(points)?;
+
+        let mut remaining = (0..points.len()).collect::<Vec<usize>>();
+
+        if calculate_signed_area(points) < 0.0 {
+            remaining.reverse();
+        }
+
+        let mut triangles = vec![];
+
+        while remaining.len() > 3 {
+            let collinear = (0..remaining.len()).find(|&position| {
+                let previous =
+                    points[remaining[(position + remaining.len() - 1) % remaining.len()]];
+                let current = points[remaining[position]];
+                let next = points[remaining[(position + 1) % remaining.len()]];
+
+                (current - previous).cross(next - current) == 0.0
+            });
+
+            if let Some(position) = collinear {
+                remaining.remove(position);
+
+                continue;
+            }
+
+            let position = (0..remaining.len())
+                .find(|&position| Self::is_ear(points, &remaining, position))
+                .ok_or_else(|| TriangulationError::NoEarFound {
+                    remaining_points: remaining.clone(),
+                })?;
+
+            triangles.push([
+                remaining[(position + remaining.len() - 1) % remaining.len()],
+                remaining[position],
+                remaining[(position + 1) % remaining.len()],
+            ]);
+
+            remaining.remove(position);
+        }
+
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+
+        Ok(triangles)
+    }
+}
+
+fn calculate_signed_area(points: &[Vec2]) -> f32 {
+    let mut area = 0.0;
+
+    for index in 0..points.len() {
+        area += points[index].cross(points[(index + 1) % points.len()]);
+    }
+
+    area / 2.0
+}
+
+fn triangle_contains(a: Vec2, b: Vec2, c: Vec2, point: Vec2) -> bool {
+    (b - a).cross(point - a) >= 0.0
+        && (c - b).cross(point - b) >= 0.0
+        && (a - c).cross(point - c) >= 0.0
+}
+
+fn edges_intersect(p1: Vec2, p2: Vec2, q1: Vec2, q2: Vec2) -> bool {
+    let d1 = (q2 - q1).cross(p1 - q1);
+    let d2 = (q2 - q1).cross(p2 - q1);
+    let d3 = (p2 - p1).cross(q1 - p1);
+    let d4 = (p2 - p1).cross(q2 - p1);
+
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+
+    (d1 == 0.0 && edge_contains(q1, q2, p1))
+        || (d2 == 0.0 && edge_contains(q1, q2, p2))
+        || (d3 == 0.0 && edge_contains(p1, p2, q1))
+        || (d4 == 0.0 && edge_contains(p1, p2, q2))
+}
+
+fn edge_contains(start: Vec2, end: Vec2, point: Vec2) -> bool {
+    point.x >= start.x.min(end.x)
+        && point.x <= start.x.max(end.x)
+        && point.y >= start.y.min(end.y)
+        && point.y <= start.y.max(end.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        triangulation::{
+            calculate_signed_area, EarClippingTriangulator, TriangulatePolygon, TriangulationError,
+        },
+        vectors::Vec2,
+    };
+
+    fn create_points(coordinates: &[(f32, f32)]) -> Vec<Vec2> {
+        coordinates.iter().map(|&point| Vec2::from(point)).collect()
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)])]
+    #[case(&[(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (4.0, 0.0), (4.0, 1.0), (1.0, 1.0), (1.0, 4.0), (0.0, 4.0)])]
+    #[case(&[(0.0, 4.0), (1.0, 4.0), (1.0, 1.0), (4.0, 1.0), (4.0, 0.0), (0.0, 0.0)])]
+    #[case(&[(0.0, 0.0), (2.0, 1.0), (4.0, 0.0), (4.0, 4.0), (2.0, 3.0), (0.0, 4.0)])]
+    #[case(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)])]
+    fn when_an_ear_clipping_triangulator_triangulates_a_simple_polygon_then_the_triangles_cover_it_counter_clockwise(
+        #[case] coordinates: &[(f32, f32)],
+    ) {
+        let points = create_points(coordinates);
+
+        let triangulator = EarClippingTriangulator::new();
+
+        let result = triangulator.triangulate_polygon(&points).unwrap();
+
+        let triangle_areas = result
+            .iter()
+            .map(|triangle| {
+                calculate_signed_area(&[
+                    points[triangle[0]],
+                    points[triangle[1]],
+                    points[triangle[2]],
+                ])
+            })
+            .collect::<Vec<f32>>();
+
+        assert!(result.len() <= points.len() - 2);
+        assert!(triangle_areas.iter().all(|area| *area > 0.0));
+        assert_eq!(
+            calculate_signed_area(&points).abs(),
+            triangle_areas.iter().sum::<f32>()
+        );
+    }
+
+    #[rstest]
+    #[case(&[(0.0, 0.0), (1.0, 0.0)], TriangulationError::TooFewPoints { number_of_points: 2 })]
+    #[case(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (1.0, 0.0)], TriangulationError::DuplicatePoint { index: 3, first_index: 1 })]
+    #[case(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)], TriangulationError::ZeroArea)]
+    #[case(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)], TriangulationError::SelfIntersecting { edge: 0, other_edge: 2 })]
+    #[case(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 0.0), (0.0, 4.0)], TriangulationError::SelfIntersecting { edge: 0, other_edge: 2 })]
+    #[case(&[(0.30000022, 2e-7), (0.30000022, 0.2000002), (0.0, 0.3000001), (0.1000001, 0.2000001), (0.1000002, 0.2), (0.1, 0.2000001)], TriangulationError::NoEarFound { remaining_points: vec![2, 3, 4, 5] })]
+    fn when_an_ear_clipping_triangulator_triangulates_a_degenerate_polygon_then_an_error_is_returned(
+        #[case] coordinates: &[(f32, f32)],
+        #[case] expected_error: TriangulationError,
+    ) {
+        let points = create_points(coordinates);
+
+        let triangulator = EarClippingTriangulator::new();
+
+        let result = triangulator.triangulate_polygon(&points);
+
+        assert_eq!(Err(expected_error), result);
+    }
+
+    #[test]
+    fn when_no_ear_is_found_then_the_error_names_the_remaining_points() {
+        let error = TriangulationError::NoEarFound {
+            remaining_points: vec![2, 3, 4, 5],
+        };
+
+        assert_eq!(
+            "no ear could be clipped from points [2, 3, 4, 5]; the outline may overlap itself",
+            error.to_string()
+        );
+    }
+}
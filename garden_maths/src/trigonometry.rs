@@ -54,8 +54,16 @@ impl CalculateOpposite for OppositeCalculator {
     }
 }
 
+pub const MINIMUM_CIRCLE_SEGMENTS: u32 = 3;
+
+pub const MAXIMUM_CIRCLE_SEGMENTS: u32 = 360;
+
+pub trait CalculateCircleSegments {
+    fn calculate_circle_segments(&self, radius: f64, tolerance: f64) -> u32;
+}
+
 pub trait CalculateTrigonometry:
-    ConvertDegreesToRadians + CalculateAdjacent + CalculateOpposite
+    ConvertDegreesToRadians + CalculateAdjacent + CalculateOpposite + CalculateCircleSegments
 {
 }
 
@@ -118,6 +126,33 @@ impl<TDegreesToRadiansConverter, TAdjacentCalculator, TOppositeCalculator: Calcu
     }
 }
 
+impl<
+        TDegreesToRadiansConverter: ConvertDegreesToRadians,
+        TAdjacentCalculator: CalculateAdjacent,
+        TOppositeCalculator,
+    > CalculateCircleSegments
+    for TrigonometryCalculator<TDegreesToRadiansConverter, TAdjacentCalculator, TOppositeCalculator>
+{
+    fn calculate_circle_segments(&self, radius: f64, tolerance: f64) -> u32 {
+        for segments in MINIMUM_CIRCLE_SEGMENTS..MAXIMUM_CIRCLE_SEGMENTS {
+            let half_angle_radians = self
+                .degrees_to_radians_converter
+                .convert_degrees_to_radians(180.0 / segments as f64);
+
+            let chord_error = radius
+                - self
+                    .adjacent_calculator
+                    .calculate_adjacent(radius, half_angle_radians);
+
+            if chord_error <= tolerance {
+                return segments;
+            }
+        }
+
+        MAXIMUM_CIRCLE_SEGMENTS
+    }
+}
+
 impl<
         TDegreesToRadiansConverter: ConvertDegreesToRadians,
         TAdjacentCalculator: CalculateAdjacent,
@@ -129,11 +164,14 @@ impl<
 
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use rstest::rstest;
 
     use crate::trigonometry::{
-        AdjacentCalculator, CalculateAdjacent, CalculateOpposite, ConvertDegreesToRadians,
-        DegreesToRadiansConverter, OppositeCalculator,
+        AdjacentCalculator, CalculateAdjacent, CalculateCircleSegments, CalculateOpposite,
+        ConvertDegreesToRadians, DegreesToRadiansConverter, OppositeCalculator,
+        TrigonometryCalculator,
     };
 
     #[rstest]
@@ -196,4 +234,29 @@ mod tests {
 
         assert_eq!(expected_opposite, result);
     }
+
+    #[rstest]
+    #[case(1.0, 1.0, 3)]
+    #[case(1.0, 0.5, 3)]
+    #[case(1.0, 0.1, 7)]
+    #[case(1.0, 0.01, 23)]
+    #[case(10.0, 0.01, 71)]
+    #[case(100.0, 0.01, 223)]
+    #[case(1000.0, 0.01, 360)]
+    #[case(1.0, 0.0, 360)]
+    fn when_a_trigonometry_calculator_calculates_circle_segments_then_the_fewest_segments_within_the_tolerance_are_calculated(
+        #[case] radius: f64,
+        #[case] tolerance: f64,
+        #[case] expected_segments: u32,
+    ) {
+        let trigonometry_calculator = TrigonometryCalculator::new(
+            Rc::new(DegreesToRadiansConverter::new()),
+            Rc::new(AdjacentCalculator::new()),
+            Rc::new(OppositeCalculator::new()),
+        );
+
+        let result = trigonometry_calculator.calculate_circle_segments(radius, tolerance);
+
+        assert_eq!(expected_segments, result);
+    }
 }
@@ -0,0 +1,339 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 1.0);
+    pub const X: Self = Self::new(1.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(self, other: Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn distance(self, other: Self) -> f32 {
+        (other - self).length()
+    }
+
+    pub fn normalise(self) -> Option<Self> {
+        let length = self.length();
+
+        if length == 0.0 || !length.is_finite() {
+            return None;
+        }
+
+        Some(self / length)
+    }
+
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    pub fn perpendicular(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate(self, angle_radians: f32) -> Self {
+        let (sin, cos) = angle_radians.sin_cos();
+
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    pub fn extend(self, z: f32) -> Vec3 {
+        Vec3::new(self.x, self.y, z)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f32> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, scalar: f32) -> Self {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Mul<Vec2> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, other: Vec2) -> Self {
+        Self::new(self.x * other.x, self.y * other.y)
+    }
+}
+
+impl MulAssign<f32> for Vec2 {
+    fn mul_assign(&mut self, scalar: f32) {
+        *self = *self * scalar;
+    }
+}
+
+impl Div<f32> for Vec2 {
+    type Output = Self;
+
+    fn div(self, scalar: f32) -> Self {
+        Self::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl From<(f32, f32)> for Vec2 {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Vec2> for (f32, f32) {
+    fn from(vec2: Vec2) -> Self {
+        (vec2.x, vec2.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn distance(self, other: Self) -> f32 {
+        (other - self).length()
+    }
+
+    pub fn normalise(self) -> Option<Self> {
+        let length = self.length();
+
+        if length == 0.0 || !length.is_finite() {
+            return None;
+        }
+
+        Some(self / length)
+    }
+
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    pub fn truncate(self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Self;
+
+    fn mul(self, scalar: f32) -> Self {
+        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Mul<Vec3> for Vec3 {
+    type Output = Self;
+
+    fn mul(self, other: Vec3) -> Self {
+        Self::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+}
+
+impl MulAssign<f32> for Vec3 {
+    fn mul_assign(&mut self, scalar: f32) {
+        *self = *self * scalar;
+    }
+}
+
+impl Div<f32> for Vec3 {
+    type Output = Self;
+
+    fn div(self, scalar: f32) -> Self {
+        Self::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::vectors::{Vec2, Vec3};
+
+    #[rstest]
+    #[case(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0), 11.0, -2.0)]
+    #[case(Vec2::X, Vec2::Y, 0.0, 1.0)]
+    #[case(Vec2::Y, Vec2::X, 0.0, -1.0)]
+    fn when_two_vec2s_are_multiplied_then_the_dot_and_cross_products_are_calculated(
+        #[case] a: Vec2,
+        #[case] b: Vec2,
+        #[case] expected_dot: f32,
+        #[case] expected_cross: f32,
+    ) {
+        assert_eq!(expected_dot, a.dot(b));
+        assert_eq!(expected_cross, a.cross(b));
+    }
+
+    #[test]
+    fn when_a_vec2_is_normalised_then_it_has_a_length_of_one_and_a_zero_vec2_cannot_be_normalised()
+    {
+        let vec2 = Vec2::new(3.0, 4.0);
+
+        assert_eq!(5.0, vec2.length());
+        assert_eq!(Some(Vec2::new(0.6, 0.8)), vec2.normalise());
+        assert_eq!(None, Vec2::ZERO.normalise());
+    }
+
+    #[rstest]
+    #[case(0.0, Vec2::new(-2.0, 4.0))]
+    #[case(0.25, Vec2::new(-1.0, 3.0))]
+    #[case(1.0, Vec2::new(2.0, 0.0))]
+    fn when_two_vec2s_are_lerped_then_the_result_is_between_them(
+        #[case] t: f32,
+        #[case] expected_result: Vec2,
+    ) {
+        let result = Vec2::new(-2.0, 4.0).lerp(Vec2::new(2.0, 0.0), t);
+
+        assert_eq!(expected_result, result);
+    }
+
+    #[test]
+    fn when_two_vec3s_are_crossed_then_the_result_is_perpendicular_to_both() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-2.0, 0.5, 4.0);
+
+        let result = a.cross(b);
+
+        assert_eq!(Vec3::Z, Vec3::X.cross(Vec3::Y));
+        assert_eq!(0.0, result.dot(a));
+        assert_eq!(0.0, result.dot(b));
+    }
+
+    #[test]
+    fn when_a_vec3_is_normalised_then_it_has_a_length_of_one() {
+        let vec3 = Vec3::new(2.0, 3.0, 6.0);
+
+        assert_eq!(7.0, vec3.length());
+        assert_eq!(
+            Some(Vec3::new(2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0)),
+            vec3.normalise()
+        );
+        assert_eq!(None, Vec3::ZERO.normalise());
+    }
+}
@@ -0,0 +1,620 @@
+use std::{io, rc::Rc};
+
+use garden::{
+    input::{
+        convert_mouse_button_to_name, find_key_by_name, find_mouse_button_by_name, InputEvent,
+        InputRecording, Key, MouseButton, RecordedInputEvent, SaveInputRecording,
+    },
+    GetName,
+};
+use garden_json::{
+    convert_f32_to_json, create_object_json_schema, write_json_file, ConvertJsonToValue,
+    GetJsonSchema, JsonToF32Converter, JsonToStringConverter, JsonToU64Converter,
+    ReadJsonDocuments,
+};
+use garden_loading::{Load, LoadError};
+use serde_json::{json, Value};
+
+pub struct InputRecordingLoader<TJsonToInputRecordingConverter, TJsonDocumentsReader> {
+    json_to_input_recording_converter: TJsonToInputRecordingConverter,
+    json_documents_reader: TJsonDocumentsReader,
+}
+
+impl<TJsonToInputRecordingConverter, TJsonDocumentsReader>
+    InputRecordingLoader<TJsonToInputRecordingConverter, TJsonDocumentsReader>
+{
+    fn new(
+        json_to_input_recording_converter: TJsonToInputRecordingConverter,
+        json_documents_reader: TJsonDocumentsReader,
+    ) -> Self {
+        Self {
+            json_to_input_recording_converter,
+            json_documents_reader,
+        }
+    }
+}
+
+impl<
+        TJsonToInputRecordingConverter: ConvertJsonToValue<InputRecording>,
+        TJsonDocumentsReader: ReadJsonDocuments,
+    > Load<InputRecording>
+    for InputRecordingLoader<TJsonToInputRecordingConverter, TJsonDocumentsReader>
+{
+    fn load(self) -> Result<InputRecording, LoadError> {
+        let json_documents = self.json_documents_reader.read_json_documents()?;
+
+        let mut recorded_input_events = Vec::new();
+        let mut number_of_ticks = 0;
+
+        for json_document in json_documents.iter() {
+            let input_recording = self
+                .json_to_input_recording_converter
+                .convert_json_to_value(json_document.get_json())
+                .map_err(|error| error.in_file(json_document.get_file_path()))?;
+
+            recorded_input_events.extend_from_slice(input_recording.get_recorded_input_events());
+            number_of_ticks = number_of_ticks.max(input_recording.get_number_of_ticks());
+        }
+
+        Ok(InputRecording::new(recorded_input_events, number_of_ticks))
+    }
+}
+
+impl<TJsonToInputRecordingConverter: GetJsonSchema, TJsonDocumentsReader> GetJsonSchema
+    for InputRecordingLoader<TJsonToInputRecordingConverter, TJsonDocumentsReader>
+{
+    fn get_json_schema(&self) -> Value {
+        self.json_to_input_recording_converter.get_json_schema()
+    }
+}
+
+pub struct InputRecordingFileSaver {
+    file_path: String,
+}
+
+impl InputRecordingFileSaver {
+    pub fn new(file_path: &str) -> Self {
+        Self {
+            file_path: file_path.to_string(),
+        }
+    }
+}
+
+impl SaveInputRecording for InputRecordingFileSaver {
+    fn save_input_recording(&self, input_recording: &InputRecording) -> io::Result<()> {
+        write_json_file(
+            &self.file_path,
+            &convert_input_recording_to_json(input_recording),
+        )
+    }
+}
+
+pub struct JsonToInputEventConverter<TJsonToStringConverter, TJsonToF32Converter> {
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+}
+
+impl<TJsonToStringConverter, TJsonToF32Converter>
+    JsonToInputEventConverter<TJsonToStringConverter, TJsonToF32Converter>
+{
+    fn new(
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        json_to_f32_converter: Rc<TJsonToF32Converter>,
+    ) -> Self {
+        Self {
+            json_to_string_converter,
+            json_to_f32_converter,
+        }
+    }
+}
+
+impl<
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+    > JsonToInputEventConverter<TJsonToStringConverter, TJsonToF32Converter>
+{
+    fn convert_json_to_key(&self, json: &Value) -> Result<Key, LoadError> {
+        let name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["key"])
+            .map_err(|error| error.within("key"))?;
+
+        find_key_by_name(&name).ok_or_else(|| LoadError::unknown_value(name).within("key"))
+    }
+
+    fn convert_json_to_mouse_button(&self, json: &Value) -> Result<MouseButton, LoadError> {
+        let name = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["button"])
+            .map_err(|error| error.within("button"))?;
+
+        find_mouse_button_by_name(&name)
+            .ok_or_else(|| LoadError::unknown_value(name).within("button"))
+    }
+
+    fn convert_json_to_pair(
+        &self,
+        json: &Value,
+        first_name: &str,
+        second_name: &str,
+    ) -> Result<(f32, f32), LoadError> {
+        let first = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json[first_name])
+            .map_err(|error| error.within(first_name))?;
+
+        let second = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json[second_name])
+            .map_err(|error| error.within(second_name))?;
+
+        Ok((first, second))
+    }
+}
+
+impl<
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+    > ConvertJsonToValue<InputEvent>
+    for JsonToInputEventConverter<TJsonToStringConverter, TJsonToF32Converter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<InputEvent, LoadError> {
+        let value_type = self
+            .json_to_string_converter
+            .convert_json_to_value(&json["type"])
+            .map_err(|error| error.within("type"))?;
+
+        match value_type.as_str() {
+            "keyPressed" => Ok(InputEvent::KeyPressed(self.convert_json_to_key(json)?)),
+            "keyReleased" => Ok(InputEvent::KeyReleased(self.convert_json_to_key(json)?)),
+            "mouseButtonPressed" => Ok(InputEvent::MouseButtonPressed(
+                self.convert_json_to_mouse_button(json)?,
+            )),
+            "mouseButtonReleased" => Ok(InputEvent::MouseButtonReleased(
+                self.convert_json_to_mouse_button(json)?,
+            )),
+            "mouseMoved" => {
+                let (x, y) = self.convert_json_to_pair(json, "x", "y")?;
+
+                Ok(InputEvent::MouseMoved { x, y })
+            }
+            "mouseWheelScrolled" => {
+                let (x, y) = self.convert_json_to_pair(json, "x", "y")?;
+
+                Ok(InputEvent::MouseWheelScrolled { x, y })
+            }
+            "windowResized" => {
+                let (width, height) = self.convert_json_to_pair(json, "width", "height")?;
+
+                Ok(InputEvent::WindowResized { width, height })
+            }
+            _ => Err(LoadError::unknown_type(value_type).within("type")),
+        }
+    }
+}
+
+impl<TJsonToStringConverter: GetJsonSchema, TJsonToF32Converter: GetJsonSchema> GetJsonSchema
+    for JsonToInputEventConverter<TJsonToStringConverter, TJsonToF32Converter>
+{
+    fn get_json_schema(&self) -> Value {
+        let string_json_schema = self.json_to_string_converter.get_json_schema();
+        let f32_json_schema = self.json_to_f32_converter.get_json_schema();
+
+        let json_schemas = [
+            ("keyPressed", vec![("key", string_json_schema.clone())]),
+            ("keyReleased", vec![("key", string_json_schema.clone())]),
+            (
+                "mouseButtonPressed",
+                vec![("button", string_json_schema.clone())],
+            ),
+            (
+                "mouseButtonReleased",
+                vec![("button", string_json_schema.clone())],
+            ),
+            (
+                "mouseMoved",
+                vec![
+                    ("x", f32_json_schema.clone()),
+                    ("y", f32_json_schema.clone()),
+                ],
+            ),
+            (
+                "mouseWheelScrolled",
+                vec![
+                    ("x", f32_json_schema.clone()),
+                    ("y", f32_json_schema.clone()),
+                ],
+            ),
+            (
+                "windowResized",
+                vec![
+                    ("width", f32_json_schema.clone()),
+                    ("height", f32_json_schema.clone()),
+                ],
+            ),
+        ]
+        .into_iter()
+        .map(|(value_type, mut properties)| {
+            properties.insert(0, ("type", json!({ "const": value_type })));
+
+            create_object_json_schema(properties)
+        })
+        .collect::<Vec<Value>>();
+
+        json!({ "oneOf": json_schemas })
+    }
+}
+
+pub struct JsonToRecordedInputEventConverter<TJsonToU64Converter, TJsonToInputEventConverter> {
+    json_to_u64_converter: Rc<TJsonToU64Converter>,
+    json_to_input_event_converter: Rc<TJsonToInputEventConverter>,
+}
+
+impl<TJsonToU64Converter, TJsonToInputEventConverter>
+    JsonToRecordedInputEventConverter<TJsonToU64Converter, TJsonToInputEventConverter>
+{
+    fn new(
+        json_to_u64_converter: Rc<TJsonToU64Converter>,
+        json_to_input_event_converter: Rc<TJsonToInputEventConverter>,
+    ) -> Self {
+        Self {
+            json_to_u64_converter,
+            json_to_input_event_converter,
+        }
+    }
+}
+
+impl<
+        TJsonToU64Converter: ConvertJsonToValue<u64>,
+        TJsonToInputEventConverter: ConvertJsonToValue<InputEvent>,
+    > ConvertJsonToValue<RecordedInputEvent>
+    for JsonToRecordedInputEventConverter<TJsonToU64Converter, TJsonToInputEventConverter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<RecordedInputEvent, LoadError> {
+        let tick = self
+            .json_to_u64_converter
+            .convert_json_to_value(&json["tick"])
+            .map_err(|error| error.within("tick"))?;
+
+        let input_event = self
+            .json_to_input_event_converter
+            .convert_json_to_value(&json["event"])
+            .map_err(|error| error.within("event"))?;
+
+        Ok(RecordedInputEvent::new(tick, input_event))
+    }
+}
+
+impl<TJsonToU64Converter: GetJsonSchema, TJsonToInputEventConverter: GetJsonSchema> GetJsonSchema
+    for JsonToRecordedInputEventConverter<TJsonToU64Converter, TJsonToInputEventConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            ("tick", self.json_to_u64_converter.get_json_schema()),
+            (
+                "event",
+                self.json_to_input_event_converter.get_json_schema(),
+            ),
+        ])
+    }
+}
+
+pub struct JsonToInputRecordingConverter<TJsonToU64Converter, TJsonToRecordedInputEventConverter> {
+    json_to_u64_converter: Rc<TJsonToU64Converter>,
+    json_to_recorded_input_event_converter: Rc<TJsonToRecordedInputEventConverter>,
+}
+
+impl<TJsonToU64Converter, TJsonToRecordedInputEventConverter>
+    JsonToInputRecordingConverter<TJsonToU64Converter, TJsonToRecordedInputEventConverter>
+{
+    fn new(
+        json_to_u64_converter: Rc<TJsonToU64Converter>,
+        json_to_recorded_input_event_converter: Rc<TJsonToRecordedInputEventConverter>,
+    ) -> Self {
+        Self {
+            json_to_u64_converter,
+            json_to_recorded_input_event_converter,
+        }
+    }
+}
+
+impl<
+        TJsonToU64Converter: ConvertJsonToValue<u64>,
+        TJsonToRecordedInputEventConverter: ConvertJsonToValue<RecordedInputEvent>,
+    > ConvertJsonToValue<InputRecording>
+    for JsonToInputRecordingConverter<TJsonToU64Converter, TJsonToRecordedInputEventConverter>
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<InputRecording, LoadError> {
+        let number_of_ticks = self
+            .json_to_u64_converter
+            .convert_json_to_value(&json["numberOfTicks"])
+            .map_err(|error| error.within("numberOfTicks"))?;
+
+        let events_json = match &json["events"] {
+            Value::Null => return Err(LoadError::missing_field().within("events")),
+            Value::Array(events_json) => events_json,
+            _ => return Err(LoadError::wrong_field_type("array").within("events")),
+        };
+
+        let mut recorded_input_events = Vec::new();
+
+        for (index, event_json) in events_json.iter().enumerate() {
+            let recorded_input_event = self
+                .json_to_recorded_input_event_converter
+                .convert_json_to_value(event_json)
+                .map_err(|error| error.within(&index.to_string()).within("events"))?;
+
+            recorded_input_events.push(recorded_input_event);
+        }
+
+        Ok(InputRecording::new(recorded_input_events, number_of_ticks))
+    }
+}
+
+impl<TJsonToU64Converter: GetJsonSchema, TJsonToRecordedInputEventConverter: GetJsonSchema>
+    GetJsonSchema
+    for JsonToInputRecordingConverter<TJsonToU64Converter, TJsonToRecordedInputEventConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema(vec![
+            (
+                "numberOfTicks",
+                self.json_to_u64_converter.get_json_schema(),
+            ),
+            (
+                "events",
+                json!({
+                    "type": "array",
+                    "items": self.json_to_recorded_input_event_converter.get_json_schema()
+                }),
+            ),
+        ])
+    }
+}
+
+pub fn convert_input_event_to_json(input_event: &InputEvent) -> Value {
+    match *input_event {
+        InputEvent::KeyPressed(key) => json!({ "type": "keyPressed", "key": key.get_name() }),
+        InputEvent::KeyReleased(key) => json!({ "type": "keyReleased", "key": key.get_name() }),
+        InputEvent::MouseButtonPressed(mouse_button) => json!({
+            "type": "mouseButtonPressed",
+            "button": convert_mouse_button_to_name(mouse_button)
+        }),
+        InputEvent::MouseButtonReleased(mouse_button) => json!({
+            "type": "mouseButtonReleased",
+            "button": convert_mouse_button_to_name(mouse_button)
+        }),
+        InputEvent::MouseMoved { x, y } => json!({
+            "type": "mouseMoved",
+            "x": convert_f32_to_json(x),
+            "y": convert_f32_to_json(y)
+        }),
+        InputEvent::MouseWheelScrolled { x, y } => json!({
+            "type": "mouseWheelScrolled",
+            "x": convert_f32_to_json(x),
+            "y": convert_f32_to_json(y)
+        }),
+        InputEvent::WindowResized { width, height } => json!({
+            "type": "windowResized",
+            "width": convert_f32_to_json(width),
+            "height": convert_f32_to_json(height)
+        }),
+    }
+}
+
+pub fn convert_input_recording_to_json(input_recording: &InputRecording) -> Value {
+    let events = input_recording
+        .get_recorded_input_events()
+        .iter()
+        .map(|recorded_input_event| {
+            json!({
+                "tick": recorded_input_event.get_tick(),
+                "event": convert_input_event_to_json(recorded_input_event.get_input_event())
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    json!({
+        "numberOfTicks": input_recording.get_number_of_ticks(),
+        "events": events
+    })
+}
+
+pub type ComposedJsonToInputRecordingConverter = JsonToInputRecordingConverter<
+    JsonToU64Converter,
+    JsonToRecordedInputEventConverter<
+        JsonToU64Converter,
+        JsonToInputEventConverter<JsonToStringConverter, JsonToF32Converter>,
+    >,
+>;
+
+pub fn compose_json_to_input_recording_converter(
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_u64_converter: Rc<JsonToU64Converter>,
+) -> ComposedJsonToInputRecordingConverter {
+    let json_to_input_event_converter = Rc::new(JsonToInputEventConverter::new(
+        json_to_string_converter,
+        json_to_f32_converter,
+    ));
+
+    let json_to_recorded_input_event_converter = Rc::new(JsonToRecordedInputEventConverter::new(
+        json_to_u64_converter.clone(),
+        json_to_input_event_converter,
+    ));
+
+    JsonToInputRecordingConverter::new(
+        json_to_u64_converter,
+        json_to_recorded_input_event_converter,
+    )
+}
+
+pub fn compose_input_recording_loader<TJsonDocumentsReader: ReadJsonDocuments>(
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_u64_converter: Rc<JsonToU64Converter>,
+    json_documents_reader: TJsonDocumentsReader,
+) -> InputRecordingLoader<ComposedJsonToInputRecordingConverter, TJsonDocumentsReader> {
+    InputRecordingLoader::new(
+        compose_json_to_input_recording_converter(
+            json_to_string_converter,
+            json_to_f32_converter,
+            json_to_u64_converter,
+        ),
+        json_documents_reader,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process, rc::Rc};
+
+    use garden::input::{
+        InputEvent, InputRecording, Key, MouseButton, RecordedInputEvent, SaveInputRecording,
+    };
+    use garden_json::{
+        ConvertJsonToValue, GetJsonSchema, JsonFileReader, JsonSchemaValidator, JsonToF32Converter,
+        JsonToStringConverter, JsonToU64Converter, ValidateJson,
+    };
+    use garden_loading::{Load, LoadError};
+    use serde_json::json;
+
+    use crate::{
+        compose_input_recording_loader, compose_json_to_input_recording_converter,
+        convert_input_recording_to_json, InputRecordingFileSaver,
+    };
+
+    fn create_input_recording() -> InputRecording {
+        InputRecording::new(
+            vec![
+                RecordedInputEvent::new(
+                    1,
+                    InputEvent::WindowResized {
+                        width: 800.0,
+                        height: 600.0,
+                    },
+                ),
+                RecordedInputEvent::new(3, InputEvent::KeyPressed(Key::Space)),
+                RecordedInputEvent::new(3, InputEvent::MouseMoved { x: 10.5, y: -2.25 }),
+                RecordedInputEvent::new(4, InputEvent::MouseButtonPressed(MouseButton::Other(8))),
+                RecordedInputEvent::new(9, InputEvent::MouseWheelScrolled { x: 0.0, y: 1.0 }),
+                RecordedInputEvent::new(12, InputEvent::MouseButtonReleased(MouseButton::Other(8))),
+                RecordedInputEvent::new(12, InputEvent::KeyReleased(Key::Space)),
+            ],
+            20,
+        )
+    }
+
+    fn compose_converter_for_test() -> impl ConvertJsonToValue<InputRecording> + GetJsonSchema {
+        compose_json_to_input_recording_converter(
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToU64Converter::new()),
+        )
+    }
+
+    #[test]
+    fn when_an_input_recording_is_converted_to_json_and_back_then_it_is_unchanged() {
+        let input_recording = create_input_recording();
+
+        let json = convert_input_recording_to_json(&input_recording);
+
+        let result = compose_converter_for_test()
+            .convert_json_to_value(&json)
+            .unwrap();
+
+        assert_eq!(20, result.get_number_of_ticks());
+        assert_eq!(
+            input_recording.get_recorded_input_events(),
+            result.get_recorded_input_events()
+        );
+    }
+
+    #[test]
+    fn when_an_input_recording_is_converted_to_json_then_it_matches_the_schema() {
+        let json_to_input_recording_converter = compose_converter_for_test();
+
+        let json_schema_validator =
+            JsonSchemaValidator::new(json_to_input_recording_converter.get_json_schema());
+
+        assert_eq!(
+            Ok(()),
+            json_schema_validator
+                .validate_json(&convert_input_recording_to_json(&create_input_recording()))
+        );
+        assert_eq!(
+            Err(LoadError::wrong_field_type("integer")
+                .within("tick")
+                .within("0")
+                .within("events")),
+            json_schema_validator.validate_json(&json!({
+                "numberOfTicks": 2,
+                "events": [{ "tick": 1.5, "event": { "type": "keyPressed", "key": "A" } }]
+            }))
+        );
+    }
+
+    #[test]
+    fn when_a_recorded_event_names_an_unknown_key_then_an_unknown_value_error_is_returned() {
+        let json = json!({
+            "numberOfTicks": 2,
+            "events": [
+                { "tick": 1, "event": { "type": "keyPressed", "key": "A" } },
+                { "tick": 2, "event": { "type": "keyPressed", "key": "Hyper" } }
+            ]
+        });
+
+        let result = compose_converter_for_test().convert_json_to_value(&json);
+
+        assert_eq!(
+            Err(LoadError::unknown_value("Hyper".to_string())
+                .within("key")
+                .within("event")
+                .within("1")
+                .within("events")),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn when_an_input_recording_is_saved_to_a_file_then_it_can_be_loaded_from_the_file() {
+        let file_path = env::temp_dir().join(format!(
+            "garden_replay_when_an_input_recording_is_saved_to_a_file_{}.json",
+            process::id()
+        ));
+        let file_path = file_path.to_str().unwrap();
+
+        InputRecordingFileSaver::new(file_path)
+            .save_input_recording(&create_input_recording())
+            .unwrap();
+
+        let result = compose_input_recording_loader(
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToU64Converter::new()),
+            JsonFileReader::new(file_path),
+        )
+        .load();
+
+        fs::remove_file(file_path).unwrap();
+
+        let input_recording = result.unwrap();
+        assert_eq!(20, input_recording.get_number_of_ticks());
+        assert_eq!(
+            create_input_recording().get_recorded_input_events(),
+            input_recording.get_recorded_input_events()
+        );
+    }
+
+    #[test]
+    fn when_an_input_recording_is_saved_to_a_missing_directory_then_an_error_is_returned() {
+        let file_path = env::temp_dir()
+            .join(format!("garden_replay_missing_directory_{}", process::id()))
+            .join("recording.json");
+
+        let result = InputRecordingFileSaver::new(file_path.to_str().unwrap())
+            .save_input_recording(&create_input_recording());
+
+        assert!(result.is_err());
+    }
+}
@@ -1,13 +1,34 @@
-use garden::{GetHeight, GetWidth};
+use garden::{
+    viewport::{GetScaleMode, ScaleMode},
+    GetBackgroundColour, GetHeight, GetName, GetWidth,
+};
+use garden_content::{GetB, GetG, GetR, Rgb};
+use garden_json::{convert_f32_to_json, ConvertToJson};
+use serde_json::{json, Value};
 
 pub struct TwoDScene {
     width: f32,
     height: f32,
+    scale_mode: ScaleMode,
+    background_rgb: Option<Rgb>,
 }
 
 impl TwoDScene {
-    pub fn new(width: f32, height: f32) -> Self {
-        Self { width, height }
+    pub fn new(width: f32, height: f32, scale_mode: ScaleMode) -> Self {
+        Self {
+            width,
+            height,
+            scale_mode,
+            background_rgb: None,
+        }
+    }
+
+    pub fn get_background_rgb(&self) -> Option<&Rgb> {
+        self.background_rgb.as_ref()
+    }
+
+    pub fn set_background_rgb(&mut self, background_rgb: Rgb) {
+        self.background_rgb = Some(background_rgb);
     }
 }
 
@@ -23,17 +44,53 @@ impl GetHeight for TwoDScene {
     }
 }
 
+impl GetScaleMode for TwoDScene {
+    fn get_scale_mode(&self) -> ScaleMode {
+        self.scale_mode
+    }
+}
+
+impl GetBackgroundColour for TwoDScene {
+    fn get_background_colour(&self) -> Option<(f32, f32, f32)> {
+        self.background_rgb
+            .as_ref()
+            .map(|rgb| (rgb.get_r(), rgb.get_g(), rgb.get_b()))
+    }
+}
+
+impl ConvertToJson for TwoDScene {
+    fn convert_to_json(&self) -> Value {
+        let mut json = json!({
+            "width": convert_f32_to_json(self.width),
+            "height": convert_f32_to_json(self.height),
+            "scaleMode": self.scale_mode.get_name()
+        });
+
+        if let Some(background_rgb) = &self.background_rgb {
+            json["backgroundRgb"] = background_rgb.convert_to_json();
+        }
+
+        json
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::TwoDScene;
-    use garden::{GetHeight, GetWidth};
+    use garden::{
+        viewport::{GetScaleMode, ScaleMode},
+        GetBackgroundColour, GetHeight, GetWidth,
+    };
+    use garden_content::Rgb;
+    use garden_json::ConvertToJson;
+    use serde_json::json;
 
     #[test]
     fn when_a_two_d_scene_gets_its_width_then_the_width_is_returned() {
         let width = 123.45;
         let height = 0.0;
 
-        let two_d_point = TwoDScene::new(width, height);
+        let two_d_point = TwoDScene::new(width, height, ScaleMode::Expand);
 
         let result = two_d_point.get_width();
 
@@ -45,10 +102,62 @@ mod tests {
         let width = 0.0;
         let height = 123.45;
 
-        let two_d_point = TwoDScene::new(width, height);
+        let two_d_point = TwoDScene::new(width, height, ScaleMode::Expand);
 
         let result = two_d_point.get_height();
 
         assert_eq!(result, height);
     }
+
+    #[test]
+    fn when_a_two_d_scene_gets_its_scale_mode_then_the_scale_mode_is_returned() {
+        let two_d_scene = TwoDScene::new(0.0, 0.0, ScaleMode::Letterbox);
+
+        let result = two_d_scene.get_scale_mode();
+
+        assert_eq!(ScaleMode::Letterbox, result);
+    }
+
+    #[test]
+    fn when_a_two_d_scene_is_converted_to_json_then_the_width_height_and_scale_mode_are_converted()
+    {
+        let two_d_scene = TwoDScene::new(123.45, 678.9, ScaleMode::Integer);
+
+        let result = two_d_scene.convert_to_json();
+
+        assert_eq!(
+            json!({ "width": 123.45, "height": 678.9, "scaleMode": "integer" }),
+            result
+        );
+    }
+
+    #[test]
+    fn when_a_two_d_scene_without_a_background_rgb_gets_its_background_colour_then_none_is_returned(
+    ) {
+        let two_d_scene = TwoDScene::new(0.0, 0.0, ScaleMode::Expand);
+
+        let result = two_d_scene.get_background_colour();
+
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn when_a_two_d_scene_with_a_background_rgb_is_converted_to_json_then_the_background_rgb_is_converted(
+    ) {
+        let mut two_d_scene = TwoDScene::new(800.0, 600.0, ScaleMode::Expand);
+        two_d_scene.set_background_rgb(Rgb::new(0.25, 0.5, 0.75));
+
+        let result = two_d_scene.convert_to_json();
+
+        assert_eq!(Some((0.25, 0.5, 0.75)), two_d_scene.get_background_colour());
+        assert_eq!(
+            json!({
+                "width": 800.0,
+                "height": 600.0,
+                "scaleMode": "expand",
+                "backgroundRgb": { "r": 0.25, "g": 0.5, "b": 0.75 }
+            }),
+            result
+        );
+    }
 }
@@ -1,19 +1,25 @@
-use garden::{
-    gl, Initialise, OnCreateGlutinVbo, OnDraw, RunEndComponent, RunFullComponent, RunLoop,
-};
+use garden::{DrawComponent, RunComponentLifecycle, UpdateComponent};
 
+use garden_json::JsonFileReader;
 use garden_json_component::JsonComponent;
-use garden_loading::Load;
+use garden_loading::{Load, LoadError};
 use garden_scenes::TwoDScene;
 use garden_scenes_loading::compose_scene_loader;
 
-pub fn compose_component(json_component: &JsonComponent) -> ScenesComponent<TwoDScene> {
-    let scene_loader = compose_scene_loader(json_component.get_json_to_f32_converter());
-    let scene = scene_loader.load();
+pub fn compose_component(
+    json_component: &JsonComponent,
+    file_path: &str,
+) -> Result<ScenesComponent<TwoDScene>, LoadError> {
+    let scene_loader = compose_scene_loader(
+        json_component.get_json_to_f32_converter(),
+        json_component.get_json_to_string_converter(),
+        JsonFileReader::new(file_path),
+    );
+    let scene = scene_loader.load()?;
 
     let content_component = ScenesComponent::new(scene);
 
-    content_component
+    Ok(content_component)
 }
 
 pub trait GetScene<TScene> {
@@ -30,27 +36,11 @@ impl<TScene> ScenesComponent<TScene> {
     }
 }
 
-impl<TScene> Initialise for ScenesComponent<TScene> {
-    fn initialise(&self) {}
-}
-
-impl<TScene> RunLoop for ScenesComponent<TScene> {
-    fn run_loop(&self) {}
-}
-
-impl<TScene> RunEndComponent for ScenesComponent<TScene> {
-    fn run_end_component(self) {}
-}
-
-impl<TScene> OnDraw for ScenesComponent<TScene> {
-    unsafe fn on_draw(&self, gl: &garden::gl::Gl) {}
-}
+impl<TScene> RunComponentLifecycle for ScenesComponent<TScene> {}
 
-impl<TScene> OnCreateGlutinVbo for ScenesComponent<TScene> {
-    unsafe fn on_create_glutin_vbo(&self, gl: &gl::Gl) {}
-}
+impl<TScene> UpdateComponent for ScenesComponent<TScene> {}
 
-impl<TScene> RunFullComponent for ScenesComponent<TScene> {}
+impl<TScene> DrawComponent for ScenesComponent<TScene> {}
 
 impl<TScene> GetScene<TScene> for ScenesComponent<TScene> {
     fn get_scene(&self) -> &TScene {
@@ -1,111 +1,348 @@
-use std::{fs, rc::Rc};
+use std::rc::Rc;
 
-use garden_json::{ConvertJsonToValue, JsonToF32Converter};
-use garden_loading::Load;
+use garden::viewport::{find_scale_mode_by_name, ScaleMode, SCALE_MODE_NAMES};
+use garden_content::Rgb;
+use garden_content_loading::JsonToRgbConverter;
+use garden_json::{
+    create_enum_json_schema, create_object_json_schema_with_optional_properties,
+    ConvertJsonToValue, GetJsonSchema, JsonToF32Converter, JsonToStringConverter,
+    ReadJsonDocuments,
+};
+use garden_loading::{Load, LoadError};
 use garden_scenes::TwoDScene;
-use serde_json::Value;
+use serde_json::{json, Value};
 
-pub struct SceneLoader<TJsonToSceneConverter> {
+pub struct SceneLoader<TJsonToSceneConverter, TJsonDocumentsReader> {
     json_to_scene_converter: TJsonToSceneConverter,
+    json_documents_reader: TJsonDocumentsReader,
 }
 
-impl<TJsonToSceneConverter> SceneLoader<TJsonToSceneConverter> {
-    fn new(json_to_scene_converter: TJsonToSceneConverter) -> Self {
+impl<TJsonToSceneConverter, TJsonDocumentsReader>
+    SceneLoader<TJsonToSceneConverter, TJsonDocumentsReader>
+{
+    fn new(
+        json_to_scene_converter: TJsonToSceneConverter,
+        json_documents_reader: TJsonDocumentsReader,
+    ) -> Self {
         Self {
             json_to_scene_converter,
+            json_documents_reader,
         }
     }
 }
 
-impl<TJsonToF32Converter: ConvertJsonToValue<TwoDScene>> Load<TwoDScene>
-    for SceneLoader<TJsonToF32Converter>
+impl<
+        TJsonToSceneConverter: ConvertJsonToValue<TwoDScene>,
+        TJsonDocumentsReader: ReadJsonDocuments,
+    > Load<TwoDScene> for SceneLoader<TJsonToSceneConverter, TJsonDocumentsReader>
 {
-    fn load(self) -> TwoDScene {
-        let file_contents = fs::read_to_string("content.json").unwrap();
+    fn load(self) -> Result<TwoDScene, LoadError> {
+        let json_documents = self.json_documents_reader.read_json_documents()?;
+
+        let mut scene_json_documents = json_documents
+            .iter()
+            .filter(|json_document| !json_document.get_json()["scene"].is_null());
+
+        let scene_json_document = match scene_json_documents.next() {
+            Some(scene_json_document) => scene_json_document,
+            None => {
+                let file_path = json_documents
+                    .first()
+                    .map_or("", |json_document| json_document.get_file_path());
 
-        let json: Value = serde_json::from_str(file_contents.as_str()).unwrap();
+                return Err(LoadError::missing_field()
+                    .within("scene")
+                    .in_file(file_path));
+            }
+        };
+
+        if let Some(duplicate_scene_json_document) = scene_json_documents.next() {
+            return Err(LoadError::DuplicateName {
+                name: "scene".to_string(),
+                file_path: duplicate_scene_json_document.get_file_path().to_string(),
+                first_file_path: scene_json_document.get_file_path().to_string(),
+            });
+        }
 
         self.json_to_scene_converter
-            .convert_json_to_value(&json["scene"])
+            .convert_json_to_value(&scene_json_document.get_json()["scene"])
+            .map_err(|error| {
+                error
+                    .within("scene")
+                    .in_file(scene_json_document.get_file_path())
+            })
     }
 }
 
-pub struct JsonToSceneConverter<TJsonToF32Converter> {
+impl<TJsonToSceneConverter: GetJsonSchema, TJsonDocumentsReader> GetJsonSchema
+    for SceneLoader<TJsonToSceneConverter, TJsonDocumentsReader>
+{
+    fn get_json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "scene": self.json_to_scene_converter.get_json_schema()
+            }
+        })
+    }
+}
+
+pub struct JsonToSceneConverter<TJsonToF32Converter, TJsonToStringConverter, TJsonToRgbConverter> {
     json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_rgb_converter: Rc<TJsonToRgbConverter>,
 }
 
-impl<TJsonToF32Converter> JsonToSceneConverter<TJsonToF32Converter> {
-    fn new(json_to_f32_converter: Rc<TJsonToF32Converter>) -> Self {
+impl<TJsonToF32Converter, TJsonToStringConverter, TJsonToRgbConverter>
+    JsonToSceneConverter<TJsonToF32Converter, TJsonToStringConverter, TJsonToRgbConverter>
+{
+    fn new(
+        json_to_f32_converter: Rc<TJsonToF32Converter>,
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        json_to_rgb_converter: Rc<TJsonToRgbConverter>,
+    ) -> Self {
         Self {
             json_to_f32_converter,
+            json_to_string_converter,
+            json_to_rgb_converter,
         }
     }
 }
 
-impl<TJsonToF32Converter: ConvertJsonToValue<f32>> ConvertJsonToValue<TwoDScene>
-    for JsonToSceneConverter<TJsonToF32Converter>
+impl<
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToRgbConverter: ConvertJsonToValue<Rgb>,
+    > ConvertJsonToValue<TwoDScene>
+    for JsonToSceneConverter<TJsonToF32Converter, TJsonToStringConverter, TJsonToRgbConverter>
 {
-    fn convert_json_to_value(&self, json: &Value) -> TwoDScene {
+    fn convert_json_to_value(&self, json: &Value) -> Result<TwoDScene, LoadError> {
         let width = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["width"]);
+            .convert_json_to_value(&json["width"])
+            .map_err(|error| error.within("width"))?;
 
         let height = self
             .json_to_f32_converter
-            .convert_json_to_value(&json["height"]);
+            .convert_json_to_value(&json["height"])
+            .map_err(|error| error.within("height"))?;
+
+        let scale_mode = match &json["scaleMode"] {
+            Value::Null => ScaleMode::default(),
+            scale_mode => {
+                let name = self
+                    .json_to_string_converter
+                    .convert_json_to_value(scale_mode)
+                    .map_err(|error| error.within("scaleMode"))?;
 
-        TwoDScene::new(width, height)
+                find_scale_mode_by_name(&name)
+                    .ok_or_else(|| LoadError::unknown_value(name).within("scaleMode"))?
+            }
+        };
+
+        let mut scene = TwoDScene::new(width, height, scale_mode);
+
+        if !json["backgroundRgb"].is_null() {
+            let background_rgb = self
+                .json_to_rgb_converter
+                .convert_json_to_value(&json["backgroundRgb"])
+                .map_err(|error| error.within("backgroundRgb"))?;
+
+            scene.set_background_rgb(background_rgb);
+        }
+
+        Ok(scene)
+    }
+}
+
+impl<
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToRgbConverter: GetJsonSchema,
+    > GetJsonSchema
+    for JsonToSceneConverter<TJsonToF32Converter, TJsonToStringConverter, TJsonToRgbConverter>
+{
+    fn get_json_schema(&self) -> Value {
+        create_object_json_schema_with_optional_properties(
+            vec![
+                ("width", self.json_to_f32_converter.get_json_schema()),
+                ("height", self.json_to_f32_converter.get_json_schema()),
+            ],
+            vec![
+                (
+                    "scaleMode",
+                    create_enum_json_schema(&SCALE_MODE_NAMES.map(|(_, name)| name)),
+                ),
+                (
+                    "backgroundRgb",
+                    self.json_to_rgb_converter.get_json_schema(),
+                ),
+            ],
+        )
     }
 }
 
 pub fn compose_json_to_scene_converter(
     json_to_f32_converter: Rc<JsonToF32Converter>,
-) -> JsonToSceneConverter<JsonToF32Converter> {
-    JsonToSceneConverter::new(json_to_f32_converter)
+    json_to_string_converter: Rc<JsonToStringConverter>,
+) -> JsonToSceneConverter<
+    JsonToF32Converter,
+    JsonToStringConverter,
+    JsonToRgbConverter<JsonToF32Converter>,
+> {
+    let json_to_rgb_converter = Rc::new(JsonToRgbConverter::new(Rc::clone(&json_to_f32_converter)));
+
+    JsonToSceneConverter::new(
+        json_to_f32_converter,
+        json_to_string_converter,
+        json_to_rgb_converter,
+    )
 }
 
-pub fn compose_scene_loader(
+pub fn compose_scene_loader<TJsonDocumentsReader: ReadJsonDocuments>(
     json_to_f32_converter: Rc<JsonToF32Converter>,
-) -> SceneLoader<JsonToSceneConverter<JsonToF32Converter>> {
-    SceneLoader::new(compose_json_to_scene_converter(json_to_f32_converter))
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    json_documents_reader: TJsonDocumentsReader,
+) -> SceneLoader<
+    JsonToSceneConverter<
+        JsonToF32Converter,
+        JsonToStringConverter,
+        JsonToRgbConverter<JsonToF32Converter>,
+    >,
+    TJsonDocumentsReader,
+> {
+    SceneLoader::new(
+        compose_json_to_scene_converter(json_to_f32_converter, json_to_string_converter),
+        json_documents_reader,
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use std::rc::Rc;
+    use std::{env, fs, path::PathBuf, process, rc::Rc};
 
-    use garden::{GetHeight, GetWidth};
-    use garden_json::{ConvertJsonToValue, JsonToF32Converter};
+    use garden::{
+        viewport::{GetScaleMode, ScaleMode},
+        GetBackgroundColour, GetHeight, GetWidth,
+    };
+    use garden_content_loading::JsonToRgbConverter;
+    use garden_json::{
+        ConvertJsonToValue, ConvertToJson, GetJsonSchema, JsonFileReader, JsonReader,
+        JsonSchemaValidator, JsonToF32Converter, JsonToStringConverter, ValidateJson,
+    };
+    use garden_loading::{Load, LoadError};
     use garden_scenes::TwoDScene;
     use mockall::{mock, predicate};
     use serde_json::{json, Value};
 
-    use crate::{compose_json_to_scene_converter, JsonToSceneConverter};
+    use crate::{compose_json_to_scene_converter, compose_scene_loader, JsonToSceneConverter};
+
+    fn create_test_directory(test_name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!(
+            "garden_scenes_loading_{test_name}_{}",
+            process::id()
+        ));
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
 
     #[test]
     fn when_a_json_to_scene_converter_is_composed_and_converts_json_to_a_two_d_scene_then_the_two_d_scene_is_converted(
     ) {
         let json_to_f32_converter = Rc::new(JsonToF32Converter::new());
 
-        let json_to_scene_converter = compose_json_to_scene_converter(json_to_f32_converter);
+        let json_to_scene_converter = compose_json_to_scene_converter(
+            json_to_f32_converter,
+            Rc::new(JsonToStringConverter::new()),
+        );
 
         let json = json!({
             "width": 123.45,
             "height": 678.90
         });
 
-        let expected_result = TwoDScene::new(123.45, 678.90);
+        let expected_result = TwoDScene::new(123.45, 678.90, ScaleMode::Expand);
 
-        let result = json_to_scene_converter.convert_json_to_value(&json);
+        let result = json_to_scene_converter
+            .convert_json_to_value(&json)
+            .unwrap();
 
         assert_eq!(expected_result.get_width(), result.get_width());
         assert_eq!(expected_result.get_height(), result.get_height());
     }
 
+    #[test]
+    fn when_a_two_d_scene_is_converted_to_json_and_back_then_the_two_d_scene_is_unchanged() {
+        let json_to_scene_converter = compose_json_to_scene_converter(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+        );
+
+        let two_d_scene = json_to_scene_converter
+            .convert_json_to_value(&json!({
+                "width": 800.0,
+                "height": 600.5,
+                "scaleMode": "letterbox",
+                "backgroundRgb": { "r": 0.2, "g": 0.4, "b": 0.6 }
+            }))
+            .unwrap();
+
+        let result = json_to_scene_converter
+            .convert_json_to_value(&two_d_scene.convert_to_json())
+            .unwrap();
+
+        assert_eq!(two_d_scene.get_width(), result.get_width());
+        assert_eq!(two_d_scene.get_height(), result.get_height());
+        assert_eq!(ScaleMode::Letterbox, result.get_scale_mode());
+        assert_eq!(Some((0.2, 0.4, 0.6)), result.get_background_colour());
+    }
+
+    #[test]
+    fn when_a_scene_loader_gets_its_json_schema_then_the_scene_block_is_validated() {
+        let scene_loader = compose_scene_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            JsonReader::new("fixture", "{}".as_bytes()),
+        );
+
+        let json_schema_validator = JsonSchemaValidator::new(scene_loader.get_json_schema());
+
+        assert_eq!(
+            Ok(()),
+            json_schema_validator.validate_json(&json!({
+                "scene": { "width": 10.0, "height": 10.0 },
+                "content": { "objects": [] }
+            }))
+        );
+        assert_eq!(
+            Err(LoadError::wrong_field_type("number")
+                .within("height")
+                .within("scene")),
+            json_schema_validator.validate_json(&json!({
+                "scene": { "width": 10.0, "height": "10" }
+            }))
+        );
+        assert_eq!(
+            Ok(()),
+            json_schema_validator.validate_json(&json!({
+                "scene": { "width": 10.0, "height": 10.0, "scaleMode": "integer" }
+            }))
+        );
+        assert_eq!(
+            Err(LoadError::unknown_value("fit".to_string())
+                .within("scaleMode")
+                .within("scene")),
+            json_schema_validator.validate_json(&json!({
+                "scene": { "width": 10.0, "height": 10.0, "scaleMode": "fit" }
+            }))
+        );
+    }
+
     mock! {
         JsonToF32Converter {}
         impl ConvertJsonToValue<f32> for JsonToF32Converter {
-            fn convert_json_to_value(&self, json: &Value) -> f32;
+            fn convert_json_to_value(&self, json: &Value) -> Result<f32, LoadError>;
         }
     }
 
@@ -125,23 +362,179 @@ mod tests {
             .expect_convert_json_to_value()
             .with(predicate::eq(width))
             .times(1)
-            .returning(|x| 123.45);
+            .returning(|x| Ok(123.45));
         json_to_f32_converter
             .expect_convert_json_to_value()
             .with(predicate::eq(height))
             .times(1)
-            .returning(|x| 678.90);
+            .returning(|x| Ok(678.90));
 
         let json_to_f32_converter_rc = Rc::new(json_to_f32_converter);
 
-        let expected_result = TwoDScene::new(123.45, 678.90);
+        let expected_result = TwoDScene::new(123.45, 678.90, ScaleMode::Expand);
 
-        let json_to_scene_converter =
-            JsonToSceneConverter::new(Rc::clone(&json_to_f32_converter_rc));
+        let json_to_scene_converter = JsonToSceneConverter::new(
+            Rc::clone(&json_to_f32_converter_rc),
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(JsonToRgbConverter::new(Rc::new(JsonToF32Converter::new()))),
+        );
 
-        let result = json_to_scene_converter.convert_json_to_value(&json);
+        let result = json_to_scene_converter
+            .convert_json_to_value(&json)
+            .unwrap();
 
         assert_eq!(expected_result.get_width(), result.get_width());
         assert_eq!(expected_result.get_height(), result.get_height());
     }
+
+    #[test]
+    fn when_a_json_to_scene_converter_converts_json_without_a_height_then_a_missing_field_error_is_returned(
+    ) {
+        let json_to_f32_converter = Rc::new(JsonToF32Converter::new());
+
+        let json_to_scene_converter = compose_json_to_scene_converter(
+            json_to_f32_converter,
+            Rc::new(JsonToStringConverter::new()),
+        );
+
+        let json = json!({
+            "width": 123.45
+        });
+
+        let result = json_to_scene_converter.convert_json_to_value(&json);
+
+        assert_eq!(
+            Some(LoadError::MissingField {
+                path: "/height".to_string(),
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_scene_converter_converts_json_with_an_unknown_scale_mode_then_an_unknown_value_error_is_returned(
+    ) {
+        let json_to_scene_converter = compose_json_to_scene_converter(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+        );
+
+        let result = json_to_scene_converter.convert_json_to_value(&json!({
+            "width": 800.0,
+            "height": 600.0,
+            "scaleMode": "fit"
+        }));
+
+        assert_eq!(
+            Some(LoadError::unknown_value("fit".to_string()).within("scaleMode")),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_json_to_scene_converter_converts_json_with_an_invalid_background_rgb_then_the_error_is_within_the_background_rgb(
+    ) {
+        let json_to_scene_converter = compose_json_to_scene_converter(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+        );
+
+        let result = json_to_scene_converter.convert_json_to_value(&json!({
+            "width": 800.0,
+            "height": 600.0,
+            "backgroundRgb": { "r": 0.2, "g": 0.4 }
+        }));
+
+        assert_eq!(
+            Some(
+                LoadError::missing_field()
+                    .within("b")
+                    .within("backgroundRgb")
+            ),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_scene_loader_loads_from_a_reader_then_the_two_d_scene_is_loaded() {
+        let scene_loader = compose_scene_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            JsonReader::new(
+                "fixture",
+                r#"{ "scene": { "width": 100.0, "height": 50.0 } }"#.as_bytes(),
+            ),
+        );
+
+        let result = scene_loader.load().unwrap();
+
+        assert_eq!(100.0, result.get_width());
+        assert_eq!(50.0, result.get_height());
+        assert_eq!(ScaleMode::Expand, result.get_scale_mode());
+        assert_eq!(None, result.get_background_colour());
+    }
+
+    #[test]
+    fn when_a_scene_loader_loads_a_manifest_with_two_scenes_then_a_duplicate_name_error_is_returned(
+    ) {
+        let directory = create_test_directory("manifest_test");
+        fs::write(
+            directory.join("manifest.json"),
+            r#"{ "files": ["scene-1.json", "scene-2.json"] }"#,
+        )
+        .unwrap();
+        fs::write(
+            directory.join("scene-1.json"),
+            r#"{ "scene": { "width": 100.0, "height": 50.0 } }"#,
+        )
+        .unwrap();
+        fs::write(
+            directory.join("scene-2.json"),
+            r#"{ "scene": { "width": 200.0, "height": 50.0 } }"#,
+        )
+        .unwrap();
+
+        let scene_loader = compose_scene_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            JsonFileReader::new(directory.join("manifest.json").to_str().unwrap()),
+        );
+
+        let result = scene_loader.load();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            Some(LoadError::DuplicateName {
+                name: "scene".to_string(),
+                file_path: directory.join("scene-2.json").to_str().unwrap().to_string(),
+                first_file_path: directory.join("scene-1.json").to_str().unwrap().to_string(),
+            }),
+            result.err()
+        );
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn when_a_scene_loader_loads_a_ron_file_then_the_two_d_scene_is_loaded() {
+        let directory = create_test_directory("ron_test");
+        fs::write(
+            directory.join("scene.ron"),
+            "// Level 1\n{ \"scene\": { \"width\": 100.0, \"height\": 50.0, }, }\n",
+        )
+        .unwrap();
+
+        let scene_loader = compose_scene_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            JsonFileReader::new(directory.join("scene.ron").to_str().unwrap()),
+        );
+
+        let result = scene_loader.load().unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(100.0, result.get_width());
+        assert_eq!(50.0, result.get_height());
+    }
 }
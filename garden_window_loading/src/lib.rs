@@ -0,0 +1,485 @@
+use std::rc::Rc;
+
+use garden::window::{find_fullscreen_mode_by_name, WindowSettings, FULLSCREEN_MODE_NAMES};
+use garden_json::{
+    create_enum_json_schema, create_object_json_schema,
+    create_object_json_schema_with_optional_properties, ConvertJsonToValue, GetJsonSchema,
+    JsonToBoolConverter, JsonToF32Converter, JsonToStringConverter, JsonToU64Converter,
+    ReadJsonDocuments,
+};
+use garden_loading::{Load, LoadError};
+use serde_json::{json, Value};
+
+pub struct WindowSettingsLoader<TJsonToWindowSettingsConverter, TJsonDocumentsReader> {
+    json_to_window_settings_converter: TJsonToWindowSettingsConverter,
+    json_documents_reader: TJsonDocumentsReader,
+}
+
+impl<TJsonToWindowSettingsConverter, TJsonDocumentsReader>
+    WindowSettingsLoader<TJsonToWindowSettingsConverter, TJsonDocumentsReader>
+{
+    fn new(
+        json_to_window_settings_converter: TJsonToWindowSettingsConverter,
+        json_documents_reader: TJsonDocumentsReader,
+    ) -> Self {
+        Self {
+            json_to_window_settings_converter,
+            json_documents_reader,
+        }
+    }
+}
+
+impl<
+        TJsonToWindowSettingsConverter: ConvertJsonToValue<WindowSettings>,
+        TJsonDocumentsReader: ReadJsonDocuments,
+    > Load<WindowSettings>
+    for WindowSettingsLoader<TJsonToWindowSettingsConverter, TJsonDocumentsReader>
+{
+    fn load(self) -> Result<WindowSettings, LoadError> {
+        let json_documents = self.json_documents_reader.read_json_documents()?;
+
+        let mut window_json_documents = json_documents
+            .iter()
+            .filter(|json_document| !json_document.get_json()["window"].is_null());
+
+        let window_json_document = match window_json_documents.next() {
+            Some(window_json_document) => window_json_document,
+            None => return Ok(WindowSettings::new()),
+        };
+
+        if let Some(duplicate_window_json_document) = window_json_documents.next() {
+            return Err(LoadError::DuplicateName {
+                name: "window".to_string(),
+                file_path: duplicate_window_json_document.get_file_path().to_string(),
+                first_file_path: window_json_document.get_file_path().to_string(),
+            });
+        }
+
+        self.json_to_window_settings_converter
+            .convert_json_to_value(&window_json_document.get_json()["window"])
+            .map_err(|error| {
+                error
+                    .within("window")
+                    .in_file(window_json_document.get_file_path())
+            })
+    }
+}
+
+impl<TJsonToWindowSettingsConverter: GetJsonSchema, TJsonDocumentsReader> GetJsonSchema
+    for WindowSettingsLoader<TJsonToWindowSettingsConverter, TJsonDocumentsReader>
+{
+    fn get_json_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "window": self.json_to_window_settings_converter.get_json_schema()
+            }
+        })
+    }
+}
+
+pub struct JsonToWindowSettingsConverter<
+    TJsonToF32Converter,
+    TJsonToU64Converter,
+    TJsonToStringConverter,
+    TJsonToBoolConverter,
+> {
+    json_to_f32_converter: Rc<TJsonToF32Converter>,
+    json_to_u64_converter: Rc<TJsonToU64Converter>,
+    json_to_string_converter: Rc<TJsonToStringConverter>,
+    json_to_bool_converter: Rc<TJsonToBoolConverter>,
+}
+
+impl<TJsonToF32Converter, TJsonToU64Converter, TJsonToStringConverter, TJsonToBoolConverter>
+    JsonToWindowSettingsConverter<
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToStringConverter,
+        TJsonToBoolConverter,
+    >
+{
+    fn new(
+        json_to_f32_converter: Rc<TJsonToF32Converter>,
+        json_to_u64_converter: Rc<TJsonToU64Converter>,
+        json_to_string_converter: Rc<TJsonToStringConverter>,
+        json_to_bool_converter: Rc<TJsonToBoolConverter>,
+    ) -> Self {
+        Self {
+            json_to_f32_converter,
+            json_to_u64_converter,
+            json_to_string_converter,
+            json_to_bool_converter,
+        }
+    }
+}
+
+impl<
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToU64Converter: ConvertJsonToValue<u64>,
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToBoolConverter: ConvertJsonToValue<bool>,
+    >
+    JsonToWindowSettingsConverter<
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToStringConverter,
+        TJsonToBoolConverter,
+    >
+{
+    fn convert_json_to_size(&self, json: &Value) -> Result<(u32, u32), LoadError> {
+        let width = self
+            .json_to_u64_converter
+            .convert_json_to_value(&json["width"])
+            .map_err(|error| error.within("width"))?;
+
+        let height = self
+            .json_to_u64_converter
+            .convert_json_to_value(&json["height"])
+            .map_err(|error| error.within("height"))?;
+
+        Ok((width as u32, height as u32))
+    }
+
+    fn convert_json_to_clear_colour(&self, json: &Value) -> Result<(f32, f32, f32), LoadError> {
+        let red = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["r"])
+            .map_err(|error| error.within("r"))?;
+
+        let green = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["g"])
+            .map_err(|error| error.within("g"))?;
+
+        let blue = self
+            .json_to_f32_converter
+            .convert_json_to_value(&json["b"])
+            .map_err(|error| error.within("b"))?;
+
+        Ok((red, green, blue))
+    }
+}
+
+impl<
+        TJsonToF32Converter: ConvertJsonToValue<f32>,
+        TJsonToU64Converter: ConvertJsonToValue<u64>,
+        TJsonToStringConverter: ConvertJsonToValue<String>,
+        TJsonToBoolConverter: ConvertJsonToValue<bool>,
+    > ConvertJsonToValue<WindowSettings>
+    for JsonToWindowSettingsConverter<
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToStringConverter,
+        TJsonToBoolConverter,
+    >
+{
+    fn convert_json_to_value(&self, json: &Value) -> Result<WindowSettings, LoadError> {
+        let mut window_settings = WindowSettings::new();
+
+        if !json["title"].is_null() {
+            let title = self
+                .json_to_string_converter
+                .convert_json_to_value(&json["title"])
+                .map_err(|error| error.within("title"))?;
+
+            window_settings.set_title(&title);
+        }
+
+        if !json["size"].is_null() {
+            let (width, height) = self
+                .convert_json_to_size(&json["size"])
+                .map_err(|error| error.within("size"))?;
+
+            window_settings.set_size(width, height);
+        }
+
+        if !json["minSize"].is_null() {
+            let (width, height) = self
+                .convert_json_to_size(&json["minSize"])
+                .map_err(|error| error.within("minSize"))?;
+
+            window_settings.set_min_size(width, height);
+        }
+
+        if !json["pixelsPerUnit"].is_null() {
+            let pixels_per_unit = self
+                .json_to_f32_converter
+                .convert_json_to_value(&json["pixelsPerUnit"])
+                .map_err(|error| error.within("pixelsPerUnit"))?;
+
+            if pixels_per_unit <= 0.0 {
+                return Err(LoadError::invalid_value(
+                    pixels_per_unit.to_string(),
+                    "must be > 0".to_string(),
+                )
+                .within("pixelsPerUnit"));
+            }
+
+            window_settings.set_pixels_per_unit(pixels_per_unit);
+        }
+
+        if !json["fullscreen"].is_null() {
+            let name = self
+                .json_to_string_converter
+                .convert_json_to_value(&json["fullscreen"])
+                .map_err(|error| error.within("fullscreen"))?;
+
+            let fullscreen_mode = find_fullscreen_mode_by_name(&name)
+                .ok_or_else(|| LoadError::unknown_value(name).within("fullscreen"))?;
+
+            window_settings.set_fullscreen_mode(fullscreen_mode);
+        }
+
+        if !json["resizable"].is_null() {
+            let resizable = self
+                .json_to_bool_converter
+                .convert_json_to_value(&json["resizable"])
+                .map_err(|error| error.within("resizable"))?;
+
+            window_settings.set_resizable(resizable);
+        }
+
+        if !json["vsync"].is_null() {
+            let vsync = self
+                .json_to_bool_converter
+                .convert_json_to_value(&json["vsync"])
+                .map_err(|error| error.within("vsync"))?;
+
+            window_settings.set_vsync(vsync);
+        }
+
+        if !json["clearColour"].is_null() {
+            let (red, green, blue) = self
+                .convert_json_to_clear_colour(&json["clearColour"])
+                .map_err(|error| error.within("clearColour"))?;
+
+            window_settings.set_clear_colour(red, green, blue);
+        }
+
+        Ok(window_settings)
+    }
+}
+
+impl<
+        TJsonToF32Converter: GetJsonSchema,
+        TJsonToU64Converter: GetJsonSchema,
+        TJsonToStringConverter: GetJsonSchema,
+        TJsonToBoolConverter: GetJsonSchema,
+    > GetJsonSchema
+    for JsonToWindowSettingsConverter<
+        TJsonToF32Converter,
+        TJsonToU64Converter,
+        TJsonToStringConverter,
+        TJsonToBoolConverter,
+    >
+{
+    fn get_json_schema(&self) -> Value {
+        let size_json_schema = create_object_json_schema(vec![
+            ("width", self.json_to_u64_converter.get_json_schema()),
+            ("height", self.json_to_u64_converter.get_json_schema()),
+        ]);
+
+        create_object_json_schema_with_optional_properties(
+            vec![],
+            vec![
+                ("title", self.json_to_string_converter.get_json_schema()),
+                ("size", size_json_schema.clone()),
+                ("minSize", size_json_schema),
+                (
+                    "pixelsPerUnit",
+                    self.json_to_f32_converter.get_json_schema(),
+                ),
+                (
+                    "fullscreen",
+                    create_enum_json_schema(&FULLSCREEN_MODE_NAMES.map(|(_, name)| name)),
+                ),
+                ("resizable", self.json_to_bool_converter.get_json_schema()),
+                ("vsync", self.json_to_bool_converter.get_json_schema()),
+                (
+                    "clearColour",
+                    create_object_json_schema(vec![
+                        ("r", self.json_to_f32_converter.get_json_schema()),
+                        ("g", self.json_to_f32_converter.get_json_schema()),
+                        ("b", self.json_to_f32_converter.get_json_schema()),
+                    ]),
+                ),
+            ],
+        )
+    }
+}
+
+pub fn compose_json_to_window_settings_converter(
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_u64_converter: Rc<JsonToU64Converter>,
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    json_to_bool_converter: Rc<JsonToBoolConverter>,
+) -> JsonToWindowSettingsConverter<
+    JsonToF32Converter,
+    JsonToU64Converter,
+    JsonToStringConverter,
+    JsonToBoolConverter,
+> {
+    JsonToWindowSettingsConverter::new(
+        json_to_f32_converter,
+        json_to_u64_converter,
+        json_to_string_converter,
+        json_to_bool_converter,
+    )
+}
+
+pub fn compose_window_settings_loader<TJsonDocumentsReader: ReadJsonDocuments>(
+    json_to_f32_converter: Rc<JsonToF32Converter>,
+    json_to_u64_converter: Rc<JsonToU64Converter>,
+    json_to_string_converter: Rc<JsonToStringConverter>,
+    json_to_bool_converter: Rc<JsonToBoolConverter>,
+    json_documents_reader: TJsonDocumentsReader,
+) -> WindowSettingsLoader<
+    JsonToWindowSettingsConverter<
+        JsonToF32Converter,
+        JsonToU64Converter,
+        JsonToStringConverter,
+        JsonToBoolConverter,
+    >,
+    TJsonDocumentsReader,
+> {
+    WindowSettingsLoader::new(
+        compose_json_to_window_settings_converter(
+            json_to_f32_converter,
+            json_to_u64_converter,
+            json_to_string_converter,
+            json_to_bool_converter,
+        ),
+        json_documents_reader,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use garden::window::{FullscreenMode, WindowSettings};
+    use garden_json::{
+        GetJsonSchema, JsonReader, JsonSchemaValidator, JsonToBoolConverter, JsonToF32Converter,
+        JsonToStringConverter, JsonToU64Converter, ValidateJson,
+    };
+    use garden_loading::{Load, LoadError};
+    use serde_json::json;
+
+    use crate::compose_window_settings_loader;
+
+    fn load_window_settings(json: &str) -> Result<WindowSettings, LoadError> {
+        compose_window_settings_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToU64Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(JsonToBoolConverter::new()),
+            JsonReader::new("fixture", json.as_bytes()),
+        )
+        .load()
+    }
+
+    #[test]
+    fn when_a_window_settings_loader_loads_a_window_block_then_every_setting_is_loaded() {
+        let result = load_window_settings(
+            r#"{
+                "window": {
+                    "title": "Garden",
+                    "size": { "width": 1280, "height": 720 },
+                    "minSize": { "width": 640, "height": 360 },
+                    "pixelsPerUnit": 16.0,
+                    "fullscreen": "borderless",
+                    "resizable": false,
+                    "vsync": false,
+                    "clearColour": { "r": 0.5, "g": 0.25, "b": 1.0 }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(Some("Garden"), result.get_title());
+        assert_eq!(Some((1280, 720)), result.get_size());
+        assert_eq!(Some((640, 360)), result.get_min_size());
+        assert_eq!(16.0, result.get_pixels_per_unit());
+        assert_eq!(FullscreenMode::Borderless, result.get_fullscreen_mode());
+        assert!(!result.is_resizable());
+        assert!(!result.is_vsync_enabled());
+        assert_eq!((0.5, 0.25, 1.0), result.get_clear_colour());
+    }
+
+    #[test]
+    fn when_a_window_settings_loader_loads_documents_without_a_window_block_then_the_default_settings_are_loaded(
+    ) {
+        let result = load_window_settings(r#"{ "scene": { "width": 10.0, "height": 10.0 } }"#);
+
+        assert_eq!(Ok(WindowSettings::new()), result);
+    }
+
+    #[test]
+    fn when_a_window_settings_loader_loads_an_unknown_fullscreen_mode_then_an_unknown_value_error_is_returned(
+    ) {
+        let result = load_window_settings(r#"{ "window": { "fullscreen": "full" } }"#);
+
+        assert_eq!(
+            Some(
+                LoadError::unknown_value("full".to_string())
+                    .within("fullscreen")
+                    .within("window")
+                    .in_file("fixture")
+            ),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn when_a_window_settings_loader_loads_a_pixels_per_unit_that_is_not_positive_then_an_invalid_value_error_is_returned(
+    ) {
+        for (pixels_per_unit, expected_value) in [("0.0", "0"), ("-2.5", "-2.5")] {
+            let result = load_window_settings(&format!(
+                r#"{{ "window": {{ "pixelsPerUnit": {pixels_per_unit} }} }}"#
+            ));
+
+            assert_eq!(
+                Some(
+                    LoadError::invalid_value(expected_value.to_string(), "must be > 0".to_string())
+                        .within("pixelsPerUnit")
+                        .within("window")
+                        .in_file("fixture")
+                ),
+                result.err()
+            );
+        }
+    }
+
+    #[test]
+    fn when_a_window_settings_loader_gets_its_json_schema_then_the_window_block_is_validated() {
+        let window_settings_loader = compose_window_settings_loader(
+            Rc::new(JsonToF32Converter::new()),
+            Rc::new(JsonToU64Converter::new()),
+            Rc::new(JsonToStringConverter::new()),
+            Rc::new(JsonToBoolConverter::new()),
+            JsonReader::new("fixture", "{}".as_bytes()),
+        );
+
+        let json_schema_validator =
+            JsonSchemaValidator::new(window_settings_loader.get_json_schema());
+
+        assert_eq!(
+            Ok(()),
+            json_schema_validator.validate_json(&json!({
+                "window": { "size": { "width": 800, "height": 600 }, "vsync": true }
+            }))
+        );
+        assert_eq!(
+            Err(LoadError::wrong_field_type("boolean")
+                .within("vsync")
+                .within("window")),
+            json_schema_validator.validate_json(&json!({ "window": { "vsync": "on" } }))
+        );
+        assert_eq!(
+            Err(LoadError::unknown_value("full".to_string())
+                .within("fullscreen")
+                .within("window")),
+            json_schema_validator.validate_json(&json!({ "window": { "fullscreen": "full" } }))
+        );
+    }
+}
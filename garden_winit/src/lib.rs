@@ -1,9 +1,20 @@
-use garden::{Create, GetName, RunFullComponent};
+use std::{cell::RefCell, io, rc::Rc};
+
+use garden::{
+    camera::Camera,
+    input::{InputEvent, Key, LiveInputEventStream, MouseButton, RunInputEventStream},
+    window::WindowSettings,
+    AddComponent, Create, GetName, RunFullComponent, RunLoopSystem, SetInputEventStream,
+    SetTickRate,
+};
 use garden_games::{
     create_end_system, create_game_name_provider, create_start_system, End, EndEngine, EndSystem,
     GameNameProvider, Start, StartEngine, StartSystem,
 };
-use winit::event_loop::EventLoop;
+use winit::{
+    event::{ElementState, MouseScrollDelta, VirtualKeyCode, WindowEvent},
+    event_loop::EventLoop,
+};
 
 pub struct GameInstance<'a, TEngine> {
     name: &'a str,
@@ -17,17 +28,17 @@ impl<'a, TEngine: RunEngine> GameInstance<'a, TEngine> {
 }
 
 impl<'a, TEngine: RunEngine> RunGameInstance for GameInstance<'a, TEngine> {
-    fn run_game_instance(self, event_loop: EventLoop<()>) {
-        self.engine.run_engine(event_loop)
+    fn run_game_instance(self) -> io::Result<()> {
+        self.engine.run_engine()
     }
 }
 
 pub trait CreateLoopSystem<TLoopSystem> {
-    fn create_loop_system(&self, event_loop: &EventLoop<()>) -> TLoopSystem;
+    fn create_loop_system(&self, event_loop: EventLoop<()>) -> TLoopSystem;
 }
 
 pub trait RunEngine {
-    fn run_engine(self, event_loop: EventLoop<()>);
+    fn run_engine(self) -> io::Result<()>;
 }
 
 pub trait RunLoop {
@@ -35,11 +46,7 @@ pub trait RunLoop {
 }
 
 pub trait RunGameInstance {
-    fn run_game_instance(self, event_loop: EventLoop<()>);
-}
-
-pub trait RunLoopSystem {
-    fn run_loop_system(self, event_loop: EventLoop<()>, components: Vec<Box<dyn RunFullComponent>>);
+    fn run_game_instance(self) -> io::Result<()>;
 }
 
 pub struct Engine<
@@ -53,6 +60,7 @@ pub struct Engine<
     end_system: TEndSystem,
     game_name_provider: TGetName,
     components: Vec<Box<dyn RunFullComponent>>,
+    input_event_stream: Box<dyn RunInputEventStream>,
 }
 
 impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End, TGetName: GetName>
@@ -64,6 +72,7 @@ impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End, TGetName:
         end_system: TEndSystem,
         game_name_provider: TGetName,
         components: Vec<Box<dyn RunFullComponent>>,
+        input_event_stream: Box<dyn RunInputEventStream>,
     ) -> Self {
         Self {
             start_system,
@@ -71,6 +80,7 @@ impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End, TGetName:
             end_system,
             game_name_provider,
             components,
+            input_event_stream,
         }
     }
 }
@@ -78,31 +88,43 @@ impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End, TGetName:
 impl<TStartSystem: Start, TLoopSystem: RunLoopSystem, TEndSystem: End, TGetName: GetName> RunEngine
     for Engine<TStartSystem, TLoopSystem, TEndSystem, TGetName>
 {
-    fn run_engine(self, event_loop: EventLoop<()>) {
+    fn run_engine(mut self) -> io::Result<()> {
         self.start_system.start();
 
-        for component in self.components.iter() {
+        for component in self.components.iter_mut() {
             component.initialise();
         }
 
-        self.loop_system
-            .run_loop_system(event_loop, self.components);
+        let result = self
+            .loop_system
+            .run_loop_system(&mut self.components, self.input_event_stream.as_mut());
+
+        for component in self.components.into_iter() {
+            component.run_end_component();
+        }
 
         self.end_system.end();
+
+        result.map(|_| ())
     }
 }
 
 pub trait BuildGameInstance<'a, TEngine> {
-    fn build_game_instance(self, event_loop: &EventLoop<()>) -> GameInstance<'a, TEngine>;
+    fn build_game_instance(self, event_loop: EventLoop<()>) -> GameInstance<'a, TEngine>;
+}
+
+pub trait SetCamera {
+    fn set_camera(&mut self, camera: Rc<RefCell<Camera>>);
 }
 
-pub trait AddComponent {
-    fn add<T: RunFullComponent + 'static>(&mut self, t: T);
+pub trait SetWindowSettings {
+    fn set_window_settings(&mut self, window_settings: WindowSettings);
 }
 
 pub struct GameInstanceBuilder<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator> {
     name: &'a str,
     full_components: Vec<Box<dyn RunFullComponent>>,
+    input_event_stream: Box<dyn RunInputEventStream>,
     engine_starter_creator: TEngineStarterCreator,
     loop_system_creator: TLoopSystemCreator,
     engine_ender_creator: TEngineEnderCreator,
@@ -121,6 +143,7 @@ impl<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator>
         Self {
             name,
             full_components,
+            input_event_stream: Box::new(LiveInputEventStream::new()),
             engine_starter_creator,
             loop_system_creator,
             engine_ender_creator,
@@ -138,6 +161,43 @@ impl<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator> AddComp
     }
 }
 
+impl<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator> SetInputEventStream
+    for GameInstanceBuilder<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator>
+{
+    fn set_input_event_stream<TInputEventStream: RunInputEventStream + 'static>(
+        &mut self,
+        input_event_stream: TInputEventStream,
+    ) {
+        self.input_event_stream = Box::new(input_event_stream);
+    }
+}
+
+impl<'a, TEngineStarterCreator, TLoopSystemCreator: SetCamera, TEngineEnderCreator> SetCamera
+    for GameInstanceBuilder<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator>
+{
+    fn set_camera(&mut self, camera: Rc<RefCell<Camera>>) {
+        self.loop_system_creator.set_camera(camera);
+    }
+}
+
+impl<'a, TEngineStarterCreator, TLoopSystemCreator: SetWindowSettings, TEngineEnderCreator>
+    SetWindowSettings
+    for GameInstanceBuilder<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator>
+{
+    fn set_window_settings(&mut self, window_settings: WindowSettings) {
+        self.loop_system_creator
+            .set_window_settings(window_settings);
+    }
+}
+
+impl<'a, TEngineStarterCreator, TLoopSystemCreator: SetTickRate, TEngineEnderCreator> SetTickRate
+    for GameInstanceBuilder<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator>
+{
+    fn set_tick_rate(&mut self, ticks_per_second: f64) {
+        self.loop_system_creator.set_tick_rate(ticks_per_second);
+    }
+}
+
 impl<
         'a,
         TEngineStarter: StartEngine,
@@ -159,7 +219,7 @@ impl<
 {
     fn build_game_instance(
         self,
-        event_loop: &EventLoop<()>,
+        event_loop: EventLoop<()>,
     ) -> GameInstance<
         'a,
         Engine<
@@ -183,6 +243,7 @@ impl<
             self.engine_ender_creator,
             event_loop,
             self.full_components,
+            self.input_event_stream,
         );
 
         GameInstance::<
@@ -210,7 +271,6 @@ pub fn create_game_instance_builder<
     engine_starter_creator: TEngineStarterCreator,
     loop_system_creator: TLoopSystemCreator,
     engine_ender_creator: TEngineEnderCreator,
-    event_loop: &EventLoop<()>,
 ) -> GameInstanceBuilder<'a, TEngineStarterCreator, TLoopSystemCreator, TEngineEnderCreator> {
     let game_instance_buillder = GameInstanceBuilder::<
         'a,
@@ -241,8 +301,9 @@ pub fn create_engine<
     engine_starter_creator: TEngineStarterCreator,
     loop_system_creator: TLoopSystemCreator,
     engine_ender_creator: TEngineEnderCreator,
-    event_loop: &EventLoop<()>,
+    event_loop: EventLoop<()>,
     components: Vec<Box<dyn RunFullComponent>>,
+    input_event_stream: Box<dyn RunInputEventStream>,
 ) -> Engine<StartSystem<TEngineStarter>, TLoopSystem, EndSystem<TEngineEnder>, GameNameProvider<'a>>
 {
     Engine::<
@@ -255,6 +316,273 @@ pub fn create_engine<
         loop_system_creator.create_loop_system(event_loop),
         create_end_system::<TEngineEnder, TEngineEnderCreator>(engine_ender_creator),
         create_game_name_provider(name),
-        components
+        components,
+        input_event_stream
     )
 }
+
+pub trait ConvertWindowEventToInputEvent {
+    fn convert_window_event_to_input_event(&self, window_event: &WindowEvent)
+        -> Option<InputEvent>;
+}
+
+pub struct WindowEventToInputEventConverter {}
+
+impl WindowEventToInputEventConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for WindowEventToInputEventConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConvertWindowEventToInputEvent for WindowEventToInputEventConverter {
+    fn convert_window_event_to_input_event(
+        &self,
+        window_event: &WindowEvent,
+    ) -> Option<InputEvent> {
+        match window_event {
+            WindowEvent::KeyboardInput { input, .. } => {
+                let key = convert_virtual_key_code_to_key(input.virtual_keycode?)?;
+
+                match input.state {
+                    ElementState::Pressed => Some(InputEvent::KeyPressed(key)),
+                    ElementState::Released => Some(InputEvent::KeyReleased(key)),
+                }
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let mouse_button = match button {
+                    winit::event::MouseButton::Left => MouseButton::Left,
+                    winit::event::MouseButton::Right => MouseButton::Right,
+                    winit::event::MouseButton::Middle => MouseButton::Middle,
+                    winit::event::MouseButton::Other(other) => MouseButton::Other(*other),
+                };
+
+                match state {
+                    ElementState::Pressed => Some(InputEvent::MouseButtonPressed(mouse_button)),
+                    ElementState::Released => Some(InputEvent::MouseButtonReleased(mouse_button)),
+                }
+            }
+            WindowEvent::CursorMoved { position, .. } => Some(InputEvent::MouseMoved {
+                x: position.x as f32,
+                y: position.y as f32,
+            }),
+            WindowEvent::MouseWheel { delta, .. } => match delta {
+                MouseScrollDelta::LineDelta(x, y) => {
+                    Some(InputEvent::MouseWheelScrolled { x: *x, y: *y })
+                }
+                MouseScrollDelta::PixelDelta(position) => Some(InputEvent::MouseWheelScrolled {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                }),
+            },
+            WindowEvent::Resized(size) => Some(InputEvent::WindowResized {
+                width: size.width as f32,
+                height: size.height as f32,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn convert_virtual_key_code_to_key(virtual_key_code: VirtualKeyCode) -> Option<Key> {
+    let key = match virtual_key_code {
+        VirtualKeyCode::A => Key::A,
+        VirtualKeyCode::B => Key::B,
+        VirtualKeyCode::C => Key::C,
+        VirtualKeyCode::D => Key::D,
+        VirtualKeyCode::E => Key::E,
+        VirtualKeyCode::F => Key::F,
+        VirtualKeyCode::G => Key::G,
+        VirtualKeyCode::H => Key::H,
+        VirtualKeyCode::I => Key::I,
+        VirtualKeyCode::J => Key::J,
+        VirtualKeyCode::K => Key::K,
+        VirtualKeyCode::L => Key::L,
+        VirtualKeyCode::M => Key::M,
+        VirtualKeyCode::N => Key::N,
+        VirtualKeyCode::O => Key::O,
+        VirtualKeyCode::P => Key::P,
+        VirtualKeyCode::Q => Key::Q,
+        VirtualKeyCode::R => Key::R,
+        VirtualKeyCode::S => Key::S,
+        VirtualKeyCode::T => Key::T,
+        VirtualKeyCode::U => Key::U,
+        VirtualKeyCode::V => Key::V,
+        VirtualKeyCode::W => Key::W,
+        VirtualKeyCode::X => Key::X,
+        VirtualKeyCode::Y => Key::Y,
+        VirtualKeyCode::Z => Key::Z,
+        VirtualKeyCode::Key0 => Key::Key0,
+        VirtualKeyCode::Key1 => Key::Key1,
+        VirtualKeyCode::Key2 => Key::Key2,
+        VirtualKeyCode::Key3 => Key::Key3,
+        VirtualKeyCode::Key4 => Key::Key4,
+        VirtualKeyCode::Key5 => Key::Key5,
+        VirtualKeyCode::Key6 => Key::Key6,
+        VirtualKeyCode::Key7 => Key::Key7,
+        VirtualKeyCode::Key8 => Key::Key8,
+        VirtualKeyCode::Key9 => Key::Key9,
+        VirtualKeyCode::F1 => Key::F1,
+        VirtualKeyCode::F2 => Key::F2,
+        VirtualKeyCode::F3 => Key::F3,
+        VirtualKeyCode::F4 => Key::F4,
+        VirtualKeyCode::F5 => Key::F5,
+        VirtualKeyCode::F6 => Key::F6,
+        VirtualKeyCode::F7 => Key::F7,
+        VirtualKeyCode::F8 => Key::F8,
+        VirtualKeyCode::F9 => Key::F9,
+        VirtualKeyCode::F10 => Key::F10,
+        VirtualKeyCode::F11 => Key::F11,
+        VirtualKeyCode::F12 => Key::F12,
+        VirtualKeyCode::Up => Key::Up,
+        VirtualKeyCode::Down => Key::Down,
+        VirtualKeyCode::Left => Key::Left,
+        VirtualKeyCode::Right => Key::Right,
+        VirtualKeyCode::Space => Key::Space,
+        VirtualKeyCode::Return => Key::Enter,
+        VirtualKeyCode::Escape => Key::Escape,
+        VirtualKeyCode::Tab => Key::Tab,
+        VirtualKeyCode::Back => Key::Backspace,
+        VirtualKeyCode::LShift => Key::LeftShift,
+        VirtualKeyCode::RShift => Key::RightShift,
+        VirtualKeyCode::LControl => Key::LeftControl,
+        VirtualKeyCode::RControl => Key::RightControl,
+        VirtualKeyCode::LAlt => Key::LeftAlt,
+        VirtualKeyCode::RAlt => Key::RightAlt,
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use garden::input::{InputEvent, Key, MouseButton};
+    use winit::{
+        dpi::{PhysicalPosition, PhysicalSize},
+        event::{
+            DeviceId, ElementState, KeyboardInput, ModifiersState, MouseScrollDelta, TouchPhase,
+            VirtualKeyCode, WindowEvent,
+        },
+    };
+
+    use crate::{ConvertWindowEventToInputEvent, WindowEventToInputEventConverter};
+
+    #[allow(deprecated)]
+    fn create_keyboard_input_event(
+        virtual_keycode: Option<VirtualKeyCode>,
+        state: ElementState,
+    ) -> WindowEvent<'static> {
+        WindowEvent::KeyboardInput {
+            device_id: unsafe { DeviceId::dummy() },
+            input: KeyboardInput {
+                scancode: 0,
+                state,
+                virtual_keycode,
+                modifiers: ModifiersState::empty(),
+            },
+            is_synthetic: false,
+        }
+    }
+
+    #[test]
+    fn when_a_key_press_is_converted_then_it_becomes_a_key_pressed_event() {
+        let converter = WindowEventToInputEventConverter::new();
+
+        let input_event = converter.convert_window_event_to_input_event(
+            &create_keyboard_input_event(Some(VirtualKeyCode::Return), ElementState::Pressed),
+        );
+
+        assert!(matches!(
+            input_event,
+            Some(InputEvent::KeyPressed(Key::Enter))
+        ));
+    }
+
+    #[test]
+    fn when_a_key_release_is_converted_then_it_becomes_a_key_released_event() {
+        let converter = WindowEventToInputEventConverter::new();
+
+        let input_event = converter.convert_window_event_to_input_event(
+            &create_keyboard_input_event(Some(VirtualKeyCode::W), ElementState::Released),
+        );
+
+        assert!(matches!(input_event, Some(InputEvent::KeyReleased(Key::W))));
+    }
+
+    #[test]
+    fn when_an_unmapped_key_is_converted_then_there_is_no_input_event() {
+        let converter = WindowEventToInputEventConverter::new();
+
+        assert!(converter
+            .convert_window_event_to_input_event(&create_keyboard_input_event(
+                Some(VirtualKeyCode::Numlock),
+                ElementState::Pressed
+            ))
+            .is_none());
+        assert!(converter
+            .convert_window_event_to_input_event(&create_keyboard_input_event(
+                None,
+                ElementState::Pressed
+            ))
+            .is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn when_mouse_events_are_converted_then_they_become_mouse_input_events() {
+        let converter = WindowEventToInputEventConverter::new();
+
+        let button_event =
+            converter.convert_window_event_to_input_event(&WindowEvent::MouseInput {
+                device_id: unsafe { DeviceId::dummy() },
+                state: ElementState::Pressed,
+                button: winit::event::MouseButton::Right,
+                modifiers: ModifiersState::empty(),
+            });
+        let moved_event =
+            converter.convert_window_event_to_input_event(&WindowEvent::CursorMoved {
+                device_id: unsafe { DeviceId::dummy() },
+                position: PhysicalPosition::new(12.0, 34.0),
+                modifiers: ModifiersState::empty(),
+            });
+        let wheel_event = converter.convert_window_event_to_input_event(&WindowEvent::MouseWheel {
+            device_id: unsafe { DeviceId::dummy() },
+            delta: MouseScrollDelta::LineDelta(0.0, -1.0),
+            phase: TouchPhase::Moved,
+            modifiers: ModifiersState::empty(),
+        });
+
+        assert!(matches!(
+            button_event,
+            Some(InputEvent::MouseButtonPressed(MouseButton::Right))
+        ));
+        assert!(matches!(
+            moved_event,
+            Some(InputEvent::MouseMoved { x, y }) if x == 12.0 && y == 34.0
+        ));
+        assert!(matches!(
+            wheel_event,
+            Some(InputEvent::MouseWheelScrolled { x, y }) if x == 0.0 && y == -1.0
+        ));
+    }
+
+    #[test]
+    fn when_a_resize_is_converted_then_it_becomes_a_window_resized_event() {
+        let converter = WindowEventToInputEventConverter::new();
+
+        let input_event = converter.convert_window_event_to_input_event(&WindowEvent::Resized(
+            PhysicalSize::new(640, 480),
+        ));
+
+        assert!(matches!(
+            input_event,
+            Some(InputEvent::WindowResized { width, height }) if width == 640.0 && height == 480.0
+        ));
+    }
+}